@@ -25,6 +25,9 @@ export const A320Failure = Object.freeze({
     OutflowValve: 21017,
     SafetyValve: 21018,
     RapidDecompression: 21019,
+    CabinDoorSealLeak: 21020,
+    CabinWindowCrack: 21021,
+    CargoDoorUnsealed: 21022,
 
     Fac1Failure: 22000,
     Fac2Failure: 22001,
@@ -50,6 +53,26 @@ export const A320Failure = Object.freeze({
     DirectCurrentHot1: 24111,
     DirectCurrentHot2: 24112,
     DirectCurrentGndFltService: 24113,
+    DirectCurrentEssential401PP: 24114,
+    DirectCurrentGndFltService601PP: 24115,
+
+    Engine1Fire: 26000,
+    Engine2Fire: 26001,
+    ApuFire: 26002,
+    Engine1FireLoopA: 26010,
+    Engine1FireLoopB: 26011,
+    Engine2FireLoopA: 26012,
+    Engine2FireLoopB: 26013,
+    ApuFireLoopA: 26014,
+    ApuFireLoopB: 26015,
+    FwdCargoSmoke: 26100,
+    AftCargoSmoke: 26101,
+    LavatorySmoke: 26102,
+    FwdCargoSmokeDetector1: 26110,
+    FwdCargoSmokeDetector2: 26111,
+    AftCargoSmokeDetector1: 26112,
+    AftCargoSmokeDetector2: 26113,
+    LavatorySmokeDetector: 26114,
 
     Elac1Failure: 27000,
     Elac2Failure: 27001,
@@ -58,6 +81,94 @@ export const A320Failure = Object.freeze({
     Sec3Failure: 27004,
     Fcdc1Failure: 27005,
     Fcdc2Failure: 27006,
+    FlapsLeftJam: 27100,
+    FlapsRightJam: 27101,
+    SlatsLeftJam: 27102,
+    SlatsRightJam: 27103,
+    LeftAileronBlueActuatorJam: 27200,
+    LeftAileronGreenActuatorJam: 27201,
+    RightAileronBlueActuatorJam: 27202,
+    RightAileronGreenActuatorJam: 27203,
+    LeftElevatorBlueActuatorJam: 27204,
+    LeftElevatorGreenActuatorJam: 27205,
+    RightElevatorBlueActuatorJam: 27206,
+    RightElevatorYellowActuatorJam: 27207,
+    RudderGreenActuatorJam: 27208,
+    RudderBlueActuatorJam: 27209,
+    RudderYellowActuatorJam: 27210,
+    LeftSpoiler1ActuatorJam: 27211,
+    LeftSpoiler2ActuatorJam: 27212,
+    LeftSpoiler3ActuatorJam: 27213,
+    LeftSpoiler4ActuatorJam: 27214,
+    LeftSpoiler5ActuatorJam: 27215,
+    RightSpoiler1ActuatorJam: 27216,
+    RightSpoiler2ActuatorJam: 27217,
+    RightSpoiler3ActuatorJam: 27218,
+    RightSpoiler4ActuatorJam: 27219,
+    RightSpoiler5ActuatorJam: 27220,
+    LeftAileronBlueActuatorRunaway: 27300,
+    LeftAileronGreenActuatorRunaway: 27301,
+    RightAileronBlueActuatorRunaway: 27302,
+    RightAileronGreenActuatorRunaway: 27303,
+    LeftElevatorBlueActuatorRunaway: 27304,
+    LeftElevatorGreenActuatorRunaway: 27305,
+    RightElevatorBlueActuatorRunaway: 27306,
+    RightElevatorYellowActuatorRunaway: 27307,
+    RudderGreenActuatorRunaway: 27308,
+    RudderBlueActuatorRunaway: 27309,
+    RudderYellowActuatorRunaway: 27310,
+    LeftSpoiler1ActuatorRunaway: 27311,
+    LeftSpoiler2ActuatorRunaway: 27312,
+    LeftSpoiler3ActuatorRunaway: 27313,
+    LeftSpoiler4ActuatorRunaway: 27314,
+    LeftSpoiler5ActuatorRunaway: 27315,
+    RightSpoiler1ActuatorRunaway: 27316,
+    RightSpoiler2ActuatorRunaway: 27317,
+    RightSpoiler3ActuatorRunaway: 27318,
+    RightSpoiler4ActuatorRunaway: 27319,
+    RightSpoiler5ActuatorRunaway: 27320,
+    LeftAileronBlueActuatorDisconnect: 27400,
+    LeftAileronGreenActuatorDisconnect: 27401,
+    RightAileronBlueActuatorDisconnect: 27402,
+    RightAileronGreenActuatorDisconnect: 27403,
+    LeftElevatorBlueActuatorDisconnect: 27404,
+    LeftElevatorGreenActuatorDisconnect: 27405,
+    RightElevatorBlueActuatorDisconnect: 27406,
+    RightElevatorYellowActuatorDisconnect: 27407,
+    RudderGreenActuatorDisconnect: 27408,
+    RudderBlueActuatorDisconnect: 27409,
+    RudderYellowActuatorDisconnect: 27410,
+    LeftSpoiler1ActuatorDisconnect: 27411,
+    LeftSpoiler2ActuatorDisconnect: 27412,
+    LeftSpoiler3ActuatorDisconnect: 27413,
+    LeftSpoiler4ActuatorDisconnect: 27414,
+    LeftSpoiler5ActuatorDisconnect: 27415,
+    RightSpoiler1ActuatorDisconnect: 27416,
+    RightSpoiler2ActuatorDisconnect: 27417,
+    RightSpoiler3ActuatorDisconnect: 27418,
+    RightSpoiler4ActuatorDisconnect: 27419,
+    RightSpoiler5ActuatorDisconnect: 27420,
+    LeftAileronBlueActuatorRodEndFracture: 27500,
+    LeftAileronGreenActuatorRodEndFracture: 27501,
+    RightAileronBlueActuatorRodEndFracture: 27502,
+    RightAileronGreenActuatorRodEndFracture: 27503,
+    LeftElevatorBlueActuatorRodEndFracture: 27504,
+    LeftElevatorGreenActuatorRodEndFracture: 27505,
+    RightElevatorBlueActuatorRodEndFracture: 27506,
+    RightElevatorYellowActuatorRodEndFracture: 27507,
+    RudderGreenActuatorRodEndFracture: 27508,
+    RudderBlueActuatorRodEndFracture: 27509,
+    RudderYellowActuatorRodEndFracture: 27510,
+    LeftSpoiler1ActuatorRodEndFracture: 27511,
+    LeftSpoiler2ActuatorRodEndFracture: 27512,
+    LeftSpoiler3ActuatorRodEndFracture: 27513,
+    LeftSpoiler4ActuatorRodEndFracture: 27514,
+    LeftSpoiler5ActuatorRodEndFracture: 27515,
+    RightSpoiler1ActuatorRodEndFracture: 27516,
+    RightSpoiler2ActuatorRodEndFracture: 27517,
+    RightSpoiler3ActuatorRodEndFracture: 27518,
+    RightSpoiler4ActuatorRodEndFracture: 27519,
+    RightSpoiler5ActuatorRodEndFracture: 27520,
 
     GreenReservoirLeak: 29000,
     BlueReservoirLeak: 29001,
@@ -72,6 +183,24 @@ export const A320Failure = Object.freeze({
     BlueEpumpOverheat: 29010,
     YellowEdpOverheat: 29011,
     YellowEpumpOverheat: 29012,
+    GreenFireShutoffValveStuck: 29013,
+    YellowFireShutoffValveStuck: 29014,
+    GreenPriorityValveStuck: 29015,
+    BluePriorityValveStuck: 29016,
+    YellowPriorityValveStuck: 29017,
+    GreenLeakMeasurementValveStuck: 29018,
+    BlueLeakMeasurementValveStuck: 29019,
+    YellowLeakMeasurementValveStuck: 29020,
+    GreenAccumulatorPrechargeLoss: 29021,
+    BlueAccumulatorPrechargeLoss: 29022,
+    YellowAccumulatorPrechargeLoss: 29023,
+    PtuFailure: 29024,
+    PtuJam: 29025,
+
+    Eng1AntiIceValveStuckClosed: 30000,
+    Eng2AntiIceValveStuckClosed: 30001,
+    Eng1AntiIcePressureSwitch: 30002,
+    Eng2AntiIcePressureSwitch: 30003,
 
     LeftPfdDisplay: 31000,
     RightPfdDisplay: 31001,
@@ -102,12 +231,52 @@ export const A320Failure = Object.freeze({
     GearActuatorJammedGearDoorLeft: 32024,
     GearActuatorJammedGearDoorRight: 32025,
 
+    GearUplockJammedGearNose: 32040,
+    GearUplockJammedGearLeft: 32041,
+    GearUplockJammedGearRight: 32042,
+    GearUplockJammedGearDoorNose: 32043,
+    GearUplockJammedGearDoorLeft: 32044,
+    GearUplockJammedGearDoorRight: 32045,
+
+    GearDoorJammedNose: 32050,
+    GearDoorJammedLeft: 32051,
+    GearDoorJammedRight: 32052,
+
+    GearSelectorValveStuck: 32060,
+
     GreenBrakeHydraulicLeak: 32100,
     YellowBrakeHydraulicLeak: 32101,
     YellowBrakeAccumulatorGasLeak: 32150,
 
     RadioAltimeter1: 34000,
     RadioAltimeter2: 34001,
+
+    Eng1PylonBleedLeak: 36000,
+    Eng2PylonBleedLeak: 36001,
+    LeftWingBleedLeak: 36002,
+    RightWingBleedLeak: 36003,
+    ApuBleedLeak: 36004,
+    Eng1PrvStuckOpen: 36010,
+    Eng2PrvStuckOpen: 36011,
+    Eng1PrvStuckClosed: 36012,
+    Eng2PrvStuckClosed: 36013,
+    Eng1HpValveStuckOpen: 36014,
+    Eng2HpValveStuckOpen: 36015,
+    CrossBleedValveStuck: 36016,
+    Eng1PrecoolerClogged: 36017,
+    Eng2PrecoolerClogged: 36018,
+
+    ApuEgtSensor: 49000,
+    ApuSpeedSensor: 49001,
+    ApuInletPressureSensor: 49002,
+    ApuFuelControlUnit: 49003,
+    ApuOilLowPressure: 49004,
+    ApuAirIntakeFlapStuck: 49005,
+
+    Reverser1LeftSleeveUnlocked: 78000,
+    Reverser1RightSleeveUnlocked: 78001,
+    Reverser2LeftSleeveUnlocked: 78002,
+    Reverser2RightSleeveUnlocked: 78003,
 });
 
 export const A320FailureDefinitions: FailureDefinition[] = [
@@ -131,6 +300,9 @@ export const A320FailureDefinitions: FailureDefinition[] = [
     [21, A320Failure.OutflowValve, 'Outflow Valve'],
     [21, A320Failure.SafetyValve, 'Safety Valve'],
     [21, A320Failure.RapidDecompression, 'Rapid Decompression'],
+    [21, A320Failure.CabinDoorSealLeak, 'Cabin Door Seal Leak'],
+    [21, A320Failure.CabinWindowCrack, 'Cabin Window Crack'],
+    [21, A320Failure.CargoDoorUnsealed, 'Cargo Door Not Sealed'],
 
     [22, A320Failure.Fac1Failure, 'FAC 1'],
     [22, A320Failure.Fac2Failure, 'FAC 2'],
@@ -156,6 +328,26 @@ export const A320FailureDefinitions: FailureDefinition[] = [
     [24, A320Failure.DirectCurrentHot1, 'DC HOT 1'],
     [24, A320Failure.DirectCurrentHot2, 'DC HOT 2'],
     [24, A320Failure.DirectCurrentGndFltService, 'DC GND FLT SRV'],
+    [24, A320Failure.DirectCurrentEssential401PP, 'DC ESS 401PP'],
+    [24, A320Failure.DirectCurrentGndFltService601PP, 'DC GND FLT SRV 601PP'],
+
+    [26, A320Failure.Engine1Fire, 'Engine 1 Fire'],
+    [26, A320Failure.Engine2Fire, 'Engine 2 Fire'],
+    [26, A320Failure.ApuFire, 'APU Fire'],
+    [26, A320Failure.Engine1FireLoopA, 'Engine 1 Fire Detection Loop A'],
+    [26, A320Failure.Engine1FireLoopB, 'Engine 1 Fire Detection Loop B'],
+    [26, A320Failure.Engine2FireLoopA, 'Engine 2 Fire Detection Loop A'],
+    [26, A320Failure.Engine2FireLoopB, 'Engine 2 Fire Detection Loop B'],
+    [26, A320Failure.ApuFireLoopA, 'APU Fire Detection Loop A'],
+    [26, A320Failure.ApuFireLoopB, 'APU Fire Detection Loop B'],
+    [26, A320Failure.FwdCargoSmoke, 'Forward Cargo Smoke'],
+    [26, A320Failure.AftCargoSmoke, 'Aft Cargo Smoke'],
+    [26, A320Failure.LavatorySmoke, 'Lavatory Smoke'],
+    [26, A320Failure.FwdCargoSmokeDetector1, 'Forward Cargo Smoke Detector 1'],
+    [26, A320Failure.FwdCargoSmokeDetector2, 'Forward Cargo Smoke Detector 2'],
+    [26, A320Failure.AftCargoSmokeDetector1, 'Aft Cargo Smoke Detector 1'],
+    [26, A320Failure.AftCargoSmokeDetector2, 'Aft Cargo Smoke Detector 2'],
+    [26, A320Failure.LavatorySmokeDetector, 'Lavatory Smoke Detector'],
 
     [27, A320Failure.Elac1Failure, 'ELAC 1'],
     [27, A320Failure.Elac2Failure, 'ELAC 2'],
@@ -164,6 +356,94 @@ export const A320FailureDefinitions: FailureDefinition[] = [
     [27, A320Failure.Sec3Failure, 'SEC 3'],
     [27, A320Failure.Fcdc1Failure, 'FCDC 1'],
     [27, A320Failure.Fcdc2Failure, 'FCDC 2'],
+    [27, A320Failure.FlapsLeftJam, 'Left flaps transmission jam'],
+    [27, A320Failure.FlapsRightJam, 'Right flaps transmission jam'],
+    [27, A320Failure.SlatsLeftJam, 'Left slats transmission jam'],
+    [27, A320Failure.SlatsRightJam, 'Right slats transmission jam'],
+    [27, A320Failure.LeftAileronBlueActuatorJam, 'Left aileron blue actuator jam'],
+    [27, A320Failure.LeftAileronGreenActuatorJam, 'Left aileron green actuator jam'],
+    [27, A320Failure.RightAileronBlueActuatorJam, 'Right aileron blue actuator jam'],
+    [27, A320Failure.RightAileronGreenActuatorJam, 'Right aileron green actuator jam'],
+    [27, A320Failure.LeftElevatorBlueActuatorJam, 'Left elevator blue actuator jam'],
+    [27, A320Failure.LeftElevatorGreenActuatorJam, 'Left elevator green actuator jam'],
+    [27, A320Failure.RightElevatorBlueActuatorJam, 'Right elevator blue actuator jam'],
+    [27, A320Failure.RightElevatorYellowActuatorJam, 'Right elevator yellow actuator jam'],
+    [27, A320Failure.RudderGreenActuatorJam, 'Rudder green actuator jam'],
+    [27, A320Failure.RudderBlueActuatorJam, 'Rudder blue actuator jam'],
+    [27, A320Failure.RudderYellowActuatorJam, 'Rudder yellow actuator jam'],
+    [27, A320Failure.LeftSpoiler1ActuatorJam, 'Left spoiler 1 actuator jam'],
+    [27, A320Failure.LeftSpoiler2ActuatorJam, 'Left spoiler 2 actuator jam'],
+    [27, A320Failure.LeftSpoiler3ActuatorJam, 'Left spoiler 3 actuator jam'],
+    [27, A320Failure.LeftSpoiler4ActuatorJam, 'Left spoiler 4 actuator jam'],
+    [27, A320Failure.LeftSpoiler5ActuatorJam, 'Left spoiler 5 actuator jam'],
+    [27, A320Failure.RightSpoiler1ActuatorJam, 'Right spoiler 1 actuator jam'],
+    [27, A320Failure.RightSpoiler2ActuatorJam, 'Right spoiler 2 actuator jam'],
+    [27, A320Failure.RightSpoiler3ActuatorJam, 'Right spoiler 3 actuator jam'],
+    [27, A320Failure.RightSpoiler4ActuatorJam, 'Right spoiler 4 actuator jam'],
+    [27, A320Failure.RightSpoiler5ActuatorJam, 'Right spoiler 5 actuator jam'],
+    [27, A320Failure.LeftAileronBlueActuatorRunaway, 'Left aileron blue actuator runaway'],
+    [27, A320Failure.LeftAileronGreenActuatorRunaway, 'Left aileron green actuator runaway'],
+    [27, A320Failure.RightAileronBlueActuatorRunaway, 'Right aileron blue actuator runaway'],
+    [27, A320Failure.RightAileronGreenActuatorRunaway, 'Right aileron green actuator runaway'],
+    [27, A320Failure.LeftElevatorBlueActuatorRunaway, 'Left elevator blue actuator runaway'],
+    [27, A320Failure.LeftElevatorGreenActuatorRunaway, 'Left elevator green actuator runaway'],
+    [27, A320Failure.RightElevatorBlueActuatorRunaway, 'Right elevator blue actuator runaway'],
+    [27, A320Failure.RightElevatorYellowActuatorRunaway, 'Right elevator yellow actuator runaway'],
+    [27, A320Failure.RudderGreenActuatorRunaway, 'Rudder green actuator runaway'],
+    [27, A320Failure.RudderBlueActuatorRunaway, 'Rudder blue actuator runaway'],
+    [27, A320Failure.RudderYellowActuatorRunaway, 'Rudder yellow actuator runaway'],
+    [27, A320Failure.LeftSpoiler1ActuatorRunaway, 'Left spoiler 1 actuator runaway'],
+    [27, A320Failure.LeftSpoiler2ActuatorRunaway, 'Left spoiler 2 actuator runaway'],
+    [27, A320Failure.LeftSpoiler3ActuatorRunaway, 'Left spoiler 3 actuator runaway'],
+    [27, A320Failure.LeftSpoiler4ActuatorRunaway, 'Left spoiler 4 actuator runaway'],
+    [27, A320Failure.LeftSpoiler5ActuatorRunaway, 'Left spoiler 5 actuator runaway'],
+    [27, A320Failure.RightSpoiler1ActuatorRunaway, 'Right spoiler 1 actuator runaway'],
+    [27, A320Failure.RightSpoiler2ActuatorRunaway, 'Right spoiler 2 actuator runaway'],
+    [27, A320Failure.RightSpoiler3ActuatorRunaway, 'Right spoiler 3 actuator runaway'],
+    [27, A320Failure.RightSpoiler4ActuatorRunaway, 'Right spoiler 4 actuator runaway'],
+    [27, A320Failure.RightSpoiler5ActuatorRunaway, 'Right spoiler 5 actuator runaway'],
+    [27, A320Failure.LeftAileronBlueActuatorDisconnect, 'Left aileron blue actuator servo disconnect'],
+    [27, A320Failure.LeftAileronGreenActuatorDisconnect, 'Left aileron green actuator servo disconnect'],
+    [27, A320Failure.RightAileronBlueActuatorDisconnect, 'Right aileron blue actuator servo disconnect'],
+    [27, A320Failure.RightAileronGreenActuatorDisconnect, 'Right aileron green actuator servo disconnect'],
+    [27, A320Failure.LeftElevatorBlueActuatorDisconnect, 'Left elevator blue actuator servo disconnect'],
+    [27, A320Failure.LeftElevatorGreenActuatorDisconnect, 'Left elevator green actuator servo disconnect'],
+    [27, A320Failure.RightElevatorBlueActuatorDisconnect, 'Right elevator blue actuator servo disconnect'],
+    [27, A320Failure.RightElevatorYellowActuatorDisconnect, 'Right elevator yellow actuator servo disconnect'],
+    [27, A320Failure.RudderGreenActuatorDisconnect, 'Rudder green actuator servo disconnect'],
+    [27, A320Failure.RudderBlueActuatorDisconnect, 'Rudder blue actuator servo disconnect'],
+    [27, A320Failure.RudderYellowActuatorDisconnect, 'Rudder yellow actuator servo disconnect'],
+    [27, A320Failure.LeftSpoiler1ActuatorDisconnect, 'Left spoiler 1 actuator servo disconnect'],
+    [27, A320Failure.LeftSpoiler2ActuatorDisconnect, 'Left spoiler 2 actuator servo disconnect'],
+    [27, A320Failure.LeftSpoiler3ActuatorDisconnect, 'Left spoiler 3 actuator servo disconnect'],
+    [27, A320Failure.LeftSpoiler4ActuatorDisconnect, 'Left spoiler 4 actuator servo disconnect'],
+    [27, A320Failure.LeftSpoiler5ActuatorDisconnect, 'Left spoiler 5 actuator servo disconnect'],
+    [27, A320Failure.RightSpoiler1ActuatorDisconnect, 'Right spoiler 1 actuator servo disconnect'],
+    [27, A320Failure.RightSpoiler2ActuatorDisconnect, 'Right spoiler 2 actuator servo disconnect'],
+    [27, A320Failure.RightSpoiler3ActuatorDisconnect, 'Right spoiler 3 actuator servo disconnect'],
+    [27, A320Failure.RightSpoiler4ActuatorDisconnect, 'Right spoiler 4 actuator servo disconnect'],
+    [27, A320Failure.RightSpoiler5ActuatorDisconnect, 'Right spoiler 5 actuator servo disconnect'],
+    [27, A320Failure.LeftAileronBlueActuatorRodEndFracture, 'Left aileron blue actuator rod end fracture'],
+    [27, A320Failure.LeftAileronGreenActuatorRodEndFracture, 'Left aileron green actuator rod end fracture'],
+    [27, A320Failure.RightAileronBlueActuatorRodEndFracture, 'Right aileron blue actuator rod end fracture'],
+    [27, A320Failure.RightAileronGreenActuatorRodEndFracture, 'Right aileron green actuator rod end fracture'],
+    [27, A320Failure.LeftElevatorBlueActuatorRodEndFracture, 'Left elevator blue actuator rod end fracture'],
+    [27, A320Failure.LeftElevatorGreenActuatorRodEndFracture, 'Left elevator green actuator rod end fracture'],
+    [27, A320Failure.RightElevatorBlueActuatorRodEndFracture, 'Right elevator blue actuator rod end fracture'],
+    [27, A320Failure.RightElevatorYellowActuatorRodEndFracture, 'Right elevator yellow actuator rod end fracture'],
+    [27, A320Failure.RudderGreenActuatorRodEndFracture, 'Rudder green actuator rod end fracture'],
+    [27, A320Failure.RudderBlueActuatorRodEndFracture, 'Rudder blue actuator rod end fracture'],
+    [27, A320Failure.RudderYellowActuatorRodEndFracture, 'Rudder yellow actuator rod end fracture'],
+    [27, A320Failure.LeftSpoiler1ActuatorRodEndFracture, 'Left spoiler 1 actuator rod end fracture'],
+    [27, A320Failure.LeftSpoiler2ActuatorRodEndFracture, 'Left spoiler 2 actuator rod end fracture'],
+    [27, A320Failure.LeftSpoiler3ActuatorRodEndFracture, 'Left spoiler 3 actuator rod end fracture'],
+    [27, A320Failure.LeftSpoiler4ActuatorRodEndFracture, 'Left spoiler 4 actuator rod end fracture'],
+    [27, A320Failure.LeftSpoiler5ActuatorRodEndFracture, 'Left spoiler 5 actuator rod end fracture'],
+    [27, A320Failure.RightSpoiler1ActuatorRodEndFracture, 'Right spoiler 1 actuator rod end fracture'],
+    [27, A320Failure.RightSpoiler2ActuatorRodEndFracture, 'Right spoiler 2 actuator rod end fracture'],
+    [27, A320Failure.RightSpoiler3ActuatorRodEndFracture, 'Right spoiler 3 actuator rod end fracture'],
+    [27, A320Failure.RightSpoiler4ActuatorRodEndFracture, 'Right spoiler 4 actuator rod end fracture'],
+    [27, A320Failure.RightSpoiler5ActuatorRodEndFracture, 'Right spoiler 5 actuator rod end fracture'],
 
     [29, A320Failure.GreenReservoirLeak, 'Green reservoir leak'],
     [29, A320Failure.BlueReservoirLeak, 'Blue reservoir leak'],
@@ -178,6 +458,24 @@ export const A320FailureDefinitions: FailureDefinition[] = [
     [29, A320Failure.BlueEpumpOverheat, 'Blue electric pump overheat'],
     [29, A320Failure.YellowEdpOverheat, 'Yellow engine pump overheat'],
     [29, A320Failure.YellowEpumpOverheat, 'Yellow electric pump overheat'],
+    [29, A320Failure.GreenFireShutoffValveStuck, 'Green fire shutoff valve stuck'],
+    [29, A320Failure.YellowFireShutoffValveStuck, 'Yellow fire shutoff valve stuck'],
+    [29, A320Failure.GreenPriorityValveStuck, 'Green priority valve stuck'],
+    [29, A320Failure.BluePriorityValveStuck, 'Blue priority valve stuck'],
+    [29, A320Failure.YellowPriorityValveStuck, 'Yellow priority valve stuck'],
+    [29, A320Failure.GreenLeakMeasurementValveStuck, 'Green leak measurement valve stuck'],
+    [29, A320Failure.BlueLeakMeasurementValveStuck, 'Blue leak measurement valve stuck'],
+    [29, A320Failure.YellowLeakMeasurementValveStuck, 'Yellow leak measurement valve stuck'],
+    [29, A320Failure.GreenAccumulatorPrechargeLoss, 'Green accumulator precharge loss'],
+    [29, A320Failure.BlueAccumulatorPrechargeLoss, 'Blue accumulator precharge loss'],
+    [29, A320Failure.YellowAccumulatorPrechargeLoss, 'Yellow accumulator precharge loss'],
+    [29, A320Failure.PtuFailure, 'PTU failure'],
+    [29, A320Failure.PtuJam, 'PTU jam'],
+
+    [30, A320Failure.Eng1AntiIceValveStuckClosed, 'Engine 1 anti-ice valve stuck closed'],
+    [30, A320Failure.Eng2AntiIceValveStuckClosed, 'Engine 2 anti-ice valve stuck closed'],
+    [30, A320Failure.Eng1AntiIcePressureSwitch, 'Engine 1 anti-ice pressure switch'],
+    [30, A320Failure.Eng2AntiIcePressureSwitch, 'Engine 2 anti-ice pressure switch'],
 
     [31, A320Failure.LeftPfdDisplay, 'Captain PFD display'],
     [31, A320Failure.RightPfdDisplay, 'F/O PFD display'],
@@ -206,6 +504,16 @@ export const A320FailureDefinitions: FailureDefinition[] = [
     [32, A320Failure.GearActuatorJammedGearDoorNose, 'Nose gear door jammed actuator'],
     [32, A320Failure.GearActuatorJammedGearDoorLeft, 'Main left gear door jammed actuator'],
     [32, A320Failure.GearActuatorJammedGearDoorRight, 'Main right gear door jammed actuator'],
+    [32, A320Failure.GearUplockJammedGearNose, 'Nose gear jammed uplock'],
+    [32, A320Failure.GearUplockJammedGearLeft, 'Main left gear jammed uplock'],
+    [32, A320Failure.GearUplockJammedGearRight, 'Main right gear jammed uplock'],
+    [32, A320Failure.GearUplockJammedGearDoorNose, 'Nose gear door jammed uplock'],
+    [32, A320Failure.GearUplockJammedGearDoorLeft, 'Main left gear door jammed uplock'],
+    [32, A320Failure.GearUplockJammedGearDoorRight, 'Main right gear door jammed uplock'],
+    [32, A320Failure.GearDoorJammedNose, 'Nose gear door jammed'],
+    [32, A320Failure.GearDoorJammedLeft, 'Main left gear door jammed'],
+    [32, A320Failure.GearDoorJammedRight, 'Main right gear door jammed'],
+    [32, A320Failure.GearSelectorValveStuck, 'Gear selector valve stuck'],
 
     [32, A320Failure.GreenBrakeHydraulicLeak, 'Green brakes circuit leak'],
     [32, A320Failure.YellowBrakeHydraulicLeak, 'Yellow brakes circuit leak'],
@@ -213,4 +521,31 @@ export const A320FailureDefinitions: FailureDefinition[] = [
 
     [34, A320Failure.RadioAltimeter1, 'RA 1'],
     [34, A320Failure.RadioAltimeter2, 'RA 2'],
+
+    [36, A320Failure.Eng1PylonBleedLeak, 'Engine 1 pylon bleed duct leak'],
+    [36, A320Failure.Eng2PylonBleedLeak, 'Engine 2 pylon bleed duct leak'],
+    [36, A320Failure.LeftWingBleedLeak, 'Left wing bleed duct leak'],
+    [36, A320Failure.RightWingBleedLeak, 'Right wing bleed duct leak'],
+    [36, A320Failure.ApuBleedLeak, 'APU bleed duct leak'],
+    [36, A320Failure.Eng1PrvStuckOpen, 'Engine 1 pressure regulating valve stuck open'],
+    [36, A320Failure.Eng2PrvStuckOpen, 'Engine 2 pressure regulating valve stuck open'],
+    [36, A320Failure.Eng1PrvStuckClosed, 'Engine 1 pressure regulating valve stuck closed'],
+    [36, A320Failure.Eng2PrvStuckClosed, 'Engine 2 pressure regulating valve stuck closed'],
+    [36, A320Failure.Eng1HpValveStuckOpen, 'Engine 1 HP valve stuck open'],
+    [36, A320Failure.Eng2HpValveStuckOpen, 'Engine 2 HP valve stuck open'],
+    [36, A320Failure.CrossBleedValveStuck, 'Crossbleed valve stuck'],
+    [36, A320Failure.Eng1PrecoolerClogged, 'Engine 1 precooler clogged'],
+    [36, A320Failure.Eng2PrecoolerClogged, 'Engine 2 precooler clogged'],
+
+    [49, A320Failure.ApuEgtSensor, 'APU EGT sensor'],
+    [49, A320Failure.ApuSpeedSensor, 'APU speed sensor'],
+    [49, A320Failure.ApuInletPressureSensor, 'APU inlet pressure sensor'],
+    [49, A320Failure.ApuFuelControlUnit, 'APU fuel control unit'],
+    [49, A320Failure.ApuOilLowPressure, 'APU oil low pressure'],
+    [49, A320Failure.ApuAirIntakeFlapStuck, 'APU air intake flap stuck'],
+
+    [78, A320Failure.Reverser1LeftSleeveUnlocked, 'Reverser 1 left sleeve unlocked'],
+    [78, A320Failure.Reverser1RightSleeveUnlocked, 'Reverser 1 right sleeve unlocked'],
+    [78, A320Failure.Reverser2LeftSleeveUnlocked, 'Reverser 2 left sleeve unlocked'],
+    [78, A320Failure.Reverser2RightSleeveUnlocked, 'Reverser 2 right sleeve unlocked'],
 ];
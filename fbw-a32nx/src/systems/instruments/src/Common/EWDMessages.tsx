@@ -244,6 +244,17 @@ const EWDMessages = {
     '260015007': '\x1b<7m BEFORE OPEN CRG DOORS:',
     '260015008': '\x1b<7m .BEFORE OPEN CRG DOORS:',
     '260015009': '\x1b<5m -PAX..........DISEMBARK',
+    '260016001': '\x1b<2m\x1b4mSMOKE\x1bm AFT CARGO SMOKE',
+    '260016002': '\x1b<5m -AFT ISOL VALVE.....OFF',
+    '260016003': '\x1b<5m -CAB FANS...........OFF',
+    '260016004': '\x1b<7m  .IF AFT CARG CLOSED:',
+    '260016005': '\x1b<5m -AGENT............DISCH',
+    '260016006': '\x1b<7m  .WHEN ON GROUND:',
+    '260016007': '\x1b<7m BEFORE OPEN CRG DOORS:',
+    '260016008': '\x1b<7m .BEFORE OPEN CRG DOORS:',
+    '260016009': '\x1b<5m -PAX..........DISEMBARK',
+    '260017001': '\x1b<4m\x1b4mSMOKE\x1bm LAVATORY SMOKE',
+    '260017002': '\x1b<5m -CAB CREW........ADVISE',
     '270005201': '\x1b<2m\x1b4mF/CTL\x1bm FLAP LVR NOT ZERO',
     '270008501': '\x1b<2m\x1b4mCONFIG\x1bm',
     '270008502': '\x1b<2mSLATS NOT IN T.O CONFIG',
@@ -398,6 +409,16 @@ const EWDMessages = {
     '340023502': '\x1b<2m -VFE...............215',
     '340024001': '\x1b<2m\x1b4mOVERSPEED\x1bm',
     '340024002': '\x1b<2m -VFE...............230',
+    '360001001': '\x1b<4m\x1b4mAIR\x1bm ENG 1 BLEED LEAK',
+    '360001002': '\x1b<5m -ENG 1 BLEED........OFF',
+    '360001003': '\x1b<5m -X BLEED...........SHUT',
+    '360001004': '\x1b<5m -WING ANTI ICE......OFF',
+    '360002001': '\x1b<4m\x1b4mAIR\x1bm ENG 2 BLEED LEAK',
+    '360002002': '\x1b<5m -ENG 2 BLEED........OFF',
+    '360002003': '\x1b<5m -X BLEED...........SHUT',
+    '360002004': '\x1b<5m -WING ANTI ICE......OFF',
+    '360003001': '\x1b<4m\x1b4mAIR\x1bm APU BLEED LEAK',
+    '360003002': '\x1b<5m -APU BLEED..........OFF',
     '770002701': '\x1b<2m\x1b4mENG\x1bm ALL ENGINES FAILURE',
     '770002702': '\x1b<5m -EMER ELEC PWR...MAN ON',
     '770002703': '\x1b<5m OPT RELIGHT SPD.280/.77',
@@ -415,6 +436,12 @@ const EWDMessages = {
     '770064701': '\x1b<4m\x1b4mENG\x1bm \x1b<4mTHR LEVERS NOT SET',
     '770064702': '\x1b<5m -THR LEVERS.....MCT/FLX',
     '770064703': '\x1b<5m -THR LEVERS.....TO/GA',
+    '780001001': '\x1b<4m\x1b4mENG\x1bm 1 REVERSE UNLOCKED',
+    '780001002': '\x1b<5m -THR LEVER 1.......IDLE',
+    '780001003': '\x1b<5m MAX SPEED.......300/.78',
+    '780002001': '\x1b<4m\x1b4mENG\x1bm 2 REVERSE UNLOCKED',
+    '780002002': '\x1b<5m -THR LEVER 2.......IDLE',
+    '780002003': '\x1b<5m MAX SPEED.......300/.78',
 };
 
 export default EWDMessages;
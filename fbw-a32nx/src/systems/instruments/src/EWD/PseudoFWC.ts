@@ -723,23 +723,29 @@ export class PseudoFWC {
 
     private readonly apuAgentPB = Subject.create(false);
 
-    private readonly apuFireTest = Subject.create(false);
+    private readonly apuFireDetected = Subject.create(false);
 
     private readonly cargoFireAgentDisch = Subject.create(false);
 
-    private readonly cargoFireTest = Subject.create(false);
+    private readonly fwdCargoSmokeDetected = Subject.create(false);
+
+    private readonly aftCargoFireAgentDisch = Subject.create(false);
+
+    private readonly aftCargoSmokeDetected = Subject.create(false);
+
+    private readonly lavatorySmokeDetected = Subject.create(false);
 
     private readonly eng1Agent1PB = Subject.create(false);
 
     private readonly eng1Agent2PB = Subject.create(false);
 
-    private readonly eng1FireTest = Subject.create(false);
+    private readonly eng1FireDetected = Subject.create(false);
 
     private readonly eng2Agent1PB = Subject.create(false);
 
     private readonly eng2Agent2PB = Subject.create(false);
 
-    private readonly eng2FireTest = Subject.create(false);
+    private readonly eng2FireDetected = Subject.create(false);
 
     private readonly fireButton1 = Subject.create(false);
 
@@ -805,6 +811,16 @@ export class PseudoFWC {
 
     private readonly wingAntiIce = Subject.create(false);
 
+    private readonly eng1BleedLeak = Subject.create(false);
+
+    private readonly eng2BleedLeak = Subject.create(false);
+
+    private readonly apuBleedLeak = Subject.create(false);
+
+    private readonly eng1ReverserUnlocked = Subject.create(false);
+
+    private readonly eng2ReverserUnlocked = Subject.create(false);
+
     private readonly voiceVhf3 = Subject.create(0);
 
     /* SETTINGS */
@@ -1303,6 +1319,12 @@ export class PseudoFWC {
         this.tcasFault.set(SimVar.GetSimVarValue('L:A32NX_TCAS_FAULT', 'bool'));
         this.tcasSensitivity.set(SimVar.GetSimVarValue('L:A32NX_TCAS_SENSITIVITY', 'Enum'));
         this.wingAntiIce.set(SimVar.GetSimVarValue('L:A32NX_PNEU_WING_ANTI_ICE_SYSTEM_SELECTED', 'bool'));
+
+        this.eng1BleedLeak.set(SimVar.GetSimVarValue('L:A32NX_PNEU_ENG_1_PYLON_LEAK', 'bool') || SimVar.GetSimVarValue('L:A32NX_PNEU_ENG_1_WING_LEAK', 'bool'));
+        this.eng2BleedLeak.set(SimVar.GetSimVarValue('L:A32NX_PNEU_ENG_2_PYLON_LEAK', 'bool') || SimVar.GetSimVarValue('L:A32NX_PNEU_ENG_2_WING_LEAK', 'bool'));
+        this.apuBleedLeak.set(SimVar.GetSimVarValue('L:A32NX_PNEU_APU_BLEED_LEAK', 'bool'));
+        this.eng1ReverserUnlocked.set(SimVar.GetSimVarValue('L:A32NX_REVERSER_1_UNLOCKED', 'bool'));
+        this.eng2ReverserUnlocked.set(SimVar.GetSimVarValue('L:A32NX_REVERSER_2_UNLOCKED', 'bool'));
         this.voiceVhf3.set(SimVar.GetSimVarValue('A:COM ACTIVE FREQUENCY:3', 'number'));
 
         /* FUEL */
@@ -1591,16 +1613,19 @@ export class PseudoFWC {
         this.fireButton1.set(SimVar.GetSimVarValue('L:A32NX_FIRE_BUTTON_ENG1', 'bool'));
         this.fireButton2.set(SimVar.GetSimVarValue('L:A32NX_FIRE_BUTTON_ENG2', 'bool'));
         this.fireButtonAPU.set(SimVar.GetSimVarValue('L:A32NX_FIRE_BUTTON_APU', 'bool'));
-        this.eng1FireTest.set(SimVar.GetSimVarValue('L:A32NX_FIRE_TEST_ENG1', 'bool'));
-        this.eng2FireTest.set(SimVar.GetSimVarValue('L:A32NX_FIRE_TEST_ENG2', 'bool'));
-        this.apuFireTest.set(SimVar.GetSimVarValue('L:A32NX_FIRE_TEST_APU', 'bool'));
+        this.eng1FireDetected.set(SimVar.GetSimVarValue('L:A32NX_FIRE_ENG1_DETECTED', 'bool'));
+        this.eng2FireDetected.set(SimVar.GetSimVarValue('L:A32NX_FIRE_ENG2_DETECTED', 'bool'));
+        this.apuFireDetected.set(SimVar.GetSimVarValue('L:A32NX_FIRE_APU_DETECTED', 'bool'));
         this.eng1Agent1PB.set(SimVar.GetSimVarValue('L:A32NX_FIRE_ENG1_AGENT1_Discharge', 'bool'));
         this.eng1Agent2PB.set(SimVar.GetSimVarValue('L:A32NX_FIRE_ENG1_AGENT2_Discharge', 'bool'));
         this.eng2Agent1PB.set(SimVar.GetSimVarValue('L:A32NX_FIRE_ENG2_AGENT1_Discharge', 'bool'));
         this.eng2Agent2PB.set(SimVar.GetSimVarValue('L:A32NX_FIRE_ENG2_AGENT2_Discharge', 'bool'));
         this.apuAgentPB.set(SimVar.GetSimVarValue('L:A32NX_FIRE_APU_AGENT1_Discharge', 'bool'));
-        this.cargoFireTest.set(SimVar.GetSimVarValue('L:A32NX_FIRE_TEST_CARGO', 'bool'));
+        this.fwdCargoSmokeDetected.set(SimVar.GetSimVarValue('L:A32NX_SMOKE_FWD_CARGO_DETECTED', 'bool'));
         this.cargoFireAgentDisch.set(SimVar.GetSimVarValue('L:A32NX_CARGOSMOKE_FWD_DISCHARGED', 'bool'));
+        this.aftCargoSmokeDetected.set(SimVar.GetSimVarValue('L:A32NX_SMOKE_AFT_CARGO_DETECTED', 'bool'));
+        this.lavatorySmokeDetected.set(SimVar.GetSimVarValue('L:A32NX_SMOKE_LAVATORY_DETECTED', 'bool'));
+        this.aftCargoFireAgentDisch.set(SimVar.GetSimVarValue('L:A32NX_CARGOSMOKE_AFT_DISCHARGED', 'bool'));
 
         this.agent1Eng1Discharge.set(this.agent1Eng1DischargeTimer.write(this.fireButton1.get(), deltaTime));
         this.agent2Eng1Discharge.set(this.agent2Eng1DischargeTimer.write(this.fireButton1.get() && this.eng1Agent1PB.get() && !this.aircraftOnGround.get(), deltaTime));
@@ -1688,11 +1713,11 @@ export class PseudoFWC {
         this.landAsapRed.set(!this.aircraftOnGround.get()
             && (
                 this.fireButton1.get()
-                || this.eng1FireTest.get()
+                || this.eng1FireDetected.get()
                 || this.fireButton2.get()
-                || this.eng2FireTest.get()
+                || this.eng2FireDetected.get()
                 || this.fireButtonAPU.get()
-                || this.apuFireTest.get()
+                || this.apuFireDetected.get()
                 || this.emergencyGeneratorOn.get()
                 || (this.engine1State.get() === 0 && this.engine2State.get() === 0)
                 || (this.greenLP.get() && this.yellowLP.get())
@@ -1701,7 +1726,7 @@ export class PseudoFWC {
             ));
 
         // fire always forces the master warning and SC aural on
-        this.fireActive.set([this.eng1FireTest.get(), this.eng2FireTest.get(), this.apuFireTest.get(), this.cargoFireTest.get()].some((e) => e));
+        this.fireActive.set([this.eng1FireDetected.get(), this.eng2FireDetected.get(), this.apuFireDetected.get(), this.fwdCargoSmokeDetected.get(), this.aftCargoSmokeDetected.get()].some((e) => e));
 
         const flightPhase = this.fwcFlightPhase.get();
         let tempMemoArrayLeft:string[] = [];
@@ -2063,7 +2088,7 @@ export class PseudoFWC {
         },
         2600010: { // ENG 1 FIRE
             flightPhaseInhib: [],
-            simVarIsActive: MappedSubject.create(([eng1FireTest, fireButton1]) => eng1FireTest || fireButton1, this.eng1FireTest, this.fireButton1),
+            simVarIsActive: MappedSubject.create(([eng1FireDetected, fireButton1]) => eng1FireDetected || fireButton1, this.eng1FireDetected, this.fireButton1),
             whichCodeToReturn: () => [
                 0,
                 this.throttle1Position.get() !== 0 && !this.aircraftOnGround.get() ? 1 : null,
@@ -2093,7 +2118,7 @@ export class PseudoFWC {
         },
         2600020: { // ENG 2 FIRE
             flightPhaseInhib: [],
-            simVarIsActive: MappedSubject.create(([eng2FireTest, fireButton2]) => eng2FireTest || fireButton2, this.eng2FireTest, this.fireButton2),
+            simVarIsActive: MappedSubject.create(([eng2FireDetected, fireButton2]) => eng2FireDetected || fireButton2, this.eng2FireDetected, this.fireButton2),
             whichCodeToReturn: () => [
                 0,
                 this.throttle2Position.get() !== 0 && !this.aircraftOnGround.get() ? 1 : null,
@@ -2123,7 +2148,7 @@ export class PseudoFWC {
         },
         2600030: { // APU FIRE
             flightPhaseInhib: [],
-            simVarIsActive: MappedSubject.create(([apuFireTest, fireButtonAPU]) => apuFireTest || fireButtonAPU, this.apuFireTest, this.fireButtonAPU),
+            simVarIsActive: MappedSubject.create(([apuFireDetected, fireButtonAPU]) => apuFireDetected || fireButtonAPU, this.apuFireDetected, this.fireButtonAPU),
             whichCodeToReturn: () => [
                 0,
                 !this.fireButtonAPU.get() ? 1 : null,
@@ -2722,7 +2747,7 @@ export class PseudoFWC {
         },
         2600150: { // SMOKE FWD CARGO SMOKE
             flightPhaseInhib: [4, 5, 7, 8],
-            simVarIsActive: this.cargoFireTest,
+            simVarIsActive: this.fwdCargoSmokeDetected,
             // TODO no separate slats indication
             whichCodeToReturn: () => [
                 0,
@@ -2740,6 +2765,78 @@ export class PseudoFWC {
             sysPage: -1,
             side: 'LEFT',
         },
+        2600160: { // SMOKE AFT CARGO SMOKE
+            flightPhaseInhib: [4, 5, 7, 8],
+            simVarIsActive: this.aftCargoSmokeDetected,
+            whichCodeToReturn: () => [
+                0,
+                SimVar.GetSimVarValue('L:A32NX_OVHD_VENT_CAB_FANS_PB_IS_ON', 'bool') === 1 ? 2 : null,
+                [1, 10].includes(this.fwcFlightPhase.get()) && !this.aftCargoFireAgentDisch.get() ? 3 : null,
+                !this.aftCargoFireAgentDisch.get() ? 4 : null,
+                !this.aircraftOnGround.get() ? 5 : null,
+                !this.aircraftOnGround.get() ? 6 : null,
+                this.aircraftOnGround.get() ? 7 : null,
+                this.aircraftOnGround.get() ? 8 : null,
+            ],
+            codesToReturn: ['260016001', '260016002', '260016003', '260016004', '260016005', '260016006', '260016007', '260016008', '260016009'],
+            memoInhibit: () => false,
+            failure: 3,
+            sysPage: -1,
+            side: 'LEFT',
+        },
+        2600170: { // SMOKE LAVATORY SMOKE
+            flightPhaseInhib: [4, 5, 7, 8],
+            simVarIsActive: this.lavatorySmokeDetected,
+            whichCodeToReturn: () => [0, 1],
+            codesToReturn: ['260017001', '260017002'],
+            memoInhibit: () => false,
+            failure: 2,
+            sysPage: -1,
+            side: 'LEFT',
+        },
+        3600010: { // AIR ENG 1 BLEED LEAK
+            flightPhaseInhib: [4, 5, 7, 8],
+            simVarIsActive: this.eng1BleedLeak,
+            whichCodeToReturn: () => [
+                0,
+                SimVar.GetSimVarValue('L:A32NX_OVHD_PNEU_ENG_1_BLEED_PB_IS_AUTO', 'bool') === 1 ? 1 : null,
+                !SimVar.GetSimVarValue('L:A32NX_PNEU_XBLEED_VALVE_FULLY_CLOSED', 'bool') ? 2 : null,
+                this.wingAntiIce.get() ? 3 : null,
+            ],
+            codesToReturn: ['360001001', '360001002', '360001003', '360001004'],
+            memoInhibit: () => false,
+            failure: 2,
+            sysPage: 1,
+            side: 'LEFT',
+        },
+        3600020: { // AIR ENG 2 BLEED LEAK
+            flightPhaseInhib: [4, 5, 7, 8],
+            simVarIsActive: this.eng2BleedLeak,
+            whichCodeToReturn: () => [
+                0,
+                SimVar.GetSimVarValue('L:A32NX_OVHD_PNEU_ENG_2_BLEED_PB_IS_AUTO', 'bool') === 1 ? 1 : null,
+                !SimVar.GetSimVarValue('L:A32NX_PNEU_XBLEED_VALVE_FULLY_CLOSED', 'bool') ? 2 : null,
+                this.wingAntiIce.get() ? 3 : null,
+            ],
+            codesToReturn: ['360002001', '360002002', '360002003', '360002004'],
+            memoInhibit: () => false,
+            failure: 2,
+            sysPage: 1,
+            side: 'LEFT',
+        },
+        3600030: { // AIR APU BLEED LEAK
+            flightPhaseInhib: [4, 5, 7, 8],
+            simVarIsActive: this.apuBleedLeak,
+            whichCodeToReturn: () => [
+                0,
+                SimVar.GetSimVarValue('L:A32NX_OVHD_APU_BLEED_PB_IS_ON', 'bool') === 1 ? 1 : null,
+            ],
+            codesToReturn: ['360003001', '360003002'],
+            memoInhibit: () => false,
+            failure: 2,
+            sysPage: 1,
+            side: 'LEFT',
+        },
         7700647: { // THR LEVERS NOT SET  (on ground)
             flightPhaseInhib: [1, 4, 5, 6, 7, 8, 10],
             simVarIsActive: MappedSubject.create(
@@ -2757,6 +2854,34 @@ export class PseudoFWC {
             sysPage: -1,
             side: 'LEFT',
         },
+        7800010: { // ENG 1 REVERSE UNLOCKED (in flight)
+            flightPhaseInhib: [1, 2, 3, 4, 8, 9, 10],
+            simVarIsActive: this.eng1ReverserUnlocked,
+            whichCodeToReturn: () => [
+                0,
+                this.throttle1Position.get() > 0 ? 1 : null,
+                2,
+            ],
+            codesToReturn: ['780001001', '780001002', '780001003'],
+            memoInhibit: () => false,
+            failure: 2,
+            sysPage: 0,
+            side: 'LEFT',
+        },
+        7800020: { // ENG 2 REVERSE UNLOCKED (in flight)
+            flightPhaseInhib: [1, 2, 3, 4, 8, 9, 10],
+            simVarIsActive: this.eng2ReverserUnlocked,
+            whichCodeToReturn: () => [
+                0,
+                this.throttle2Position.get() > 0 ? 1 : null,
+                2,
+            ],
+            codesToReturn: ['780002001', '780002002', '780002003'],
+            memoInhibit: () => false,
+            failure: 2,
+            sysPage: 0,
+            side: 'LEFT',
+        },
         3200060: { // NW ANTI SKID INACTIVE
             flightPhaseInhib: [4, 5],
             simVarIsActive: this.antiskidActive.map((v) => !v),
@@ -12,6 +12,11 @@ export const DoorPage = () => {
     const [catering] = useSimVar('INTERACTIVE POINT OPEN:3', 'percent', 1000);
     const [cargoLocked] = useSimVar('L:A32NX_FWD_DOOR_CARGO_LOCKED', 'bool', 1000);
     const [oxygen] = useSimVar('L:PUSH_OVHD_OXYGEN_CREW', 'bool', 1000);
+    const [crewOxygenPressure] = useSimVar('L:A32NX_OXYGEN_CREW_BOTTLE_PRESSURE', 'psi', 1000);
+    const [crewOxygenPressureLow] = useSimVar('L:A32NX_OXYGEN_CREW_BOTTLE_PRESSURE_IS_LOW', 'bool', 1000);
+    const [crewOxygenRegulatorLow] = useSimVar('L:A32NX_OXYGEN_CREW_REGUL_LO_PR', 'bool', 1000);
+
+    const displayedCrewOxygenPressure = Math.round(crewOxygenPressure / 10) * 10;
     const [slides] = useSimVar('L:A32NX_SLIDES_ARMED', 'bool', 1000);
 
     return (
@@ -70,7 +75,7 @@ export const DoorPage = () => {
 
                     <text
                         id="oxy"
-                        className={oxygen ? 'OxyWarn' : 'Oxygen'}
+                        className={oxygen || crewOxygenRegulatorLow ? 'OxyWarn' : 'Oxygen'}
                         x="490"
                         y="18"
                         textAnchor="middle"
@@ -79,9 +84,13 @@ export const DoorPage = () => {
                         CKPT OXY
                     </text>
 
-                    <text id="psi_val" className="Value" x="432" y="42" textAnchor="middle" alignmentBaseline="central">1700</text>
+                    <text id="psi_val" className={crewOxygenPressureLow ? 'OxyWarn' : 'Value'} x="432" y="42" textAnchor="middle" alignmentBaseline="central">
+                        {displayedCrewOxygenPressure}
+                    </text>
                     <text id="psi_unit" className="Unit" x="486" y="43" textAnchor="middle" alignmentBaseline="central">PSI</text>
-                    <text id="psi_val_right" className="Value" x="538" y="42" textAnchor="middle" alignmentBaseline="central">1700</text>
+                    <text id="psi_val_right" className={crewOxygenPressureLow ? 'OxyWarn' : 'Value'} x="538" y="42" textAnchor="middle" alignmentBaseline="central">
+                        {displayedCrewOxygenPressure}
+                    </text>
                 </g>
             </svg>
 
@@ -133,6 +133,12 @@ impl PackFlowControllers for A320AirConditioning {
     }
 }
 
+impl CabinAltitude for A320AirConditioning {
+    fn altitude(&self) -> Length {
+        self.a320_pressurization_system.altitude()
+    }
+}
+
 impl SimulationElement for A320AirConditioning {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.a320_cabin.accept(visitor);
@@ -17,6 +17,10 @@ use systems::{
 use uom::si::{f64::*, velocity::knot};
 
 pub(crate) const APU_START_MOTOR_BUS_TYPE: ElectricalBusType = ElectricalBusType::Sub("49-42-00");
+pub(crate) const DC_ESS_401PP_BUS_TYPE: ElectricalBusType =
+    ElectricalBusType::DirectCurrentNamed("401PP");
+pub(crate) const DC_GND_FLT_SERVICE_601PP_BUS_TYPE: ElectricalBusType =
+    ElectricalBusType::DirectCurrentNamed("601PP");
 
 pub(super) struct A320DirectCurrentElectrical {
     dc_bus_1: ElectricalBus,
@@ -25,6 +29,7 @@ pub(super) struct A320DirectCurrentElectrical {
     dc_bus_2_tie_contactor: Contactor,
     dc_bat_bus: ElectricalBus,
     dc_ess_bus: ElectricalBus,
+    dc_ess_401pp_bus: ElectricalBus,
     dc_bat_bus_to_dc_ess_bus_contactor: Contactor,
     dc_ess_shed_bus: ElectricalBus,
     dc_ess_shed_contactor: Contactor,
@@ -45,6 +50,7 @@ pub(super) struct A320DirectCurrentElectrical {
     apu_start_contactors: Contactor,
     apu_start_motor_bus: ElectricalBus,
     dc_gnd_flt_service_bus: ElectricalBus,
+    dc_gnd_flt_service_601pp_bus: ElectricalBus,
     tr_2_to_dc_gnd_flt_service_bus_contactor: Contactor,
     dc_bus_2_to_dc_gnd_flt_service_bus_contactor: Contactor,
 }
@@ -57,6 +63,7 @@ impl A320DirectCurrentElectrical {
             dc_bus_2_tie_contactor: Contactor::new(context, "1PC2"),
             dc_bat_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrentBattery),
             dc_ess_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrentEssential),
+            dc_ess_401pp_bus: ElectricalBus::new(context, DC_ESS_401PP_BUS_TYPE),
             dc_bat_bus_to_dc_ess_bus_contactor: Contactor::new(context, "4PC"),
             dc_ess_shed_bus: ElectricalBus::new(
                 context,
@@ -83,6 +90,10 @@ impl A320DirectCurrentElectrical {
                 context,
                 ElectricalBusType::DirectCurrentGndFltService,
             ),
+            dc_gnd_flt_service_601pp_bus: ElectricalBus::new(
+                context,
+                DC_GND_FLT_SERVICE_601PP_BUS_TYPE,
+            ),
             tr_2_to_dc_gnd_flt_service_bus_contactor: Contactor::new(context, "3PX"),
             dc_bus_2_to_dc_gnd_flt_service_bus_contactor: Contactor::new(context, "8PN"),
         }
@@ -143,6 +154,11 @@ impl A320DirectCurrentElectrical {
             &self.dc_bus_2_to_dc_gnd_flt_service_bus_contactor,
             &self.dc_gnd_flt_service_bus,
         );
+        // 601PP is a sub-bus protected by circuit breakers behind DC GND/FLT SERVICE bus
+        electricity.flow(
+            &self.dc_gnd_flt_service_bus,
+            &self.dc_gnd_flt_service_601pp_bus,
+        );
 
         electricity.flow(&self.dc_bus_1, &self.dc_bus_1_tie_contactor);
         electricity.flow(&self.dc_bus_2, &self.dc_bus_2_tie_contactor);
@@ -229,6 +245,8 @@ impl A320DirectCurrentElectrical {
         electricity.flow(&self.dc_bat_bus_to_dc_ess_bus_contactor, &self.dc_ess_bus);
         electricity.flow(&self.tr_ess_contactor, &self.dc_ess_bus);
         electricity.flow(&self.hot_bus_2_to_dc_ess_bus_contactor, &self.dc_ess_bus);
+        // 401PP is a sub-bus protected by circuit breakers behind DC ESS bus
+        electricity.flow(&self.dc_ess_bus, &self.dc_ess_401pp_bus);
 
         self.dc_ess_shed_contactor
             .close_when(self.hot_bus_2_to_dc_ess_bus_contactor.is_open());
@@ -335,6 +353,7 @@ impl SimulationElement for A320DirectCurrentElectrical {
         self.dc_bus_2.accept(visitor);
         self.dc_bat_bus.accept(visitor);
         self.dc_ess_bus.accept(visitor);
+        self.dc_ess_401pp_bus.accept(visitor);
         self.dc_ess_shed_bus.accept(visitor);
         self.hot_bus_1.accept(visitor);
         self.hot_bus_2.accept(visitor);
@@ -343,6 +362,7 @@ impl SimulationElement for A320DirectCurrentElectrical {
         self.apu_start_motor_bus.accept(visitor);
 
         self.dc_gnd_flt_service_bus.accept(visitor);
+        self.dc_gnd_flt_service_601pp_bus.accept(visitor);
         self.tr_2_to_dc_gnd_flt_service_bus_contactor
             .accept(visitor);
         self.dc_bus_2_to_dc_gnd_flt_service_bus_contactor
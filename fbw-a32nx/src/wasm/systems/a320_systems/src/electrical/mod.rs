@@ -7,7 +7,9 @@ use self::{
     direct_current::A320DirectCurrentElectrical,
     galley::{MainGalley, SecondaryGalley},
 };
-pub(super) use direct_current::APU_START_MOTOR_BUS_TYPE;
+pub(super) use direct_current::{
+    APU_START_MOTOR_BUS_TYPE, DC_ESS_401PP_BUS_TYPE, DC_GND_FLT_SERVICE_601PP_BUS_TYPE,
+};
 
 use uom::si::{angular_velocity::revolution_per_minute, f64::*};
 
@@ -46,8 +46,8 @@ use systems::{
             AngularPositioningController, RudderMechanicalControl, YawDamperActuatorController,
         },
         trimmable_horizontal_stabilizer::{
-            ManualPitchTrimController, PitchTrimActuatorController,
-            TrimmableHorizontalStabilizerAssembly,
+            ManualPitchTrimController, PitchTrimActuatorController, PitchTrimMotorPowerSupply,
+            PitchTrimMotorPowerSupplySelector, TrimmableHorizontalStabilizerAssembly,
         },
         Accumulator, ElectricPump, EngineDrivenPump, HeatingElement, HydraulicCircuit,
         HydraulicCircuitController, HydraulicPressureSensors, PowerTransferUnit,
@@ -64,7 +64,8 @@ use systems::{
         AirbusEngineDrivenPumpId, DelayedFalseLogicGate, DelayedPulseTrueLogicGate,
         DelayedTrueLogicGate, ElectricalBusType, ElectricalBuses, EmergencyElectricalRatPushButton,
         EmergencyElectricalState, EmergencyGeneratorControlUnit, EmergencyGeneratorPower,
-        EngineFirePushButtons, GearWheel, HydraulicColor, LandingGearHandle, LgciuInterface,
+        EngineFirePushButtons, FlapSlatTransmissionId, FlightControlActuatorId,
+        FlightControlSurfaceId, GearWheel, HydraulicColor, LandingGearHandle, LgciuInterface,
         LgciuWeightOnWheels, RamAirTurbineController, ReservoirAirPressure, ReverserPosition,
         SectionPressure, TrimmableHorizontalStabilizer,
     },
@@ -74,6 +75,8 @@ use systems::{
     },
 };
 
+use crate::electrical::{DC_ESS_401PP_BUS_TYPE, DC_GND_FLT_SERVICE_601PP_BUS_TYPE};
+
 mod flaps_computer;
 use flaps_computer::SlatFlapComplex;
 
@@ -178,11 +181,14 @@ impl A320HydraulicCircuitFactory {
             false,
             Pressure::new::<psi>(Self::HYDRAULIC_TARGET_PRESSURE_PSI),
             PriorityValve::new(
+                HydraulicColor::Green,
                 Pressure::new::<psi>(Self::PRIORITY_VALVE_PRESSURE_CUTOFF_PSI),
                 Pressure::new::<psi>(Self::PRIORITY_VALVE_PRESSURE_OPENED_PSI),
             ),
             Pressure::new::<psi>(Self::ACCUMULATOR_GAS_PRE_CHARGE_PSI),
             Volume::new::<gallon>(Self::ACCUMULATOR_MAX_VOLUME_GALLONS),
+            DC_ESS_401PP_BUS_TYPE,
+            DC_GND_FLT_SERVICE_601PP_BUS_TYPE,
         )
     }
 
@@ -204,11 +210,14 @@ impl A320HydraulicCircuitFactory {
             false,
             Pressure::new::<psi>(Self::HYDRAULIC_TARGET_PRESSURE_PSI),
             PriorityValve::new(
+                HydraulicColor::Blue,
                 Pressure::new::<psi>(Self::PRIORITY_VALVE_PRESSURE_CUTOFF_PSI),
                 Pressure::new::<psi>(Self::PRIORITY_VALVE_PRESSURE_OPENED_PSI),
             ),
             Pressure::new::<psi>(Self::ACCUMULATOR_GAS_PRE_CHARGE_PSI),
             Volume::new::<gallon>(Self::ACCUMULATOR_MAX_VOLUME_GALLONS),
+            DC_ESS_401PP_BUS_TYPE,
+            DC_GND_FLT_SERVICE_601PP_BUS_TYPE,
         )
     }
 
@@ -236,11 +245,14 @@ impl A320HydraulicCircuitFactory {
             false,
             Pressure::new::<psi>(Self::HYDRAULIC_TARGET_PRESSURE_PSI),
             PriorityValve::new(
+                HydraulicColor::Yellow,
                 Pressure::new::<psi>(Self::PRIORITY_VALVE_PRESSURE_CUTOFF_PSI),
                 Pressure::new::<psi>(Self::PRIORITY_VALVE_PRESSURE_OPENED_PSI),
             ),
             Pressure::new::<psi>(Self::ACCUMULATOR_GAS_PRE_CHARGE_PSI),
             Volume::new::<gallon>(Self::ACCUMULATOR_MAX_VOLUME_GALLONS),
+            DC_ESS_401PP_BUS_TYPE,
+            DC_GND_FLT_SERVICE_601PP_BUS_TYPE,
         )
     }
 }
@@ -425,21 +437,31 @@ impl A320AileronFactory {
     fn a320_aileron_assembly(
         context: &mut InitContext,
         init_drooped_down: bool,
+        id: ActuatorSide,
     ) -> HydraulicLinearActuatorAssembly<2> {
         let aileron_body = Self::a320_aileron_body(init_drooped_down);
 
         let aileron_actuator_outward = Self::a320_aileron_actuator(context, &aileron_body);
         let aileron_actuator_inward = Self::a320_aileron_actuator(context, &aileron_body);
 
-        HydraulicLinearActuatorAssembly::new(
+        let surface = match id {
+            ActuatorSide::Left => FlightControlSurfaceId::LeftAileron(1),
+            ActuatorSide::Right => FlightControlSurfaceId::RightAileron(1),
+        };
+
+        HydraulicLinearActuatorAssembly::new_with_failures(
             [aileron_actuator_outward, aileron_actuator_inward],
             aileron_body,
+            [
+                FlightControlActuatorId::new(surface, AileronActuatorPosition::Blue as usize + 1),
+                FlightControlActuatorId::new(surface, AileronActuatorPosition::Green as usize + 1),
+            ],
         )
     }
 
     fn new_aileron(context: &mut InitContext, id: ActuatorSide) -> AileronAssembly {
         let init_drooped_down = !context.is_in_flight();
-        let assembly = Self::a320_aileron_assembly(context, init_drooped_down);
+        let assembly = Self::a320_aileron_assembly(context, init_drooped_down, id);
         AileronAssembly::new(context, id, assembly, Self::new_a320_aileron_aero_model())
     }
 
@@ -534,12 +556,25 @@ impl A320SpoilerFactory {
     }
 
     /// Builds a spoiler assembly consisting of the spoiler physical rigid body and one hydraulic actuator
-    fn a320_spoiler_assembly(context: &mut InitContext) -> HydraulicLinearActuatorAssembly<1> {
+    fn a320_spoiler_assembly(
+        context: &mut InitContext,
+        id: ActuatorSide,
+        id_number: usize,
+    ) -> HydraulicLinearActuatorAssembly<1> {
         let spoiler_body = Self::a320_spoiler_body();
 
         let spoiler_actuator = Self::a320_spoiler_actuator(context, &spoiler_body);
 
-        HydraulicLinearActuatorAssembly::new([spoiler_actuator], spoiler_body)
+        let surface = match id {
+            ActuatorSide::Left => FlightControlSurfaceId::LeftSpoiler(id_number),
+            ActuatorSide::Right => FlightControlSurfaceId::RightSpoiler(id_number),
+        };
+
+        HydraulicLinearActuatorAssembly::new_with_failures(
+            [spoiler_actuator],
+            spoiler_body,
+            [FlightControlActuatorId::new(surface, 1)],
+        )
     }
 
     fn new_a320_spoiler_group(context: &mut InitContext, id: ActuatorSide) -> SpoilerGroup {
@@ -568,7 +603,7 @@ impl A320SpoilerFactory {
         id: ActuatorSide,
         id_number: usize,
     ) -> SpoilerElement {
-        let assembly = Self::a320_spoiler_assembly(context);
+        let assembly = Self::a320_spoiler_assembly(context, id, id_number);
         SpoilerElement::new(
             context,
             id,
@@ -676,21 +711,31 @@ impl A320ElevatorFactory {
     fn a320_elevator_assembly(
         context: &mut InitContext,
         init_drooped_down: bool,
+        id: ActuatorSide,
     ) -> HydraulicLinearActuatorAssembly<2> {
         let elevator_body = Self::a320_elevator_body(init_drooped_down);
 
         let elevator_actuator_outboard = Self::a320_elevator_actuator(context, &elevator_body);
         let elevator_actuator_inbord = Self::a320_elevator_actuator(context, &elevator_body);
 
-        HydraulicLinearActuatorAssembly::new(
+        let surface = match id {
+            ActuatorSide::Left => FlightControlSurfaceId::LeftElevator(1),
+            ActuatorSide::Right => FlightControlSurfaceId::RightElevator(1),
+        };
+
+        HydraulicLinearActuatorAssembly::new_with_failures(
             [elevator_actuator_outboard, elevator_actuator_inbord],
             elevator_body,
+            [
+                FlightControlActuatorId::new(surface, 1),
+                FlightControlActuatorId::new(surface, 2),
+            ],
         )
     }
 
     fn new_elevator(context: &mut InitContext, id: ActuatorSide) -> ElevatorAssembly {
         let init_drooped_down = !context.is_in_flight();
-        let assembly = Self::a320_elevator_assembly(context, init_drooped_down);
+        let assembly = Self::a320_elevator_assembly(context, init_drooped_down, id);
         ElevatorAssembly::new(context, id, assembly, Self::new_a320_elevator_aero_model())
     }
 
@@ -795,13 +840,27 @@ impl A320RudderFactory {
         let rudder_actuator_blue = Self::a320_rudder_actuator(context, &rudder_body);
         let rudder_actuator_yellow = Self::a320_rudder_actuator(context, &rudder_body);
 
-        HydraulicLinearActuatorAssembly::new(
+        HydraulicLinearActuatorAssembly::new_with_failures(
             [
                 rudder_actuator_green,
                 rudder_actuator_blue,
                 rudder_actuator_yellow,
             ],
             rudder_body,
+            [
+                FlightControlActuatorId::new(
+                    FlightControlSurfaceId::Rudder(1),
+                    RudderActuatorPosition::Green as usize + 1,
+                ),
+                FlightControlActuatorId::new(
+                    FlightControlSurfaceId::Rudder(1),
+                    RudderActuatorPosition::Blue as usize + 1,
+                ),
+                FlightControlActuatorId::new(
+                    FlightControlSurfaceId::Rudder(1),
+                    RudderActuatorPosition::Yellow as usize + 1,
+                ),
+            ],
         )
     }
 
@@ -1710,6 +1769,8 @@ impl A320Hydraulic {
             flap_system: FlapSlatAssembly::new(
                 context,
                 "FLAPS",
+                FlapSlatTransmissionId::FlapsLeft,
+                FlapSlatTransmissionId::FlapsRight,
                 Volume::new::<cubic_inch>(0.32),
                 AngularVelocity::new::<radian_per_second>(0.13),
                 Angle::new::<degree>(251.97),
@@ -1723,6 +1784,8 @@ impl A320Hydraulic {
             slat_system: FlapSlatAssembly::new(
                 context,
                 "SLATS",
+                FlapSlatTransmissionId::SlatsLeft,
+                FlapSlatTransmissionId::SlatsRight,
                 Volume::new::<cubic_inch>(0.32),
                 AngularVelocity::new::<radian_per_second>(0.13),
                 Angle::new::<degree>(334.16),
@@ -1791,6 +1854,7 @@ impl A320Hydraulic {
                 Angle::new::<degree>(360. * 8.19), // 1.87 rotations down 6.32 up,
                 AngularVelocity::new::<revolution_per_minute>(5000.),
                 Ratio::new::<ratio>(2035. / 6.13),
+                A320TrimInputController::MOTORS_POWER_BUSES,
                 Angle::new::<degree>(-4.),
                 Angle::new::<degree>(17.5),
             ),
@@ -2127,6 +2191,7 @@ impl A320Hydraulic {
             context,
             self.slats_flaps_complex.flap_demand(),
             self.slats_flaps_complex.flap_demand(),
+            self.slats_flaps_complex.flap_wingtip_brakes_engaged(),
             self.green_circuit.system_section(),
             self.yellow_circuit.system_section(),
         );
@@ -2135,6 +2200,7 @@ impl A320Hydraulic {
             context,
             self.slats_flaps_complex.slat_demand(),
             self.slats_flaps_complex.slat_demand(),
+            self.slats_flaps_complex.slat_wingtip_brakes_engaged(),
             self.blue_circuit.system_section(),
             self.green_circuit.system_section(),
         );
@@ -2160,6 +2226,17 @@ impl A320Hydraulic {
             self.blue_circuit.system_section(),
             self.yellow_circuit.system_section(),
         );
+
+        self.aileron_system_controller.update(
+            self.blue_circuit.system_section(),
+            self.green_circuit.system_section(),
+        );
+
+        self.elevator_system_controller.update(
+            self.blue_circuit.system_section(),
+            self.green_circuit.system_section(),
+            self.yellow_circuit.system_section(),
+        );
     }
 
     // For each hydraulic loop retrieves volumes from and to each actuator and pass it to the loops
@@ -4468,6 +4545,136 @@ impl HydraulicAssemblyController for AileronController {
 impl HydraulicLocking for AileronController {}
 impl ElectroHydrostaticPowered for AileronController {}
 
+/// Servo control mode of a primary flight control actuator
+/// - Active: actuator controls surface position
+/// - Damping: actuator follows surface movement, damping it
+/// - Centering: actuator hydraulically holds surface at neutral position
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ServoControlMode {
+    Active,
+    Damping,
+    Centering,
+}
+
+/// Tells if a flight control computer can command its servo controls: it must be
+/// operative, not faulty and its supply bus must be powered
+struct FlightControlComputerAvailability {
+    operative_id: VariableIdentifier,
+    fault_id: Option<VariableIdentifier>,
+
+    powered_by: ElectricalBusType,
+
+    is_operative: bool,
+    is_faulty: bool,
+    is_powered: bool,
+}
+impl FlightControlComputerAvailability {
+    fn new_elac(context: &mut InitContext, number: usize, powered_by: ElectricalBusType) -> Self {
+        Self {
+            operative_id: context.get_identifier(format!("ELAC_{}_DIGITAL_OP_VALIDATED", number)),
+            fault_id: None,
+
+            powered_by,
+
+            is_operative: false,
+            is_faulty: false,
+            is_powered: false,
+        }
+    }
+
+    fn new_sec(context: &mut InitContext, number: usize, powered_by: ElectricalBusType) -> Self {
+        Self {
+            operative_id: context.get_identifier(format!("SEC_{}_PUSHBUTTON_PRESSED", number)),
+            fault_id: Some(context.get_identifier(format!("SEC_{}_FAULT_LIGHT_ON", number))),
+
+            powered_by,
+
+            is_operative: false,
+            is_faulty: false,
+            is_powered: false,
+        }
+    }
+
+    fn is_available(&self) -> bool {
+        self.is_operative && !self.is_faulty && self.is_powered
+    }
+}
+impl SimulationElement for FlightControlComputerAvailability {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.is_operative = reader.read(&self.operative_id);
+
+        if let Some(fault_id) = &self.fault_id {
+            self.is_faulty = reader.read(fault_id);
+        }
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+}
+
+/// Tells if a servo control circuit has enough pressure to be used in active mode
+#[derive(Default)]
+struct ServoControlPressureMonitor {
+    is_pressure_available: bool,
+}
+impl ServoControlPressureMonitor {
+    const MIN_PRESSURE_LO_HYST_FOR_ACTIVE_CONTROL_PSI: f64 = 700.;
+    const MIN_PRESSURE_HI_HYST_FOR_ACTIVE_CONTROL_PSI: f64 = 1200.;
+
+    fn update(&mut self, pressure: &impl SectionPressure) {
+        let pressure_psi = pressure.pressure_downstream_leak_valve().get::<psi>();
+
+        if pressure_psi > Self::MIN_PRESSURE_HI_HYST_FOR_ACTIVE_CONTROL_PSI {
+            self.is_pressure_available = true;
+        } else if pressure_psi < Self::MIN_PRESSURE_LO_HYST_FOR_ACTIVE_CONTROL_PSI {
+            self.is_pressure_available = false;
+        }
+    }
+
+    fn is_pressure_available(&self) -> bool {
+        self.is_pressure_available
+    }
+}
+
+/// Computes the servo control modes of the two actuators of a surface.
+/// Computer requests are kept unless the actuator requested active has lost its pressure while the
+/// one requested in damping is still pressurised: active control is then reconfigured to that one.
+/// Without any computer able to command the surface, actuators revert to `no_computer_mode`.
+fn servo_control_modes_after_reconfiguration(
+    requested_active: [bool; 2],
+    pressure_available: [bool; 2],
+    is_any_computer_available: bool,
+    no_computer_mode: ServoControlMode,
+) -> [ServoControlMode; 2] {
+    if !is_any_computer_available {
+        return [no_computer_mode; 2];
+    }
+
+    let mut modes = requested_active.map(|is_active| {
+        if is_active {
+            ServoControlMode::Active
+        } else {
+            ServoControlMode::Damping
+        }
+    });
+
+    for actuator_index in 0..2 {
+        let other_index = 1 - actuator_index;
+
+        if requested_active[actuator_index]
+            && !pressure_available[actuator_index]
+            && !requested_active[other_index]
+            && pressure_available[other_index]
+        {
+            modes[actuator_index] = ServoControlMode::Damping;
+            modes[other_index] = ServoControlMode::Active;
+        }
+    }
+
+    modes
+}
+
 struct AileronSystemHydraulicController {
     left_aileron_blue_actuator_solenoid_id: VariableIdentifier,
     right_aileron_blue_actuator_solenoid_id: VariableIdentifier,
@@ -4479,10 +4686,24 @@ struct AileronSystemHydraulicController {
     left_aileron_green_actuator_position_demand_id: VariableIdentifier,
     right_aileron_green_actuator_position_demand_id: VariableIdentifier,
 
+    left_solenoids_energized: [bool; 2],
+    right_solenoids_energized: [bool; 2],
+
+    elacs: [FlightControlComputerAvailability; 2],
+
+    // Pressure monitors are in [Blue circuit, Green circuit] order
+    pressure_monitors: [ServoControlPressureMonitor; 2],
+
+    left_modes: [ServoControlMode; 2],
+    right_modes: [ServoControlMode; 2],
+
     left_aileron_controllers: [AileronController; 2],
     right_aileron_controllers: [AileronController; 2],
 }
 impl AileronSystemHydraulicController {
+    const ELAC_1_POWER_BUS: ElectricalBusType = ElectricalBusType::DirectCurrentEssential;
+    const ELAC_2_POWER_BUS: ElectricalBusType = ElectricalBusType::DirectCurrent(2);
+
     fn new(context: &mut InitContext) -> Self {
         Self {
             left_aileron_blue_actuator_solenoid_id: context
@@ -4503,12 +4724,63 @@ impl AileronSystemHydraulicController {
             right_aileron_green_actuator_position_demand_id: context
                 .get_identifier("RIGHT_AIL_GREEN_COMMANDED_POSITION".to_owned()),
 
+            left_solenoids_energized: [false; 2],
+            right_solenoids_energized: [false; 2],
+
+            elacs: [
+                FlightControlComputerAvailability::new_elac(context, 1, Self::ELAC_1_POWER_BUS),
+                FlightControlComputerAvailability::new_elac(context, 2, Self::ELAC_2_POWER_BUS),
+            ],
+
+            pressure_monitors: [
+                ServoControlPressureMonitor::default(),
+                ServoControlPressureMonitor::default(),
+            ],
+
+            left_modes: [ServoControlMode::Damping; 2],
+            right_modes: [ServoControlMode::Damping; 2],
+
             // Controllers are in outward->inward order, so for aileron [Blue circuit, Green circuit]
             left_aileron_controllers: [AileronController::new(), AileronController::new()],
             right_aileron_controllers: [AileronController::new(), AileronController::new()],
         }
     }
 
+    fn update(
+        &mut self,
+        blue_pressure: &impl SectionPressure,
+        green_pressure: &impl SectionPressure,
+    ) {
+        self.pressure_monitors[AileronActuatorPosition::Blue as usize].update(blue_pressure);
+        self.pressure_monitors[AileronActuatorPosition::Green as usize].update(green_pressure);
+
+        let pressure_available = [
+            self.pressure_monitors[AileronActuatorPosition::Blue as usize].is_pressure_available(),
+            self.pressure_monitors[AileronActuatorPosition::Green as usize].is_pressure_available(),
+        ];
+        let is_any_elac_available = self.elacs.iter().any(|elac| elac.is_available());
+
+        // Ailerons have no centering mode: they are left in damping without computer
+        self.left_modes = servo_control_modes_after_reconfiguration(
+            self.left_solenoids_energized,
+            pressure_available,
+            is_any_elac_available,
+            ServoControlMode::Damping,
+        );
+        self.right_modes = servo_control_modes_after_reconfiguration(
+            self.right_solenoids_energized,
+            pressure_available,
+            is_any_elac_available,
+            ServoControlMode::Damping,
+        );
+
+        Self::update_aileron_controllers_modes(&mut self.left_aileron_controllers, self.left_modes);
+        Self::update_aileron_controllers_modes(
+            &mut self.right_aileron_controllers,
+            self.right_modes,
+        );
+    }
+
     fn left_controllers(
         &self,
     ) -> &[impl HydraulicAssemblyController + HydraulicLocking + ElectroHydrostaticPowered] {
@@ -4521,6 +4793,16 @@ impl AileronSystemHydraulicController {
         &self.right_aileron_controllers[..]
     }
 
+    #[cfg(test)]
+    fn left_modes(&self) -> [ServoControlMode; 2] {
+        self.left_modes
+    }
+
+    #[cfg(test)]
+    fn right_modes(&self) -> [ServoControlMode; 2] {
+        self.right_modes
+    }
+
     fn update_aileron_controllers_positions(
         &mut self,
         left_position_requests: [Ratio; 2],
@@ -4543,53 +4825,28 @@ impl AileronSystemHydraulicController {
             );
     }
 
-    /// Will drive mode from solenoid state
-    /// -If energized actuator controls position
-    /// -If not energized actuator is slaved in damping
+    /// Will drive actuator mode from servo control mode
+    /// -If active actuator controls position
+    /// -If damping actuator is slaved in damping
     /// -We differentiate case of all actuators in damping mode where we set a more dampened
     /// mode to reach realistic slow droop speed.
-    fn update_aileron_controllers_solenoids(
-        &mut self,
-        left_solenoids_energized: [bool; 2],
-        right_solenoids_energized: [bool; 2],
+    fn update_aileron_controllers_modes(
+        controllers: &mut [AileronController; 2],
+        modes: [ServoControlMode; 2],
     ) {
-        if left_solenoids_energized.iter().any(|x| *x) {
-            self.left_aileron_controllers[AileronActuatorPosition::Blue as usize].set_mode(
-                Self::aileron_actuator_mode_from_solenoid(
-                    left_solenoids_energized[AileronActuatorPosition::Blue as usize],
-                ),
-            );
-            self.left_aileron_controllers[AileronActuatorPosition::Green as usize].set_mode(
-                Self::aileron_actuator_mode_from_solenoid(
-                    left_solenoids_energized[AileronActuatorPosition::Green as usize],
-                ),
-            );
-        } else {
-            for controller in &mut self.left_aileron_controllers {
-                controller.set_mode(LinearActuatorMode::ClosedCircuitDamping);
+        if modes.iter().any(|mode| *mode == ServoControlMode::Active) {
+            for (controller, mode) in controllers.iter_mut().zip(modes) {
+                controller.set_mode(Self::aileron_actuator_mode_from_servo_mode(mode));
             }
-        }
-
-        if right_solenoids_energized.iter().any(|x| *x) {
-            self.right_aileron_controllers[AileronActuatorPosition::Blue as usize].set_mode(
-                Self::aileron_actuator_mode_from_solenoid(
-                    right_solenoids_energized[AileronActuatorPosition::Blue as usize],
-                ),
-            );
-            self.right_aileron_controllers[AileronActuatorPosition::Green as usize].set_mode(
-                Self::aileron_actuator_mode_from_solenoid(
-                    right_solenoids_energized[AileronActuatorPosition::Green as usize],
-                ),
-            );
         } else {
-            for controller in &mut self.right_aileron_controllers {
+            for controller in controllers {
                 controller.set_mode(LinearActuatorMode::ClosedCircuitDamping);
             }
         }
     }
 
-    fn aileron_actuator_mode_from_solenoid(solenoid_energized: bool) -> LinearActuatorMode {
-        if solenoid_energized {
+    fn aileron_actuator_mode_from_servo_mode(mode: ServoControlMode) -> LinearActuatorMode {
+        if mode == ServoControlMode::Active {
             LinearActuatorMode::PositionControl
         } else {
             LinearActuatorMode::ActiveDamping
@@ -4601,6 +4858,12 @@ impl AileronSystemHydraulicController {
     }
 }
 impl SimulationElement for AileronSystemHydraulicController {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.elacs, visitor);
+
+        visitor.visit(self);
+    }
+
     fn read(&mut self, reader: &mut SimulatorReader) {
         // Note that we reverse left, as positions are just passed through msfs for now
         self.update_aileron_controllers_positions(
@@ -4622,16 +4885,14 @@ impl SimulationElement for AileronSystemHydraulicController {
             ],
         );
 
-        self.update_aileron_controllers_solenoids(
-            [
-                reader.read(&self.left_aileron_blue_actuator_solenoid_id),
-                reader.read(&self.left_aileron_green_actuator_solenoid_id),
-            ],
-            [
-                reader.read(&self.right_aileron_blue_actuator_solenoid_id),
-                reader.read(&self.right_aileron_green_actuator_solenoid_id),
-            ],
-        );
+        self.left_solenoids_energized = [
+            reader.read(&self.left_aileron_blue_actuator_solenoid_id),
+            reader.read(&self.left_aileron_green_actuator_solenoid_id),
+        ];
+        self.right_solenoids_energized = [
+            reader.read(&self.right_aileron_blue_actuator_solenoid_id),
+            reader.read(&self.right_aileron_green_actuator_solenoid_id),
+        ];
     }
 }
 
@@ -4646,10 +4907,28 @@ struct ElevatorSystemHydraulicController {
     left_elevator_green_actuator_position_demand_id: VariableIdentifier,
     right_elevator_yellow_actuator_position_demand_id: VariableIdentifier,
 
+    left_solenoids_energized: [bool; 2],
+    right_solenoids_energized: [bool; 2],
+
+    // Elevators can be commanded by both ELACs and SEC 1 and 2
+    computers: [FlightControlComputerAvailability; 4],
+
+    blue_pressure_monitor: ServoControlPressureMonitor,
+    green_pressure_monitor: ServoControlPressureMonitor,
+    yellow_pressure_monitor: ServoControlPressureMonitor,
+
+    left_modes: [ServoControlMode; 2],
+    right_modes: [ServoControlMode; 2],
+
     left_controllers: [AileronController; 2],
     right_controllers: [AileronController; 2],
 }
 impl ElevatorSystemHydraulicController {
+    const ELAC_1_POWER_BUS: ElectricalBusType = ElectricalBusType::DirectCurrentEssential;
+    const ELAC_2_POWER_BUS: ElectricalBusType = ElectricalBusType::DirectCurrent(2);
+    const SEC_1_POWER_BUS: ElectricalBusType = ElectricalBusType::DirectCurrentEssential;
+    const SEC_2_POWER_BUS: ElectricalBusType = ElectricalBusType::DirectCurrent(2);
+
     fn new(context: &mut InitContext) -> Self {
         Self {
             left_elevator_blue_actuator_solenoid_id: context
@@ -4670,12 +4949,69 @@ impl ElevatorSystemHydraulicController {
             right_elevator_yellow_actuator_position_demand_id: context
                 .get_identifier("RIGHT_ELEV_YELLOW_COMMANDED_POSITION".to_owned()),
 
+            left_solenoids_energized: [false; 2],
+            right_solenoids_energized: [false; 2],
+
+            computers: [
+                FlightControlComputerAvailability::new_elac(context, 1, Self::ELAC_1_POWER_BUS),
+                FlightControlComputerAvailability::new_elac(context, 2, Self::ELAC_2_POWER_BUS),
+                FlightControlComputerAvailability::new_sec(context, 1, Self::SEC_1_POWER_BUS),
+                FlightControlComputerAvailability::new_sec(context, 2, Self::SEC_2_POWER_BUS),
+            ],
+
+            blue_pressure_monitor: ServoControlPressureMonitor::default(),
+            green_pressure_monitor: ServoControlPressureMonitor::default(),
+            yellow_pressure_monitor: ServoControlPressureMonitor::default(),
+
+            left_modes: [ServoControlMode::Active; 2],
+            right_modes: [ServoControlMode::Active; 2],
+
             // Controllers are in outboard->inboard order
             left_controllers: [AileronController::new(), AileronController::new()],
             right_controllers: [AileronController::new(), AileronController::new()],
         }
     }
 
+    fn update(
+        &mut self,
+        blue_pressure: &impl SectionPressure,
+        green_pressure: &impl SectionPressure,
+        yellow_pressure: &impl SectionPressure,
+    ) {
+        self.blue_pressure_monitor.update(blue_pressure);
+        self.green_pressure_monitor.update(green_pressure);
+        self.yellow_pressure_monitor.update(yellow_pressure);
+
+        let is_any_computer_available = self
+            .computers
+            .iter()
+            .any(|computer| computer.is_available());
+
+        // Elevator has reverted logic: a de-energized solenoid requests active mode
+        // Without any computer, elevators are hydraulically held at neutral in centering mode
+        self.left_modes = servo_control_modes_after_reconfiguration(
+            self.left_solenoids_energized.map(|energized| !energized),
+            [
+                self.blue_pressure_monitor.is_pressure_available(),
+                self.green_pressure_monitor.is_pressure_available(),
+            ],
+            is_any_computer_available,
+            ServoControlMode::Centering,
+        );
+        self.right_modes = servo_control_modes_after_reconfiguration(
+            self.right_solenoids_energized.map(|energized| !energized),
+            [
+                self.blue_pressure_monitor.is_pressure_available(),
+                self.yellow_pressure_monitor.is_pressure_available(),
+            ],
+            is_any_computer_available,
+            ServoControlMode::Centering,
+        );
+
+        Self::update_elevator_controllers_modes(&mut self.left_controllers, self.left_modes);
+        Self::update_elevator_controllers_modes(&mut self.right_controllers, self.right_modes);
+    }
+
     fn left_controllers(
         &self,
     ) -> &[impl HydraulicAssemblyController + HydraulicLocking + ElectroHydrostaticPowered] {
@@ -4688,6 +5024,11 @@ impl ElevatorSystemHydraulicController {
         &self.right_controllers[..]
     }
 
+    #[cfg(test)]
+    fn left_modes(&self) -> [ServoControlMode; 2] {
+        self.left_modes
+    }
+
     fn update_elevator_controllers_positions(
         &mut self,
         left_position_requests: [Ratio; 2],
@@ -4709,52 +5050,31 @@ impl ElevatorSystemHydraulicController {
             );
     }
 
-    fn update_elevator_controllers_solenoids(
-        &mut self,
-        left_solenoids_energized: [bool; 2],
-        right_solenoids_energized: [bool; 2],
+    fn update_elevator_controllers_modes(
+        controllers: &mut [AileronController; 2],
+        modes: [ServoControlMode; 2],
     ) {
-        if left_solenoids_energized.iter().all(|x| *x) {
-            for controller in &mut self.left_controllers {
+        if modes.iter().all(|mode| *mode == ServoControlMode::Damping) {
+            for controller in controllers {
                 controller.set_mode(LinearActuatorMode::ClosedCircuitDamping);
             }
         } else {
-            self.left_controllers[LeftElevatorActuatorCircuit::Blue as usize].set_mode(
-                Self::elevator_actuator_mode_from_solenoid(
-                    left_solenoids_energized[LeftElevatorActuatorCircuit::Blue as usize],
-                ),
-            );
-            self.left_controllers[LeftElevatorActuatorCircuit::Green as usize].set_mode(
-                Self::elevator_actuator_mode_from_solenoid(
-                    left_solenoids_energized[LeftElevatorActuatorCircuit::Green as usize],
-                ),
-            );
-        }
-
-        if right_solenoids_energized.iter().all(|x| *x) {
-            for controller in &mut self.right_controllers {
-                controller.set_mode(LinearActuatorMode::ClosedCircuitDamping);
+            for (controller, mode) in controllers.iter_mut().zip(modes) {
+                match mode {
+                    ServoControlMode::Active => {
+                        controller.set_mode(LinearActuatorMode::PositionControl)
+                    }
+                    ServoControlMode::Damping => {
+                        controller.set_mode(LinearActuatorMode::ActiveDamping)
+                    }
+                    ServoControlMode::Centering => {
+                        controller.set_mode(LinearActuatorMode::PositionControl);
+                        controller.set_requested_position(
+                            Self::elevator_actuator_position_from_surface_angle(Angle::default()),
+                        );
+                    }
+                }
             }
-        } else {
-            self.right_controllers[RightElevatorActuatorCircuit::Blue as usize].set_mode(
-                Self::elevator_actuator_mode_from_solenoid(
-                    right_solenoids_energized[RightElevatorActuatorCircuit::Blue as usize],
-                ),
-            );
-            self.right_controllers[RightElevatorActuatorCircuit::Yellow as usize].set_mode(
-                Self::elevator_actuator_mode_from_solenoid(
-                    right_solenoids_energized[RightElevatorActuatorCircuit::Yellow as usize],
-                ),
-            );
-        }
-    }
-
-    fn elevator_actuator_mode_from_solenoid(solenoid_energized: bool) -> LinearActuatorMode {
-        // Elevator has reverted logic
-        if !solenoid_energized {
-            LinearActuatorMode::PositionControl
-        } else {
-            LinearActuatorMode::ActiveDamping
         }
     }
 
@@ -4767,6 +5087,12 @@ impl ElevatorSystemHydraulicController {
     }
 }
 impl SimulationElement for ElevatorSystemHydraulicController {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.computers, visitor);
+
+        visitor.visit(self);
+    }
+
     fn read(&mut self, reader: &mut SimulatorReader) {
         self.update_elevator_controllers_positions(
             [
@@ -4787,16 +5113,14 @@ impl SimulationElement for ElevatorSystemHydraulicController {
             ],
         );
 
-        self.update_elevator_controllers_solenoids(
-            [
-                reader.read(&self.left_elevator_blue_actuator_solenoid_id),
-                reader.read(&self.left_elevator_green_actuator_solenoid_id),
-            ],
-            [
-                reader.read(&self.right_elevator_blue_actuator_solenoid_id),
-                reader.read(&self.right_elevator_yellow_actuator_solenoid_id),
-            ],
-        );
+        self.left_solenoids_energized = [
+            reader.read(&self.left_elevator_blue_actuator_solenoid_id),
+            reader.read(&self.left_elevator_green_actuator_solenoid_id),
+        ];
+        self.right_solenoids_energized = [
+            reader.read(&self.right_elevator_blue_actuator_solenoid_id),
+            reader.read(&self.right_elevator_yellow_actuator_solenoid_id),
+        ];
     }
 }
 
@@ -5613,8 +5937,22 @@ struct A320TrimInputController {
 
     manual_control: bool,
     manual_control_speed: AngularVelocity,
+
+    motor_power_supplies: [PitchTrimMotorPowerSupplySelector; 3],
 }
 impl A320TrimInputController {
+    // Motor 1 is driven by ELAC 2, motor 2 by ELAC 1 or SEC 1, motor 3 by SEC 2.
+    // Each motor is supplied through the power supply of the computer driving it.
+    // Buses are given as (normal, standby), also used by the trim actuator motors
+    const MOTORS_POWER_BUSES: [(ElectricalBusType, Option<ElectricalBusType>); 3] = [
+        (
+            ElectricalBusType::DirectCurrent(2),
+            Some(ElectricalBusType::DirectCurrentHot(2)),
+        ),
+        (ElectricalBusType::DirectCurrentEssential, None),
+        (ElectricalBusType::DirectCurrent(2), None),
+    ];
+
     fn new(context: &mut InitContext) -> Self {
         Self {
             motor1_active_id: context.get_identifier("THS_1_ACTIVE_MODE_COMMANDED".to_owned()),
@@ -5634,6 +5972,10 @@ impl A320TrimInputController {
 
             manual_control: false,
             manual_control_speed: AngularVelocity::default(),
+
+            motor_power_supplies: Self::MOTORS_POWER_BUSES.map(|(normal_bus, standby_bus)| {
+                PitchTrimMotorPowerSupplySelector::new(normal_bus, standby_bus)
+            }),
         }
     }
 }
@@ -5651,6 +5993,14 @@ impl PitchTrimActuatorController for A320TrimInputController {
     fn energised_motor(&self) -> [bool; 3] {
         self.motor_active
     }
+
+    fn motor_power_supply(&self) -> [PitchTrimMotorPowerSupply; 3] {
+        [
+            self.motor_power_supplies[0].selected_supply(),
+            self.motor_power_supplies[1].selected_supply(),
+            self.motor_power_supplies[2].selected_supply(),
+        ]
+    }
 }
 impl ManualPitchTrimController for A320TrimInputController {
     fn is_manually_moved(&self) -> bool {
@@ -5662,6 +6012,12 @@ impl ManualPitchTrimController for A320TrimInputController {
     }
 }
 impl SimulationElement for A320TrimInputController {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.motor_power_supplies, visitor);
+
+        visitor.visit(self);
+    }
+
     fn read(&mut self, reader: &mut SimulatorReader) {
         self.motor_active[0] = reader.read(&self.motor1_active_id);
         self.motor_active[1] = reader.read(&self.motor2_active_id);
@@ -5830,12 +6186,18 @@ struct A320Reversers {
     reverser_1_deployed_id: VariableIdentifier,
     reverser_2_deployed_id: VariableIdentifier,
 
+    reverser_1_unlocked_id: VariableIdentifier,
+    reverser_2_unlocked_id: VariableIdentifier,
+
     reversers: [ReverserAssembly; 2],
 
     reversers_in_transition: [bool; 2],
     reversers_deployed: [bool; 2],
 }
 impl A320Reversers {
+    // Each reverser has a left and a right translating sleeve
+    const NUMBER_OF_TRANSLATING_SLEEVES: usize = 2;
+
     // TODO Check busses and power, placeholder only for now
     const REVERSER_1_SUPPLY_POWER_BUS: ElectricalBusType = ElectricalBusType::AlternatingCurrent(1);
     const REVERSER_2_SUPPLY_POWER_BUS: ElectricalBusType = ElectricalBusType::AlternatingCurrent(2);
@@ -5861,8 +6223,13 @@ impl A320Reversers {
             reverser_1_deployed_id: context.get_identifier("REVERSER_1_DEPLOYED".to_owned()),
             reverser_2_deployed_id: context.get_identifier("REVERSER_2_DEPLOYED".to_owned()),
 
+            reverser_1_unlocked_id: context.get_identifier("REVERSER_1_UNLOCKED".to_owned()),
+            reverser_2_unlocked_id: context.get_identifier("REVERSER_2_UNLOCKED".to_owned()),
+
             reversers: [
                 ReverserAssembly::new(
+                    1,
+                    Self::NUMBER_OF_TRANSLATING_SLEEVES,
                     Pressure::new::<psi>(
                         A320HydraulicCircuitFactory::HYDRAULIC_TARGET_PRESSURE_PSI,
                     ),
@@ -5877,6 +6244,8 @@ impl A320Reversers {
                     Self::REVERSER_1_SECONDARY_VALVES_SUPPLY_POWER_BUS,
                 ),
                 ReverserAssembly::new(
+                    2,
+                    Self::NUMBER_OF_TRANSLATING_SLEEVES,
                     Pressure::new::<psi>(
                         A320HydraulicCircuitFactory::HYDRAULIC_TARGET_PRESSURE_PSI,
                     ),
@@ -5971,6 +6340,15 @@ impl SimulationElement for A320Reversers {
 
         writer.write(&self.reverser_1_deployed_id, self.reversers_deployed[0]);
         writer.write(&self.reverser_2_deployed_id, self.reversers_deployed[1]);
+
+        writer.write(
+            &self.reverser_1_unlocked_id,
+            self.reversers[0].any_door_unlocked(),
+        );
+        writer.write(
+            &self.reverser_2_unlocked_id,
+            self.reversers[1].any_door_unlocked(),
+        );
     }
 }
 
@@ -5991,7 +6369,10 @@ mod tests {
                 cargo_doors::{DoorControlState, HydraulicDoorController},
                 electrical_generator::TestGenerator,
             },
-            landing_gear::{GearSystemState, LandingGear, LandingGearControlInterfaceUnitSet},
+            landing_gear::{
+                shock_strut::LandingGearCharacteristics, GearSystemState, LandingGear,
+                LandingGearControlInterfaceUnitSet,
+            },
             shared::{
                 EmergencyElectricalState, EmergencyGeneratorControlUnit, LgciuId, PotentialOrigin,
             },
@@ -6008,6 +6389,7 @@ mod tests {
             length::foot,
             mass_density::kilogram_per_cubic_meter,
             ratio::{percent, ratio},
+            thermodynamic_temperature::degree_celsius,
             volume::liter,
         };
 
@@ -6159,6 +6541,8 @@ mod tests {
             dc_1_bus: ElectricalBus,
             dc_2_bus: ElectricalBus,
             dc_ess_bus: ElectricalBus,
+            dc_ess_401pp_bus: ElectricalBus,
+            dc_ground_service_601pp_bus: ElectricalBus,
             dc_hot_1_bus: ElectricalBus,
             dc_hot_2_bus: ElectricalBus,
 
@@ -6170,6 +6554,7 @@ mod tests {
             is_dc_1_powered: bool,
             is_dc_2_powered: bool,
             is_dc_ess_powered: bool,
+            is_dc_ess_401pp_powered: bool,
             is_dc_hot_1_powered: bool,
             is_dc_hot_2_powered: bool,
         }
@@ -6186,7 +6571,7 @@ mod tests {
                         context,
                     ),
                     engine_fire_overhead: EngineFireOverheadPanel::new(context),
-                    landing_gear: LandingGear::new(context),
+                    landing_gear: LandingGear::new(context, LandingGearCharacteristics::a320()),
                     lgcius: LandingGearControlInterfaceUnitSet::new(
                         context,
                         ElectricalBusType::DirectCurrentEssential,
@@ -6215,6 +6600,11 @@ mod tests {
                         context,
                         ElectricalBusType::DirectCurrentEssential,
                     ),
+                    dc_ess_401pp_bus: ElectricalBus::new(context, DC_ESS_401PP_BUS_TYPE),
+                    dc_ground_service_601pp_bus: ElectricalBus::new(
+                        context,
+                        DC_GND_FLT_SERVICE_601PP_BUS_TYPE,
+                    ),
                     dc_hot_1_bus: ElectricalBus::new(
                         context,
                         ElectricalBusType::DirectCurrentHot(1),
@@ -6230,6 +6620,7 @@ mod tests {
                     is_dc_1_powered: true,
                     is_dc_2_powered: true,
                     is_dc_ess_powered: true,
+                    is_dc_ess_401pp_powered: true,
                     is_dc_hot_1_powered: true,
                     is_dc_hot_2_powered: true,
                 }
@@ -6322,6 +6713,18 @@ mod tests {
                     .should_open_leak_measurement_valve()
             }
 
+            fn left_aileron_servo_modes(&self) -> [ServoControlMode; 2] {
+                self.hydraulics.aileron_system_controller.left_modes()
+            }
+
+            fn right_aileron_servo_modes(&self) -> [ServoControlMode; 2] {
+                self.hydraulics.aileron_system_controller.right_modes()
+            }
+
+            fn left_elevator_servo_modes(&self) -> [ServoControlMode; 2] {
+                self.hydraulics.elevator_system_controller.left_modes()
+            }
+
             fn is_blue_leak_meas_valve_commanded_open(&self) -> bool {
                 self.hydraulics
                     .blue_circuit_controller
@@ -6365,10 +6768,18 @@ mod tests {
                 self.is_dc_2_powered = bus_is_alive;
             }
 
+            fn set_dc_hot_bus_2_is_powered(&mut self, bus_is_alive: bool) {
+                self.is_dc_hot_2_powered = bus_is_alive;
+            }
+
             fn set_dc_ess_is_powered(&mut self, bus_is_alive: bool) {
                 self.is_dc_ess_powered = bus_is_alive;
             }
 
+            fn set_dc_ess_401pp_is_powered(&mut self, bus_is_alive: bool) {
+                self.is_dc_ess_401pp_powered = bus_is_alive;
+            }
+
             fn use_worst_case_ptu(&mut self) {
                 self.hydraulics.power_transfer_unit.update_characteristics(
                     &A320PowerTransferUnitCharacteristics::new_worst_part_acceptable(),
@@ -6400,6 +6811,10 @@ mod tests {
 
                 if self.is_dc_ground_service_powered {
                     electricity.flow(&self.powered_source_ac, &self.dc_ground_service_bus);
+                    electricity.flow(
+                        &self.dc_ground_service_bus,
+                        &self.dc_ground_service_601pp_bus,
+                    );
                 }
 
                 if self.is_dc_1_powered {
@@ -6412,6 +6827,10 @@ mod tests {
 
                 if self.is_dc_ess_powered {
                     electricity.flow(&self.powered_source_ac, &self.dc_ess_bus);
+
+                    if self.is_dc_ess_401pp_powered {
+                        electricity.flow(&self.dc_ess_bus, &self.dc_ess_401pp_bus);
+                    }
                 }
 
                 if self.is_dc_hot_1_powered {
@@ -6715,6 +7134,13 @@ mod tests {
                 self.query(|a| a.is_emergency_gen_at_nominal_speed())
             }
 
+            fn ths_deflection(&mut self) -> Angle {
+                ReadByName::<A320HydraulicsTestBed, Angle>::read_by_name(
+                    self,
+                    "HYD_FINAL_THS_DEFLECTION",
+                )
+            }
+
             fn is_fire_valve_eng1_closed(&mut self) -> bool {
                 !ReadByName::<A320HydraulicsTestBed, bool>::read_by_name(
                     self,
@@ -6741,6 +7167,18 @@ mod tests {
                 self.query(|a| a.is_yellow_leak_meas_valve_commanded_open())
             }
 
+            fn left_aileron_servo_modes(&mut self) -> [ServoControlMode; 2] {
+                self.query(|a| a.left_aileron_servo_modes())
+            }
+
+            fn right_aileron_servo_modes(&mut self) -> [ServoControlMode; 2] {
+                self.query(|a| a.right_aileron_servo_modes())
+            }
+
+            fn left_elevator_servo_modes(&mut self) -> [ServoControlMode; 2] {
+                self.query(|a| a.left_elevator_servo_modes())
+            }
+
             fn is_green_leak_meas_valve_commanded_open(&mut self) -> bool {
                 self.query(|a| a.is_green_leak_meas_valve_commanded_open())
             }
@@ -6856,6 +7294,15 @@ mod tests {
                 self
             }
 
+            fn set_ths_motor_command(mut self, motor_number: usize, position: Angle) -> Self {
+                self.write_by_name(&format!("THS_{}_ACTIVE_MODE_COMMANDED", motor_number), true);
+                self.write_by_name(
+                    &format!("THS_{}_COMMANDED_POSITION", motor_number),
+                    position.get::<degree>(),
+                );
+                self
+            }
+
             fn set_eng1_fire_button(mut self, is_active: bool) -> Self {
                 self.write_by_name("FIRE_BUTTON_ENG1", is_active);
                 self
@@ -7118,6 +7565,11 @@ mod tests {
                 self
             }
 
+            fn dc_hot_bus_2_lost(mut self) -> Self {
+                self.command(|a| a.set_dc_hot_bus_2_is_powered(false));
+                self
+            }
+
             fn dc_bus_2_lost(mut self) -> Self {
                 self.command(|a| a.set_dc_bus_2_is_powered(false));
                 self
@@ -7128,6 +7580,11 @@ mod tests {
                 self
             }
 
+            fn dc_ess_401pp_lost(mut self) -> Self {
+                self.command(|a| a.set_dc_ess_401pp_is_powered(false));
+                self
+            }
+
             fn dc_ess_active(mut self) -> Self {
                 self.command(|a| a.set_dc_ess_is_powered(true));
                 self
@@ -7148,6 +7605,7 @@ mod tests {
                     .set_gear_lever_down()
                     .set_pushback_state(false)
                     .air_press_nominal()
+                    .set_flight_control_computers_available()
                     .set_elac1_actuators_energized()
                     .set_ailerons_neutral()
                     .set_elevator_neutral()
@@ -7256,6 +7714,24 @@ mod tests {
                 self
             }
 
+            fn set_flight_control_computers_available(mut self) -> Self {
+                self.write_by_name("ELAC_1_DIGITAL_OP_VALIDATED", true);
+                self.write_by_name("ELAC_2_DIGITAL_OP_VALIDATED", true);
+                self.write_by_name("SEC_1_PUSHBUTTON_PRESSED", true);
+                self.write_by_name("SEC_2_PUSHBUTTON_PRESSED", true);
+                self.write_by_name("SEC_1_FAULT_LIGHT_ON", false);
+                self.write_by_name("SEC_2_FAULT_LIGHT_ON", false);
+                self
+            }
+
+            fn set_flight_control_computers_failed(mut self) -> Self {
+                self.write_by_name("ELAC_1_DIGITAL_OP_VALIDATED", false);
+                self.write_by_name("ELAC_2_DIGITAL_OP_VALIDATED", false);
+                self.write_by_name("SEC_1_FAULT_LIGHT_ON", true);
+                self.write_by_name("SEC_2_FAULT_LIGHT_ON", true);
+                self
+            }
+
             fn set_elac_actuators_de_energized(mut self) -> Self {
                 self.write_by_name("LEFT_AIL_BLUE_SERVO_SOLENOID_ENERGIZED", 0.);
                 self.write_by_name("RIGHT_AIL_BLUE_SERVO_SOLENOID_ENERGIZED", 0.);
@@ -8735,6 +9211,100 @@ mod tests {
             assert!(test_bed.yellow_pressure() < Pressure::new::<psi>(500.));
         }
 
+        #[test]
+        fn green_firevalve_stays_open_without_401pp_sub_bus() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .run_one_tick();
+
+            test_bed = test_bed
+                .set_ptu_state(false)
+                .start_eng1(Ratio::new::<percent>(80.))
+                .run_waiting_for(Duration::from_secs(5));
+
+            assert!(test_bed.is_green_pressure_switch_pressurised());
+
+            test_bed = test_bed
+                .dc_ess_401pp_lost()
+                .set_eng1_fire_button(true)
+                .run_waiting_for(Duration::from_secs(20));
+
+            assert!(!test_bed.is_fire_valve_eng1_closed());
+        }
+
+        fn test_bed_with_green_and_yellow_pressurised() -> A320HydraulicsTestBed {
+            test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .run_one_tick()
+                .start_eng1(Ratio::new::<percent>(80.))
+                .start_eng2(Ratio::new::<percent>(80.))
+                .run_waiting_for(Duration::from_secs(5))
+        }
+
+        #[test]
+        fn ths_motor_1_trims_on_dc_2() {
+            let mut test_bed = test_bed_with_green_and_yellow_pressurised()
+                .set_ths_motor_command(1, Angle::new::<degree>(5.))
+                .run_waiting_for(Duration::from_secs(20));
+
+            assert!(test_bed.ths_deflection().get::<degree>() > 4.9);
+        }
+
+        #[test]
+        fn ths_motor_1_trims_on_dc_hot_2_when_dc_2_lost() {
+            let mut test_bed = test_bed_with_green_and_yellow_pressurised()
+                .dc_bus_2_lost()
+                .set_ths_motor_command(1, Angle::new::<degree>(5.))
+                .run_waiting_for(Duration::from_secs(20));
+
+            assert!(test_bed.ths_deflection().get::<degree>() > 4.9);
+        }
+
+        #[test]
+        fn ths_motor_1_is_stuck_when_dc_2_and_dc_hot_2_lost() {
+            let mut test_bed = test_bed_with_green_and_yellow_pressurised()
+                .dc_bus_2_lost()
+                .dc_hot_bus_2_lost()
+                .set_ths_motor_command(1, Angle::new::<degree>(5.))
+                .run_waiting_for(Duration::from_secs(20));
+
+            assert!(test_bed.ths_deflection().get::<degree>().abs() < 0.1);
+        }
+
+        #[test]
+        fn ths_motor_1_trims_on_dc_2_when_dc_hot_2_lost() {
+            let mut test_bed = test_bed_with_green_and_yellow_pressurised()
+                .dc_hot_bus_2_lost()
+                .set_ths_motor_command(1, Angle::new::<degree>(5.))
+                .run_waiting_for(Duration::from_secs(20));
+
+            assert!(test_bed.ths_deflection().get::<degree>() > 4.9);
+        }
+
+        #[test]
+        fn ths_motor_2_is_stuck_when_dc_ess_lost() {
+            let mut test_bed = test_bed_with_green_and_yellow_pressurised()
+                .dc_ess_lost()
+                .set_ths_motor_command(2, Angle::new::<degree>(5.))
+                .run_waiting_for(Duration::from_secs(20));
+
+            assert!(test_bed.ths_deflection().get::<degree>().abs() < 0.1);
+        }
+
+        #[test]
+        fn ths_motor_3_is_stuck_when_dc_2_lost() {
+            let mut test_bed = test_bed_with_green_and_yellow_pressurised()
+                .dc_bus_2_lost()
+                .set_ths_motor_command(3, Angle::new::<degree>(5.))
+                .run_waiting_for(Duration::from_secs(20));
+
+            assert!(test_bed.ths_deflection().get::<degree>().abs() < 0.1);
+        }
+
         #[test]
         fn yellow_brake_accumulator() {
             let mut test_bed = test_bed_on_ground_with()
@@ -10887,6 +11457,116 @@ mod tests {
             assert!(test_bed.get_right_elevator_position().get::<ratio>() > 0.3);
         }
 
+        #[test]
+        fn aileron_active_servo_reconfigures_to_green_when_blue_pressure_is_lost() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .set_ptu_state(true)
+                .set_yellow_e_pump(false)
+                .run_one_tick();
+
+            test_bed = test_bed
+                .set_ailerons_left_turn()
+                .run_waiting_for(Duration::from_secs_f64(6.));
+
+            assert!(!test_bed.is_blue_pressure_switch_pressurised());
+            assert!(test_bed.is_green_pressure_switch_pressurised());
+
+            // ELAC 1 requests left blue servo active, green one takes over
+            assert!(
+                test_bed.left_aileron_servo_modes()
+                    == [ServoControlMode::Damping, ServoControlMode::Active]
+            );
+            assert!(
+                test_bed.right_aileron_servo_modes()
+                    == [ServoControlMode::Damping, ServoControlMode::Active]
+            );
+
+            assert!(test_bed.get_left_aileron_position().get::<ratio>() > 0.9);
+            assert!(test_bed.get_right_aileron_position().get::<ratio>() < 0.1);
+        }
+
+        #[test]
+        fn ailerons_do_not_respond_without_elac_even_with_pressure() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .set_ptu_state(true)
+                .set_yellow_e_pump(false)
+                .set_blue_e_pump_ovrd_pressed(true)
+                .set_flight_control_computers_failed()
+                .run_one_tick();
+
+            test_bed = test_bed
+                .set_ailerons_left_turn()
+                .run_waiting_for(Duration::from_secs_f64(6.));
+
+            assert!(test_bed.is_blue_pressure_switch_pressurised());
+            assert!(test_bed.is_green_pressure_switch_pressurised());
+
+            assert!(
+                test_bed.left_aileron_servo_modes()
+                    == [ServoControlMode::Damping, ServoControlMode::Damping]
+            );
+            assert!(test_bed.get_left_aileron_position().get::<ratio>() < 0.5);
+            assert!(test_bed.get_right_aileron_position().get::<ratio>() < 0.5);
+        }
+
+        #[test]
+        fn elevators_center_when_all_computers_are_lost() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .set_blue_e_pump_ovrd_pressed(true)
+                .run_one_tick();
+
+            test_bed = test_bed
+                .set_elevator_full_up()
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert!(test_bed.get_left_elevator_position().get::<ratio>() > 0.9);
+
+            test_bed = test_bed
+                .set_flight_control_computers_failed()
+                .run_waiting_for(Duration::from_secs_f64(2.));
+
+            assert!(
+                test_bed.left_elevator_servo_modes()
+                    == [ServoControlMode::Centering, ServoControlMode::Centering]
+            );
+            assert!(test_bed.get_left_elevator_position().get::<ratio>() < 0.4);
+            assert!(test_bed.get_left_elevator_position().get::<ratio>() > 0.3);
+            assert!(test_bed.get_right_elevator_position().get::<ratio>() < 0.4);
+            assert!(test_bed.get_right_elevator_position().get::<ratio>() > 0.3);
+        }
+
+        #[test]
+        fn elevators_center_when_computers_power_supplies_are_lost() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .set_blue_e_pump_ovrd_pressed(true)
+                .run_one_tick();
+
+            test_bed = test_bed
+                .set_elevator_full_up()
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            test_bed = test_bed.dc_ess_lost();
+            test_bed.command(|a| a.set_dc_bus_2_is_powered(false));
+            test_bed = test_bed.run_waiting_for(Duration::from_secs_f64(2.));
+
+            assert!(
+                test_bed.left_elevator_servo_modes()
+                    == [ServoControlMode::Centering, ServoControlMode::Centering]
+            );
+        }
+
         #[test]
         fn cargo_door_operation_closes_yellow_leak_meas_valve() {
             let mut test_bed = test_bed_on_ground_with()
@@ -11066,6 +11746,33 @@ mod tests {
             assert!(test_bed.gear_system_state() == GearSystemState::AllUpLocked);
         }
 
+        #[test]
+        fn gear_retraction_is_slower_with_cold_soaked_fluid() {
+            fn gear_retraction_duration(mut test_bed: A320HydraulicsTestBed) -> Duration {
+                let mut duration = Duration::default();
+                while test_bed.gear_system_state() != GearSystemState::AllUpLocked
+                    && duration < Duration::from_secs(60)
+                {
+                    test_bed = test_bed.run_waiting_for(Duration::from_millis(500));
+                    duration += Duration::from_millis(500);
+                }
+
+                duration
+            }
+
+            let warm_test_bed = test_bed_on_ground_with().set_cold_dark_inputs().in_flight();
+
+            let mut cold_test_bed = test_bed_on_ground_with().set_cold_dark_inputs().in_flight();
+            cold_test_bed
+                .set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-50.));
+
+            let warm_retraction_duration = gear_retraction_duration(warm_test_bed);
+            let cold_retraction_duration = gear_retraction_duration(cold_test_bed);
+
+            assert!(warm_retraction_duration < Duration::from_secs(25));
+            assert!(cold_retraction_duration > warm_retraction_duration);
+        }
+
         #[test]
         fn gear_retracts_using_yellow_epump_plus_ptu() {
             let mut test_bed = test_bed_on_ground_with()
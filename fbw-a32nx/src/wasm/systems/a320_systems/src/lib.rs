@@ -24,11 +24,19 @@ use electrical::{
 use hydraulic::{A320Hydraulic, A320HydraulicOverheadPanel};
 use navigation::A320RadioAltimeters;
 use power_consumption::A320PowerConsumption;
+use std::time::Duration;
 use systems::simulation::InitContext;
 use systems::{enhanced_gpwc::EnhancedGroundProximityWarningComputer, shared::MachNumber};
-use uom::si::{f64::Length, length::nautical_mile, quantities::Velocity, velocity::knot};
+use uom::si::{
+    f64::{Length, Volume},
+    length::nautical_mile,
+    quantities::Velocity,
+    velocity::knot,
+    volume::liter,
+};
 
 use systems::{
+    accept_iterable,
     air_starter_unit::AirStarterUnit,
     apu::{
         Aps3200ApuGenerator, Aps3200Constants, Aps3200StartMotor, AuxiliaryPowerUnit,
@@ -37,12 +45,19 @@ use systems::{
     },
     electrical::{Electricity, ElectricitySource, ExternalPowerSource},
     engine::{leap_engine::LeapEngine, reverser_thrust::ReverserForce, EngineFireOverheadPanel},
+    fire_protection::{
+        ApuFireProtection, FireDetectionZone, FireProtectionZone, SmokeDetectionControlUnit,
+        SmokeDetectionZone,
+    },
     hydraulic::brake_circuit::AutobrakePanel,
-    landing_gear::{LandingGear, LandingGearControlInterfaceUnitSet},
+    landing_gear::{
+        shock_strut::LandingGearCharacteristics, LandingGear, LandingGearControlInterfaceUnitSet,
+    },
     navigation::adirs::{
         AirDataInertialReferenceSystem, AirDataInertialReferenceSystemOverheadPanel,
     },
-    shared::ElectricalBusType,
+    oxygen::{OxygenOverheadPanel, OxygenSystem},
+    shared::{ElectricalBusType, EngineFirePushButtons},
     simulation::{Aircraft, SimulationElement, SimulationElementVisitor, UpdateContext},
 };
 
@@ -75,6 +90,11 @@ pub struct A320 {
     radio_altimeters: A320RadioAltimeters,
     egpwc: EnhancedGroundProximityWarningComputer,
     reverse_thrust: ReverserForce,
+    oxygen: OxygenSystem,
+    oxygen_overhead: OxygenOverheadPanel,
+    engine_fire_protection: [FireProtectionZone; 2],
+    apu_fire_protection: ApuFireProtection,
+    sdcu: SmokeDetectionControlUnit,
 }
 impl A320 {
     pub fn new(context: &mut InitContext) -> A320 {
@@ -83,6 +103,16 @@ impl A320 {
                 context,
                 Velocity::new::<knot>(350.),
                 MachNumber(0.82),
+                [
+                    ElectricalBusType::AlternatingCurrentEssential,
+                    ElectricalBusType::AlternatingCurrent(2),
+                    ElectricalBusType::AlternatingCurrent(1),
+                ],
+                [
+                    ElectricalBusType::DirectCurrentHot(2),
+                    ElectricalBusType::DirectCurrentHot(2),
+                    ElectricalBusType::DirectCurrentHot(1),
+                ],
             ),
             adirs_overhead: AirDataInertialReferenceSystemOverheadPanel::new(context),
             air_conditioning: A320AirConditioning::new(context),
@@ -116,7 +146,7 @@ impl A320 {
             hydraulic: A320Hydraulic::new(context),
             hydraulic_overhead: A320HydraulicOverheadPanel::new(context),
             autobrake_panel: AutobrakePanel::new(context),
-            landing_gear: LandingGear::new(context),
+            landing_gear: LandingGear::new(context, LandingGearCharacteristics::a320()),
             pneumatic: A320Pneumatic::new(context),
             radio_altimeters: A320RadioAltimeters::new(context),
             egpwc: EnhancedGroundProximityWarningComputer::new(
@@ -133,6 +163,47 @@ impl A320 {
                 0,
             ),
             reverse_thrust: ReverserForce::new(context),
+            oxygen: OxygenSystem::new(
+                context,
+                Volume::new::<liter>(3256.),
+                Duration::from_secs(15 * 60),
+                ElectricalBusType::DirectCurrentEssential,
+            ),
+            oxygen_overhead: OxygenOverheadPanel::new(context),
+            engine_fire_protection: [
+                FireProtectionZone::new(
+                    context,
+                    FireDetectionZone::Engine(1),
+                    ElectricalBusType::DirectCurrentEssential,
+                    &[
+                        ElectricalBusType::DirectCurrentHot(1),
+                        ElectricalBusType::DirectCurrent(2),
+                    ],
+                ),
+                FireProtectionZone::new(
+                    context,
+                    FireDetectionZone::Engine(2),
+                    ElectricalBusType::DirectCurrentEssential,
+                    &[
+                        ElectricalBusType::DirectCurrentHot(2),
+                        ElectricalBusType::DirectCurrent(2),
+                    ],
+                ),
+            ],
+            apu_fire_protection: ApuFireProtection::new(
+                context,
+                ElectricalBusType::DirectCurrentBattery,
+                ElectricalBusType::DirectCurrentBattery,
+            ),
+            sdcu: SmokeDetectionControlUnit::new(
+                context,
+                &[
+                    SmokeDetectionZone::ForwardCargo,
+                    SmokeDetectionZone::AftCargo,
+                ],
+                ElectricalBusType::DirectCurrentEssential,
+                ElectricalBusType::DirectCurrentBattery,
+            ),
         }
     }
 }
@@ -146,7 +217,8 @@ impl Aircraft for A320 {
             context,
             &self.apu_overhead,
             &self.apu_fire_overhead,
-            self.pneumatic_overhead.apu_bleed_is_on(),
+            self.pneumatic_overhead.apu_bleed_is_on()
+                && !self.pneumatic.apu_bleed_valve_should_close_for_leak(),
             // This will be replaced when integrating the whole electrical system.
             // For now we use the same logic as found in the JavaScript code; ignoring whether or not
             // the engine generators are supplying electricity.
@@ -155,6 +227,7 @@ impl Aircraft for A320 {
                     && self.electrical_overhead.external_power_is_available()),
             self.pneumatic.apu_bleed_air_valve(),
             self.fuel.left_inner_tank_has_fuel_remaining(),
+            self.apu_fire_protection.fire_detected(),
         );
 
         self.electrical.update(
@@ -189,6 +262,7 @@ impl Aircraft for A320 {
 
         self.asu.update();
 
+        self.landing_gear.update(context);
         self.lgcius.update(
             context,
             &self.landing_gear,
@@ -227,7 +301,7 @@ impl Aircraft for A320 {
         self.hydraulic_overhead.update(&self.hydraulic);
 
         self.adirs.update(context, &self.adirs_overhead);
-        self.adirs_overhead.update(context, &self.adirs);
+        self.adirs_overhead.update(&self.adirs);
 
         self.power_consumption.update(context);
 
@@ -253,6 +327,17 @@ impl Aircraft for A320 {
             [self.lgcius.lgciu1(), self.lgcius.lgciu2()],
         );
 
+        self.oxygen
+            .update(context, &self.oxygen_overhead, &self.air_conditioning);
+
+        for (index, engine_fire_protection) in self.engine_fire_protection.iter_mut().enumerate() {
+            engine_fire_protection
+                .update(context, self.engine_fire_overhead.is_released(index + 1));
+        }
+        self.apu_fire_protection
+            .update(context, self.apu_fire_overhead.fire_button_is_released());
+        self.sdcu.update(context);
+
         self.egpwc.update(&self.adirs, self.lgcius.lgciu1());
     }
 }
@@ -286,6 +371,11 @@ impl SimulationElement for A320 {
         self.pneumatic.accept(visitor);
         self.egpwc.accept(visitor);
         self.reverse_thrust.accept(visitor);
+        self.oxygen.accept(visitor);
+        self.oxygen_overhead.accept(visitor);
+        accept_iterable!(self.engine_fire_protection, visitor);
+        self.apu_fire_protection.accept(visitor);
+        self.sdcu.accept(visitor);
 
         visitor.visit(self);
     }
@@ -13,9 +13,12 @@ use uom::si::{
 use systems::{
     accept_iterable,
     air_conditioning::PackFlowControllers,
+    failures::{Failure, FailureType},
     overhead::{AutoOffFaultPushButton, OnOffFaultPushButton},
     pneumatic::{
-        valve::*, BleedMonitoringComputerChannelOperationMode,
+        engine_anti_ice::{EngineAntiIce, EngineAntiIcePushButton},
+        valve::*,
+        BleedDuctLeak, BleedLeakDetectionLoop, BleedMonitoringComputerChannelOperationMode,
         BleedMonitoringComputerIsAliveSignal, BleedTemperatureSensor, CompressionChamber,
         ControllablePneumaticValve, CrossBleedValveSelectorKnob, CrossBleedValveSelectorMode,
         DifferentialPressureTransducer, EngineCompressionChamberController, EngineModeSelector,
@@ -25,10 +28,10 @@ use systems::{
         WingAntiIcePushButton, WingAntiIceSelected,
     },
     shared::{
-        pid::PidController, update_iterator::MaxStepLoop, ControllerSignal, DelayedTrueLogicGate,
-        ElectricalBusType, ElectricalBuses, EngineBleedPushbutton, EngineCorrectedN1,
-        EngineCorrectedN2, EngineFirePushButtons, EngineStartState, HydraulicColor,
-        LgciuWeightOnWheels, PackFlowValveState, PneumaticBleed, PneumaticValve,
+        pid::PidController, update_iterator::MaxStepLoop, BleedDuctId, ControllerSignal,
+        DelayedTrueLogicGate, ElectricalBusType, ElectricalBuses, EngineBleedPushbutton,
+        EngineCorrectedN1, EngineCorrectedN2, EngineFirePushButtons, EngineStartState,
+        HydraulicColor, LgciuWeightOnWheels, PackFlowValveState, PneumaticBleed, PneumaticValve,
         ReservoirAirPressure,
     },
     simulation::{
@@ -100,6 +103,7 @@ pub struct A320Pneumatic {
     cross_bleed_valve_fully_closed_id: VariableIdentifier,
     apu_bleed_air_valve_open_id: VariableIdentifier,
     apu_bleed_air_pressure_id: VariableIdentifier,
+    apu_bleed_leak_id: VariableIdentifier,
     bleed_monitoring_computers: [BleedMonitoringComputer; 2],
     engine_systems: [EngineBleedAirSystem; 2],
 
@@ -110,11 +114,14 @@ pub struct A320Pneumatic {
 
     apu_compression_chamber: CompressionChamber,
     apu_bleed_air_valve: DefaultValve,
+    apu_bleed_duct_leak: BleedDuctLeak,
+    apu_bleed_leak_detection_loop: BleedLeakDetectionLoop,
 
     air_starter_unit_compression_chamber: CompressionChamber,
     air_starter_unit_bleed_air_valve: PurelyPneumaticValve,
 
     wing_anti_ice: WingAntiIceComplex,
+    engine_anti_ice: [EngineAntiIce; 2],
 
     hydraulic_reservoir_bleed_air_valves: [PurelyPneumaticValve; 2],
     hydraulic_reservoir_bleed_air_pipe: PneumaticPipe,
@@ -130,6 +137,7 @@ pub struct A320Pneumatic {
 }
 impl A320Pneumatic {
     const PNEUMATIC_SIM_MAX_TIME_STEP: Duration = Duration::from_millis(10);
+    const APU_BLEED_LEAK_ALARM_TEMPERATURE_C: f64 = 124.;
 
     pub fn new(context: &mut InitContext) -> Self {
         Self {
@@ -142,6 +150,7 @@ impl A320Pneumatic {
                 .get_identifier("APU_BLEED_AIR_VALVE_OPEN".to_owned()),
             apu_bleed_air_pressure_id: context
                 .get_identifier("PNEU_APU_BLEED_CONTAINER_PRESSURE".to_owned()),
+            apu_bleed_leak_id: context.get_identifier("PNEU_APU_BLEED_LEAK".to_owned()),
             bleed_monitoring_computers: [
                 BleedMonitoringComputer::new(
                     context,
@@ -167,11 +176,22 @@ impl A320Pneumatic {
             ],
             apu_compression_chamber: CompressionChamber::new(Volume::new::<cubic_meter>(5.)),
             apu_bleed_air_valve: DefaultValve::new_closed(),
+            apu_bleed_duct_leak: BleedDuctLeak::new(BleedDuctId::Apu),
+            apu_bleed_leak_detection_loop: BleedLeakDetectionLoop::new(
+                ThermodynamicTemperature::new::<degree_celsius>(
+                    Self::APU_BLEED_LEAK_ALARM_TEMPERATURE_C,
+                ),
+                ElectricalBusType::DirectCurrentEssentialShed,
+            ),
             air_starter_unit_compression_chamber: CompressionChamber::new(
                 Volume::new::<cubic_meter>(5.),
             ),
             air_starter_unit_bleed_air_valve: PurelyPneumaticValve::default(),
             wing_anti_ice: WingAntiIceComplex::new(context),
+            engine_anti_ice: [
+                EngineAntiIce::new(context, 1, ElectricalBusType::DirectCurrentEssentialShed),
+                EngineAntiIce::new(context, 2, ElectricalBusType::DirectCurrent(2)),
+            ],
             hydraulic_reservoir_bleed_air_valves: [
                 PurelyPneumaticValve::new(),
                 PurelyPneumaticValve::new(),
@@ -263,10 +283,21 @@ impl A320Pneumatic {
         self.apu_compression_chamber.update(apu);
         self.air_starter_unit_compression_chamber.update(asu);
 
+        // The leaking duct section lies downstream of the APU bleed valve
+        if self.apu_bleed_air_valve.is_open() {
+            self.apu_bleed_duct_leak
+                .update_move_fluid(context, &mut self.apu_compression_chamber);
+        } else {
+            self.apu_bleed_duct_leak.update_isolated();
+        }
+        self.apu_bleed_leak_detection_loop
+            .update(context, &self.apu_bleed_duct_leak);
+
         for bleed_monitoring_computer in self.bleed_monitoring_computers.iter_mut() {
             bleed_monitoring_computer.update(
                 context,
                 &self.engine_systems,
+                &self.apu_bleed_leak_detection_loop,
                 &self.apu_bleed_air_valve,
                 overhead_panel,
                 engine_fire_push_buttons,
@@ -277,7 +308,7 @@ impl A320Pneumatic {
 
             // I am not exactly sure if both BMCs should actually control this valve all the time.
             self.cross_bleed_valve
-                .update_open_amount(&bleed_monitoring_computer.main_channel);
+                .update_open_amount(bleed_monitoring_computer);
         }
 
         let [bmc_one, bmc_two] = &mut self.bleed_monitoring_computers;
@@ -319,6 +350,19 @@ impl A320Pneumatic {
             );
         }
 
+        // Nacelle anti-ice air is tapped upstream of the pressure regulating valve
+        for (engine_anti_ice, engine_system) in self
+            .engine_anti_ice
+            .iter_mut()
+            .zip(self.engine_systems.iter_mut())
+        {
+            engine_anti_ice.update(
+                context,
+                overhead_panel.engine_anti_ice_is_on(engine_system.number),
+                &mut engine_system.transfer_pressure_pipe,
+            );
+        }
+
         self.wing_anti_ice.update(
             context,
             &mut self.engine_systems,
@@ -381,6 +425,13 @@ impl A320Pneumatic {
     pub fn packs(&mut self) -> &mut [PackComplex; 2] {
         &mut self.packs
     }
+
+    /// Whether a BMC has detected a leak that requires the APU bleed valve to be closed
+    pub fn apu_bleed_valve_should_close_for_leak(&self) -> bool {
+        self.bleed_monitoring_computers
+            .iter()
+            .any(|bmc| bmc.should_close_apu_bleed_valve())
+    }
 }
 impl PneumaticBleed for A320Pneumatic {
     fn apu_bleed_is_on(&self) -> bool {
@@ -415,6 +466,9 @@ impl SimulationElement for A320Pneumatic {
         self.cross_bleed_valve.accept(visitor);
         self.fadec.accept(visitor);
         self.wing_anti_ice.accept(visitor);
+        accept_iterable!(self.engine_anti_ice, visitor);
+        self.apu_bleed_duct_leak.accept(visitor);
+        self.apu_bleed_leak_detection_loop.accept(visitor);
 
         accept_iterable!(self.bleed_monitoring_computers, visitor);
         accept_iterable!(self.engine_systems, visitor);
@@ -444,6 +498,12 @@ impl SimulationElement for A320Pneumatic {
             &self.apu_bleed_air_pressure_id,
             self.apu_compression_chamber.pressure(),
         );
+        writer.write(
+            &self.apu_bleed_leak_id,
+            self.bleed_monitoring_computers
+                .iter()
+                .any(|bmc| bmc.has_apu_bleed_leak),
+        );
     }
 }
 impl ReservoirAirPressure for A320Pneumatic {
@@ -498,6 +558,7 @@ struct BleedMonitoringComputer {
     backup_channel: BleedMonitoringComputerChannel,
     powered_by: ElectricalBusType,
     is_powered: bool,
+    has_apu_bleed_leak: bool,
 }
 impl BleedMonitoringComputer {
     fn new(
@@ -521,6 +582,7 @@ impl BleedMonitoringComputer {
             ),
             powered_by,
             is_powered: true,
+            has_apu_bleed_leak: false,
         }
     }
 
@@ -528,6 +590,7 @@ impl BleedMonitoringComputer {
         &mut self,
         context: &UpdateContext,
         sensors: &[EngineBleedAirSystem; 2],
+        apu_bleed_leak_detection_loop: &BleedLeakDetectionLoop,
         apu_bleed_valve: &impl PneumaticValve,
         overhead_panel: &A320PneumaticOverheadPanel,
         engine_fire_push_buttons: &impl EngineFirePushButtons,
@@ -535,6 +598,13 @@ impl BleedMonitoringComputer {
         fadec: &FullAuthorityDigitalEngineControl,
         wing_anti_ice: &impl WingAntiIceSelected,
     ) {
+        if !self.is_powered() {
+            self.has_apu_bleed_leak = false;
+            self.main_channel.reset_leak_detection();
+            self.backup_channel.reset_leak_detection();
+        }
+        self.has_apu_bleed_leak |= apu_bleed_leak_detection_loop.leak_detected();
+
         self.main_channel.update(
             context,
             &sensors[self.main_channel_engine_number - 1],
@@ -602,6 +672,31 @@ impl BleedMonitoringComputer {
     fn is_powered(&self) -> bool {
         self.is_powered
     }
+
+    fn has_leak(&self) -> bool {
+        self.has_apu_bleed_leak || self.main_channel.has_leak() || self.backup_channel.has_leak()
+    }
+
+    /// The APU bleed duct joins the left side of the crossbleed duct,
+    /// so its valve also isolates a leak on the engine 1 side.
+    fn should_close_apu_bleed_valve(&self) -> bool {
+        self.has_apu_bleed_leak
+            || (self.main_channel_engine_number == 1 && self.main_channel.has_leak())
+            || (self.backup_channel_engine_number == 1 && self.backup_channel.has_leak())
+    }
+}
+impl ControllerSignal<CrossBleedValveSignal> for BleedMonitoringComputer {
+    fn signal(&self) -> Option<CrossBleedValveSignal> {
+        if self.has_leak()
+            && self.main_channel.cross_bleed_valve_selector == CrossBleedValveSelectorMode::Auto
+        {
+            Some(CrossBleedValveSignal::new_closed(
+                CrossBleedValveSignalType::Automatic,
+            ))
+        } else {
+            self.main_channel.signal()
+        }
+    }
 }
 impl SimulationElement for BleedMonitoringComputer {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -651,10 +746,14 @@ struct BleedMonitoringComputerChannel {
     flight_phase_loop: FlightPhaseLoop,
     low_temperature_regulation_active: DelayedTrueLogicGate,
     should_command_onside_prv_closed: bool,
+    has_pylon_leak: bool,
+    has_wing_leak: bool,
 
     low_temperature_id: VariableIdentifier,
     overheat_id: VariableIdentifier,
     overpressure_id: VariableIdentifier,
+    pylon_leak_id: VariableIdentifier,
+    wing_leak_id: VariableIdentifier,
 }
 impl BleedMonitoringComputerChannel {
     const PRESSURE_REGULATING_VALVE_SINGLE_BLEED_CONFIG_TARGET_PSI: f64 = 50.;
@@ -704,11 +803,15 @@ impl BleedMonitoringComputerChannel {
             low_temperature_regulation_active: DelayedTrueLogicGate::new(Duration::from_secs(20)),
             should_command_onside_prv_closed: false,
             is_in_dual_bleed_config: false,
+            has_pylon_leak: false,
+            has_wing_leak: false,
             low_temperature_id: context
                 .get_identifier(format!("PNEU_ENG_{}_LOW_TEMPERATURE", engine_number)),
             overheat_id: context.get_identifier(format!("PNEU_ENG_{}_OVERHEAT", engine_number)),
             overpressure_id: context
                 .get_identifier(format!("PNEU_ENG_{}_OVERPRESSURE", engine_number)),
+            pylon_leak_id: context.get_identifier(format!("PNEU_ENG_{}_PYLON_LEAK", engine_number)),
+            wing_leak_id: context.get_identifier(format!("PNEU_ENG_{}_WING_LEAK", engine_number)),
         }
     }
 
@@ -735,6 +838,10 @@ impl BleedMonitoringComputerChannel {
 
         self.pressure_regulating_valve_is_closed = !sensors.pressure_regulating_valve_is_open();
 
+        // Leaks stay latched until the BMC loses power
+        self.has_pylon_leak |= sensors.pylon_leak_detected();
+        self.has_wing_leak |= sensors.wing_leak_detected();
+
         // UPDATE STATE
 
         self.flight_phase_loop.update(context);
@@ -894,6 +1001,23 @@ impl BleedMonitoringComputerChannel {
         self.overheat_monitor.has_overheat()
     }
 
+    fn has_pylon_leak(&self) -> bool {
+        self.has_pylon_leak
+    }
+
+    fn has_wing_leak(&self) -> bool {
+        self.has_wing_leak
+    }
+
+    fn has_leak(&self) -> bool {
+        self.has_pylon_leak() || self.has_wing_leak()
+    }
+
+    fn reset_leak_detection(&mut self) {
+        self.has_pylon_leak = false;
+        self.has_wing_leak = false;
+    }
+
     fn determine_temperature_setpoint(&self) -> f64 {
         if self.low_temperature_regulation_active.output() {
             Self::LOW_TEMPERATURE_REGULATION_THRESHOLD
@@ -917,7 +1041,8 @@ impl BleedMonitoringComputerChannel {
             cross_bleed_valve,
         ) || sensors.engine_starter_valve_is_open()
             || self.overpressure_monitor.has_overpressure()
-            || self.overheat_monitor.has_overheat();
+            || self.overheat_monitor.has_overheat()
+            || self.has_leak();
 
         let should_command_offside_prv_closed = self.should_command_prv_closed(
             self.engine_number % 2 + 1,
@@ -1022,6 +1147,8 @@ impl SimulationElement for BleedMonitoringComputerChannel {
             writer.write(&self.low_temperature_id, self.has_low_temperature());
             writer.write(&self.overheat_id, self.has_overheat());
             writer.write(&self.overpressure_id, self.has_overpressure());
+            writer.write(&self.pylon_leak_id, self.has_pylon_leak());
+            writer.write(&self.wing_leak_id, self.has_wing_leak());
         }
     }
 }
@@ -1072,11 +1199,24 @@ struct EngineBleedAirSystem {
     regulated_pressure_transducer: PressureTransducer,
     differential_pressure_transducer: DifferentialPressureTransducer,
     bleed_temperature_sensor: BleedTemperatureSensor,
+
+    pylon_duct_leak: BleedDuctLeak,
+    wing_duct_leak: BleedDuctLeak,
+    pylon_leak_detection_loop: BleedLeakDetectionLoop,
+    wing_leak_detection_loop: BleedLeakDetectionLoop,
+
+    pressure_regulating_valve_stuck_open_failure: Failure,
+    pressure_regulating_valve_stuck_closed_failure: Failure,
+    high_pressure_valve_stuck_open_failure: Failure,
+    precooler_clogged_failure: Failure,
 }
 impl EngineBleedAirSystem {
     const MIN_ENGINE_START_CONTAINER_PRESSURE_PSIG_HIGH: f64 = 10.;
     const MIN_ENGINE_START_CONTAINER_PRESSURE_PSIG_LOW: f64 = 5.;
 
+    const PYLON_LEAK_ALARM_TEMPERATURE_C: f64 = 204.;
+    const WING_LEAK_ALARM_TEMPERATURE_C: f64 = 124.;
+
     fn new(context: &mut InitContext, number: usize, powered_by: ElectricalBusType) -> Self {
         Self {
             number,
@@ -1192,6 +1332,30 @@ impl EngineBleedAirSystem {
             regulated_pressure_transducer: PressureTransducer::new(powered_by),
             differential_pressure_transducer: DifferentialPressureTransducer::new(powered_by),
             bleed_temperature_sensor: BleedTemperatureSensor::new(powered_by),
+            pylon_duct_leak: BleedDuctLeak::new(BleedDuctId::EnginePylon(number)),
+            wing_duct_leak: BleedDuctLeak::new(BleedDuctId::Wing(number)),
+            pylon_leak_detection_loop: BleedLeakDetectionLoop::new(
+                ThermodynamicTemperature::new::<degree_celsius>(
+                    Self::PYLON_LEAK_ALARM_TEMPERATURE_C,
+                ),
+                powered_by,
+            ),
+            wing_leak_detection_loop: BleedLeakDetectionLoop::new(
+                ThermodynamicTemperature::new::<degree_celsius>(
+                    Self::WING_LEAK_ALARM_TEMPERATURE_C,
+                ),
+                powered_by,
+            ),
+            pressure_regulating_valve_stuck_open_failure: Failure::new(
+                FailureType::PressureRegulatingValveStuckOpen(number),
+            ),
+            pressure_regulating_valve_stuck_closed_failure: Failure::new(
+                FailureType::PressureRegulatingValveStuckClosed(number),
+            ),
+            high_pressure_valve_stuck_open_failure: Failure::new(
+                FailureType::HighPressureValveStuckOpen(number),
+            ),
+            precooler_clogged_failure: Failure::new(FailureType::PrecoolerClogged(number)),
         }
     }
 
@@ -1204,6 +1368,8 @@ impl EngineBleedAirSystem {
         fan_air_valve_controller: &impl ControllerSignal<FanAirValveSignal>,
         engine: &(impl EngineCorrectedN1 + EngineCorrectedN2),
     ) {
+        self.update_failures();
+
         // Update engines
         self.fan_compression_chamber_controller
             .update(context, engine);
@@ -1259,6 +1425,10 @@ impl EngineBleedAirSystem {
             &mut self.precooler_supply_pipe,
             &mut self.precooler_outlet_pipe,
         );
+        self.pylon_duct_leak
+            .update_move_fluid(context, &mut self.precooler_inlet_pipe);
+        self.wing_duct_leak
+            .update_move_fluid(context, &mut self.precooler_outlet_pipe);
         self.engine_starter_valve
             .update_move_fluid_with_transfer_speed(
                 context,
@@ -1279,6 +1449,38 @@ impl EngineBleedAirSystem {
 
         self.bleed_temperature_sensor
             .update(&self.precooler_outlet_pipe);
+
+        self.pylon_leak_detection_loop
+            .update(context, &self.pylon_duct_leak);
+        self.wing_leak_detection_loop
+            .update(context, &self.wing_duct_leak);
+    }
+
+    fn update_failures(&mut self) {
+        self.pressure_regulating_valve.set_jammed_open_amount(
+            if self
+                .pressure_regulating_valve_stuck_open_failure
+                .is_active()
+            {
+                Some(Ratio::new::<ratio>(1.))
+            } else if self
+                .pressure_regulating_valve_stuck_closed_failure
+                .is_active()
+            {
+                Some(Ratio::new::<ratio>(0.))
+            } else {
+                None
+            },
+        );
+        self.high_pressure_valve.set_jammed_open_amount(
+            if self.high_pressure_valve_stuck_open_failure.is_active() {
+                Some(Ratio::new::<ratio>(1.))
+            } else {
+                None
+            },
+        );
+        self.precooler
+            .set_clogged(self.precooler_clogged_failure.is_active());
     }
 
     fn update_engine_start_pressurization(&mut self, context: &UpdateContext) {
@@ -1369,6 +1571,14 @@ impl EngineBleedAirSystem {
     fn bleed_temperature_sensor_temperature(&self) -> Option<ThermodynamicTemperature> {
         self.bleed_temperature_sensor.signal()
     }
+
+    fn pylon_leak_detected(&self) -> bool {
+        self.pylon_leak_detection_loop.leak_detected()
+    }
+
+    fn wing_leak_detected(&self) -> bool {
+        self.wing_leak_detection_loop.leak_detected()
+    }
 }
 impl SimulationElement for EngineBleedAirSystem {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -1381,6 +1591,18 @@ impl SimulationElement for EngineBleedAirSystem {
         self.differential_pressure_transducer.accept(visitor);
         self.bleed_temperature_sensor.accept(visitor);
 
+        self.pylon_duct_leak.accept(visitor);
+        self.wing_duct_leak.accept(visitor);
+        self.pylon_leak_detection_loop.accept(visitor);
+        self.wing_leak_detection_loop.accept(visitor);
+
+        self.pressure_regulating_valve_stuck_open_failure
+            .accept(visitor);
+        self.pressure_regulating_valve_stuck_closed_failure
+            .accept(visitor);
+        self.high_pressure_valve_stuck_open_failure.accept(visitor);
+        self.precooler_clogged_failure.accept(visitor);
+
         visitor.visit(self);
     }
 
@@ -1544,6 +1766,7 @@ pub struct A320PneumaticOverheadPanel {
     engine_1_bleed: AutoOffFaultPushButton,
     engine_2_bleed: AutoOffFaultPushButton,
     wing_anti_ice: WingAntiIcePushButton,
+    engine_anti_ice: [EngineAntiIcePushButton; 2],
 }
 impl A320PneumaticOverheadPanel {
     pub fn new(context: &mut InitContext) -> Self {
@@ -1553,6 +1776,10 @@ impl A320PneumaticOverheadPanel {
             engine_1_bleed: AutoOffFaultPushButton::new_auto(context, "PNEU_ENG_1_BLEED"),
             engine_2_bleed: AutoOffFaultPushButton::new_auto(context, "PNEU_ENG_2_BLEED"),
             wing_anti_ice: WingAntiIcePushButton::new_off(context),
+            engine_anti_ice: [
+                EngineAntiIcePushButton::new_off(context, 1),
+                EngineAntiIcePushButton::new_off(context, 2),
+            ],
         }
     }
 
@@ -1571,6 +1798,10 @@ impl A320PneumaticOverheadPanel {
             _ => panic!("Invalid engine number"),
         }
     }
+
+    pub fn engine_anti_ice_is_on(&self, engine_number: usize) -> bool {
+        self.engine_anti_ice[engine_number - 1].is_on()
+    }
 }
 impl EngineBleedPushbutton<2> for A320PneumaticOverheadPanel {
     fn engine_bleed_pushbuttons_are_auto(&self) -> [bool; 2] {
@@ -1584,6 +1815,7 @@ impl SimulationElement for A320PneumaticOverheadPanel {
         self.engine_1_bleed.accept(visitor);
         self.engine_2_bleed.accept(visitor);
         self.wing_anti_ice.accept(visitor);
+        accept_iterable!(self.engine_anti_ice, visitor);
 
         visitor.visit(self);
     }
@@ -1776,6 +2008,7 @@ pub struct CrossBleedValve {
     is_powered_for_automatic_control: bool,
     target_open_amount: Ratio,
     valve_speed: Ratio,
+    stuck_failure: Failure,
 }
 impl CrossBleedValve {
     pub fn new(valve_speed: Ratio) -> Self {
@@ -1786,6 +2019,7 @@ impl CrossBleedValve {
             is_powered_for_automatic_control: false,
             target_open_amount: Ratio::default(),
             valve_speed,
+            stuck_failure: Failure::new(FailureType::CrossBleedValveStuck),
         }
     }
 
@@ -1811,6 +2045,12 @@ impl CrossBleedValve {
     }
 
     fn update_open_amount(&mut self, controller: &impl ControllerSignal<CrossBleedValveSignal>) {
+        // A seized valve no longer follows either of its motors
+        if self.stuck_failure.is_active() {
+            self.target_open_amount = self.open_amount;
+            return;
+        }
+
         if let Some(signal) = controller.signal() {
             if signal.signal_type == CrossBleedValveSignalType::Manual
                 && self.is_powered_for_manual_control
@@ -1841,6 +2081,12 @@ impl FullyClosed for CrossBleedValve {
     }
 }
 impl SimulationElement for CrossBleedValve {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.stuck_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn receive_power(&mut self, buses: &impl ElectricalBuses) {
         self.is_powered_for_manual_control =
             buses.is_powered(ElectricalBusType::DirectCurrentEssentialShed);
@@ -1918,7 +2164,7 @@ pub mod tests {
         },
         shared::{
             arinc429::{Arinc429Word, SignStatus},
-            interpolation, ApuBleedAirValveSignal, CabinAltitude, CabinSimulation,
+            interpolation, ApuBleedAirValveSignal, BleedDuctId, CabinAltitude, CabinSimulation,
             ControllerSignal, ElectricalBusType, ElectricalBuses, EmergencyElectricalState,
             EngineCorrectedN1, EngineFirePushButtons, EngineStartState, HydraulicColor,
             InternationalStandardAtmosphere, LgciuWeightOnWheels, MachNumber, PackFlowValveState,
@@ -2884,6 +3130,23 @@ pub mod tests {
             self
         }
 
+        fn engine_anti_ice_push_button(mut self, number: usize, is_on: bool) -> Self {
+            self.write_by_name(
+                &format!("BUTTON_OVHD_ANTI_ICE_ENG_{}_POSITION", number),
+                is_on,
+            );
+
+            self
+        }
+
+        fn engine_anti_ice_valve_is_open(&mut self, number: usize) -> bool {
+            self.read_by_name(&format!("PNEU_ENG_{}_ANTI_ICE_VALVE_OPEN", number))
+        }
+
+        fn engine_anti_ice_has_fault(&mut self, number: usize) -> bool {
+            self.read_by_name(&format!("PNEU_ENG_{}_ANTI_ICE_HAS_FAULT", number))
+        }
+
         fn wing_anti_ice_system_on(&mut self) -> bool {
             self.read_by_name("PNEU_WING_ANTI_ICE_SYSTEM_ON")
         }
@@ -3679,6 +3942,42 @@ pub mod tests {
         assert!(test_bed.contains_variable_with_name("PNEU_ENG_2_OVERPRESSURE"));
     }
 
+    #[test]
+    fn engine_anti_ice_simvars() {
+        let test_bed = test_bed();
+
+        assert!(test_bed.contains_variable_with_name("BUTTON_OVHD_ANTI_ICE_ENG_1_POSITION"));
+        assert!(test_bed.contains_variable_with_name("PNEU_ENG_1_ANTI_ICE_VALVE_OPEN"));
+        assert!(test_bed.contains_variable_with_name("PNEU_ENG_2_ANTI_ICE_HAS_FAULT"));
+        assert!(test_bed.contains_variable_with_name("ICING_STATE_ENG_2_NACELLE"));
+    }
+
+    #[test]
+    fn engine_anti_ice_valve_opens_with_engine_running() {
+        let mut test_bed = test_bed_with()
+            .idle_eng1()
+            .idle_eng2()
+            .engine_anti_ice_push_button(1, true)
+            .and_stabilize();
+
+        assert!(test_bed.engine_anti_ice_valve_is_open(1));
+        assert!(!test_bed.engine_anti_ice_valve_is_open(2));
+        assert!(!test_bed.engine_anti_ice_has_fault(1));
+        assert!(!test_bed.engine_anti_ice_has_fault(2));
+    }
+
+    #[test]
+    fn engine_anti_ice_has_fault_without_engine_bleed_pressure() {
+        let mut test_bed = test_bed_with()
+            .stop_eng1()
+            .stop_eng2()
+            .engine_anti_ice_push_button(1, true)
+            .and_stabilize();
+
+        assert!(!test_bed.engine_anti_ice_valve_is_open(1));
+        assert!(test_bed.engine_anti_ice_has_fault(1));
+    }
+
     #[test]
     fn wing_anti_ice_simvars() {
         let test_bed = test_bed();
@@ -5101,6 +5400,168 @@ pub mod tests {
         }
     }
 
+    #[test]
+    fn pylon_leak_closes_pressure_regulating_valve() {
+        let mut test_bed = test_bed_with()
+            .toga_eng1()
+            .toga_eng2()
+            .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Auto)
+            .and_stabilize();
+
+        assert!(test_bed.pr_valve_is_open(1));
+
+        test_bed.fail(FailureType::BleedDuctLeak(BleedDuctId::EnginePylon(1)));
+        test_bed = test_bed.and_stabilize();
+
+        assert!(!test_bed.pr_valve_is_open(1));
+        assert!(test_bed.pr_valve_is_open(2));
+
+        let has_pylon_leak: bool = test_bed.read_by_name("PNEU_ENG_1_PYLON_LEAK");
+        assert!(has_pylon_leak);
+    }
+
+    #[test]
+    fn wing_leak_closes_pressure_regulating_valve() {
+        let mut test_bed = test_bed_with()
+            .toga_eng1()
+            .toga_eng2()
+            .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Auto)
+            .and_stabilize();
+
+        test_bed.fail(FailureType::BleedDuctLeak(BleedDuctId::Wing(2)));
+        test_bed = test_bed.and_stabilize();
+
+        assert!(test_bed.pr_valve_is_open(1));
+        assert!(!test_bed.pr_valve_is_open(2));
+
+        let has_wing_leak: bool = test_bed.read_by_name("PNEU_ENG_2_WING_LEAK");
+        assert!(has_wing_leak);
+    }
+
+    #[test]
+    fn leak_detection_stays_latched_after_leak_is_isolated() {
+        let mut test_bed = test_bed_with()
+            .toga_eng1()
+            .toga_eng2()
+            .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Auto)
+            .and_stabilize();
+
+        test_bed.fail(FailureType::BleedDuctLeak(BleedDuctId::EnginePylon(1)));
+        test_bed = test_bed.and_stabilize();
+        test_bed.unfail(FailureType::BleedDuctLeak(BleedDuctId::EnginePylon(1)));
+        test_bed = test_bed.and_stabilize();
+
+        assert!(!test_bed.pr_valve_is_open(1));
+    }
+
+    #[test]
+    fn apu_bleed_leak_closes_cross_bleed_valve_and_apu_bleed_valve() {
+        let mut test_bed = test_bed_with()
+            .stop_eng1()
+            .stop_eng2()
+            .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Auto)
+            .set_bleed_air_running()
+            .and_stabilize();
+
+        assert!(test_bed.cross_bleed_valve_is_open());
+        assert!(!test_bed.query(|a| a.pneumatic.apu_bleed_valve_should_close_for_leak()));
+
+        test_bed.fail(FailureType::BleedDuctLeak(BleedDuctId::Apu));
+        test_bed = test_bed.and_stabilize();
+
+        assert!(!test_bed.cross_bleed_valve_is_open());
+        assert!(test_bed.query(|a| a.pneumatic.apu_bleed_valve_should_close_for_leak()));
+
+        let has_apu_bleed_leak: bool = test_bed.read_by_name("PNEU_APU_BLEED_LEAK");
+        assert!(has_apu_bleed_leak);
+    }
+
+    #[test]
+    fn pressure_regulating_valve_stuck_open_ignores_bleed_push_button() {
+        let mut test_bed = test_bed_with()
+            .idle_eng1()
+            .idle_eng2()
+            .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Auto)
+            .and_stabilize();
+
+        assert!(test_bed.pr_valve_is_open(1));
+
+        test_bed.fail(FailureType::PressureRegulatingValveStuckOpen(1));
+        test_bed = test_bed.set_engine_bleed_push_button_off(1).and_stabilize();
+
+        assert!(test_bed.pr_valve_is_open(1));
+    }
+
+    #[test]
+    fn pressure_regulating_valve_stuck_closed_does_not_open() {
+        let mut test_bed = test_bed_with()
+            .idle_eng1()
+            .idle_eng2()
+            .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Auto);
+
+        test_bed.fail(FailureType::PressureRegulatingValveStuckClosed(1));
+        test_bed = test_bed.and_stabilize();
+
+        assert!(!test_bed.pr_valve_is_open(1));
+        assert!(test_bed.pr_valve_is_open(2));
+    }
+
+    #[test]
+    fn high_pressure_valve_stuck_open_stays_open_at_high_n1() {
+        let mut test_bed = test_bed_with()
+            .toga_eng1()
+            .toga_eng2()
+            .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Auto)
+            .and_stabilize();
+
+        assert!(!test_bed.hp_valve_is_open(1));
+
+        test_bed.fail(FailureType::HighPressureValveStuckOpen(1));
+        test_bed = test_bed.and_stabilize();
+
+        assert!(test_bed.hp_valve_is_open(1));
+        assert!(!test_bed.hp_valve_is_open(2));
+    }
+
+    #[test]
+    fn cross_bleed_valve_stuck_does_not_follow_selector() {
+        let mut test_bed = test_bed_with()
+            .idle_eng1()
+            .idle_eng2()
+            .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Open)
+            .and_stabilize();
+
+        assert!(test_bed.cross_bleed_valve_is_open());
+
+        test_bed.fail(FailureType::CrossBleedValveStuck);
+        test_bed = test_bed
+            .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Shut)
+            .and_stabilize();
+
+        assert!(test_bed.cross_bleed_valve_is_open());
+    }
+
+    #[test]
+    fn clogged_precooler_raises_bleed_temperature() {
+        let mut nominal_test_bed = test_bed_with()
+            .toga_eng1()
+            .idle_eng2()
+            .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Shut);
+        nominal_test_bed.run_multiple_frames(Duration::from_secs(5));
+
+        let mut clogged_test_bed = test_bed_with()
+            .toga_eng1()
+            .idle_eng2()
+            .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Shut);
+        clogged_test_bed.fail(FailureType::PrecoolerClogged(1));
+        clogged_test_bed.run_multiple_frames(Duration::from_secs(5));
+
+        assert!(
+            clogged_test_bed.precooler_outlet_temperature(1)
+                > nominal_test_bed.precooler_outlet_temperature(1)
+        );
+    }
+
     mod overhead {
         use super::*;
 
@@ -1,346 +1,455 @@
 use systems::simulation::InitContext;
 use systems::{
-    electrical::consumption::{FlightPhasePowerConsumer, PowerConsumerFlightPhase},
+    electrical::consumption::{
+        EquipmentPowerConsumption, PowerConsumerCondition, PowerConsumerDefinition,
+        PowerConsumerFlightPhase,
+    },
     shared::ElectricalBusType,
     simulation::{SimulationElement, SimulationElementVisitor, UpdateContext},
 };
-use uom::si::{f64::*, power::watt};
 
-/// This type provides an aggregated form of power consumption.
-/// We haven't yet implemented all power consumers and thus need something to
-/// consume power, as otherwise electrical load is nearly 0.
+const ENGINES_RUNNING: [PowerConsumerFlightPhase; 5] = [
+    PowerConsumerFlightPhase::AfterStart,
+    PowerConsumerFlightPhase::Takeoff,
+    PowerConsumerFlightPhase::Flight,
+    PowerConsumerFlightPhase::Landing,
+    PowerConsumerFlightPhase::TaxiIn,
+];
+
+const TAKEOFF_AND_LANDING: [PowerConsumerFlightPhase; 2] = [
+    PowerConsumerFlightPhase::Takeoff,
+    PowerConsumerFlightPhase::Landing,
+];
+
+const CENTER_TANK_FEEDING: [PowerConsumerFlightPhase; 2] = [
+    PowerConsumerFlightPhase::Flight,
+    PowerConsumerFlightPhase::Landing,
+];
+
+/// The equipment which isn't simulated by a system of its own. The wattages are derived
+/// from the per bus consumption figures provided by komp.
+const A320_POWER_CONSUMERS: [PowerConsumerDefinition; 57] = [
+    PowerConsumerDefinition::new(
+        "FWD GALLEY",
+        ElectricalBusType::AlternatingCurrent(1),
+        9500.,
+        9500.,
+        PowerConsumerCondition::GalleyNotShed,
+    ),
+    PowerConsumerDefinition::new(
+        "CABIN LIGHTING FWD",
+        ElectricalBusType::AlternatingCurrent(1),
+        2400.,
+        2600.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "IN FLIGHT ENTERTAINMENT 1",
+        ElectricalBusType::AlternatingCurrent(1),
+        3000.,
+        3000.,
+        PowerConsumerCondition::GalleyNotShed,
+    ),
+    PowerConsumerDefinition::new(
+        "LAVATORY WATER HEATERS FWD",
+        ElectricalBusType::AlternatingCurrent(1),
+        1000.,
+        1000.,
+        PowerConsumerCondition::GalleyNotShed,
+    ),
+    PowerConsumerDefinition::new(
+        "WINDSHIELD HEAT CAPT",
+        ElectricalBusType::AlternatingCurrent(1),
+        2200.,
+        3000.,
+        PowerConsumerCondition::FlightPhases(&ENGINES_RUNNING),
+    ),
+    PowerConsumerDefinition::new(
+        "WINDOW HEAT CAPT",
+        ElectricalBusType::AlternatingCurrent(1),
+        600.,
+        800.,
+        PowerConsumerCondition::FlightPhases(&ENGINES_RUNNING),
+    ),
+    PowerConsumerDefinition::new(
+        "FUEL PUMP L1",
+        ElectricalBusType::AlternatingCurrent(1),
+        1100.,
+        2200.,
+        PowerConsumerCondition::FlightPhases(&ENGINES_RUNNING),
+    ),
+    PowerConsumerDefinition::new(
+        "FUEL PUMP C1",
+        ElectricalBusType::AlternatingCurrent(1),
+        1100.,
+        2200.,
+        PowerConsumerCondition::FlightPhases(&CENTER_TANK_FEEDING),
+    ),
+    PowerConsumerDefinition::new(
+        "FUEL PUMP R1",
+        ElectricalBusType::AlternatingCurrent(1),
+        1100.,
+        2200.,
+        PowerConsumerCondition::FlightPhases(&ENGINES_RUNNING),
+    ),
+    PowerConsumerDefinition::new(
+        "RECIRCULATION FAN 1",
+        ElectricalBusType::AlternatingCurrent(1),
+        1800.,
+        3000.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "AVIONICS 1",
+        ElectricalBusType::AlternatingCurrent(1),
+        4500.,
+        4500.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "AVIONICS VENTILATION 1",
+        ElectricalBusType::AlternatingCurrent(1),
+        900.,
+        1500.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "CARGO HEAT AND VENTILATION",
+        ElectricalBusType::AlternatingCurrent(1),
+        2500.,
+        2500.,
+        PowerConsumerCondition::InFlight,
+    ),
+    PowerConsumerDefinition::new(
+        "DRAIN MAST HEAT FWD",
+        ElectricalBusType::AlternatingCurrent(1),
+        300.,
+        300.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "LANDING LIGHT L",
+        ElectricalBusType::AlternatingCurrent(1),
+        600.,
+        900.,
+        PowerConsumerCondition::FlightPhases(&TAKEOFF_AND_LANDING),
+    ),
+    PowerConsumerDefinition::new(
+        "NAVIGATION AND LOGO LIGHTS 1",
+        ElectricalBusType::AlternatingCurrent(1),
+        400.,
+        400.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "WING ILLUMINATION AND STROBES 1",
+        ElectricalBusType::AlternatingCurrent(1),
+        350.,
+        350.,
+        PowerConsumerCondition::FlightPhases(&ENGINES_RUNNING),
+    ),
+    PowerConsumerDefinition::new(
+        "COCKPIT AND ANNUNCIATOR LIGHTING",
+        ElectricalBusType::AlternatingCurrent(1),
+        500.,
+        500.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "AFT GALLEY",
+        ElectricalBusType::AlternatingCurrent(2),
+        7000.,
+        7000.,
+        PowerConsumerCondition::GalleyNotShed,
+    ),
+    PowerConsumerDefinition::new(
+        "CABIN LIGHTING AFT",
+        ElectricalBusType::AlternatingCurrent(2),
+        2400.,
+        2600.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "IN FLIGHT ENTERTAINMENT 2",
+        ElectricalBusType::AlternatingCurrent(2),
+        3000.,
+        3000.,
+        PowerConsumerCondition::GalleyNotShed,
+    ),
+    PowerConsumerDefinition::new(
+        "LAVATORY WATER HEATERS AFT",
+        ElectricalBusType::AlternatingCurrent(2),
+        1000.,
+        1000.,
+        PowerConsumerCondition::GalleyNotShed,
+    ),
+    PowerConsumerDefinition::new(
+        "WINDSHIELD HEAT F/O",
+        ElectricalBusType::AlternatingCurrent(2),
+        2200.,
+        3000.,
+        PowerConsumerCondition::FlightPhases(&ENGINES_RUNNING),
+    ),
+    PowerConsumerDefinition::new(
+        "WINDOW HEAT F/O",
+        ElectricalBusType::AlternatingCurrent(2),
+        600.,
+        800.,
+        PowerConsumerCondition::FlightPhases(&ENGINES_RUNNING),
+    ),
+    PowerConsumerDefinition::new(
+        "FUEL PUMP L2",
+        ElectricalBusType::AlternatingCurrent(2),
+        1100.,
+        2200.,
+        PowerConsumerCondition::FlightPhases(&ENGINES_RUNNING),
+    ),
+    PowerConsumerDefinition::new(
+        "FUEL PUMP C2",
+        ElectricalBusType::AlternatingCurrent(2),
+        1100.,
+        2200.,
+        PowerConsumerCondition::FlightPhases(&CENTER_TANK_FEEDING),
+    ),
+    PowerConsumerDefinition::new(
+        "FUEL PUMP R2",
+        ElectricalBusType::AlternatingCurrent(2),
+        1100.,
+        2200.,
+        PowerConsumerCondition::FlightPhases(&ENGINES_RUNNING),
+    ),
+    PowerConsumerDefinition::new(
+        "RECIRCULATION FAN 2",
+        ElectricalBusType::AlternatingCurrent(2),
+        1800.,
+        3000.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "AVIONICS 2",
+        ElectricalBusType::AlternatingCurrent(2),
+        4000.,
+        4000.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "AVIONICS VENTILATION 2",
+        ElectricalBusType::AlternatingCurrent(2),
+        900.,
+        1500.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "LAVATORY AND GALLEY EXTRACTION FAN",
+        ElectricalBusType::AlternatingCurrent(2),
+        500.,
+        900.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "DRAIN MAST HEAT AFT",
+        ElectricalBusType::AlternatingCurrent(2),
+        300.,
+        300.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "LANDING LIGHT R",
+        ElectricalBusType::AlternatingCurrent(2),
+        600.,
+        900.,
+        PowerConsumerCondition::FlightPhases(&TAKEOFF_AND_LANDING),
+    ),
+    PowerConsumerDefinition::new(
+        "NAVIGATION AND LOGO LIGHTS 2",
+        ElectricalBusType::AlternatingCurrent(2),
+        400.,
+        400.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "WING ILLUMINATION AND STROBES 2",
+        ElectricalBusType::AlternatingCurrent(2),
+        350.,
+        350.,
+        PowerConsumerCondition::FlightPhases(&ENGINES_RUNNING),
+    ),
+    PowerConsumerDefinition::new(
+        "AVIONICS ESSENTIAL",
+        ElectricalBusType::AlternatingCurrentEssential,
+        455.7,
+        455.7,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "PROBE HEAT CAPT",
+        ElectricalBusType::AlternatingCurrentEssential,
+        420.,
+        420.,
+        PowerConsumerCondition::FlightPhases(&ENGINES_RUNNING),
+    ),
+    PowerConsumerDefinition::new(
+        "AVIONICS ESSENTIAL SHED",
+        ElectricalBusType::AlternatingCurrentEssentialShed,
+        560.5,
+        560.5,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "PROBE HEAT F/O",
+        ElectricalBusType::AlternatingCurrentEssentialShed,
+        263.,
+        263.,
+        PowerConsumerCondition::FlightPhases(&ENGINES_RUNNING),
+    ),
+    PowerConsumerDefinition::new(
+        "STANDBY INSTRUMENTS",
+        ElectricalBusType::AlternatingCurrentStaticInverter,
+        135.,
+        135.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "CABIN SERVICE LIGHTING",
+        ElectricalBusType::AlternatingCurrentGndFltService,
+        1035.,
+        1035.,
+        PowerConsumerCondition::OnGround,
+    ),
+    PowerConsumerDefinition::new(
+        "POTABLE WATER COMPRESSOR",
+        ElectricalBusType::AlternatingCurrentGndFltService,
+        1055.,
+        1500.,
+        PowerConsumerCondition::OnGround,
+    ),
+    PowerConsumerDefinition::new(
+        "CARGO LIGHTING AND SERVICE OUTLETS",
+        ElectricalBusType::AlternatingCurrentGndFltService,
+        2628.,
+        2628.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "DC AVIONICS 1",
+        ElectricalBusType::DirectCurrent(1),
+        252.,
+        252.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "CARGO SMOKE DETECTION",
+        ElectricalBusType::DirectCurrent(1),
+        28.,
+        28.,
+        PowerConsumerCondition::InFlight,
+    ),
+    PowerConsumerDefinition::new(
+        "ENGINE INTERFACE UNIT 1",
+        ElectricalBusType::DirectCurrent(1),
+        56.,
+        84.,
+        PowerConsumerCondition::FlightPhases(&ENGINES_RUNNING),
+    ),
+    PowerConsumerDefinition::new(
+        "DC AVIONICS 2",
+        ElectricalBusType::DirectCurrent(2),
+        336.,
+        336.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "ENGINE INTERFACE UNIT 2",
+        ElectricalBusType::DirectCurrent(2),
+        56.,
+        84.,
+        PowerConsumerCondition::FlightPhases(&ENGINES_RUNNING),
+    ),
+    PowerConsumerDefinition::new(
+        "DC ESSENTIAL AVIONICS",
+        ElectricalBusType::DirectCurrentEssential,
+        140.,
+        168.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "DC ESSENTIAL SHED AVIONICS",
+        ElectricalBusType::DirectCurrentEssentialShed,
+        168.,
+        224.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "EMERGENCY EXIT LIGHTING CHARGE",
+        ElectricalBusType::DirectCurrentEssentialShed,
+        28.,
+        28.,
+        PowerConsumerCondition::FlightPhases(&ENGINES_RUNNING),
+    ),
+    PowerConsumerDefinition::new(
+        "BATTERY BUS MONITORING",
+        ElectricalBusType::DirectCurrentBattery,
+        28.,
+        28.,
+        PowerConsumerCondition::FlightPhases(&ENGINES_RUNNING),
+    ),
+    PowerConsumerDefinition::new(
+        "ENGINE FIRE DETECTION 1",
+        ElectricalBusType::DirectCurrentHot(1),
+        11.,
+        108.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "ADIRU ON BATTERY MONITORING",
+        ElectricalBusType::DirectCurrentHot(1),
+        4.3,
+        4.3,
+        PowerConsumerCondition::InFlight,
+    ),
+    PowerConsumerDefinition::new(
+        "ENGINE FIRE DETECTION 2",
+        ElectricalBusType::DirectCurrentHot(2),
+        24.3,
+        24.3,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "SERVICE INTERPHONE AND LIGHTS",
+        ElectricalBusType::DirectCurrentGndFltService,
+        84.,
+        168.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "CARGO DOOR OPERATION",
+        ElectricalBusType::DirectCurrentGndFltService,
+        84.,
+        84.,
+        PowerConsumerCondition::OnGround,
+    ),
+];
+
+/// This type provides the power consumption of equipment which isn't simulated
+/// by a system of its own. Each piece of equipment consumes power from its own bus,
+/// thus shedding a bus removes the demand of the equipment it powers.
 pub(super) struct A320PowerConsumption {
-    ac_bus_1_consumer: FlightPhasePowerConsumer,
-    ac_bus_2_consumer: FlightPhasePowerConsumer,
-    ac_ess_bus_consumer: FlightPhasePowerConsumer,
-    ac_ess_shed_bus_consumer: FlightPhasePowerConsumer,
-    ac_stat_inv_bus_consumer: FlightPhasePowerConsumer,
-    ac_gnd_flt_service_consumer: FlightPhasePowerConsumer,
-    dc_bus_1_consumer: FlightPhasePowerConsumer,
-    dc_bus_2_consumer: FlightPhasePowerConsumer,
-    dc_ess_bus_consumer: FlightPhasePowerConsumer,
-    dc_ess_shed_bus_consumer: FlightPhasePowerConsumer,
-    dc_bat_bus_consumer: FlightPhasePowerConsumer,
-    dc_hot_bus_1_consumer: FlightPhasePowerConsumer,
-    dc_hot_bus_2_consumer: FlightPhasePowerConsumer,
-    dc_gnd_flt_service_consumer: FlightPhasePowerConsumer,
+    equipment: EquipmentPowerConsumption,
 }
 impl A320PowerConsumption {
     pub fn new(context: &mut InitContext) -> Self {
-        // The watts in this function are all provided by komp.
         Self {
-            ac_bus_1_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::AlternatingCurrent(1),
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(26816.3),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(30350.1),
-                ),
-                (
-                    PowerConsumerFlightPhase::Takeoff,
-                    Power::new::<watt>(33797.3),
-                ),
-                (
-                    PowerConsumerFlightPhase::Flight,
-                    Power::new::<watt>(39032.5),
-                ),
-                (
-                    PowerConsumerFlightPhase::Landing,
-                    Power::new::<watt>(30733.3),
-                ),
-                (
-                    PowerConsumerFlightPhase::TaxiIn,
-                    Power::new::<watt>(30243.1),
-                ),
-            ]),
-            ac_bus_2_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::AlternatingCurrent(2),
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(26960.2),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(21735.8),
-                ),
-                (
-                    PowerConsumerFlightPhase::Takeoff,
-                    Power::new::<watt>(25183.),
-                ),
-                (
-                    PowerConsumerFlightPhase::Flight,
-                    Power::new::<watt>(29777.4),
-                ),
-                (
-                    PowerConsumerFlightPhase::Landing,
-                    Power::new::<watt>(22119.),
-                ),
-                (
-                    PowerConsumerFlightPhase::TaxiIn,
-                    Power::new::<watt>(24475.8),
-                ),
-            ]),
-            ac_ess_bus_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::AlternatingCurrentEssential,
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(455.7),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(715.7),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(875.7)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(875.7)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(715.7)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(715.7)),
-            ]),
-            ac_ess_shed_bus_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::AlternatingCurrentEssentialShed,
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(560.5),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(823.5),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(823.5)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(823.5)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(823.5)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(823.5)),
-            ]),
-            ac_stat_inv_bus_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::AlternatingCurrentStaticInverter,
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(135.),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(135.),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(135.)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(135.)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(135.)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(135.)),
-            ]),
-            ac_gnd_flt_service_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::AlternatingCurrentGndFltService,
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(4718.),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(3663.),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(2628.)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(2628.)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(2628.)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(3663.)),
-            ]),
-            dc_bus_1_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::DirectCurrent(1),
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(252.),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(308.),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(364.)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(280.)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(364.)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(336.)),
-            ]),
-            dc_bus_2_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::DirectCurrent(2),
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(532.),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(448.),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(392.)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(392.)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(392.)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(448.)),
-            ]),
-            dc_ess_bus_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::DirectCurrentEssential,
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(168.),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(140.),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(168.)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(140.)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(168.)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(140.)),
-            ]),
-            dc_ess_shed_bus_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::DirectCurrentEssentialShed,
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(224.),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(168.),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(196.)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(196.)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(196.)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(168.)),
-            ]),
-            dc_bat_bus_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::DirectCurrentBattery,
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(0.),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(28.),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(28.)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(28.)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(28.)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(28.)),
-            ]),
-            dc_hot_bus_1_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::DirectCurrentHot(1),
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(108.),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(11.),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(15.3)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(15.3)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(15.3)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(11.)),
-            ]),
-            dc_hot_bus_2_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::DirectCurrentHot(2),
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(24.3),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(24.3),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(24.3)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(24.3)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(24.3)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(24.3)),
-            ]),
-            dc_gnd_flt_service_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::DirectCurrentGndFltService,
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(168.),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(84.),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(84.)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(84.)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(112.)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(84.)),
-            ]),
+            equipment: EquipmentPowerConsumption::new(context, &A320_POWER_CONSUMERS),
         }
     }
 
     pub fn update(&mut self, context: &UpdateContext) {
-        self.ac_bus_1_consumer.update(context);
-        self.ac_bus_2_consumer.update(context);
-        self.ac_ess_bus_consumer.update(context);
-        self.ac_ess_shed_bus_consumer.update(context);
-        self.ac_stat_inv_bus_consumer.update(context);
-        self.ac_gnd_flt_service_consumer.update(context);
-        self.dc_bus_1_consumer.update(context);
-        self.dc_bus_2_consumer.update(context);
-        self.dc_ess_bus_consumer.update(context);
-        self.dc_ess_shed_bus_consumer.update(context);
-        self.dc_bat_bus_consumer.update(context);
-        self.dc_hot_bus_1_consumer.update(context);
-        self.dc_hot_bus_2_consumer.update(context);
-        self.dc_gnd_flt_service_consumer.update(context);
+        self.equipment.update(context);
     }
 }
 impl SimulationElement for A320PowerConsumption {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
-        self.ac_bus_1_consumer.accept(visitor);
-        self.ac_bus_2_consumer.accept(visitor);
-        self.ac_ess_bus_consumer.accept(visitor);
-        self.ac_ess_shed_bus_consumer.accept(visitor);
-        self.ac_stat_inv_bus_consumer.accept(visitor);
-        self.ac_gnd_flt_service_consumer.accept(visitor);
-        self.dc_bus_1_consumer.accept(visitor);
-        self.dc_bus_2_consumer.accept(visitor);
-        self.dc_ess_bus_consumer.accept(visitor);
-        self.dc_ess_shed_bus_consumer.accept(visitor);
-        self.dc_bat_bus_consumer.accept(visitor);
-        self.dc_hot_bus_1_consumer.accept(visitor);
-        self.dc_hot_bus_2_consumer.accept(visitor);
-        self.dc_gnd_flt_service_consumer.accept(visitor);
+        self.equipment.accept(visitor);
 
         visitor.visit(self);
     }
@@ -28,9 +28,11 @@ use systems::air_conditioning::{
     acs_controller::AcscId, cabin_pressure_controller::CpcId, Channel, ZoneType,
 };
 use systems::failures::FailureType;
+use systems::fire_protection::{FireDetectionLoopId, FireDetectionZone, SmokeDetectionZone};
 use systems::shared::{
-    AirbusElectricPumpId, AirbusEngineDrivenPumpId, ElectricalBusType, GearActuatorId,
-    HydraulicColor, LgciuId, ProximityDetectorId,
+    AirbusElectricPumpId, AirbusEngineDrivenPumpId, BleedDuctId, ElectricalBusType,
+    FlapSlatTransmissionId, FlightControlActuatorId, FlightControlSurfaceId, GearActuatorId,
+    HydraulicColor, LgciuId, ProximityDetectorId, ReverserDoorId,
 };
 use systems_wasm::aspects::ExecuteOn;
 use systems_wasm::{MsfsSimulationBuilder, Variable};
@@ -98,6 +100,9 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
         (21_017, FailureType::OutflowValveFault),
         (21_018, FailureType::SafetyValveFault),
         (21_019, FailureType::RapidDecompression),
+        (21_020, FailureType::CabinDoorSealLeak),
+        (21_021, FailureType::CabinWindowCrack),
+        (21_022, FailureType::CargoDoorUnsealed),
         (24_000, FailureType::TransformerRectifier(1)),
         (24_001, FailureType::TransformerRectifier(2)),
         (24_002, FailureType::TransformerRectifier(3)),
@@ -161,6 +166,668 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             24_113,
             FailureType::ElectricalBus(ElectricalBusType::DirectCurrentGndFltService),
         ),
+        (
+            24_114,
+            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentNamed("401PP")),
+        ),
+        (
+            24_115,
+            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentNamed("601PP")),
+        ),
+        (26_000, FailureType::EngineFire(1)),
+        (26_001, FailureType::EngineFire(2)),
+        (26_002, FailureType::ApuFire),
+        (
+            26_010,
+            FailureType::FireDetectionLoop(FireDetectionZone::Engine(1), FireDetectionLoopId::A),
+        ),
+        (
+            26_011,
+            FailureType::FireDetectionLoop(FireDetectionZone::Engine(1), FireDetectionLoopId::B),
+        ),
+        (
+            26_012,
+            FailureType::FireDetectionLoop(FireDetectionZone::Engine(2), FireDetectionLoopId::A),
+        ),
+        (
+            26_013,
+            FailureType::FireDetectionLoop(FireDetectionZone::Engine(2), FireDetectionLoopId::B),
+        ),
+        (
+            26_014,
+            FailureType::FireDetectionLoop(FireDetectionZone::Apu, FireDetectionLoopId::A),
+        ),
+        (
+            26_015,
+            FailureType::FireDetectionLoop(FireDetectionZone::Apu, FireDetectionLoopId::B),
+        ),
+        (26_100, FailureType::Smoke(SmokeDetectionZone::ForwardCargo)),
+        (26_101, FailureType::Smoke(SmokeDetectionZone::AftCargo)),
+        (26_102, FailureType::Smoke(SmokeDetectionZone::Lavatory)),
+        (
+            26_110,
+            FailureType::SmokeDetector(SmokeDetectionZone::ForwardCargo, 1),
+        ),
+        (
+            26_111,
+            FailureType::SmokeDetector(SmokeDetectionZone::ForwardCargo, 2),
+        ),
+        (
+            26_112,
+            FailureType::SmokeDetector(SmokeDetectionZone::AftCargo, 1),
+        ),
+        (
+            26_113,
+            FailureType::SmokeDetector(SmokeDetectionZone::AftCargo, 2),
+        ),
+        (
+            26_114,
+            FailureType::SmokeDetector(SmokeDetectionZone::Lavatory, 1),
+        ),
+        (
+            27_100,
+            FailureType::FlapSlatJam(FlapSlatTransmissionId::FlapsLeft),
+        ),
+        (
+            27_101,
+            FailureType::FlapSlatJam(FlapSlatTransmissionId::FlapsRight),
+        ),
+        (
+            27_102,
+            FailureType::FlapSlatJam(FlapSlatTransmissionId::SlatsLeft),
+        ),
+        (
+            27_103,
+            FailureType::FlapSlatJam(FlapSlatTransmissionId::SlatsRight),
+        ),
+        (
+            27_200,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftAileron(1),
+                1,
+            )),
+        ),
+        (
+            27_201,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftAileron(1),
+                2,
+            )),
+        ),
+        (
+            27_202,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightAileron(1),
+                1,
+            )),
+        ),
+        (
+            27_203,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightAileron(1),
+                2,
+            )),
+        ),
+        (
+            27_204,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftElevator(1),
+                1,
+            )),
+        ),
+        (
+            27_205,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftElevator(1),
+                2,
+            )),
+        ),
+        (
+            27_206,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightElevator(1),
+                1,
+            )),
+        ),
+        (
+            27_207,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightElevator(1),
+                2,
+            )),
+        ),
+        (
+            27_208,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::Rudder(1),
+                1,
+            )),
+        ),
+        (
+            27_209,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::Rudder(1),
+                2,
+            )),
+        ),
+        (
+            27_210,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::Rudder(1),
+                3,
+            )),
+        ),
+        (
+            27_211,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(1),
+                1,
+            )),
+        ),
+        (
+            27_212,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(2),
+                1,
+            )),
+        ),
+        (
+            27_213,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(3),
+                1,
+            )),
+        ),
+        (
+            27_214,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(4),
+                1,
+            )),
+        ),
+        (
+            27_215,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(5),
+                1,
+            )),
+        ),
+        (
+            27_216,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(1),
+                1,
+            )),
+        ),
+        (
+            27_217,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(2),
+                1,
+            )),
+        ),
+        (
+            27_218,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(3),
+                1,
+            )),
+        ),
+        (
+            27_219,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(4),
+                1,
+            )),
+        ),
+        (
+            27_220,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(5),
+                1,
+            )),
+        ),
+        (
+            27_300,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftAileron(1),
+                1,
+            )),
+        ),
+        (
+            27_301,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftAileron(1),
+                2,
+            )),
+        ),
+        (
+            27_302,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightAileron(1),
+                1,
+            )),
+        ),
+        (
+            27_303,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightAileron(1),
+                2,
+            )),
+        ),
+        (
+            27_304,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftElevator(1),
+                1,
+            )),
+        ),
+        (
+            27_305,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftElevator(1),
+                2,
+            )),
+        ),
+        (
+            27_306,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightElevator(1),
+                1,
+            )),
+        ),
+        (
+            27_307,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightElevator(1),
+                2,
+            )),
+        ),
+        (
+            27_308,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::Rudder(1),
+                1,
+            )),
+        ),
+        (
+            27_309,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::Rudder(1),
+                2,
+            )),
+        ),
+        (
+            27_310,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::Rudder(1),
+                3,
+            )),
+        ),
+        (
+            27_311,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(1),
+                1,
+            )),
+        ),
+        (
+            27_312,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(2),
+                1,
+            )),
+        ),
+        (
+            27_313,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(3),
+                1,
+            )),
+        ),
+        (
+            27_314,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(4),
+                1,
+            )),
+        ),
+        (
+            27_315,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(5),
+                1,
+            )),
+        ),
+        (
+            27_316,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(1),
+                1,
+            )),
+        ),
+        (
+            27_317,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(2),
+                1,
+            )),
+        ),
+        (
+            27_318,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(3),
+                1,
+            )),
+        ),
+        (
+            27_319,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(4),
+                1,
+            )),
+        ),
+        (
+            27_320,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(5),
+                1,
+            )),
+        ),
+        (
+            27_400,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftAileron(1),
+                1,
+            )),
+        ),
+        (
+            27_401,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftAileron(1),
+                2,
+            )),
+        ),
+        (
+            27_402,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightAileron(1),
+                1,
+            )),
+        ),
+        (
+            27_403,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightAileron(1),
+                2,
+            )),
+        ),
+        (
+            27_404,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftElevator(1),
+                1,
+            )),
+        ),
+        (
+            27_405,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftElevator(1),
+                2,
+            )),
+        ),
+        (
+            27_406,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightElevator(1),
+                1,
+            )),
+        ),
+        (
+            27_407,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightElevator(1),
+                2,
+            )),
+        ),
+        (
+            27_408,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::Rudder(1),
+                1,
+            )),
+        ),
+        (
+            27_409,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::Rudder(1),
+                2,
+            )),
+        ),
+        (
+            27_410,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::Rudder(1),
+                3,
+            )),
+        ),
+        (
+            27_411,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(1),
+                1,
+            )),
+        ),
+        (
+            27_412,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(2),
+                1,
+            )),
+        ),
+        (
+            27_413,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(3),
+                1,
+            )),
+        ),
+        (
+            27_414,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(4),
+                1,
+            )),
+        ),
+        (
+            27_415,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(5),
+                1,
+            )),
+        ),
+        (
+            27_416,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(1),
+                1,
+            )),
+        ),
+        (
+            27_417,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(2),
+                1,
+            )),
+        ),
+        (
+            27_418,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(3),
+                1,
+            )),
+        ),
+        (
+            27_419,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(4),
+                1,
+            )),
+        ),
+        (
+            27_420,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(5),
+                1,
+            )),
+        ),
+        (
+            27_500,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftAileron(1),
+                1,
+            )),
+        ),
+        (
+            27_501,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftAileron(1),
+                2,
+            )),
+        ),
+        (
+            27_502,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightAileron(1),
+                1,
+            )),
+        ),
+        (
+            27_503,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightAileron(1),
+                2,
+            )),
+        ),
+        (
+            27_504,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftElevator(1),
+                1,
+            )),
+        ),
+        (
+            27_505,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftElevator(1),
+                2,
+            )),
+        ),
+        (
+            27_506,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightElevator(1),
+                1,
+            )),
+        ),
+        (
+            27_507,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightElevator(1),
+                2,
+            )),
+        ),
+        (
+            27_508,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::Rudder(1),
+                1,
+            )),
+        ),
+        (
+            27_509,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::Rudder(1),
+                2,
+            )),
+        ),
+        (
+            27_510,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::Rudder(1),
+                3,
+            )),
+        ),
+        (
+            27_511,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(1),
+                1,
+            )),
+        ),
+        (
+            27_512,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(2),
+                1,
+            )),
+        ),
+        (
+            27_513,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(3),
+                1,
+            )),
+        ),
+        (
+            27_514,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(4),
+                1,
+            )),
+        ),
+        (
+            27_515,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(5),
+                1,
+            )),
+        ),
+        (
+            27_516,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(1),
+                1,
+            )),
+        ),
+        (
+            27_517,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(2),
+                1,
+            )),
+        ),
+        (
+            27_518,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(3),
+                1,
+            )),
+        ),
+        (
+            27_519,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(4),
+                1,
+            )),
+        ),
+        (
+            27_520,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(5),
+                1,
+            )),
+        ),
         (29_000, FailureType::ReservoirLeak(HydraulicColor::Green)),
         (29_001, FailureType::ReservoirLeak(HydraulicColor::Blue)),
         (29_002, FailureType::ReservoirLeak(HydraulicColor::Yellow)),
@@ -198,6 +865,56 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             29_012,
             FailureType::ElecPumpOverheat(AirbusElectricPumpId::Yellow),
         ),
+        (
+            29_013,
+            FailureType::FireShutoffValveStuck(HydraulicColor::Green, 1),
+        ),
+        (
+            29_014,
+            FailureType::FireShutoffValveStuck(HydraulicColor::Yellow, 1),
+        ),
+        (
+            29_015,
+            FailureType::PriorityValveStuck(HydraulicColor::Green),
+        ),
+        (
+            29_016,
+            FailureType::PriorityValveStuck(HydraulicColor::Blue),
+        ),
+        (
+            29_017,
+            FailureType::PriorityValveStuck(HydraulicColor::Yellow),
+        ),
+        (
+            29_018,
+            FailureType::LeakMeasurementValveStuck(HydraulicColor::Green),
+        ),
+        (
+            29_019,
+            FailureType::LeakMeasurementValveStuck(HydraulicColor::Blue),
+        ),
+        (
+            29_020,
+            FailureType::LeakMeasurementValveStuck(HydraulicColor::Yellow),
+        ),
+        (
+            29_021,
+            FailureType::AccumulatorPrechargeLoss(HydraulicColor::Green),
+        ),
+        (
+            29_022,
+            FailureType::AccumulatorPrechargeLoss(HydraulicColor::Blue),
+        ),
+        (
+            29_023,
+            FailureType::AccumulatorPrechargeLoss(HydraulicColor::Yellow),
+        ),
+        (29_024, FailureType::PowerTransferUnitFailure),
+        (29_025, FailureType::PowerTransferUnitJam),
+        (30_000, FailureType::EngineAntiIceValveStuckClosed(1)),
+        (30_001, FailureType::EngineAntiIceValveStuckClosed(2)),
+        (30_002, FailureType::EngineAntiIcePressureSwitch(1)),
+        (30_003, FailureType::EngineAntiIcePressureSwitch(2)),
         (32_000, FailureType::LgciuPowerSupply(LgciuId::Lgciu1)),
         (32_001, FailureType::LgciuPowerSupply(LgciuId::Lgciu2)),
         (32_002, FailureType::LgciuInternalError(LgciuId::Lgciu1)),
@@ -274,6 +991,43 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             32_025,
             FailureType::GearActuatorJammed(GearActuatorId::GearDoorRight),
         ),
+        (
+            32_040,
+            FailureType::GearUplockJammed(GearActuatorId::GearNose),
+        ),
+        (
+            32_041,
+            FailureType::GearUplockJammed(GearActuatorId::GearLeft),
+        ),
+        (
+            32_042,
+            FailureType::GearUplockJammed(GearActuatorId::GearRight),
+        ),
+        (
+            32_043,
+            FailureType::GearUplockJammed(GearActuatorId::GearDoorNose),
+        ),
+        (
+            32_044,
+            FailureType::GearUplockJammed(GearActuatorId::GearDoorLeft),
+        ),
+        (
+            32_045,
+            FailureType::GearUplockJammed(GearActuatorId::GearDoorRight),
+        ),
+        (
+            32_050,
+            FailureType::GearDoorJammed(GearActuatorId::GearDoorNose),
+        ),
+        (
+            32_051,
+            FailureType::GearDoorJammed(GearActuatorId::GearDoorLeft),
+        ),
+        (
+            32_052,
+            FailureType::GearDoorJammed(GearActuatorId::GearDoorRight),
+        ),
+        (32_060, FailureType::GearSelectorValveStuck),
         (
             32_100,
             FailureType::BrakeHydraulicLeak(HydraulicColor::Green),
@@ -285,6 +1039,48 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
         (32_150, FailureType::BrakeAccumulatorGasLeak),
         (34_000, FailureType::RadioAltimeter(1)),
         (34_001, FailureType::RadioAltimeter(2)),
+        (
+            36_000,
+            FailureType::BleedDuctLeak(BleedDuctId::EnginePylon(1)),
+        ),
+        (
+            36_001,
+            FailureType::BleedDuctLeak(BleedDuctId::EnginePylon(2)),
+        ),
+        (36_002, FailureType::BleedDuctLeak(BleedDuctId::Wing(1))),
+        (36_003, FailureType::BleedDuctLeak(BleedDuctId::Wing(2))),
+        (36_004, FailureType::BleedDuctLeak(BleedDuctId::Apu)),
+        (36_010, FailureType::PressureRegulatingValveStuckOpen(1)),
+        (36_011, FailureType::PressureRegulatingValveStuckOpen(2)),
+        (36_012, FailureType::PressureRegulatingValveStuckClosed(1)),
+        (36_013, FailureType::PressureRegulatingValveStuckClosed(2)),
+        (36_014, FailureType::HighPressureValveStuckOpen(1)),
+        (36_015, FailureType::HighPressureValveStuckOpen(2)),
+        (36_016, FailureType::CrossBleedValveStuck),
+        (36_017, FailureType::PrecoolerClogged(1)),
+        (36_018, FailureType::PrecoolerClogged(2)),
+        (49_000, FailureType::ApuEgtSensor),
+        (49_001, FailureType::ApuSpeedSensor),
+        (49_002, FailureType::ApuInletPressureSensor),
+        (49_003, FailureType::ApuFuelControlUnit),
+        (49_004, FailureType::ApuOilLowPressure),
+        (49_005, FailureType::ApuAirIntakeFlapStuck),
+        (
+            78_000,
+            FailureType::ReverserDoorUnlocked(ReverserDoorId::new(1, 1)),
+        ),
+        (
+            78_001,
+            FailureType::ReverserDoorUnlocked(ReverserDoorId::new(1, 2)),
+        ),
+        (
+            78_002,
+            FailureType::ReverserDoorUnlocked(ReverserDoorId::new(2, 1)),
+        ),
+        (
+            78_003,
+            FailureType::ReverserDoorUnlocked(ReverserDoorId::new(2, 2)),
+        ),
     ])
     .provides_aircraft_variable("ACCELERATION BODY X", "feet per second squared", 0)?
     .provides_aircraft_variable("ACCELERATION BODY Y", "feet per second squared", 0)?
@@ -303,6 +1099,8 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .provides_aircraft_variable("AMBIENT WIND Y", "meter per second", 0)?
     .provides_aircraft_variable("AMBIENT WIND Z", "meter per second", 0)?
     .provides_aircraft_variable("ANTISKID BRAKES ACTIVE", "Bool", 0)?
+    .provides_aircraft_variable("ENG ON FIRE", "Bool", 1)?
+    .provides_aircraft_variable("ENG ON FIRE", "Bool", 2)?
     .provides_aircraft_variable("EXTERNAL POWER AVAILABLE", "Bool", 1)?
     .provides_aircraft_variable("FUEL TANK CENTER QUANTITY", "gallons", 0)?
     .provides_aircraft_variable("FUEL TANK LEFT MAIN QUANTITY", "gallons", 0)?
@@ -378,6 +1176,7 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .provides_named_variable("FSDT_GSX_BOARDING_CARGO_PERCENT")?
     .provides_named_variable("FSDT_GSX_DEBOARDING_CARGO_PERCENT")?
     .provides_named_variable("FSDT_GSX_BYPASS_PIN")?
+    .provides_named_variable("PUSH_OVHD_OXYGEN_CREW")?
     .with_aspect(|builder| {
         builder.copy(
             Variable::named("FSDT_GSX_BYPASS_PIN"),
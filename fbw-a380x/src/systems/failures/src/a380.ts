@@ -5,10 +5,206 @@
 import { FailureDefinition } from "@flybywiresim/fbw-sdk";
 
 export const A380Failure = Object.freeze({
+    CabinDoorSealLeak: 21020,
+    CabinWindowCrack: 21021,
+    CargoDoorUnsealed: 21022,
+
     TransformerRectifier1: 24000,
     TransformerRectifier2: 24001,
     TransformerRectifierEssential: 24002,
 
+    Eng1Fire: 26000,
+    Eng2Fire: 26001,
+    Eng3Fire: 26002,
+    Eng4Fire: 26003,
+    ApuFire: 26004,
+    Eng1FireLoopA: 26010,
+    Eng1FireLoopB: 26011,
+    Eng2FireLoopA: 26012,
+    Eng2FireLoopB: 26013,
+    Eng3FireLoopA: 26014,
+    Eng3FireLoopB: 26015,
+    Eng4FireLoopA: 26016,
+    Eng4FireLoopB: 26017,
+    ApuFireLoopA: 26018,
+    ApuFireLoopB: 26019,
+    FwdCargoSmoke: 26100,
+    AftCargoSmoke: 26101,
+    BulkCargoSmoke: 26102,
+    LavatorySmoke: 26103,
+    FwdCargoSmokeDetector1: 26110,
+    FwdCargoSmokeDetector2: 26111,
+    AftCargoSmokeDetector1: 26112,
+    AftCargoSmokeDetector2: 26113,
+    BulkCargoSmokeDetector1: 26114,
+    BulkCargoSmokeDetector2: 26115,
+    LavatorySmokeDetector: 26116,
+
+    FlapsLeftJam: 27100,
+    FlapsRightJam: 27101,
+    SlatsLeftJam: 27102,
+    SlatsRightJam: 27103,
+    LeftOutwardAileronOutwardActuatorJam: 27200,
+    LeftOutwardAileronInwardActuatorJam: 27201,
+    LeftMiddleAileronOutwardActuatorJam: 27202,
+    LeftMiddleAileronInwardActuatorJam: 27203,
+    LeftInwardAileronOutwardActuatorJam: 27204,
+    LeftInwardAileronInwardActuatorJam: 27205,
+    RightOutwardAileronOutwardActuatorJam: 27206,
+    RightOutwardAileronInwardActuatorJam: 27207,
+    RightMiddleAileronOutwardActuatorJam: 27208,
+    RightMiddleAileronInwardActuatorJam: 27209,
+    RightInwardAileronOutwardActuatorJam: 27210,
+    RightInwardAileronInwardActuatorJam: 27211,
+    LeftOutwardElevatorOutwardActuatorJam: 27212,
+    LeftOutwardElevatorInwardActuatorJam: 27213,
+    LeftInwardElevatorOutwardActuatorJam: 27214,
+    LeftInwardElevatorInwardActuatorJam: 27215,
+    RightOutwardElevatorOutwardActuatorJam: 27216,
+    RightOutwardElevatorInwardActuatorJam: 27217,
+    RightInwardElevatorOutwardActuatorJam: 27218,
+    RightInwardElevatorInwardActuatorJam: 27219,
+    UpperRudderUpperActuatorJam: 27220,
+    UpperRudderLowerActuatorJam: 27221,
+    LowerRudderUpperActuatorJam: 27222,
+    LowerRudderLowerActuatorJam: 27223,
+    LeftSpoiler1ActuatorJam: 27224,
+    LeftSpoiler2ActuatorJam: 27225,
+    LeftSpoiler3ActuatorJam: 27226,
+    LeftSpoiler4ActuatorJam: 27227,
+    LeftSpoiler5ActuatorJam: 27228,
+    LeftSpoiler6ActuatorJam: 27229,
+    LeftSpoiler7ActuatorJam: 27230,
+    LeftSpoiler8ActuatorJam: 27231,
+    RightSpoiler1ActuatorJam: 27232,
+    RightSpoiler2ActuatorJam: 27233,
+    RightSpoiler3ActuatorJam: 27234,
+    RightSpoiler4ActuatorJam: 27235,
+    RightSpoiler5ActuatorJam: 27236,
+    RightSpoiler6ActuatorJam: 27237,
+    RightSpoiler7ActuatorJam: 27238,
+    RightSpoiler8ActuatorJam: 27239,
+    LeftOutwardAileronOutwardActuatorRunaway: 27300,
+    LeftOutwardAileronInwardActuatorRunaway: 27301,
+    LeftMiddleAileronOutwardActuatorRunaway: 27302,
+    LeftMiddleAileronInwardActuatorRunaway: 27303,
+    LeftInwardAileronOutwardActuatorRunaway: 27304,
+    LeftInwardAileronInwardActuatorRunaway: 27305,
+    RightOutwardAileronOutwardActuatorRunaway: 27306,
+    RightOutwardAileronInwardActuatorRunaway: 27307,
+    RightMiddleAileronOutwardActuatorRunaway: 27308,
+    RightMiddleAileronInwardActuatorRunaway: 27309,
+    RightInwardAileronOutwardActuatorRunaway: 27310,
+    RightInwardAileronInwardActuatorRunaway: 27311,
+    LeftOutwardElevatorOutwardActuatorRunaway: 27312,
+    LeftOutwardElevatorInwardActuatorRunaway: 27313,
+    LeftInwardElevatorOutwardActuatorRunaway: 27314,
+    LeftInwardElevatorInwardActuatorRunaway: 27315,
+    RightOutwardElevatorOutwardActuatorRunaway: 27316,
+    RightOutwardElevatorInwardActuatorRunaway: 27317,
+    RightInwardElevatorOutwardActuatorRunaway: 27318,
+    RightInwardElevatorInwardActuatorRunaway: 27319,
+    UpperRudderUpperActuatorRunaway: 27320,
+    UpperRudderLowerActuatorRunaway: 27321,
+    LowerRudderUpperActuatorRunaway: 27322,
+    LowerRudderLowerActuatorRunaway: 27323,
+    LeftSpoiler1ActuatorRunaway: 27324,
+    LeftSpoiler2ActuatorRunaway: 27325,
+    LeftSpoiler3ActuatorRunaway: 27326,
+    LeftSpoiler4ActuatorRunaway: 27327,
+    LeftSpoiler5ActuatorRunaway: 27328,
+    LeftSpoiler6ActuatorRunaway: 27329,
+    LeftSpoiler7ActuatorRunaway: 27330,
+    LeftSpoiler8ActuatorRunaway: 27331,
+    RightSpoiler1ActuatorRunaway: 27332,
+    RightSpoiler2ActuatorRunaway: 27333,
+    RightSpoiler3ActuatorRunaway: 27334,
+    RightSpoiler4ActuatorRunaway: 27335,
+    RightSpoiler5ActuatorRunaway: 27336,
+    RightSpoiler6ActuatorRunaway: 27337,
+    RightSpoiler7ActuatorRunaway: 27338,
+    RightSpoiler8ActuatorRunaway: 27339,
+    LeftOutwardAileronOutwardActuatorDisconnect: 27400,
+    LeftOutwardAileronInwardActuatorDisconnect: 27401,
+    LeftMiddleAileronOutwardActuatorDisconnect: 27402,
+    LeftMiddleAileronInwardActuatorDisconnect: 27403,
+    LeftInwardAileronOutwardActuatorDisconnect: 27404,
+    LeftInwardAileronInwardActuatorDisconnect: 27405,
+    RightOutwardAileronOutwardActuatorDisconnect: 27406,
+    RightOutwardAileronInwardActuatorDisconnect: 27407,
+    RightMiddleAileronOutwardActuatorDisconnect: 27408,
+    RightMiddleAileronInwardActuatorDisconnect: 27409,
+    RightInwardAileronOutwardActuatorDisconnect: 27410,
+    RightInwardAileronInwardActuatorDisconnect: 27411,
+    LeftOutwardElevatorOutwardActuatorDisconnect: 27412,
+    LeftOutwardElevatorInwardActuatorDisconnect: 27413,
+    LeftInwardElevatorOutwardActuatorDisconnect: 27414,
+    LeftInwardElevatorInwardActuatorDisconnect: 27415,
+    RightOutwardElevatorOutwardActuatorDisconnect: 27416,
+    RightOutwardElevatorInwardActuatorDisconnect: 27417,
+    RightInwardElevatorOutwardActuatorDisconnect: 27418,
+    RightInwardElevatorInwardActuatorDisconnect: 27419,
+    UpperRudderUpperActuatorDisconnect: 27420,
+    UpperRudderLowerActuatorDisconnect: 27421,
+    LowerRudderUpperActuatorDisconnect: 27422,
+    LowerRudderLowerActuatorDisconnect: 27423,
+    LeftSpoiler1ActuatorDisconnect: 27424,
+    LeftSpoiler2ActuatorDisconnect: 27425,
+    LeftSpoiler3ActuatorDisconnect: 27426,
+    LeftSpoiler4ActuatorDisconnect: 27427,
+    LeftSpoiler5ActuatorDisconnect: 27428,
+    LeftSpoiler6ActuatorDisconnect: 27429,
+    LeftSpoiler7ActuatorDisconnect: 27430,
+    LeftSpoiler8ActuatorDisconnect: 27431,
+    RightSpoiler1ActuatorDisconnect: 27432,
+    RightSpoiler2ActuatorDisconnect: 27433,
+    RightSpoiler3ActuatorDisconnect: 27434,
+    RightSpoiler4ActuatorDisconnect: 27435,
+    RightSpoiler5ActuatorDisconnect: 27436,
+    RightSpoiler6ActuatorDisconnect: 27437,
+    RightSpoiler7ActuatorDisconnect: 27438,
+    RightSpoiler8ActuatorDisconnect: 27439,
+    LeftOutwardAileronOutwardActuatorRodEndFracture: 27500,
+    LeftOutwardAileronInwardActuatorRodEndFracture: 27501,
+    LeftMiddleAileronOutwardActuatorRodEndFracture: 27502,
+    LeftMiddleAileronInwardActuatorRodEndFracture: 27503,
+    LeftInwardAileronOutwardActuatorRodEndFracture: 27504,
+    LeftInwardAileronInwardActuatorRodEndFracture: 27505,
+    RightOutwardAileronOutwardActuatorRodEndFracture: 27506,
+    RightOutwardAileronInwardActuatorRodEndFracture: 27507,
+    RightMiddleAileronOutwardActuatorRodEndFracture: 27508,
+    RightMiddleAileronInwardActuatorRodEndFracture: 27509,
+    RightInwardAileronOutwardActuatorRodEndFracture: 27510,
+    RightInwardAileronInwardActuatorRodEndFracture: 27511,
+    LeftOutwardElevatorOutwardActuatorRodEndFracture: 27512,
+    LeftOutwardElevatorInwardActuatorRodEndFracture: 27513,
+    LeftInwardElevatorOutwardActuatorRodEndFracture: 27514,
+    LeftInwardElevatorInwardActuatorRodEndFracture: 27515,
+    RightOutwardElevatorOutwardActuatorRodEndFracture: 27516,
+    RightOutwardElevatorInwardActuatorRodEndFracture: 27517,
+    RightInwardElevatorOutwardActuatorRodEndFracture: 27518,
+    RightInwardElevatorInwardActuatorRodEndFracture: 27519,
+    UpperRudderUpperActuatorRodEndFracture: 27520,
+    UpperRudderLowerActuatorRodEndFracture: 27521,
+    LowerRudderUpperActuatorRodEndFracture: 27522,
+    LowerRudderLowerActuatorRodEndFracture: 27523,
+    LeftSpoiler1ActuatorRodEndFracture: 27524,
+    LeftSpoiler2ActuatorRodEndFracture: 27525,
+    LeftSpoiler3ActuatorRodEndFracture: 27526,
+    LeftSpoiler4ActuatorRodEndFracture: 27527,
+    LeftSpoiler5ActuatorRodEndFracture: 27528,
+    LeftSpoiler6ActuatorRodEndFracture: 27529,
+    LeftSpoiler7ActuatorRodEndFracture: 27530,
+    LeftSpoiler8ActuatorRodEndFracture: 27531,
+    RightSpoiler1ActuatorRodEndFracture: 27532,
+    RightSpoiler2ActuatorRodEndFracture: 27533,
+    RightSpoiler3ActuatorRodEndFracture: 27534,
+    RightSpoiler4ActuatorRodEndFracture: 27535,
+    RightSpoiler5ActuatorRodEndFracture: 27536,
+    RightSpoiler6ActuatorRodEndFracture: 27537,
+    RightSpoiler7ActuatorRodEndFracture: 27538,
+    RightSpoiler8ActuatorRodEndFracture: 27539,
+
     GreenReservoirLeak: 29000,
     BlueReservoirLeak: 29001,
     YellowReservoirLeak: 29002,
@@ -18,6 +214,29 @@ export const A380Failure = Object.freeze({
     GreenReservoirReturnLeak: 29006,
     BlueReservoirReturnLeak: 29007,
     YellowReservoirReturnLeak: 29008,
+    Eng1EdpAFireShutoffValveStuck: 29013,
+    Eng1EdpBFireShutoffValveStuck: 29014,
+    Eng2EdpAFireShutoffValveStuck: 29015,
+    Eng2EdpBFireShutoffValveStuck: 29016,
+    Eng3EdpAFireShutoffValveStuck: 29017,
+    Eng3EdpBFireShutoffValveStuck: 29018,
+    Eng4EdpAFireShutoffValveStuck: 29019,
+    Eng4EdpBFireShutoffValveStuck: 29020,
+    GreenPriorityValveStuck: 29021,
+    YellowPriorityValveStuck: 29022,
+    GreenLeakMeasurementValveStuck: 29023,
+    YellowLeakMeasurementValveStuck: 29024,
+    GreenAccumulatorPrechargeLoss: 29025,
+    YellowAccumulatorPrechargeLoss: 29026,
+
+    Eng1AntiIceValveStuckClosed: 30000,
+    Eng2AntiIceValveStuckClosed: 30001,
+    Eng3AntiIceValveStuckClosed: 30002,
+    Eng4AntiIceValveStuckClosed: 30003,
+    Eng1AntiIcePressureSwitch: 30004,
+    Eng2AntiIcePressureSwitch: 30005,
+    Eng3AntiIcePressureSwitch: 30006,
+    Eng4AntiIcePressureSwitch: 30007,
 
     LeftPfdDisplay: 31000,
     RightPfdDisplay: 31001,
@@ -32,15 +251,233 @@ export const A380Failure = Object.freeze({
     GearProxSensorDamageGearUplockNose1: 32006,
     GearProxSensorDamageDoorUplockLeft2: 32007,
 
+    BodyWheelSteering: 32030,
+
+    GearUplockJammedGearNose: 32040,
+    GearUplockJammedGearLeft: 32041,
+    GearUplockJammedGearRight: 32042,
+    GearUplockJammedGearDoorNose: 32043,
+    GearUplockJammedGearDoorLeft: 32044,
+    GearUplockJammedGearDoorRight: 32045,
+
+    GearDoorJammedNose: 32050,
+    GearDoorJammedLeft: 32051,
+    GearDoorJammedRight: 32052,
+
+    GearSelectorValveStuck: 32060,
+
     RadioAltimeter1: 34000,
     RadioAltimeter2: 34001,
+
+    ApuEgtSensor: 49000,
+    ApuSpeedSensor: 49001,
+    ApuInletPressureSensor: 49002,
+    ApuFuelControlUnit: 49003,
+    ApuOilLowPressure: 49004,
+    ApuAirIntakeFlapStuck: 49005,
 });
 
 export const A380FailureDefinitions: FailureDefinition[] = [
+    [21, A380Failure.CabinDoorSealLeak, 'Cabin Door Seal Leak'],
+    [21, A380Failure.CabinWindowCrack, 'Cabin Window Crack'],
+    [21, A380Failure.CargoDoorUnsealed, 'Cargo Door Not Sealed'],
+
     [24, A380Failure.TransformerRectifier1, 'TR 1'],
     [24, A380Failure.TransformerRectifier2, 'TR 2'],
     [24, A380Failure.TransformerRectifierEssential, 'ESS TR'],
 
+    [26, A380Failure.Eng1Fire, 'Engine 1 fire'],
+    [26, A380Failure.Eng2Fire, 'Engine 2 fire'],
+    [26, A380Failure.Eng3Fire, 'Engine 3 fire'],
+    [26, A380Failure.Eng4Fire, 'Engine 4 fire'],
+    [26, A380Failure.ApuFire, 'APU fire'],
+    [26, A380Failure.Eng1FireLoopA, 'Engine 1 fire detection loop A'],
+    [26, A380Failure.Eng1FireLoopB, 'Engine 1 fire detection loop B'],
+    [26, A380Failure.Eng2FireLoopA, 'Engine 2 fire detection loop A'],
+    [26, A380Failure.Eng2FireLoopB, 'Engine 2 fire detection loop B'],
+    [26, A380Failure.Eng3FireLoopA, 'Engine 3 fire detection loop A'],
+    [26, A380Failure.Eng3FireLoopB, 'Engine 3 fire detection loop B'],
+    [26, A380Failure.Eng4FireLoopA, 'Engine 4 fire detection loop A'],
+    [26, A380Failure.Eng4FireLoopB, 'Engine 4 fire detection loop B'],
+    [26, A380Failure.ApuFireLoopA, 'APU fire detection loop A'],
+    [26, A380Failure.ApuFireLoopB, 'APU fire detection loop B'],
+    [26, A380Failure.FwdCargoSmoke, 'Forward cargo smoke'],
+    [26, A380Failure.AftCargoSmoke, 'Aft cargo smoke'],
+    [26, A380Failure.BulkCargoSmoke, 'Bulk cargo smoke'],
+    [26, A380Failure.LavatorySmoke, 'Lavatory smoke'],
+    [26, A380Failure.FwdCargoSmokeDetector1, 'Forward cargo smoke detector 1'],
+    [26, A380Failure.FwdCargoSmokeDetector2, 'Forward cargo smoke detector 2'],
+    [26, A380Failure.AftCargoSmokeDetector1, 'Aft cargo smoke detector 1'],
+    [26, A380Failure.AftCargoSmokeDetector2, 'Aft cargo smoke detector 2'],
+    [26, A380Failure.BulkCargoSmokeDetector1, 'Bulk cargo smoke detector 1'],
+    [26, A380Failure.BulkCargoSmokeDetector2, 'Bulk cargo smoke detector 2'],
+    [26, A380Failure.LavatorySmokeDetector, 'Lavatory smoke detector'],
+
+    [27, A380Failure.FlapsLeftJam, 'Left flaps transmission jam'],
+    [27, A380Failure.FlapsRightJam, 'Right flaps transmission jam'],
+    [27, A380Failure.SlatsLeftJam, 'Left slats transmission jam'],
+    [27, A380Failure.SlatsRightJam, 'Right slats transmission jam'],
+    [27, A380Failure.LeftOutwardAileronOutwardActuatorJam, 'Left outward aileron outward actuator jam'],
+    [27, A380Failure.LeftOutwardAileronInwardActuatorJam, 'Left outward aileron inward actuator jam'],
+    [27, A380Failure.LeftMiddleAileronOutwardActuatorJam, 'Left middle aileron outward actuator jam'],
+    [27, A380Failure.LeftMiddleAileronInwardActuatorJam, 'Left middle aileron inward actuator jam'],
+    [27, A380Failure.LeftInwardAileronOutwardActuatorJam, 'Left inward aileron outward actuator jam'],
+    [27, A380Failure.LeftInwardAileronInwardActuatorJam, 'Left inward aileron inward actuator jam'],
+    [27, A380Failure.RightOutwardAileronOutwardActuatorJam, 'Right outward aileron outward actuator jam'],
+    [27, A380Failure.RightOutwardAileronInwardActuatorJam, 'Right outward aileron inward actuator jam'],
+    [27, A380Failure.RightMiddleAileronOutwardActuatorJam, 'Right middle aileron outward actuator jam'],
+    [27, A380Failure.RightMiddleAileronInwardActuatorJam, 'Right middle aileron inward actuator jam'],
+    [27, A380Failure.RightInwardAileronOutwardActuatorJam, 'Right inward aileron outward actuator jam'],
+    [27, A380Failure.RightInwardAileronInwardActuatorJam, 'Right inward aileron inward actuator jam'],
+    [27, A380Failure.LeftOutwardElevatorOutwardActuatorJam, 'Left outward elevator outward actuator jam'],
+    [27, A380Failure.LeftOutwardElevatorInwardActuatorJam, 'Left outward elevator inward actuator jam'],
+    [27, A380Failure.LeftInwardElevatorOutwardActuatorJam, 'Left inward elevator outward actuator jam'],
+    [27, A380Failure.LeftInwardElevatorInwardActuatorJam, 'Left inward elevator inward actuator jam'],
+    [27, A380Failure.RightOutwardElevatorOutwardActuatorJam, 'Right outward elevator outward actuator jam'],
+    [27, A380Failure.RightOutwardElevatorInwardActuatorJam, 'Right outward elevator inward actuator jam'],
+    [27, A380Failure.RightInwardElevatorOutwardActuatorJam, 'Right inward elevator outward actuator jam'],
+    [27, A380Failure.RightInwardElevatorInwardActuatorJam, 'Right inward elevator inward actuator jam'],
+    [27, A380Failure.UpperRudderUpperActuatorJam, 'Upper rudder upper actuator jam'],
+    [27, A380Failure.UpperRudderLowerActuatorJam, 'Upper rudder lower actuator jam'],
+    [27, A380Failure.LowerRudderUpperActuatorJam, 'Lower rudder upper actuator jam'],
+    [27, A380Failure.LowerRudderLowerActuatorJam, 'Lower rudder lower actuator jam'],
+    [27, A380Failure.LeftSpoiler1ActuatorJam, 'Left spoiler 1 actuator jam'],
+    [27, A380Failure.LeftSpoiler2ActuatorJam, 'Left spoiler 2 actuator jam'],
+    [27, A380Failure.LeftSpoiler3ActuatorJam, 'Left spoiler 3 actuator jam'],
+    [27, A380Failure.LeftSpoiler4ActuatorJam, 'Left spoiler 4 actuator jam'],
+    [27, A380Failure.LeftSpoiler5ActuatorJam, 'Left spoiler 5 actuator jam'],
+    [27, A380Failure.LeftSpoiler6ActuatorJam, 'Left spoiler 6 actuator jam'],
+    [27, A380Failure.LeftSpoiler7ActuatorJam, 'Left spoiler 7 actuator jam'],
+    [27, A380Failure.LeftSpoiler8ActuatorJam, 'Left spoiler 8 actuator jam'],
+    [27, A380Failure.RightSpoiler1ActuatorJam, 'Right spoiler 1 actuator jam'],
+    [27, A380Failure.RightSpoiler2ActuatorJam, 'Right spoiler 2 actuator jam'],
+    [27, A380Failure.RightSpoiler3ActuatorJam, 'Right spoiler 3 actuator jam'],
+    [27, A380Failure.RightSpoiler4ActuatorJam, 'Right spoiler 4 actuator jam'],
+    [27, A380Failure.RightSpoiler5ActuatorJam, 'Right spoiler 5 actuator jam'],
+    [27, A380Failure.RightSpoiler6ActuatorJam, 'Right spoiler 6 actuator jam'],
+    [27, A380Failure.RightSpoiler7ActuatorJam, 'Right spoiler 7 actuator jam'],
+    [27, A380Failure.RightSpoiler8ActuatorJam, 'Right spoiler 8 actuator jam'],
+    [27, A380Failure.LeftOutwardAileronOutwardActuatorRunaway, 'Left outward aileron outward actuator runaway'],
+    [27, A380Failure.LeftOutwardAileronInwardActuatorRunaway, 'Left outward aileron inward actuator runaway'],
+    [27, A380Failure.LeftMiddleAileronOutwardActuatorRunaway, 'Left middle aileron outward actuator runaway'],
+    [27, A380Failure.LeftMiddleAileronInwardActuatorRunaway, 'Left middle aileron inward actuator runaway'],
+    [27, A380Failure.LeftInwardAileronOutwardActuatorRunaway, 'Left inward aileron outward actuator runaway'],
+    [27, A380Failure.LeftInwardAileronInwardActuatorRunaway, 'Left inward aileron inward actuator runaway'],
+    [27, A380Failure.RightOutwardAileronOutwardActuatorRunaway, 'Right outward aileron outward actuator runaway'],
+    [27, A380Failure.RightOutwardAileronInwardActuatorRunaway, 'Right outward aileron inward actuator runaway'],
+    [27, A380Failure.RightMiddleAileronOutwardActuatorRunaway, 'Right middle aileron outward actuator runaway'],
+    [27, A380Failure.RightMiddleAileronInwardActuatorRunaway, 'Right middle aileron inward actuator runaway'],
+    [27, A380Failure.RightInwardAileronOutwardActuatorRunaway, 'Right inward aileron outward actuator runaway'],
+    [27, A380Failure.RightInwardAileronInwardActuatorRunaway, 'Right inward aileron inward actuator runaway'],
+    [27, A380Failure.LeftOutwardElevatorOutwardActuatorRunaway, 'Left outward elevator outward actuator runaway'],
+    [27, A380Failure.LeftOutwardElevatorInwardActuatorRunaway, 'Left outward elevator inward actuator runaway'],
+    [27, A380Failure.LeftInwardElevatorOutwardActuatorRunaway, 'Left inward elevator outward actuator runaway'],
+    [27, A380Failure.LeftInwardElevatorInwardActuatorRunaway, 'Left inward elevator inward actuator runaway'],
+    [27, A380Failure.RightOutwardElevatorOutwardActuatorRunaway, 'Right outward elevator outward actuator runaway'],
+    [27, A380Failure.RightOutwardElevatorInwardActuatorRunaway, 'Right outward elevator inward actuator runaway'],
+    [27, A380Failure.RightInwardElevatorOutwardActuatorRunaway, 'Right inward elevator outward actuator runaway'],
+    [27, A380Failure.RightInwardElevatorInwardActuatorRunaway, 'Right inward elevator inward actuator runaway'],
+    [27, A380Failure.UpperRudderUpperActuatorRunaway, 'Upper rudder upper actuator runaway'],
+    [27, A380Failure.UpperRudderLowerActuatorRunaway, 'Upper rudder lower actuator runaway'],
+    [27, A380Failure.LowerRudderUpperActuatorRunaway, 'Lower rudder upper actuator runaway'],
+    [27, A380Failure.LowerRudderLowerActuatorRunaway, 'Lower rudder lower actuator runaway'],
+    [27, A380Failure.LeftSpoiler1ActuatorRunaway, 'Left spoiler 1 actuator runaway'],
+    [27, A380Failure.LeftSpoiler2ActuatorRunaway, 'Left spoiler 2 actuator runaway'],
+    [27, A380Failure.LeftSpoiler3ActuatorRunaway, 'Left spoiler 3 actuator runaway'],
+    [27, A380Failure.LeftSpoiler4ActuatorRunaway, 'Left spoiler 4 actuator runaway'],
+    [27, A380Failure.LeftSpoiler5ActuatorRunaway, 'Left spoiler 5 actuator runaway'],
+    [27, A380Failure.LeftSpoiler6ActuatorRunaway, 'Left spoiler 6 actuator runaway'],
+    [27, A380Failure.LeftSpoiler7ActuatorRunaway, 'Left spoiler 7 actuator runaway'],
+    [27, A380Failure.LeftSpoiler8ActuatorRunaway, 'Left spoiler 8 actuator runaway'],
+    [27, A380Failure.RightSpoiler1ActuatorRunaway, 'Right spoiler 1 actuator runaway'],
+    [27, A380Failure.RightSpoiler2ActuatorRunaway, 'Right spoiler 2 actuator runaway'],
+    [27, A380Failure.RightSpoiler3ActuatorRunaway, 'Right spoiler 3 actuator runaway'],
+    [27, A380Failure.RightSpoiler4ActuatorRunaway, 'Right spoiler 4 actuator runaway'],
+    [27, A380Failure.RightSpoiler5ActuatorRunaway, 'Right spoiler 5 actuator runaway'],
+    [27, A380Failure.RightSpoiler6ActuatorRunaway, 'Right spoiler 6 actuator runaway'],
+    [27, A380Failure.RightSpoiler7ActuatorRunaway, 'Right spoiler 7 actuator runaway'],
+    [27, A380Failure.RightSpoiler8ActuatorRunaway, 'Right spoiler 8 actuator runaway'],
+    [27, A380Failure.LeftOutwardAileronOutwardActuatorDisconnect, 'Left outward aileron outward actuator servo disconnect'],
+    [27, A380Failure.LeftOutwardAileronInwardActuatorDisconnect, 'Left outward aileron inward actuator servo disconnect'],
+    [27, A380Failure.LeftMiddleAileronOutwardActuatorDisconnect, 'Left middle aileron outward actuator servo disconnect'],
+    [27, A380Failure.LeftMiddleAileronInwardActuatorDisconnect, 'Left middle aileron inward actuator servo disconnect'],
+    [27, A380Failure.LeftInwardAileronOutwardActuatorDisconnect, 'Left inward aileron outward actuator servo disconnect'],
+    [27, A380Failure.LeftInwardAileronInwardActuatorDisconnect, 'Left inward aileron inward actuator servo disconnect'],
+    [27, A380Failure.RightOutwardAileronOutwardActuatorDisconnect, 'Right outward aileron outward actuator servo disconnect'],
+    [27, A380Failure.RightOutwardAileronInwardActuatorDisconnect, 'Right outward aileron inward actuator servo disconnect'],
+    [27, A380Failure.RightMiddleAileronOutwardActuatorDisconnect, 'Right middle aileron outward actuator servo disconnect'],
+    [27, A380Failure.RightMiddleAileronInwardActuatorDisconnect, 'Right middle aileron inward actuator servo disconnect'],
+    [27, A380Failure.RightInwardAileronOutwardActuatorDisconnect, 'Right inward aileron outward actuator servo disconnect'],
+    [27, A380Failure.RightInwardAileronInwardActuatorDisconnect, 'Right inward aileron inward actuator servo disconnect'],
+    [27, A380Failure.LeftOutwardElevatorOutwardActuatorDisconnect, 'Left outward elevator outward actuator servo disconnect'],
+    [27, A380Failure.LeftOutwardElevatorInwardActuatorDisconnect, 'Left outward elevator inward actuator servo disconnect'],
+    [27, A380Failure.LeftInwardElevatorOutwardActuatorDisconnect, 'Left inward elevator outward actuator servo disconnect'],
+    [27, A380Failure.LeftInwardElevatorInwardActuatorDisconnect, 'Left inward elevator inward actuator servo disconnect'],
+    [27, A380Failure.RightOutwardElevatorOutwardActuatorDisconnect, 'Right outward elevator outward actuator servo disconnect'],
+    [27, A380Failure.RightOutwardElevatorInwardActuatorDisconnect, 'Right outward elevator inward actuator servo disconnect'],
+    [27, A380Failure.RightInwardElevatorOutwardActuatorDisconnect, 'Right inward elevator outward actuator servo disconnect'],
+    [27, A380Failure.RightInwardElevatorInwardActuatorDisconnect, 'Right inward elevator inward actuator servo disconnect'],
+    [27, A380Failure.UpperRudderUpperActuatorDisconnect, 'Upper rudder upper actuator servo disconnect'],
+    [27, A380Failure.UpperRudderLowerActuatorDisconnect, 'Upper rudder lower actuator servo disconnect'],
+    [27, A380Failure.LowerRudderUpperActuatorDisconnect, 'Lower rudder upper actuator servo disconnect'],
+    [27, A380Failure.LowerRudderLowerActuatorDisconnect, 'Lower rudder lower actuator servo disconnect'],
+    [27, A380Failure.LeftSpoiler1ActuatorDisconnect, 'Left spoiler 1 actuator servo disconnect'],
+    [27, A380Failure.LeftSpoiler2ActuatorDisconnect, 'Left spoiler 2 actuator servo disconnect'],
+    [27, A380Failure.LeftSpoiler3ActuatorDisconnect, 'Left spoiler 3 actuator servo disconnect'],
+    [27, A380Failure.LeftSpoiler4ActuatorDisconnect, 'Left spoiler 4 actuator servo disconnect'],
+    [27, A380Failure.LeftSpoiler5ActuatorDisconnect, 'Left spoiler 5 actuator servo disconnect'],
+    [27, A380Failure.LeftSpoiler6ActuatorDisconnect, 'Left spoiler 6 actuator servo disconnect'],
+    [27, A380Failure.LeftSpoiler7ActuatorDisconnect, 'Left spoiler 7 actuator servo disconnect'],
+    [27, A380Failure.LeftSpoiler8ActuatorDisconnect, 'Left spoiler 8 actuator servo disconnect'],
+    [27, A380Failure.RightSpoiler1ActuatorDisconnect, 'Right spoiler 1 actuator servo disconnect'],
+    [27, A380Failure.RightSpoiler2ActuatorDisconnect, 'Right spoiler 2 actuator servo disconnect'],
+    [27, A380Failure.RightSpoiler3ActuatorDisconnect, 'Right spoiler 3 actuator servo disconnect'],
+    [27, A380Failure.RightSpoiler4ActuatorDisconnect, 'Right spoiler 4 actuator servo disconnect'],
+    [27, A380Failure.RightSpoiler5ActuatorDisconnect, 'Right spoiler 5 actuator servo disconnect'],
+    [27, A380Failure.RightSpoiler6ActuatorDisconnect, 'Right spoiler 6 actuator servo disconnect'],
+    [27, A380Failure.RightSpoiler7ActuatorDisconnect, 'Right spoiler 7 actuator servo disconnect'],
+    [27, A380Failure.RightSpoiler8ActuatorDisconnect, 'Right spoiler 8 actuator servo disconnect'],
+    [27, A380Failure.LeftOutwardAileronOutwardActuatorRodEndFracture, 'Left outward aileron outward actuator rod end fracture'],
+    [27, A380Failure.LeftOutwardAileronInwardActuatorRodEndFracture, 'Left outward aileron inward actuator rod end fracture'],
+    [27, A380Failure.LeftMiddleAileronOutwardActuatorRodEndFracture, 'Left middle aileron outward actuator rod end fracture'],
+    [27, A380Failure.LeftMiddleAileronInwardActuatorRodEndFracture, 'Left middle aileron inward actuator rod end fracture'],
+    [27, A380Failure.LeftInwardAileronOutwardActuatorRodEndFracture, 'Left inward aileron outward actuator rod end fracture'],
+    [27, A380Failure.LeftInwardAileronInwardActuatorRodEndFracture, 'Left inward aileron inward actuator rod end fracture'],
+    [27, A380Failure.RightOutwardAileronOutwardActuatorRodEndFracture, 'Right outward aileron outward actuator rod end fracture'],
+    [27, A380Failure.RightOutwardAileronInwardActuatorRodEndFracture, 'Right outward aileron inward actuator rod end fracture'],
+    [27, A380Failure.RightMiddleAileronOutwardActuatorRodEndFracture, 'Right middle aileron outward actuator rod end fracture'],
+    [27, A380Failure.RightMiddleAileronInwardActuatorRodEndFracture, 'Right middle aileron inward actuator rod end fracture'],
+    [27, A380Failure.RightInwardAileronOutwardActuatorRodEndFracture, 'Right inward aileron outward actuator rod end fracture'],
+    [27, A380Failure.RightInwardAileronInwardActuatorRodEndFracture, 'Right inward aileron inward actuator rod end fracture'],
+    [27, A380Failure.LeftOutwardElevatorOutwardActuatorRodEndFracture, 'Left outward elevator outward actuator rod end fracture'],
+    [27, A380Failure.LeftOutwardElevatorInwardActuatorRodEndFracture, 'Left outward elevator inward actuator rod end fracture'],
+    [27, A380Failure.LeftInwardElevatorOutwardActuatorRodEndFracture, 'Left inward elevator outward actuator rod end fracture'],
+    [27, A380Failure.LeftInwardElevatorInwardActuatorRodEndFracture, 'Left inward elevator inward actuator rod end fracture'],
+    [27, A380Failure.RightOutwardElevatorOutwardActuatorRodEndFracture, 'Right outward elevator outward actuator rod end fracture'],
+    [27, A380Failure.RightOutwardElevatorInwardActuatorRodEndFracture, 'Right outward elevator inward actuator rod end fracture'],
+    [27, A380Failure.RightInwardElevatorOutwardActuatorRodEndFracture, 'Right inward elevator outward actuator rod end fracture'],
+    [27, A380Failure.RightInwardElevatorInwardActuatorRodEndFracture, 'Right inward elevator inward actuator rod end fracture'],
+    [27, A380Failure.UpperRudderUpperActuatorRodEndFracture, 'Upper rudder upper actuator rod end fracture'],
+    [27, A380Failure.UpperRudderLowerActuatorRodEndFracture, 'Upper rudder lower actuator rod end fracture'],
+    [27, A380Failure.LowerRudderUpperActuatorRodEndFracture, 'Lower rudder upper actuator rod end fracture'],
+    [27, A380Failure.LowerRudderLowerActuatorRodEndFracture, 'Lower rudder lower actuator rod end fracture'],
+    [27, A380Failure.LeftSpoiler1ActuatorRodEndFracture, 'Left spoiler 1 actuator rod end fracture'],
+    [27, A380Failure.LeftSpoiler2ActuatorRodEndFracture, 'Left spoiler 2 actuator rod end fracture'],
+    [27, A380Failure.LeftSpoiler3ActuatorRodEndFracture, 'Left spoiler 3 actuator rod end fracture'],
+    [27, A380Failure.LeftSpoiler4ActuatorRodEndFracture, 'Left spoiler 4 actuator rod end fracture'],
+    [27, A380Failure.LeftSpoiler5ActuatorRodEndFracture, 'Left spoiler 5 actuator rod end fracture'],
+    [27, A380Failure.LeftSpoiler6ActuatorRodEndFracture, 'Left spoiler 6 actuator rod end fracture'],
+    [27, A380Failure.LeftSpoiler7ActuatorRodEndFracture, 'Left spoiler 7 actuator rod end fracture'],
+    [27, A380Failure.LeftSpoiler8ActuatorRodEndFracture, 'Left spoiler 8 actuator rod end fracture'],
+    [27, A380Failure.RightSpoiler1ActuatorRodEndFracture, 'Right spoiler 1 actuator rod end fracture'],
+    [27, A380Failure.RightSpoiler2ActuatorRodEndFracture, 'Right spoiler 2 actuator rod end fracture'],
+    [27, A380Failure.RightSpoiler3ActuatorRodEndFracture, 'Right spoiler 3 actuator rod end fracture'],
+    [27, A380Failure.RightSpoiler4ActuatorRodEndFracture, 'Right spoiler 4 actuator rod end fracture'],
+    [27, A380Failure.RightSpoiler5ActuatorRodEndFracture, 'Right spoiler 5 actuator rod end fracture'],
+    [27, A380Failure.RightSpoiler6ActuatorRodEndFracture, 'Right spoiler 6 actuator rod end fracture'],
+    [27, A380Failure.RightSpoiler7ActuatorRodEndFracture, 'Right spoiler 7 actuator rod end fracture'],
+    [27, A380Failure.RightSpoiler8ActuatorRodEndFracture, 'Right spoiler 8 actuator rod end fracture'],
+
     [29, A380Failure.GreenReservoirLeak, 'Green reservoir leak'],
     [29, A380Failure.BlueReservoirLeak, 'Blue reservoir leak'],
     [29, A380Failure.YellowReservoirLeak, 'Yellow reservoir leak'],
@@ -50,6 +487,29 @@ export const A380FailureDefinitions: FailureDefinition[] = [
     [29, A380Failure.GreenReservoirReturnLeak, 'Green reservoir return leak'],
     [29, A380Failure.BlueReservoirReturnLeak, 'Blue reservoir return leak'],
     [29, A380Failure.YellowReservoirReturnLeak, 'Yellow reservoir return leak'],
+    [29, A380Failure.Eng1EdpAFireShutoffValveStuck, 'Engine 1 EDP A fire shutoff valve stuck'],
+    [29, A380Failure.Eng1EdpBFireShutoffValveStuck, 'Engine 1 EDP B fire shutoff valve stuck'],
+    [29, A380Failure.Eng2EdpAFireShutoffValveStuck, 'Engine 2 EDP A fire shutoff valve stuck'],
+    [29, A380Failure.Eng2EdpBFireShutoffValveStuck, 'Engine 2 EDP B fire shutoff valve stuck'],
+    [29, A380Failure.Eng3EdpAFireShutoffValveStuck, 'Engine 3 EDP A fire shutoff valve stuck'],
+    [29, A380Failure.Eng3EdpBFireShutoffValveStuck, 'Engine 3 EDP B fire shutoff valve stuck'],
+    [29, A380Failure.Eng4EdpAFireShutoffValveStuck, 'Engine 4 EDP A fire shutoff valve stuck'],
+    [29, A380Failure.Eng4EdpBFireShutoffValveStuck, 'Engine 4 EDP B fire shutoff valve stuck'],
+    [29, A380Failure.GreenPriorityValveStuck, 'Green priority valve stuck'],
+    [29, A380Failure.YellowPriorityValveStuck, 'Yellow priority valve stuck'],
+    [29, A380Failure.GreenLeakMeasurementValveStuck, 'Green leak measurement valve stuck'],
+    [29, A380Failure.YellowLeakMeasurementValveStuck, 'Yellow leak measurement valve stuck'],
+    [29, A380Failure.GreenAccumulatorPrechargeLoss, 'Green accumulator precharge loss'],
+    [29, A380Failure.YellowAccumulatorPrechargeLoss, 'Yellow accumulator precharge loss'],
+
+    [30, A380Failure.Eng1AntiIceValveStuckClosed, 'Engine 1 anti-ice valve stuck closed'],
+    [30, A380Failure.Eng2AntiIceValveStuckClosed, 'Engine 2 anti-ice valve stuck closed'],
+    [30, A380Failure.Eng3AntiIceValveStuckClosed, 'Engine 3 anti-ice valve stuck closed'],
+    [30, A380Failure.Eng4AntiIceValveStuckClosed, 'Engine 4 anti-ice valve stuck closed'],
+    [30, A380Failure.Eng1AntiIcePressureSwitch, 'Engine 1 anti-ice pressure switch'],
+    [30, A380Failure.Eng2AntiIcePressureSwitch, 'Engine 2 anti-ice pressure switch'],
+    [30, A380Failure.Eng3AntiIcePressureSwitch, 'Engine 3 anti-ice pressure switch'],
+    [30, A380Failure.Eng4AntiIcePressureSwitch, 'Engine 4 anti-ice pressure switch'],
 
     [31, A380Failure.LeftPfdDisplay, 'Captain PFD display'],
     [31, A380Failure.RightPfdDisplay, 'F/O PFD display'],
@@ -60,7 +520,25 @@ export const A380FailureDefinitions: FailureDefinition[] = [
     [32, A380Failure.LgciuInternalError2, 'LGCIU 2 Internal error'],
 
     [32, A380Failure.GearProxSensorDamageGearUplockNose1, 'Proximity sensor damage uplock nose gear #1'],
+    [32, A380Failure.BodyWheelSteering, 'Body wheel steering'],
+    [32, A380Failure.GearUplockJammedGearNose, 'Nose gear jammed uplock'],
+    [32, A380Failure.GearUplockJammedGearLeft, 'Main left gear jammed uplock'],
+    [32, A380Failure.GearUplockJammedGearRight, 'Main right gear jammed uplock'],
+    [32, A380Failure.GearUplockJammedGearDoorNose, 'Nose gear door jammed uplock'],
+    [32, A380Failure.GearUplockJammedGearDoorLeft, 'Main left gear door jammed uplock'],
+    [32, A380Failure.GearUplockJammedGearDoorRight, 'Main right gear door jammed uplock'],
+    [32, A380Failure.GearDoorJammedNose, 'Nose gear door jammed'],
+    [32, A380Failure.GearDoorJammedLeft, 'Main left gear door jammed'],
+    [32, A380Failure.GearDoorJammedRight, 'Main right gear door jammed'],
+    [32, A380Failure.GearSelectorValveStuck, 'Gear selector valve stuck'],
 
     [34, A380Failure.RadioAltimeter1, 'RA 1'],
     [34, A380Failure.RadioAltimeter2, 'RA 2'],
+
+    [49, A380Failure.ApuEgtSensor, 'APU EGT sensor'],
+    [49, A380Failure.ApuSpeedSensor, 'APU speed sensor'],
+    [49, A380Failure.ApuInletPressureSensor, 'APU inlet pressure sensor'],
+    [49, A380Failure.ApuFuelControlUnit, 'APU fuel control unit'],
+    [49, A380Failure.ApuOilLowPressure, 'APU oil low pressure'],
+    [49, A380Failure.ApuAirIntakeFlapStuck, 'APU air intake flap stuck'],
 ];
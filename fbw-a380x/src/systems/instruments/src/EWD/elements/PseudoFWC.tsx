@@ -138,15 +138,16 @@ const PseudoFWC: React.FC = () => {
     const [fireButton1] = useSimVar('L:A32NX_FIRE_BUTTON_ENG1', 'bool', 500);
     const [fireButton2] = useSimVar('L:A32NX_FIRE_BUTTON_ENG2', 'bool', 500);
     const [fireButtonAPU] = useSimVar('L:A32NX_FIRE_BUTTON_APU', 'bool', 500);
-    const [eng1FireTest] = useSimVar('L:A32NX_FIRE_TEST_ENG1', 'bool', 500);
-    const [eng2FireTest] = useSimVar('L:A32NX_FIRE_TEST_ENG2', 'bool', 500);
-    const [apuFireTest] = useSimVar('L:A32NX_FIRE_TEST_APU', 'bool', 500);
+    const [eng1FireDetected] = useSimVar('L:A32NX_FIRE_ENG1_DETECTED', 'bool', 500);
+    const [eng2FireDetected] = useSimVar('L:A32NX_FIRE_ENG2_DETECTED', 'bool', 500);
+    const [apuFireDetected] = useSimVar('L:A32NX_FIRE_APU_DETECTED', 'bool', 500);
     const [eng1Agent1PB] = useSimVar('L:A32NX_FIRE_ENG1_AGENT1_Discharge', 'bool', 500);
     const [eng1Agent2PB] = useSimVar('L:A32NX_FIRE_ENG1_AGENT2_Discharge', 'bool', 500);
     const [eng2Agent1PB] = useSimVar('L:A32NX_FIRE_ENG2_AGENT1_Discharge', 'bool', 500);
     const [eng2Agent2PB] = useSimVar('L:A32NX_FIRE_ENG2_AGENT2_Discharge', 'bool', 500);
     const [apuAgentPB] = useSimVar('L:A32NX_FIRE_APU_AGENT1_Discharge', 'bool', 500);
-    const [cargoFireTest] = useSimVar('L:A32NX_FIRE_TEST_CARGO', 'bool', 500);
+    const [fwdCargoSmokeDetected] = useSimVar('L:A32NX_SMOKE_FWD_CARGO_DETECTED', 'bool', 500);
+    const [aftCargoSmokeDetected] = useSimVar('L:A32NX_SMOKE_AFT_CARGO_DETECTED', 'bool', 500);
     const [cargoFireAgentDisch] = useSimVar('L:A32NX_CARGOSMOKE_FWD_DISCHARGED', 'bool', 500);
 
     /* FUEL */
@@ -715,7 +716,7 @@ const PseudoFWC: React.FC = () => {
         apuAgentPB,
         apuAvail,
         apuBleedValveOpen,
-        apuFireTest,
+        apuFireDetected,
         apuMasterSwitch,
         ATTKnob,
         autoBrake,
@@ -728,8 +729,8 @@ const PseudoFWC: React.FC = () => {
         cabAltSetResetState2,
         cabinReady,
         cabinRecircBtnOn,
+        aftCargoSmokeDetected,
         cargoFireAgentDisch,
-        cargoFireTest,
         compMesgCount,
         computedAirSpeedToNearest2,
         configPortableDevices,
@@ -741,12 +742,12 @@ const PseudoFWC: React.FC = () => {
         eng1Agent1PB,
         eng1Agent2PB,
         eng1AntiIce,
-        eng1FireTest,
+        eng1FireDetected,
         engine1State,
         eng2Agent1PB,
         eng2Agent2PB,
         eng2AntiIce,
-        eng2FireTest,
+        eng2FireDetected,
         engine2State,
         engSelectorPosition,
         excessPressure,
@@ -761,6 +762,7 @@ const PseudoFWC: React.FC = () => {
         fobRounded,
         fuel,
         fuelXFeedPBOn,
+        fwdCargoSmokeDetected,
         gpwsFlapMode,
         gpwsFlaps3,
         gpwsOff,
@@ -4,7 +4,7 @@ import React from 'react';
 
 const Oxygen: React.FC<Position & SdacActive & OnGround> = ({ x, y, active, onGround }) => {
     const minCrewOxygenPressureForFlight = 1000; // TODO Find out what this is for a crew of 5
-    const crewOxygenPressure = 1829;
+    const [crewOxygenPressure] = useSimVar('L:A32NX_OXYGEN_CREW_BOTTLE_PRESSURE', 'psi', 500);
     const ckptPressureAmber = !!(crewOxygenPressure < 350 || !active || (onGround && crewOxygenPressure < minCrewOxygenPressureForFlight));
     const crewOxygenPbAuto = useSimVar('L:PUSH_OVHD_OXYGEN_CREW', 'boolean', 500);
 
@@ -16,7 +16,7 @@ const Oxygen: React.FC<Position & SdacActive & OnGround> = ({ x, y, active, onGr
         <g transform={`translate(${x} ${y})`}>
             <g id="crewOxygen">
                 <text x={0} y={0} className={`F22 EndAlign LS1 ${!active || !crewOxygenPbAuto ? 'AmberFill' : 'White'}`}>CKPT</text>
-                <text x={86} y={0} className={`F25 EndAlign ${ckptPressureAmber ? 'Amber' : 'Green'} LS1`}>{!active ? 'XX' : crewOxygenPressure}</text>
+                <text x={86} y={0} className={`F25 EndAlign ${ckptPressureAmber ? 'Amber' : 'Green'} LS1`}>{!active ? 'XX' : Math.round(crewOxygenPressure)}</text>
                 <text x={91} y={0} className='F22 Cyan LS1'>PSI</text>
                 <text x={-30} y={26} className={`F22 Amber ${!active || !crewOxygenPbAuto ? '' : 'Hide'}`}>REGUL PR LO</text>
             </g>
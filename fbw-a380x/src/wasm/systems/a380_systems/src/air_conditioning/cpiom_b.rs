@@ -146,7 +146,6 @@ impl CoreProcessingInputOutputModuleB {
         &self.vcs_app
     }
 
-    #[cfg(test)]
     pub(super) fn cabin_altitude(&self) -> Length {
         self.cpcs_app.altitude()
     }
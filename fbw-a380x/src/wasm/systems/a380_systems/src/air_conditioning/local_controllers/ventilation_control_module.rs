@@ -5,7 +5,7 @@ use systems::{
         AirConditioningOverheadShared, CabinFansSignal, Channel, OperatingChannel,
         PressurizationOverheadShared, VcmShared,
     },
-    shared::{ControllerSignal, ElectricalBusType},
+    shared::{CargoSmokeDetection, ControllerSignal, ElectricalBusType},
     simulation::{
         InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter,
         VariableIdentifier, Write,
@@ -80,6 +80,7 @@ impl VentilationControlModule {
     pub fn update(
         &mut self,
         acs_overhead: &impl AirConditioningOverheadShared,
+        cargo_smoke: &impl CargoSmokeDetection,
         ocsm: [&impl OcsmShared; 4],
         pressurization_overhead: &impl PressurizationOverheadShared,
     ) {
@@ -94,6 +95,7 @@ impl VentilationControlModule {
                 self.bvcs.update(
                     self.active_channel.has_fault(),
                     acs_overhead,
+                    cargo_smoke.bulk_cargo_smoke_detected(),
                     pressurization_overhead,
                 );
                 self.orvp.update(ocsm, pressurization_overhead);
@@ -101,6 +103,7 @@ impl VentilationControlModule {
                 self.fcvcs.update(
                     self.active_channel.has_fault(),
                     acs_overhead,
+                    cargo_smoke.fwd_cargo_smoke_detected(),
                     pressurization_overhead,
                 )
             }
@@ -215,10 +218,12 @@ impl ForwardCargoVentilationControlSystem {
         &mut self,
         active_channel_has_fault: bool,
         acs_overhead: &impl AirConditioningOverheadShared,
+        smoke_detected: bool,
         pressurization_overhead: &impl PressurizationOverheadShared,
     ) {
-        // TODO: Add failures and smoke detection
+        // TODO: Add failures
         self.isolation_valves_open_allowed = acs_overhead.fwd_cargo_isolation_valve_is_on()
+            && !smoke_detected
             && !pressurization_overhead.ditching_is_on()
             && !active_channel_has_fault;
         self.extraction_fan_is_on =
@@ -252,10 +257,12 @@ impl BulkVentilationControlSystem {
         &mut self,
         active_channel_has_fault: bool,
         acs_overhead: &impl AirConditioningOverheadShared,
+        smoke_detected: bool,
         pressurization_overhead: &impl PressurizationOverheadShared,
     ) {
-        // TODO: Add failures and smoke detection
+        // TODO: Add failures
         self.isolation_valves_open_allowed = acs_overhead.bulk_isolation_valve_is_on()
+            && !smoke_detected
             && !pressurization_overhead.ditching_is_on()
             && !active_channel_has_fault;
         self.extraction_fan_is_on =
@@ -14,9 +14,10 @@ use systems::{
     payload::NumberOfPassengers,
     pneumatic::PneumaticContainer,
     shared::{
-        update_iterator::MaxStepLoop, CabinSimulation, CargoDoorLocked, ControllerSignal,
-        ElectricalBusType, EngineBleedPushbutton, EngineCorrectedN1, EngineFirePushButtons,
-        EngineStartState, LgciuWeightOnWheels, PackFlowValveState, PneumaticBleed,
+        update_iterator::MaxStepLoop, CabinAltitude, CabinSimulation, CargoDoorLocked,
+        CargoSmokeDetection, ControllerSignal, ElectricalBusType, EngineBleedPushbutton,
+        EngineCorrectedN1, EngineFirePushButtons, EngineStartState, LgciuWeightOnWheels,
+        PackFlowValveState, PneumaticBleed,
     },
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -98,6 +99,7 @@ impl A380AirConditioning {
         context: &UpdateContext,
         adirs: &impl AdirsToAirCondInterface,
         cargo_door_open: &impl CargoDoorLocked,
+        cargo_smoke: &impl CargoSmokeDetection,
         cpiom_b: &impl CoreProcessingInputOutputModuleShared,
         engines: [&impl EngineCorrectedN1; 4],
         engine_fire_push_buttons: &impl EngineFirePushButtons,
@@ -129,6 +131,7 @@ impl A380AirConditioning {
         self.a380_air_conditioning_system.update(
             context,
             &self.a380_cabin,
+            cargo_smoke,
             &self.cpiom_b,
             engines,
             engine_fire_push_buttons,
@@ -205,6 +208,12 @@ impl PackFlowControllers for A380AirConditioning {
     }
 }
 
+impl CabinAltitude for A380AirConditioning {
+    fn altitude(&self) -> Length {
+        self.cpiom_b.cabin_altitude()
+    }
+}
+
 impl SimulationElement for A380AirConditioning {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.a380_cabin.accept(visitor);
@@ -440,6 +449,7 @@ impl A380AirConditioningSystem {
         &mut self,
         context: &UpdateContext,
         cabin_simulation: &impl CabinSimulation,
+        cargo_smoke: &impl CargoSmokeDetection,
         cpiom_b: &CoreProcessingInputOutputModuleB,
         engines: [&impl EngineCorrectedN1; 4],
         engine_fire_push_buttons: &impl EngineFirePushButtons,
@@ -451,6 +461,7 @@ impl A380AirConditioningSystem {
     ) {
         self.update_local_controllers(
             context,
+            cargo_smoke,
             cpiom_b,
             engines,
             engine_fire_push_buttons,
@@ -478,6 +489,7 @@ impl A380AirConditioningSystem {
     fn update_local_controllers(
         &mut self,
         context: &UpdateContext,
+        cargo_smoke: &impl CargoSmokeDetection,
         cpiom_b: &CoreProcessingInputOutputModuleB,
         engines: [&impl EngineCorrectedN1; 4],
         engine_fire_push_buttons: &impl EngineFirePushButtons,
@@ -514,6 +526,7 @@ impl A380AirConditioningSystem {
         self.vcm.iter_mut().for_each(|module| {
             module.update(
                 &self.air_conditioning_overhead,
+                cargo_smoke,
                 ocsm,
                 pressurization_overhead,
             )
@@ -978,6 +991,9 @@ impl PressurizationConstants for A380PressurizationConstants {
     const OUTFLOW_VALVE_SIZE: f64 = 0.28; // m2 This is total opening area (4 OFV)
     const SAFETY_VALVE_SIZE: f64 = 0.1; // m2
     const DOOR_OPENING_AREA: f64 = 1.5; // m2
+    const DOOR_SEAL_LEAK_AREA: f64 = 0.02; // m2
+    const WINDOW_CRACK_LEAK_AREA: f64 = 0.007; // m2
+    const CARGO_DOOR_UNSEALED_LEAK_AREA: f64 = 0.05; // m2
 
     const MAX_CLIMB_RATE: f64 = 1000.; // fpm
     const MAX_CLIMB_RATE_IN_DESCENT: f64 = 500.; // fpm
@@ -988,6 +1004,7 @@ impl PressurizationConstants for A380PressurizationConstants {
     const MAX_CLIMB_CABIN_ALTITUDE: f64 = 7500.; // feet
     const MAX_SAFETY_DELTA_P: f64 = 9.; // PSI
     const MIN_SAFETY_DELTA_P: f64 = -0.725; // PSI
+    const MAX_STRUCTURAL_DELTA_P: f64 = 10.; // PSI
     const TAKEOFF_RATE: f64 = -300.;
     const DEPRESS_RATE: f64 = 500.;
     const EXCESSIVE_ALT_WARNING: f64 = 9550.; // feet
@@ -1663,6 +1680,36 @@ mod tests {
         }
     }
 
+    struct TestSmokeDetection {
+        fwd_cargo_smoke: bool,
+        bulk_cargo_smoke: bool,
+    }
+    impl TestSmokeDetection {
+        fn new() -> Self {
+            Self {
+                fwd_cargo_smoke: false,
+                bulk_cargo_smoke: false,
+            }
+        }
+        fn set_fwd_cargo_smoke(&mut self, smoke: bool) {
+            self.fwd_cargo_smoke = smoke;
+        }
+        fn set_bulk_cargo_smoke(&mut self, smoke: bool) {
+            self.bulk_cargo_smoke = smoke;
+        }
+    }
+    impl CargoSmokeDetection for TestSmokeDetection {
+        fn fwd_cargo_smoke_detected(&self) -> bool {
+            self.fwd_cargo_smoke
+        }
+        fn aft_cargo_smoke_detected(&self) -> bool {
+            false
+        }
+        fn bulk_cargo_smoke_detected(&self) -> bool {
+            self.bulk_cargo_smoke
+        }
+    }
+
     struct TestLgciu {
         compressed: bool,
     }
@@ -1707,6 +1754,7 @@ mod tests {
         adcn: TestAdcn,
         adirs: TestAdirs,
         dsms: TestDsms,
+        smoke_detection: TestSmokeDetection,
         engine_1: TestEngine,
         engine_2: TestEngine,
         engine_3: TestEngine,
@@ -1751,6 +1799,7 @@ mod tests {
                 adcn: TestAdcn::new(context),
                 adirs: TestAdirs::new(),
                 dsms: TestDsms::new(),
+                smoke_detection: TestSmokeDetection::new(),
                 engine_1: TestEngine::new(Ratio::default()),
                 engine_2: TestEngine::new(Ratio::default()),
                 engine_3: TestEngine::new(Ratio::default()),
@@ -1964,6 +2013,7 @@ mod tests {
                 context,
                 &self.adirs,
                 &self.dsms,
+                &self.smoke_detection,
                 &self.adcn,
                 [
                     &self.engine_1,
@@ -2413,6 +2463,16 @@ mod tests {
             self
         }
 
+        fn command_fwd_cargo_smoke(mut self, smoke: bool) -> Self {
+            self.command(|a| a.smoke_detection.set_fwd_cargo_smoke(smoke));
+            self
+        }
+
+        fn command_bulk_cargo_smoke(mut self, smoke: bool) -> Self {
+            self.command(|a| a.smoke_detection.set_bulk_cargo_smoke(smoke));
+            self
+        }
+
         fn command_number_of_passengers(mut self, number_of_passengers: u32) -> Self {
             self.write_by_name("FMS_PAX_NUMBER", number_of_passengers);
             self.command(|a| a.update_number_of_passengers(number_of_passengers));
@@ -2541,6 +2601,18 @@ mod tests {
             })
         }
 
+        fn overpressure_relief_valve_open_amount(&self) -> Ratio {
+            self.query(|a| {
+                a.a380_cabin_air
+                    .a380_air_conditioning_system
+                    .overpressure_relief_valve_open_amount()
+            })
+        }
+
+        fn structural_limit_exceeded(&mut self) -> bool {
+            self.read_by_name("PRESS_STRUCTURAL_LIMIT_EXCEEDED")
+        }
+
         fn pack_flow_valve_is_open(&self, pfv: usize) -> bool {
             self.query(|a| a.pneumatic.pack_flow_valve_is_open(pfv))
         }
@@ -3332,6 +3404,31 @@ mod tests {
             assert!(test_bed.cabin_vs() > Velocity::new::<foot_per_minute>(2000.));
         }
 
+        #[test]
+        fn overpressure_relief_does_not_exceed_structural_limit() {
+            let mut test_bed = test_bed()
+                .and_run()
+                .with()
+                .vertical_speed_of(Velocity::new::<foot_per_minute>(1000.))
+                .iterate(5)
+                .then()
+                .command_aircraft_climb(Length::new::<foot>(1000.), Length::new::<foot>(30000.))
+                .with()
+                .vertical_speed_of(Velocity::default())
+                .iterate(10);
+
+            let cabin_pressure = test_bed.cabin_pressure();
+            test_bed = test_bed
+                .ambient_pressure_of(cabin_pressure - Pressure::new::<psi>(9.5))
+                .iterate(2);
+
+            assert!(test_bed.overpressure_relief_valve_open_amount() > Ratio::default());
+
+            test_bed = test_bed.iterate(20);
+
+            assert!(!test_bed.structural_limit_exceeded());
+        }
+
         #[test]
         fn negative_relief_valves_open_when_delta_p_below_minus_0725_psi() {
             let test_bed = test_bed()
@@ -4965,6 +5062,28 @@ mod tests {
                 assert!(!test_bed.bulk_isolation_valves_are_open());
             }
 
+            #[test]
+            fn fwd_isolation_valves_close_on_fwd_cargo_smoke() {
+                let test_bed = test_bed()
+                    .command_fwd_isolation_valves_pb_on(true)
+                    .command_fwd_cargo_smoke(true)
+                    .iterate(5);
+
+                assert!(!test_bed.fwd_extraction_fan_is_on());
+                assert!(!test_bed.fwd_isolation_valves_are_open());
+            }
+
+            #[test]
+            fn bulk_isolation_valves_close_on_bulk_cargo_smoke() {
+                let test_bed = test_bed()
+                    .command_bulk_isolation_valves_pb_on(true)
+                    .command_bulk_cargo_smoke(true)
+                    .iterate(5);
+
+                assert!(!test_bed.bulk_extraction_fan_is_on());
+                assert!(!test_bed.bulk_isolation_valves_are_open());
+            }
+
             #[test]
             fn bulk_heater_allowed_on_when_conditions_met() {
                 let mut test_bed = test_bed()
@@ -1,5 +1,8 @@
 use super::{alternating_current::A380AlternatingCurrentElectrical, A380ElectricalOverheadPanel};
-use systems::electrical::{AlternatingCurrentElectricalSystem, Electricity};
+use systems::{
+    electrical::{AlternatingCurrentElectricalSystem, Electricity},
+    shared::EmergencyElectricalState,
+};
 
 pub(super) struct MainGalley {
     is_shed: bool,
@@ -18,11 +21,13 @@ impl MainGalley {
         electricity: &Electricity,
         alternating_current: &A380AlternatingCurrentElectrical,
         overhead: &A380ElectricalOverheadPanel,
+        emergency_elec: &impl EmergencyElectricalState,
     ) {
         self.is_shed = !alternating_current.any_non_essential_bus_powered(electricity)
             || alternating_current.main_ac_buses_powered_by_two_generators_only(electricity)
             || overhead.commercial_is_off()
-            || overhead.galy_and_cab_is_off();
+            || overhead.galy_and_cab_is_off()
+            || emergency_elec.is_in_emergency_elec();
     }
 }
 
@@ -43,9 +48,11 @@ impl SecondaryGalley {
         electricity: &Electricity,
         alternating_current: &A380AlternatingCurrentElectrical,
         overhead: &A380ElectricalOverheadPanel,
+        emergency_elec: &impl EmergencyElectricalState,
     ) {
         self.is_shed = !alternating_current.any_non_essential_bus_powered(electricity)
             || overhead.commercial_is_off()
-            || overhead.galy_and_cab_is_off();
+            || overhead.galy_and_cab_is_off()
+            || emergency_elec.is_in_emergency_elec();
     }
 }
@@ -9,7 +9,7 @@ use self::{
 };
 pub(super) use direct_current::APU_START_MOTOR_BUS_TYPE;
 
-use uom::si::{angular_velocity::revolution_per_minute, f64::*, ratio::percent};
+use uom::si::{angular_velocity::revolution_per_minute, f64::*};
 
 #[cfg(test)]
 use systems::electrical::{Battery, BatteryChargeRectifierUnit};
@@ -31,8 +31,8 @@ use systems::{
     shared::{
         update_iterator::MaxStepLoop, AdirsDiscreteOutputs, AuxiliaryPowerUnitElectrical,
         ElectricalBusType, ElectricalBuses, EmergencyElectricalRatPushButton,
-        EmergencyElectricalState, EngineFirePushButtons, LatchedTrueLogicGate, LgciuWeightOnWheels,
-        RamAirTurbineController,
+        EmergencyElectricalState, EngineFirePushButtons, EngineUncorrectedN3, LatchedTrueLogicGate,
+        LgciuWeightOnWheels, RamAirTurbineController,
     },
     simulation::{
         InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
@@ -104,7 +104,7 @@ impl A380Electrical {
         emergency_overhead: &A380EmergencyElectricalOverheadPanel,
         apu: &mut impl AuxiliaryPowerUnitElectrical,
         engine_fire_push_buttons: &impl EngineFirePushButtons,
-        engines: [&impl Engine; 4],
+        engines: [&(impl Engine + EngineUncorrectedN3); 4],
         lgciu1: &impl LgciuWeightOnWheels,
         adirs: &impl AdirsDiscreteOutputs,
     ) {
@@ -119,8 +119,12 @@ impl A380Electrical {
             adirs,
         );
 
-        self.emergency_elec
-            .update(context, electricity, &self.alternating_current);
+        self.emergency_elec.update_with_total_engine_flameout(
+            context,
+            electricity,
+            &self.alternating_current,
+            &engines,
+        );
 
         self.rat_controller
             .update(context, emergency_overhead, &self.emergency_elec);
@@ -167,10 +171,18 @@ impl A380Electrical {
             self.tefo_condition.output(),
         );
 
-        self.main_galley
-            .update(electricity, &self.alternating_current, overhead);
-        self.secondary_galley
-            .update(electricity, &self.alternating_current, overhead);
+        self.main_galley.update(
+            electricity,
+            &self.alternating_current,
+            overhead,
+            &self.emergency_elec,
+        );
+        self.secondary_galley.update(
+            electricity,
+            &self.alternating_current,
+            overhead,
+            &self.emergency_elec,
+        );
 
         // Update relay states
         let dc_ess_powered =
@@ -191,9 +203,7 @@ impl A380Electrical {
         // TEFO(total engine failure) = all engines not running and in flight. Discrete signal from EEC
         // Represents the value of relay 16XR1 and 16XR2
         self.tefo_condition.update(
-            (!engines
-                .iter()
-                .any(|e| e.corrected_n2().get::<percent>() > 50.)
+            (self.emergency_elec.is_total_engine_flameout()
                 || !dc_ess_powered
                     && !electricity.any_is_powered(&[ElectricalBusType::DirectCurrent(2)]))
                 && flt_condition,
@@ -580,7 +590,7 @@ mod a380_electrical_circuit_tests {
         shared::{
             ApuAvailable, ApuMaster, ApuStart, ContactorSignal, ControllerSignal,
             ElectricalBusType, ElectricalBuses, EngineCorrectedN1, EngineCorrectedN2,
-            EngineUncorrectedN2, PotentialOrigin,
+            EngineUncorrectedN2, EngineUncorrectedN3, PotentialOrigin,
         },
         simulation::{
             test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
@@ -2987,6 +2997,81 @@ mod a380_electrical_circuit_tests {
             .is_single(PotentialOrigin::EmergencyGenerator));
     }
 
+    #[test]
+    fn when_all_engines_flame_out_in_flight_emergency_elec_is_active_while_apu_powers_ac_buses() {
+        let test_bed = test_bed_with()
+            .running_engines()
+            .running_apu()
+            .then_continue_with()
+            .all_engines_flamed_out()
+            .run();
+
+        assert!(test_bed.in_emergency_elec());
+    }
+
+    #[test]
+    fn when_all_engines_flame_out_in_flight_rat_deploys() {
+        let test_bed = test_bed_with()
+            .running_engines()
+            .running_apu()
+            .then_continue_with()
+            .all_engines_flamed_out()
+            .run();
+
+        assert!(test_bed.rat_should_deploy());
+    }
+
+    #[test]
+    fn when_all_engines_flame_out_in_flight_galley_is_shed() {
+        let mut test_bed = test_bed_with()
+            .running_engines()
+            .then_continue_with()
+            .all_engines_flamed_out()
+            .and()
+            .running_emergency_generator()
+            .run();
+
+        assert!(test_bed
+            .ac_ess_bus_output()
+            .is_single(PotentialOrigin::EmergencyGenerator));
+        assert!(test_bed.galley_is_shed());
+    }
+
+    #[test]
+    fn when_all_engines_running_in_flight_emergency_elec_is_not_active() {
+        let test_bed = test_bed_with().running_engines().run();
+
+        assert!(!test_bed.in_emergency_elec());
+        assert!(!test_bed.rat_should_deploy());
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    #[case(3)]
+    #[case(4)]
+    fn when_a_single_engine_is_running_in_flight_emergency_elec_is_not_active(
+        #[case] number: usize,
+    ) {
+        let test_bed = test_bed_with().running_engine(number).run();
+
+        assert!(!test_bed.in_emergency_elec());
+    }
+
+    #[test]
+    fn when_all_engines_flame_out_on_the_ground_emergency_elec_is_not_active() {
+        let test_bed = test_bed_with()
+            .on_the_ground()
+            .running_engines()
+            .running_apu()
+            .then_continue_with()
+            .all_engines_flamed_out()
+            .run();
+
+        assert!(!test_bed.in_emergency_elec());
+        assert!(!test_bed.rat_should_deploy());
+    }
+
     #[rstest]
     #[case(1)]
     #[case(2)]
@@ -3206,6 +3291,10 @@ mod a380_electrical_circuit_tests {
         fn run(&mut self) {
             self.is_running = true;
         }
+
+        fn flame_out(&mut self) {
+            self.is_running = false;
+        }
     }
     impl EngineCorrectedN1 for TestEngine {
         fn corrected_n1(&self) -> Ratio {
@@ -3222,6 +3311,11 @@ mod a380_electrical_circuit_tests {
             unimplemented!()
         }
     }
+    impl EngineUncorrectedN3 for TestEngine {
+        fn uncorrected_n3(&self) -> Ratio {
+            Ratio::new::<percent>(if self.is_running { 80. } else { 0. })
+        }
+    }
     impl Engine for TestEngine {
         fn hydraulic_pump_output_speed(&self) -> AngularVelocity {
             unimplemented!()
@@ -3416,6 +3510,20 @@ mod a380_electrical_circuit_tests {
             self.engines[number - 1].run();
         }
 
+        fn flame_out_engines(&mut self) {
+            self.engines
+                .iter_mut()
+                .for_each(|engine| engine.flame_out());
+        }
+
+        fn in_emergency_elec(&self) -> bool {
+            self.elec.in_emergency_elec()
+        }
+
+        fn rat_should_deploy(&self) -> bool {
+            self.elec.rat_controller.should_deploy()
+        }
+
         fn running_apu(&mut self) {
             self.apu.set_available(true);
         }
@@ -3574,6 +3682,11 @@ mod a380_electrical_circuit_tests {
                 .running_engine(4)
         }
 
+        fn all_engines_flamed_out(mut self) -> Self {
+            self.command(|a| a.flame_out_engines());
+            self
+        }
+
         fn flight_conditions_for_a_spinning_rat(mut self) -> Self {
             self.set_true_airspeed(Velocity::new::<knot>(340.));
             self.set_ambient_air_density(MassDensity::new::<slug_per_cubic_foot>(0.002367190));
@@ -3782,6 +3895,14 @@ mod a380_electrical_circuit_tests {
             self.query(|a| a.apu_start_motor_is_powered())
         }
 
+        fn in_emergency_elec(&self) -> bool {
+            self.query(|a| a.in_emergency_elec())
+        }
+
+        fn rat_should_deploy(&self) -> bool {
+            self.query(|a| a.rat_should_deploy())
+        }
+
         fn ac_bus_output(&self, number: u8) -> Ref<Potential> {
             self.query_elec_ref(|_, elec| {
                 elec.potential_of(ElectricalBusType::AlternatingCurrent(number))
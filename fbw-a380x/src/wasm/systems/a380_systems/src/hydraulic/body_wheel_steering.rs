@@ -0,0 +1,524 @@
+use systems::{
+    failures::{Failure, FailureType},
+    hydraulic::linear_actuator::Actuator,
+    shared::{interpolation, low_pass_filter::LowPassFilter, LgciuWeightOnWheels, SectionPressure},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    },
+};
+
+use std::time::Duration;
+use uom::si::{
+    angle::{degree, radian},
+    angular_velocity::radian_per_second,
+    f64::*,
+    length::meter,
+    pressure::psi,
+    ratio::ratio,
+    velocity::knot,
+    volume::gallon,
+};
+
+/// Body wheel steering computer logic.
+///
+/// Body wheel steering is engaged on ground at low speed once the nose wheel is steered past a
+/// threshold angle. Body wheels then steer opposite to the nose wheel to reduce tyre scrubbing in
+/// tight turns. Out of these conditions, or when failed, body wheels are driven back to neutral
+/// and locked there, which is always the case during the takeoff run.
+pub struct A380BodyWheelSteeringController {
+    ground_speed_id: VariableIdentifier,
+
+    ground_speed: Velocity,
+
+    is_engaged: bool,
+    requested_position: Angle,
+
+    failure: Failure,
+}
+impl A380BodyWheelSteeringController {
+    const NOSE_WHEEL_ANGLE_ENGAGE_THRESHOLD_DEGREES: f64 = 20.;
+    const MAX_GROUND_SPEED_FOR_ENGAGEMENT_KNOT: f64 = 20.;
+
+    const NOSE_WHEEL_ANGLE_BREAKPOINTS_DEGREES: [f64; 3] = [0., 20., 70.];
+    const BODY_WHEEL_ANGLE_MAP_DEGREES: [f64; 3] = [0., 0., 15.];
+
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            ground_speed_id: context.get_identifier("GPS GROUND SPEED".to_owned()),
+
+            ground_speed: Velocity::new::<knot>(0.),
+
+            is_engaged: false,
+            requested_position: Angle::new::<degree>(0.),
+
+            failure: Failure::new(FailureType::BodyWheelSteering),
+        }
+    }
+
+    pub fn update(&mut self, nose_wheel_position: Angle, lgciu: &impl LgciuWeightOnWheels) {
+        self.is_engaged = !self.failure.is_active()
+            && lgciu.left_and_right_gear_compressed(false)
+            && self.ground_speed.get::<knot>() < Self::MAX_GROUND_SPEED_FOR_ENGAGEMENT_KNOT
+            && nose_wheel_position.get::<degree>().abs()
+                > Self::NOSE_WHEEL_ANGLE_ENGAGE_THRESHOLD_DEGREES;
+
+        self.requested_position = if self.is_engaged {
+            // Body wheels steer in the opposite direction of the nose wheel
+            -Angle::new::<degree>(
+                interpolation(
+                    &Self::NOSE_WHEEL_ANGLE_BREAKPOINTS_DEGREES,
+                    &Self::BODY_WHEEL_ANGLE_MAP_DEGREES,
+                    nose_wheel_position.get::<degree>().abs(),
+                )
+                .copysign(nose_wheel_position.get::<degree>()),
+            )
+        } else {
+            Angle::new::<degree>(0.)
+        };
+    }
+
+    pub fn requested_position(&self) -> Angle {
+        self.requested_position
+    }
+
+    /// Body wheels are locked in neutral position as soon as steering is not engaged
+    pub fn should_lock(&self) -> bool {
+        !self.is_engaged
+    }
+
+    pub fn is_engaged(&self) -> bool {
+        self.is_engaged
+    }
+}
+impl SimulationElement for A380BodyWheelSteeringController {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.ground_speed = reader.read(&self.ground_speed_id);
+    }
+}
+
+/// Hydraulic actuation of both body gears steering.
+///
+/// Actuator moves at a speed depending on available pressure, and can only lock once back in
+/// neutral position. When locked, it does not move and does not use any hydraulic fluid.
+pub struct A380BodyWheelSteeringActuator {
+    position_id: VariableIdentifier,
+    locked_id: VariableIdentifier,
+
+    current_speed: LowPassFilter<AngularVelocity>,
+    current_position: Angle,
+
+    is_locked: bool,
+
+    max_half_angle: Angle,
+    nominal_speed: AngularVelocity,
+    angular_to_linear_ratio: Ratio,
+
+    total_volume_to_actuator: Volume,
+    total_volume_to_reservoir: Volume,
+
+    actuator_area: Area,
+}
+impl A380BodyWheelSteeringActuator {
+    const MIN_PRESSURE_ALLOWING_STEERING_PSI: f64 = 300.;
+
+    const REFERENCE_PRESS_FOR_NOMINAL_SPEED_PSI: f64 = 2000.;
+
+    const CURRENT_SPEED_FILTER_TIMECONST: Duration = Duration::from_millis(150);
+
+    // Same open loop slowing down law as nose steering: speed_coefficient = GAIN * position_error^2
+    const POSITION_ERROR_TO_MAX_SPEED_GAIN: f64 = 0.2;
+
+    const MAX_ANGLE_FOR_LOCKING_DEGREES: f64 = 0.5;
+
+    // Both body gears are steered by their own actuator
+    const NUMBER_OF_ACTUATORS: f64 = 2.;
+
+    pub fn new(
+        context: &mut InitContext,
+        max_half_angle: Angle,
+        nominal_speed: AngularVelocity,
+        actuator_diameter: Length,
+        angular_to_linear_ratio: Ratio,
+    ) -> Self {
+        Self {
+            position_id: context.get_identifier("BODY_WHEEL_STEERING_POSITION_RATIO".to_owned()),
+            locked_id: context.get_identifier("BODY_WHEEL_STEERING_LOCKED".to_owned()),
+
+            current_speed: LowPassFilter::<AngularVelocity>::new(
+                Self::CURRENT_SPEED_FILTER_TIMECONST,
+            ),
+            current_position: Angle::new::<radian>(0.),
+
+            is_locked: true,
+
+            max_half_angle,
+            nominal_speed,
+            angular_to_linear_ratio,
+
+            total_volume_to_actuator: Volume::new::<gallon>(0.),
+            total_volume_to_reservoir: Volume::new::<gallon>(0.),
+
+            actuator_area: std::f64::consts::PI
+                * (actuator_diameter / 2.)
+                * (actuator_diameter / 2.),
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        section_pressure: &impl SectionPressure,
+        controller: &A380BodyWheelSteeringController,
+    ) {
+        self.update_lock(controller);
+
+        if self.is_locked {
+            self.current_speed
+                .reset(AngularVelocity::new::<radian_per_second>(0.));
+        } else {
+            let limited_requested_angle = controller
+                .requested_position()
+                .min(self.max_half_angle)
+                .max(-self.max_half_angle);
+
+            self.update_current_speed(context, section_pressure, limited_requested_angle);
+
+            self.current_position += Angle::new::<radian>(
+                self.current_speed.output().get::<radian_per_second>()
+                    * context.delta_as_secs_f64(),
+            );
+        }
+
+        self.update_flow(context);
+    }
+
+    fn update_lock(&mut self, controller: &A380BodyWheelSteeringController) {
+        self.is_locked = controller.should_lock()
+            && self.current_position.get::<degree>().abs() < Self::MAX_ANGLE_FOR_LOCKING_DEGREES;
+
+        if self.is_locked {
+            self.current_position = Angle::new::<radian>(0.);
+        }
+    }
+
+    fn update_current_speed(
+        &mut self,
+        context: &UpdateContext,
+        section_pressure: &impl SectionPressure,
+        requested_angle: Angle,
+    ) {
+        let current_pressure = section_pressure.pressure_downstream_priority_valve();
+
+        let max_speed_for_current_hydraulics_pressure =
+            if current_pressure.get::<psi>() > Self::MIN_PRESSURE_ALLOWING_STEERING_PSI {
+                (self.nominal_speed * current_pressure.get::<psi>().sqrt()
+                    / Self::REFERENCE_PRESS_FOR_NOMINAL_SPEED_PSI.sqrt())
+                .min(self.nominal_speed)
+            } else {
+                AngularVelocity::default()
+            };
+
+        let position_error_abs = (requested_angle - self.current_position).abs();
+        let max_speed_closing_to_requested_position = self.nominal_speed
+            * (Self::POSITION_ERROR_TO_MAX_SPEED_GAIN * position_error_abs.get::<degree>().powi(2))
+                .min(1.);
+
+        let final_absolute_speed =
+            max_speed_for_current_hydraulics_pressure.min(max_speed_closing_to_requested_position);
+
+        self.current_speed.update(
+            context.delta(),
+            if requested_angle > self.current_position {
+                final_absolute_speed
+            } else {
+                -final_absolute_speed
+            },
+        );
+    }
+
+    fn update_flow(&mut self, context: &UpdateContext) {
+        let linear_position_delta = Length::new::<meter>(
+            self.current_speed.output().get::<radian_per_second>().abs()
+                * context.delta_as_secs_f64()
+                * self.angular_to_linear_ratio.get::<ratio>(),
+        );
+
+        let volume = linear_position_delta * self.actuator_area * Self::NUMBER_OF_ACTUATORS;
+
+        self.total_volume_to_actuator += volume;
+        self.total_volume_to_reservoir += volume;
+    }
+
+    pub fn position_feedback(&self) -> Angle {
+        self.current_position
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.is_locked
+    }
+
+    fn position_normalized(&self) -> Ratio {
+        Ratio::new::<ratio>(
+            self.current_position.get::<radian>() / self.max_half_angle.get::<radian>(),
+        )
+    }
+}
+impl Actuator for A380BodyWheelSteeringActuator {
+    fn used_volume(&self) -> Volume {
+        self.total_volume_to_actuator
+    }
+
+    fn reservoir_return(&self) -> Volume {
+        self.total_volume_to_reservoir
+    }
+
+    fn reset_volumes(&mut self) {
+        self.total_volume_to_reservoir = Volume::new::<gallon>(0.);
+        self.total_volume_to_actuator = Volume::new::<gallon>(0.);
+    }
+}
+impl SimulationElement for A380BodyWheelSteeringActuator {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.position_id, self.position_normalized().get::<ratio>());
+        writer.write(&self.locked_id, self.is_locked);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use systems::simulation::test::{ReadByName, SimulationTestBed, TestBed, WriteByName};
+    use systems::simulation::Aircraft;
+
+    #[derive(Default)]
+    struct TestLgciu {
+        compressed: bool,
+    }
+    impl LgciuWeightOnWheels for TestLgciu {
+        fn right_gear_compressed(&self, _: bool) -> bool {
+            self.compressed
+        }
+        fn right_gear_extended(&self, _: bool) -> bool {
+            !self.compressed
+        }
+        fn left_gear_compressed(&self, _: bool) -> bool {
+            self.compressed
+        }
+        fn left_gear_extended(&self, _: bool) -> bool {
+            !self.compressed
+        }
+        fn left_and_right_gear_compressed(&self, _: bool) -> bool {
+            self.compressed
+        }
+        fn left_and_right_gear_extended(&self, _: bool) -> bool {
+            !self.compressed
+        }
+        fn nose_gear_compressed(&self, _: bool) -> bool {
+            self.compressed
+        }
+        fn nose_gear_extended(&self, _: bool) -> bool {
+            !self.compressed
+        }
+    }
+
+    #[derive(Default)]
+    struct TestHydraulicSection {
+        pressure: Pressure,
+    }
+    impl SectionPressure for TestHydraulicSection {
+        fn pressure(&self) -> Pressure {
+            self.pressure
+        }
+
+        fn pressure_downstream_leak_valve(&self) -> Pressure {
+            self.pressure
+        }
+
+        fn pressure_downstream_priority_valve(&self) -> Pressure {
+            self.pressure
+        }
+
+        fn is_pressure_switch_pressurised(&self) -> bool {
+            self.pressure.get::<psi>() > 1700.
+        }
+    }
+
+    struct TestAircraft {
+        controller: A380BodyWheelSteeringController,
+        actuator: A380BodyWheelSteeringActuator,
+
+        lgciu: TestLgciu,
+        pressure: TestHydraulicSection,
+        nose_wheel_position: Angle,
+
+        total_used_volume: Volume,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                controller: A380BodyWheelSteeringController::new(context),
+                actuator: A380BodyWheelSteeringActuator::new(
+                    context,
+                    Angle::new::<degree>(15.),
+                    AngularVelocity::new::<radian_per_second>(0.15),
+                    Length::new::<meter>(0.06),
+                    Ratio::new::<ratio>(0.2),
+                ),
+
+                lgciu: TestLgciu { compressed: true },
+                pressure: TestHydraulicSection {
+                    pressure: Pressure::new::<psi>(3000.),
+                },
+                nose_wheel_position: Angle::new::<degree>(0.),
+
+                total_used_volume: Volume::new::<gallon>(0.),
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.controller
+                .update(self.nose_wheel_position, &self.lgciu);
+            self.actuator
+                .update(context, &self.pressure, &self.controller);
+
+            self.total_used_volume += self.actuator.used_volume();
+            self.actuator.reset_volumes();
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.controller.accept(visitor);
+            self.actuator.accept(visitor);
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.write_by_name("GPS GROUND SPEED", 5.);
+        test_bed
+    }
+
+    #[test]
+    fn writes_its_states() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        assert!(test_bed.contains_variable_with_name("BODY_WHEEL_STEERING_POSITION_RATIO"));
+        assert!(test_bed.contains_variable_with_name("BODY_WHEEL_STEERING_LOCKED"));
+    }
+
+    #[test]
+    fn init_centred_and_locked() {
+        let mut test_bed = test_bed();
+        test_bed.run_multiple_frames(Duration::from_secs(1));
+
+        assert!(test_bed.query(|a| a.actuator.is_locked()));
+        assert!(test_bed.query(|a| a.actuator.position_feedback()) == Angle::new::<degree>(0.));
+
+        let locked: bool = test_bed.read_by_name("BODY_WHEEL_STEERING_LOCKED");
+        assert!(locked);
+    }
+
+    #[test]
+    fn not_engaged_below_nose_wheel_threshold() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.nose_wheel_position = Angle::new::<degree>(15.));
+        test_bed.run_multiple_frames(Duration::from_secs(5));
+
+        assert!(!test_bed.query(|a| a.controller.is_engaged()));
+        assert!(test_bed.query(|a| a.actuator.is_locked()));
+        assert!(test_bed.query(|a| a.total_used_volume) == Volume::new::<gallon>(0.));
+    }
+
+    #[test]
+    fn steers_opposite_to_nose_wheel_above_threshold() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.nose_wheel_position = Angle::new::<degree>(70.));
+        test_bed.run_multiple_frames(Duration::from_secs(5));
+
+        assert!(test_bed.query(|a| a.controller.is_engaged()));
+        assert!(!test_bed.query(|a| a.actuator.is_locked()));
+        assert!(test_bed.query(|a| a.actuator.position_feedback()) < Angle::new::<degree>(-14.));
+
+        let position_ratio: f64 = test_bed.read_by_name("BODY_WHEEL_STEERING_POSITION_RATIO");
+        assert!(position_ratio < -0.9);
+
+        assert!(test_bed.query(|a| a.total_used_volume) > Volume::new::<gallon>(0.));
+    }
+
+    #[test]
+    fn not_engaged_above_ground_speed_threshold() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("GPS GROUND SPEED", 30.);
+        test_bed.command(|a| a.nose_wheel_position = Angle::new::<degree>(-60.));
+        test_bed.run_multiple_frames(Duration::from_secs(5));
+
+        assert!(!test_bed.query(|a| a.controller.is_engaged()));
+        assert!(test_bed.query(|a| a.actuator.is_locked()));
+    }
+
+    #[test]
+    fn not_engaged_in_flight() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.lgciu.compressed = false);
+        test_bed.command(|a| a.nose_wheel_position = Angle::new::<degree>(-60.));
+        test_bed.run_multiple_frames(Duration::from_secs(5));
+
+        assert!(!test_bed.query(|a| a.controller.is_engaged()));
+        assert!(test_bed.query(|a| a.actuator.is_locked()));
+    }
+
+    #[test]
+    fn centres_and_locks_when_nose_wheel_back_below_threshold() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.nose_wheel_position = Angle::new::<degree>(-70.));
+        test_bed.run_multiple_frames(Duration::from_secs(5));
+
+        assert!(test_bed.query(|a| a.actuator.position_feedback()) > Angle::new::<degree>(14.));
+
+        test_bed.command(|a| a.nose_wheel_position = Angle::new::<degree>(0.));
+        test_bed.run_multiple_frames(Duration::from_secs(5));
+
+        assert!(test_bed.query(|a| a.actuator.is_locked()));
+        assert!(test_bed.query(|a| a.actuator.position_feedback()) == Angle::new::<degree>(0.));
+    }
+
+    #[test]
+    fn does_not_centre_without_pressure() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.nose_wheel_position = Angle::new::<degree>(-70.));
+        test_bed.run_multiple_frames(Duration::from_secs(5));
+
+        test_bed.command(|a| a.pressure.pressure = Pressure::new::<psi>(0.));
+        test_bed.command(|a| a.nose_wheel_position = Angle::new::<degree>(0.));
+        test_bed.run_multiple_frames(Duration::from_secs(5));
+
+        assert!(!test_bed.query(|a| a.actuator.is_locked()));
+        assert!(test_bed.query(|a| a.actuator.position_feedback()) > Angle::new::<degree>(10.));
+    }
+
+    #[test]
+    fn failure_centres_and_locks_body_wheels() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.nose_wheel_position = Angle::new::<degree>(70.));
+        test_bed.run_multiple_frames(Duration::from_secs(5));
+
+        assert!(test_bed.query(|a| a.controller.is_engaged()));
+
+        test_bed.fail(FailureType::BodyWheelSteering);
+        test_bed.run_multiple_frames(Duration::from_secs(5));
+
+        assert!(!test_bed.query(|a| a.controller.is_engaged()));
+        assert!(test_bed.query(|a| a.actuator.is_locked()));
+        assert!(test_bed.query(|a| a.actuator.position_feedback()) == Angle::new::<degree>(0.));
+    }
+}
@@ -1,5 +1,6 @@
 use crate::systems::shared::arinc429::{Arinc429Word, SignStatus};
-use systems::shared::FeedbackPositionPickoffUnit;
+use systems::hydraulic::flap_slat::WingtipBrakeMonitor;
+use systems::shared::{AsymmetryPositionPickoffUnits, FeedbackPositionPickoffUnit};
 
 use systems::simulation::{
     InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -73,12 +74,17 @@ struct SlatFlapControlComputer {
     slat_flap_actual_position_word_id: VariableIdentifier,
     slat_actual_position_word_id: VariableIdentifier,
     flap_actual_position_word_id: VariableIdentifier,
+    wtb_reset_id: VariableIdentifier,
 
+    wtb_reset_requested: bool,
     flaps_demanded_angle: Angle,
     slats_demanded_angle: Angle,
     flaps_feedback_angle: Angle,
     slats_feedback_angle: Angle,
     flaps_conf: FlapsConf,
+
+    flaps_wtb_monitor: WingtipBrakeMonitor,
+    slats_wtb_monitor: WingtipBrakeMonitor,
 }
 
 impl SlatFlapControlComputer {
@@ -99,12 +105,17 @@ impl SlatFlapControlComputer {
                 .get_identifier("SFCC_SLAT_ACTUAL_POSITION_WORD".to_owned()),
             flap_actual_position_word_id: context
                 .get_identifier("SFCC_FLAP_ACTUAL_POSITION_WORD".to_owned()),
+            wtb_reset_id: context.get_identifier("SFCC_WTB_RESET".to_owned()),
 
+            wtb_reset_requested: false,
             flaps_demanded_angle: Angle::new::<degree>(0.),
             slats_demanded_angle: Angle::new::<degree>(0.),
             flaps_feedback_angle: Angle::new::<degree>(0.),
             slats_feedback_angle: Angle::new::<degree>(0.),
             flaps_conf: FlapsConf::Conf0,
+
+            flaps_wtb_monitor: WingtipBrakeMonitor::new(),
+            slats_wtb_monitor: WingtipBrakeMonitor::new(),
         }
     }
 
@@ -173,8 +184,8 @@ impl SlatFlapControlComputer {
         &mut self,
         context: &UpdateContext,
         flaps_handle: &FlapsHandle,
-        flaps_feedback: &impl FeedbackPositionPickoffUnit,
-        slats_feedback: &impl FeedbackPositionPickoffUnit,
+        flaps_feedback: &(impl FeedbackPositionPickoffUnit + AsymmetryPositionPickoffUnits),
+        slats_feedback: &(impl FeedbackPositionPickoffUnit + AsymmetryPositionPickoffUnits),
     ) {
         self.flaps_conf = self.generate_configuration(flaps_handle, context);
 
@@ -182,17 +193,28 @@ impl SlatFlapControlComputer {
         self.slats_demanded_angle = Self::demanded_slats_fppu_angle_from_conf(self.flaps_conf);
         self.flaps_feedback_angle = flaps_feedback.angle();
         self.slats_feedback_angle = slats_feedback.angle();
+
+        // Wingtip brakes can only be released by a maintenance action on ground.
+        if self.wtb_reset_requested && context.is_on_ground() {
+            self.flaps_wtb_monitor.reset();
+            self.slats_wtb_monitor.reset();
+        }
+
+        let flaps_demand = self.signal_demanded_angle("FLAPS");
+        let slats_demand = self.signal_demanded_angle("SLATS");
+        self.flaps_wtb_monitor.update(flaps_demand, flaps_feedback);
+        self.slats_wtb_monitor.update(slats_demand, slats_feedback);
     }
 
     fn slat_flap_system_status_word(&self) -> Arinc429Word<u32> {
         let mut word = Arinc429Word::new(0, SignStatus::NormalOperation);
 
-        word.set_bit(11, false);
-        word.set_bit(12, false);
-        word.set_bit(13, false);
-        word.set_bit(14, false);
-        word.set_bit(15, false);
-        word.set_bit(16, false);
+        word.set_bit(11, self.slats_wtb_monitor.wingtip_brakes_engaged());
+        word.set_bit(12, self.flaps_wtb_monitor.wingtip_brakes_engaged());
+        word.set_bit(13, self.slats_wtb_monitor.asymmetry_detected());
+        word.set_bit(14, self.flaps_wtb_monitor.asymmetry_detected());
+        word.set_bit(15, self.slats_wtb_monitor.wingtip_brakes_engaged());
+        word.set_bit(16, self.flaps_wtb_monitor.wingtip_brakes_engaged());
         word.set_bit(17, self.flaps_conf == FlapsConf::Conf0);
         word.set_bit(
             18,
@@ -237,8 +259,8 @@ impl SlatFlapControlComputer {
             self.slats_feedback_angle > Angle::new::<degree>(327.4)
                 && self.slats_feedback_angle < Angle::new::<degree>(337.),
         );
-        word.set_bit(16, false);
-        word.set_bit(17, false);
+        word.set_bit(16, self.slats_wtb_monitor.wingtip_brakes_engaged());
+        word.set_bit(17, self.slats_wtb_monitor.wingtip_brakes_engaged());
         word.set_bit(18, true);
         word.set_bit(
             19,
@@ -265,12 +287,12 @@ impl SlatFlapControlComputer {
             self.flaps_feedback_angle > Angle::new::<degree>(250.)
                 && self.flaps_feedback_angle < Angle::new::<degree>(254.),
         );
-        word.set_bit(24, false);
-        word.set_bit(25, false);
+        word.set_bit(24, self.flaps_wtb_monitor.wingtip_brakes_engaged());
+        word.set_bit(25, self.flaps_wtb_monitor.wingtip_brakes_engaged());
         word.set_bit(26, false);
         word.set_bit(27, false);
-        word.set_bit(28, false);
-        word.set_bit(29, false);
+        word.set_bit(28, self.slats_wtb_monitor.asymmetry_detected());
+        word.set_bit(29, self.flaps_wtb_monitor.asymmetry_detected());
 
         word
     }
@@ -298,18 +320,20 @@ impl SlatFlapLane for SlatFlapControlComputer {
     fn signal_demanded_angle(&self, surface_type: &str) -> Option<Angle> {
         match surface_type {
             "FLAPS"
-                if Self::surface_movement_required(
-                    self.flaps_demanded_angle,
-                    self.flaps_feedback_angle,
-                ) =>
+                if !self.flaps_wtb_monitor.wingtip_brakes_engaged()
+                    && Self::surface_movement_required(
+                        self.flaps_demanded_angle,
+                        self.flaps_feedback_angle,
+                    ) =>
             {
                 Some(self.flaps_demanded_angle)
             }
             "SLATS"
-                if Self::surface_movement_required(
-                    self.slats_demanded_angle,
-                    self.slats_feedback_angle,
-                ) =>
+                if !self.slats_wtb_monitor.wingtip_brakes_engaged()
+                    && Self::surface_movement_required(
+                        self.slats_demanded_angle,
+                        self.slats_feedback_angle,
+                    ) =>
             {
                 Some(self.slats_demanded_angle)
             }
@@ -320,6 +344,10 @@ impl SlatFlapLane for SlatFlapControlComputer {
 }
 
 impl SimulationElement for SlatFlapControlComputer {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.wtb_reset_requested = reader.read(&self.wtb_reset_id);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.flaps_conf_index_id, self.flaps_conf as u8);
 
@@ -361,8 +389,8 @@ impl SlatFlapComplex {
     pub fn update(
         &mut self,
         context: &UpdateContext,
-        flaps_feedback: &impl FeedbackPositionPickoffUnit,
-        slats_feedback: &impl FeedbackPositionPickoffUnit,
+        flaps_feedback: &(impl FeedbackPositionPickoffUnit + AsymmetryPositionPickoffUnits),
+        slats_feedback: &(impl FeedbackPositionPickoffUnit + AsymmetryPositionPickoffUnits),
     ) {
         self.sfcc
             .update(context, &self.flaps_handle, flaps_feedback, slats_feedback);
@@ -375,6 +403,14 @@ impl SlatFlapComplex {
     pub fn slat_demand(&self) -> Option<Angle> {
         self.sfcc.signal_demanded_angle("SLATS")
     }
+
+    pub fn flap_wingtip_brakes_engaged(&self) -> bool {
+        self.sfcc.flaps_wtb_monitor.wingtip_brakes_engaged()
+    }
+
+    pub fn slat_wingtip_brakes_engaged(&self) -> bool {
+        self.sfcc.slats_wtb_monitor.wingtip_brakes_engaged()
+    }
 }
 impl SimulationElement for SlatFlapComplex {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -397,6 +433,7 @@ mod tests {
 
     struct SlatFlapGear {
         current_angle: Angle,
+        left_jammed_angle: Option<Angle>,
         speed: AngularVelocity,
         max_angle: Angle,
         left_position_percent_id: VariableIdentifier,
@@ -410,6 +447,15 @@ mod tests {
             self.current_angle
         }
     }
+    impl AsymmetryPositionPickoffUnits for SlatFlapGear {
+        fn left_angle(&self) -> Angle {
+            self.left_jammed_angle.unwrap_or(self.current_angle)
+        }
+
+        fn right_angle(&self) -> Angle {
+            self.current_angle
+        }
+    }
 
     impl SlatFlapGear {
         const ANGLE_DELTA_DEGREE: f64 = 0.01;
@@ -422,6 +468,7 @@ mod tests {
         ) -> Self {
             Self {
                 current_angle: Angle::new::<degree>(0.),
+                left_jammed_angle: None,
                 speed,
                 max_angle,
 
@@ -439,6 +486,10 @@ mod tests {
             }
         }
 
+        fn jam_left_side(&mut self) {
+            self.left_jammed_angle = Some(self.current_angle);
+        }
+
         fn update(
             &mut self,
             context: &UpdateContext,
@@ -647,6 +698,34 @@ mod tests {
             })
         }
 
+        fn jam_left_flaps(mut self) -> Self {
+            self.command(|a| a.flap_gear.jam_left_side());
+            self
+        }
+
+        fn on_ground(mut self) -> Self {
+            self.set_on_ground(true);
+            self
+        }
+
+        fn command_wtb_reset(mut self, is_pressed: bool) -> Self {
+            self.write_by_name("SFCC_WTB_RESET", is_pressed);
+            self
+        }
+
+        fn move_slats_without_demand(mut self, angle: f64) -> Self {
+            self.command(|a| a.slat_gear.current_angle += Angle::new::<degree>(angle));
+            self
+        }
+
+        fn is_flap_wtb_engaged(&self) -> bool {
+            self.query(|a| a.slat_flap_complex.flap_wingtip_brakes_engaged())
+        }
+
+        fn is_slat_wtb_engaged(&self) -> bool {
+            self.query(|a| a.slat_flap_complex.slat_wingtip_brakes_engaged())
+        }
+
         fn get_flaps_conf(&self) -> FlapsConf {
             self.query(|a| a.slat_flap_complex.sfcc.flaps_conf)
         }
@@ -1524,4 +1603,64 @@ mod tests {
                 <= angle_delta
         );
     }
+
+    #[test]
+    fn flaps_asymmetry_engages_wingtip_brakes_and_locks_flaps() {
+        let mut test_bed = test_bed_with()
+            .set_green_hyd_pressure()
+            .set_yellow_hyd_pressure()
+            .set_blue_hyd_pressure()
+            .set_indicated_airspeed(0.)
+            .set_flaps_handle_position(2)
+            .run_waiting_for(Duration::from_secs(5));
+
+        test_bed = test_bed
+            .jam_left_flaps()
+            .run_waiting_for(Duration::from_secs(2));
+
+        assert!(test_bed.is_flap_wtb_engaged());
+        assert!(!test_bed.is_slat_wtb_engaged());
+        assert!(test_bed
+            .query(|a| a.slat_flap_complex.flap_demand())
+            .is_none());
+
+        let locked_angle = test_bed.get_flaps_fppu_feedback();
+        test_bed = test_bed.run_waiting_for(Duration::from_secs(10));
+        assert!((test_bed.get_flaps_fppu_feedback() - locked_angle).abs() < f64::EPSILON);
+
+        assert!(test_bed.read_slat_flap_system_status_word().get_bit(12));
+        assert!(test_bed.read_slat_flap_system_status_word().get_bit(14));
+        assert!(test_bed.read_slat_flap_system_status_word().get_bit(16));
+        assert!(test_bed.read_slat_flap_actual_position_word().get_bit(24));
+        assert!(test_bed.read_slat_flap_actual_position_word().get_bit(25));
+        assert!(test_bed.read_slat_flap_actual_position_word().get_bit(29));
+    }
+
+    #[test]
+    fn wingtip_brakes_released_by_maintenance_reset_on_ground() {
+        let mut test_bed = test_bed_with()
+            .set_green_hyd_pressure()
+            .set_yellow_hyd_pressure()
+            .set_blue_hyd_pressure()
+            .set_indicated_airspeed(0.)
+            .on_ground()
+            .set_flaps_handle_position(0)
+            .run_waiting_for(Duration::from_secs(5))
+            .move_slats_without_demand(10.)
+            .run_one_tick();
+
+        assert!(test_bed.is_slat_wtb_engaged());
+
+        test_bed = test_bed.command_wtb_reset(true).run_one_tick();
+
+        assert!(!test_bed.is_slat_wtb_engaged());
+        assert!(!test_bed.read_slat_flap_system_status_word().get_bit(15));
+
+        test_bed = test_bed
+            .command_wtb_reset(false)
+            .run_waiting_for(Duration::from_secs(10));
+
+        assert!(!test_bed.is_slat_wtb_engaged());
+        assert!(test_bed.get_slats_fppu_feedback().abs() < 1.);
+    }
 }
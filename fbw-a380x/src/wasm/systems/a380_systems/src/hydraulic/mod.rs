@@ -37,7 +37,8 @@ use systems::{
         pumps::PumpCharacteristics,
         pushback::PushbackTug,
         trimmable_horizontal_stabilizer::{
-            TrimmableHorizontalStabilizerActuator, TrimmableHorizontalStabilizerMotorController,
+            PitchTrimMotorPowerSupplySelector, TrimmableHorizontalStabilizerActuator,
+            TrimmableHorizontalStabilizerMotorController,
         },
         Accumulator, ElectricPump, EngineDrivenPump, HeatingElement, HydraulicCircuit,
         HydraulicCircuitController, HydraulicPressureSensors, ManualPump, PressureSwitch,
@@ -49,7 +50,8 @@ use systems::{
         interpolation, random_from_range, update_iterator::MaxStepLoop, AdirsDiscreteOutputs,
         AdirsMeasurementOutputs, AirbusElectricPumpId, AirbusEngineDrivenPumpId, CargoDoorLocked,
         DelayedFalseLogicGate, DelayedTrueLogicGate, ElectricalBusType, ElectricalBuses,
-        EngineFirePushButtons, GearWheel, HydraulicColor, LandingGearHandle, LgciuInterface,
+        EngineFirePushButtons, FlapSlatTransmissionId, FlightControlActuatorId,
+        FlightControlSurfaceId, GearWheel, HydraulicColor, LandingGearHandle, LgciuInterface,
         LgciuWeightOnWheels, ReservoirAirPressure, SectionPressure, SurfacesPositions,
     },
     simulation::{
@@ -66,6 +68,8 @@ mod engine_pump_disc;
 use engine_pump_disc::EnginePumpDisconnectionClutch;
 pub mod autobrakes;
 use autobrakes::A380AutobrakeController;
+pub mod body_wheel_steering;
+use body_wheel_steering::{A380BodyWheelSteeringActuator, A380BodyWheelSteeringController};
 
 #[cfg(test)]
 use systems::hydraulic::PressureSwitchState;
@@ -200,11 +204,14 @@ impl A380HydraulicCircuitFactory {
             true,
             Pressure::new::<psi>(Self::HYDRAULIC_TARGET_PRESSURE_PSI),
             PriorityValve::new(
+                HydraulicColor::Green,
                 Pressure::new::<psi>(Self::PRIORITY_VALVE_PRESSURE_CUTOFF_PSI),
                 Pressure::new::<psi>(Self::PRIORITY_VALVE_PRESSURE_OPENED_PSI),
             ),
             Pressure::new::<psi>(Self::ACCUMULATOR_GAS_PRE_CHARGE_PSI),
             Volume::new::<gallon>(Self::ACCUMULATOR_MAX_VOLUME_GALLONS),
+            ElectricalBusType::DirectCurrentEssential,
+            ElectricalBusType::DirectCurrentGndFltService,
         )
     }
 
@@ -226,11 +233,14 @@ impl A380HydraulicCircuitFactory {
             false,
             Pressure::new::<psi>(Self::HYDRAULIC_TARGET_PRESSURE_PSI),
             PriorityValve::new(
+                HydraulicColor::Yellow,
                 Pressure::new::<psi>(Self::PRIORITY_VALVE_PRESSURE_CUTOFF_PSI),
                 Pressure::new::<psi>(Self::PRIORITY_VALVE_PRESSURE_OPENED_PSI),
             ),
             Pressure::new::<psi>(Self::ACCUMULATOR_GAS_PRE_CHARGE_PSI),
             Volume::new::<gallon>(Self::ACCUMULATOR_MAX_VOLUME_GALLONS),
+            ElectricalBusType::DirectCurrentEssential,
+            ElectricalBusType::DirectCurrentGndFltService,
         )
     }
 }
@@ -451,6 +461,7 @@ impl A380AileronFactory {
         context: &mut InitContext,
         init_drooped_down: bool,
         powered_by: Option<ElectricalBusType>,
+        id: ActuatorSide,
         panel: AileronPanelPosition,
     ) -> HydraulicLinearActuatorAssembly<2> {
         let aileron_body = Self::a380_aileron_body(init_drooped_down, panel);
@@ -459,9 +470,21 @@ impl A380AileronFactory {
         let aileron_actuator_inward =
             Self::a380_aileron_actuator(context, &aileron_body, powered_by);
 
-        HydraulicLinearActuatorAssembly::new(
+        let surface = match id {
+            ActuatorSide::Left => FlightControlSurfaceId::LeftAileron(panel as usize + 1),
+            ActuatorSide::Right => FlightControlSurfaceId::RightAileron(panel as usize + 1),
+        };
+
+        HydraulicLinearActuatorAssembly::new_with_failures(
             [aileron_actuator_outward, aileron_actuator_inward],
             aileron_body,
+            [
+                FlightControlActuatorId::new(
+                    surface,
+                    AileronActuatorPosition::Outward as usize + 1,
+                ),
+                FlightControlActuatorId::new(surface, AileronActuatorPosition::Inward as usize + 1),
+            ],
         )
     }
 
@@ -471,18 +494,21 @@ impl A380AileronFactory {
             context,
             init_drooped_down,
             None,
+            id,
             AileronPanelPosition::Outward,
         );
         let assembly_middle = Self::a380_aileron_assembly(
             context,
             init_drooped_down,
             Some(Self::MIDDLE_PANEL_EHA_BUS),
+            id,
             AileronPanelPosition::Middle,
         );
         let assembly_inward = Self::a380_aileron_assembly(
             context,
             init_drooped_down,
             Some(Self::INWARD_PANEL_EHA_BUS),
+            id,
             AileronPanelPosition::Inward,
         );
         AileronAssembly::new(
@@ -605,13 +631,24 @@ impl A380SpoilerFactory {
     /// Builds a spoiler assembly consisting of the spoiler physical rigid body and one hydraulic actuator
     fn a380_spoiler_assembly(
         context: &mut InitContext,
+        id: ActuatorSide,
+        id_number: usize,
         powered_by: Option<ElectricalBusType>,
     ) -> HydraulicLinearActuatorAssembly<1> {
         let spoiler_body = Self::a380_spoiler_body();
 
         let spoiler_actuator = Self::a380_spoiler_actuator(context, &spoiler_body, powered_by);
 
-        HydraulicLinearActuatorAssembly::new([spoiler_actuator], spoiler_body)
+        let surface = match id {
+            ActuatorSide::Left => FlightControlSurfaceId::LeftSpoiler(id_number),
+            ActuatorSide::Right => FlightControlSurfaceId::RightSpoiler(id_number),
+        };
+
+        HydraulicLinearActuatorAssembly::new_with_failures(
+            [spoiler_actuator],
+            spoiler_body,
+            [FlightControlActuatorId::new(surface, 1)],
+        )
     }
 
     fn new_a380_spoiler_group(context: &mut InitContext, id: ActuatorSide) -> SpoilerGroup {
@@ -644,7 +681,7 @@ impl A380SpoilerFactory {
         id_number: usize,
         powered_by: Option<ElectricalBusType>,
     ) -> SpoilerElement {
-        let assembly = Self::a380_spoiler_assembly(context, powered_by);
+        let assembly = Self::a380_spoiler_assembly(context, id, id_number, powered_by);
         SpoilerElement::new(
             context,
             id,
@@ -784,6 +821,7 @@ impl A380ElevatorFactory {
         context: &mut InitContext,
         init_drooped_down: bool,
         powered_by: Option<ElectricalBusType>,
+        id: ActuatorSide,
         is_outer: bool,
     ) -> HydraulicLinearActuatorAssembly<2> {
         let elevator_body = Self::a380_elevator_body(init_drooped_down, is_outer);
@@ -793,9 +831,30 @@ impl A380ElevatorFactory {
         let elevator_actuator_inbord =
             Self::a380_elevator_actuator(context, &elevator_body, powered_by);
 
-        HydraulicLinearActuatorAssembly::new(
+        let panel = if is_outer {
+            ElevatorPanelPosition::Outward
+        } else {
+            ElevatorPanelPosition::Inward
+        } as usize
+            + 1;
+        let surface = match id {
+            ActuatorSide::Left => FlightControlSurfaceId::LeftElevator(panel),
+            ActuatorSide::Right => FlightControlSurfaceId::RightElevator(panel),
+        };
+
+        HydraulicLinearActuatorAssembly::new_with_failures(
             [elevator_actuator_outboard, elevator_actuator_inbord],
             elevator_body,
+            [
+                FlightControlActuatorId::new(
+                    surface,
+                    ElevatorActuatorPosition::Outward as usize + 1,
+                ),
+                FlightControlActuatorId::new(
+                    surface,
+                    ElevatorActuatorPosition::Inward as usize + 1,
+                ),
+            ],
         )
     }
 
@@ -810,6 +869,7 @@ impl A380ElevatorFactory {
             } else {
                 Some(Self::RIGHT_OUTWARD_PANEL_EHA_BUS)
             },
+            id,
             true,
         );
         let assembly_inward = Self::a380_elevator_assembly(
@@ -820,6 +880,7 @@ impl A380ElevatorFactory {
             } else {
                 Some(Self::RIGHT_INWARD_PANEL_EHA_BUS)
             },
+            id,
             false,
         );
         ElevatorAssembly::new(
@@ -973,9 +1034,22 @@ impl A380RudderFactory {
         let rudder_actuator_lower =
             Self::a380_rudder_actuator(context, &rudder_body, lower_powered_by);
 
-        HydraulicLinearActuatorAssembly::new(
+        let surface = FlightControlSurfaceId::Rudder(
+            if is_upper_body {
+                RudderPanelPosition::Upper
+            } else {
+                RudderPanelPosition::Lower
+            } as usize
+                + 1,
+        );
+
+        HydraulicLinearActuatorAssembly::new_with_failures(
             [rudder_actuator_upper, rudder_actuator_lower],
             rudder_body,
+            [
+                FlightControlActuatorId::new(surface, RudderActuatorPosition::Upper as usize + 1),
+                FlightControlActuatorId::new(surface, RudderActuatorPosition::Lower as usize + 1),
+            ],
         )
     }
 
@@ -1500,6 +1574,9 @@ impl A380GearSystemFactory {
 pub(super) struct A380Hydraulic {
     nose_steering: SteeringActuator,
 
+    body_wheel_steering: A380BodyWheelSteeringActuator,
+    body_wheel_steering_controller: A380BodyWheelSteeringController,
+
     core_hydraulic_updater: MaxStepLoop,
 
     brake_steer_computer: A380HydraulicBrakeSteerComputerUnit,
@@ -1644,6 +1721,15 @@ impl A380Hydraulic {
                 Ratio::new::<ratio>(0.18),
             ),
 
+            body_wheel_steering: A380BodyWheelSteeringActuator::new(
+                context,
+                Angle::new::<degree>(15.),
+                AngularVelocity::new::<radian_per_second>(0.15),
+                Length::new::<meter>(0.06),
+                Ratio::new::<ratio>(0.2),
+            ),
+            body_wheel_steering_controller: A380BodyWheelSteeringController::new(context),
+
             core_hydraulic_updater: MaxStepLoop::new(Self::HYDRAULIC_SIM_TIME_STEP),
 
             brake_steer_computer: A380HydraulicBrakeSteerComputerUnit::new(context),
@@ -1825,6 +1911,8 @@ impl A380Hydraulic {
             flap_system: FlapSlatAssembly::new(
                 context,
                 "FLAPS",
+                FlapSlatTransmissionId::FlapsLeft,
+                FlapSlatTransmissionId::FlapsRight,
                 Volume::new::<cubic_inch>(0.32),
                 AngularVelocity::new::<radian_per_second>(0.13),
                 Angle::new::<degree>(251.97),
@@ -1838,6 +1926,8 @@ impl A380Hydraulic {
             slat_system: FlapSlatAssembly::new(
                 context,
                 "SLATS",
+                FlapSlatTransmissionId::SlatsLeft,
+                FlapSlatTransmissionId::SlatsRight,
                 Volume::new::<cubic_inch>(0.32),
                 AngularVelocity::new::<radian_per_second>(0.13),
                 Angle::new::<degree>(334.16),
@@ -2222,6 +2312,14 @@ impl A380Hydraulic {
             &self.bypass_pin,
         );
 
+        self.body_wheel_steering_controller
+            .update(self.nose_steering.position_feedback(), lgciu1);
+        self.body_wheel_steering.update(
+            context,
+            self.yellow_circuit.system_section(),
+            &self.body_wheel_steering_controller,
+        );
+
         // Process brake logic (which circuit brakes) and send brake demands (how much)
         self.brake_steer_computer.update(
             context,
@@ -2256,6 +2354,7 @@ impl A380Hydraulic {
             context,
             self.slats_flaps_complex.flap_demand(),
             self.slats_flaps_complex.flap_demand(),
+            self.slats_flaps_complex.flap_wingtip_brakes_engaged(),
             self.green_circuit.system_section(),
             self.yellow_circuit.system_section(),
         );
@@ -2264,6 +2363,7 @@ impl A380Hydraulic {
             context,
             self.slats_flaps_complex.slat_demand(),
             self.slats_flaps_complex.slat_demand(),
+            self.slats_flaps_complex.slat_wingtip_brakes_engaged(),
             self.green_circuit.system_section(),
             self.green_circuit.system_section(),
         );
@@ -2460,6 +2560,9 @@ impl A380Hydraulic {
         self.yellow_circuit
             .update_system_actuator_volumes(&mut self.nose_steering);
 
+        self.yellow_circuit
+            .update_system_actuator_volumes(&mut self.body_wheel_steering);
+
         self.yellow_circuit
             .update_system_actuator_volumes(self.right_elevator.actuator(
                 ElevatorActuatorPosition::Outward,
@@ -2907,6 +3010,8 @@ impl SimulationElement for A380Hydraulic {
         self.braking_force.accept(visitor);
 
         self.nose_steering.accept(visitor);
+        self.body_wheel_steering_controller.accept(visitor);
+        self.body_wheel_steering.accept(visitor);
         self.slats_flaps_complex.accept(visitor);
         self.flap_system.accept(visitor);
         self.slat_system.accept(visitor);
@@ -5394,9 +5499,20 @@ struct TrimmableHorizontalStabilizerSystemHydraulicController {
     position_requests_from_fbw: [Angle; 2],
     solenoid_energized_from_fbw: [bool; 2],
 
+    solenoid_power_supplies: [PitchTrimMotorPowerSupplySelector; 2],
+
     controllers: [TrimmableHorizontalStabilizerMotorElectricalController; 2],
 }
 impl TrimmableHorizontalStabilizerSystemHydraulicController {
+    // Green motor servo is driven by PRIM 1, yellow motor servo by PRIM 2. Servo solenoids are supplied
+    // through the power supply of the PRIM driving them
+    const GREEN_SERVO_NORMAL_POWER_BUS: ElectricalBusType =
+        ElectricalBusType::DirectCurrentEssential;
+    const GREEN_SERVO_STANDBY_POWER_BUS: ElectricalBusType = ElectricalBusType::DirectCurrentHot(1);
+    const YELLOW_SERVO_NORMAL_POWER_BUS: ElectricalBusType = ElectricalBusType::DirectCurrent(2);
+    const YELLOW_SERVO_STANDBY_POWER_BUS: ElectricalBusType =
+        ElectricalBusType::DirectCurrentHot(2);
+
     fn new(context: &mut InitContext) -> Self {
         Self {
             ths_green_actuator_solenoid_id: context
@@ -5412,6 +5528,18 @@ impl TrimmableHorizontalStabilizerSystemHydraulicController {
             position_requests_from_fbw: [Angle::default(); 2],
             solenoid_energized_from_fbw: [false; 2],
 
+            // Power supplies are in green->yellow order
+            solenoid_power_supplies: [
+                PitchTrimMotorPowerSupplySelector::new(
+                    Self::GREEN_SERVO_NORMAL_POWER_BUS,
+                    Some(Self::GREEN_SERVO_STANDBY_POWER_BUS),
+                ),
+                PitchTrimMotorPowerSupplySelector::new(
+                    Self::YELLOW_SERVO_NORMAL_POWER_BUS,
+                    Some(Self::YELLOW_SERVO_STANDBY_POWER_BUS),
+                ),
+            ],
+
             // Controllers are in green->yellow order
             controllers: [
                 TrimmableHorizontalStabilizerMotorElectricalController::new(),
@@ -5443,17 +5571,23 @@ impl TrimmableHorizontalStabilizerSystemHydraulicController {
     }
 
     fn update_ths_controllers_solenoids(&mut self) {
-        self.controllers[TrimmableHorizontalStabilizerMotorPosition::Green as usize].set_mode(
-            self.solenoid_energized_from_fbw
-                [TrimmableHorizontalStabilizerMotorPosition::Green as usize],
-        );
-        self.controllers[TrimmableHorizontalStabilizerMotorPosition::Yellow as usize].set_mode(
-            self.solenoid_energized_from_fbw
-                [TrimmableHorizontalStabilizerMotorPosition::Yellow as usize],
-        );
+        for ((controller, energized_from_fbw), power_supply) in self
+            .controllers
+            .iter_mut()
+            .zip(self.solenoid_energized_from_fbw)
+            .zip(&self.solenoid_power_supplies)
+        {
+            controller.set_mode(energized_from_fbw && power_supply.is_powered());
+        }
     }
 }
 impl SimulationElement for TrimmableHorizontalStabilizerSystemHydraulicController {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.solenoid_power_supplies, visitor);
+
+        visitor.visit(self);
+    }
+
     fn read(&mut self, reader: &mut SimulatorReader) {
         self.position_requests_from_fbw = [
             -Angle::new::<degree>(reader.read(&self.ths_green_actuator_position_demand_id)),
@@ -6389,8 +6523,9 @@ struct A380GravityExtension {
     handle_angle: Angle,
 }
 impl A380GravityExtension {
-    const PLACEHOLDER_SPEED_TO_ROTATE_HANDLE_DEG_S: f64 = 50.;
-    const PLACEHOLDER_SPEED_TO_RESET_HANDLE_DEG_S: f64 = 200.;
+    // Release mechanism is motor driven: each of the three release stages takes a fixed time
+    const TIME_PER_HANDLE_TURN_S: f64 = 10.;
+    const TIME_TO_RESET_HANDLE_S: f64 = 5.;
 
     fn new(context: &mut InitContext) -> Self {
         Self {
@@ -6406,10 +6541,10 @@ impl A380GravityExtension {
     fn update(&mut self, context: &UpdateContext) {
         let angle_change: Angle = match self.switch_position {
             A380GravityExtensionSwitchPosition::Reset => Angle::new::<degree>(
-                -Self::PLACEHOLDER_SPEED_TO_RESET_HANDLE_DEG_S * context.delta_as_secs_f64(),
+                -360. * 3. / Self::TIME_TO_RESET_HANDLE_S * context.delta_as_secs_f64(),
             ),
             A380GravityExtensionSwitchPosition::Extension => Angle::new::<degree>(
-                Self::PLACEHOLDER_SPEED_TO_ROTATE_HANDLE_DEG_S * context.delta_as_secs_f64(),
+                360. / Self::TIME_PER_HANDLE_TURN_S * context.delta_as_secs_f64(),
             ),
             A380GravityExtensionSwitchPosition::Off => Angle::default(),
         };
@@ -6487,7 +6622,10 @@ mod tests {
             engine::{trent_engine::TrentEngine, EngineFireOverheadPanel},
             failures::FailureType,
             hydraulic::cargo_doors::{DoorControlState, HydraulicDoorController},
-            landing_gear::{GearSystemState, LandingGear, LandingGearControlInterfaceUnitSet},
+            landing_gear::{
+                shock_strut::LandingGearCharacteristics, GearSystemState, LandingGear,
+                LandingGearControlInterfaceUnitSet,
+            },
             shared::{
                 arinc429::{Arinc429Word, SignStatus},
                 EmergencyElectricalState, LgciuId, PotentialOrigin,
@@ -6685,7 +6823,7 @@ mod tests {
                     overhead: A380HydraulicOverheadPanel::new(context),
                     autobrake_panel: A380AutobrakePanel::new(context),
                     engine_fire_overhead: EngineFireOverheadPanel::new(context),
-                    landing_gear: LandingGear::new(context),
+                    landing_gear: LandingGear::new(context, LandingGearCharacteristics::a380()),
                     lgcius: LandingGearControlInterfaceUnitSet::new(
                         context,
                         ElectricalBusType::DirectCurrentEssential,
@@ -6871,9 +7009,17 @@ mod tests {
                 self.is_ac_eha_powered = bus_is_alive;
             }
 
-            fn _set_dc_ess_is_powered(&mut self, bus_is_alive: bool) {
+            fn set_dc_ess_is_powered(&mut self, bus_is_alive: bool) {
                 self.is_dc_ess_powered = bus_is_alive;
             }
+
+            fn set_dc_hot_bus_is_powered(&mut self, bus_number: usize, bus_is_alive: bool) {
+                match bus_number {
+                    1 => self.is_dc_hot_1_powered = bus_is_alive,
+                    2 => self.is_dc_hot_2_powered = bus_is_alive,
+                    _ => panic!("No DC HOT bus {} in test aircraft", bus_number),
+                }
+            }
         }
 
         impl Aircraft for A380HydraulicsTestAircraft {
@@ -7224,6 +7370,40 @@ mod tests {
                 Ratio::new::<ratio>(self.read_by_name("NOSE_WHEEL_POSITION_RATIO"))
             }
 
+            fn get_body_wheel_steering_ratio(&mut self) -> Ratio {
+                Ratio::new::<ratio>(self.read_by_name("BODY_WHEEL_STEERING_POSITION_RATIO"))
+            }
+
+            fn is_body_wheel_steering_locked(&mut self) -> bool {
+                self.read_by_name("BODY_WHEEL_STEERING_LOCKED")
+            }
+
+            fn ths_deflection(&mut self) -> Angle {
+                Angle::new::<degree>(ReadByName::<A380HydraulicsTestBed, f64>::read_by_name(
+                    self,
+                    "HYD_FINAL_THS_DEFLECTION",
+                ))
+            }
+
+            fn set_ths_servo_command(mut self, color: HydraulicColor, position: Angle) -> Self {
+                let color_name = match color {
+                    HydraulicColor::Green => "GREEN",
+                    HydraulicColor::Yellow => "YELLOW",
+                    HydraulicColor::Blue => panic!("NO BLUE CIRCUIT IN A380"),
+                };
+
+                self.write_by_name(
+                    &format!("THS_{}_SERVO_SOLENOID_ENERGIZED", color_name),
+                    true,
+                );
+                // Flight computers send THS position with inverted sign
+                self.write_by_name(
+                    &format!("THS_{}_COMMANDED_POSITION", color_name),
+                    -position.get::<degree>(),
+                );
+                self
+            }
+
             fn _is_fire_valve_eng1_closed(&mut self) -> bool {
                 !ReadByName::<A380HydraulicsTestBed, bool>::read_by_name(
                     self,
@@ -7611,13 +7791,18 @@ mod tests {
                 self
             }
 
-            fn _dc_ess_lost(mut self) -> Self {
-                self.command(|a| a._set_dc_ess_is_powered(false));
+            fn dc_ess_lost(mut self) -> Self {
+                self.command(|a| a.set_dc_ess_is_powered(false));
                 self
             }
 
             fn _dc_ess_active(mut self) -> Self {
-                self.command(|a| a._set_dc_ess_is_powered(true));
+                self.command(|a| a.set_dc_ess_is_powered(true));
+                self
+            }
+
+            fn dc_hot_bus_lost(mut self, bus_number: usize) -> Self {
+                self.command(|a| a.set_dc_hot_bus_is_powered(bus_number, false));
                 self
             }
 
@@ -8086,6 +8271,58 @@ mod tests {
             test_bed_in_flight()
         }
 
+        fn test_bed_with_green_and_yellow_pressurised() -> A380HydraulicsTestBed {
+            test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .start_eng1(Ratio::new::<percent>(80.))
+                .start_eng4(Ratio::new::<percent>(80.))
+                .run_waiting_for(Duration::from_secs(5))
+        }
+
+        #[test]
+        fn ths_green_servo_trims_on_dc_hot_1_when_dc_ess_lost() {
+            let mut test_bed = test_bed_with_green_and_yellow_pressurised()
+                .dc_ess_lost()
+                .set_ths_servo_command(HydraulicColor::Green, Angle::new::<degree>(3.))
+                .run_waiting_for(Duration::from_secs(10));
+
+            assert!(test_bed.ths_deflection().get::<degree>() > 2.9);
+        }
+
+        #[test]
+        fn ths_green_servo_is_stuck_when_dc_ess_and_dc_hot_1_lost() {
+            let mut test_bed = test_bed_with_green_and_yellow_pressurised()
+                .dc_ess_lost()
+                .dc_hot_bus_lost(1)
+                .set_ths_servo_command(HydraulicColor::Green, Angle::new::<degree>(3.))
+                .run_waiting_for(Duration::from_secs(10));
+
+            assert!(test_bed.ths_deflection().get::<degree>().abs() < 0.1);
+        }
+
+        #[test]
+        fn ths_yellow_servo_trims_on_dc_hot_2_when_dc_2_lost() {
+            let mut test_bed = test_bed_with_green_and_yellow_pressurised()
+                .dc_bus_2_lost()
+                .set_ths_servo_command(HydraulicColor::Yellow, Angle::new::<degree>(3.))
+                .run_waiting_for(Duration::from_secs(10));
+
+            assert!(test_bed.ths_deflection().get::<degree>() > 2.9);
+        }
+
+        #[test]
+        fn ths_yellow_servo_is_stuck_when_dc_2_and_dc_hot_2_lost() {
+            let mut test_bed = test_bed_with_green_and_yellow_pressurised()
+                .dc_bus_2_lost()
+                .dc_hot_bus_lost(2)
+                .set_ths_servo_command(HydraulicColor::Yellow, Angle::new::<degree>(3.))
+                .run_waiting_for(Duration::from_secs(10));
+
+            assert!(test_bed.ths_deflection().get::<degree>().abs() < 0.1);
+        }
+
         #[test]
         fn outward_left_aileron_panel_responds_only_with_green_pressure_on_outward_jack() {
             let mut test_bed = test_bed_on_ground_with()
@@ -10099,6 +10336,57 @@ mod tests {
             assert!(test_bed.nose_steering_position().get::<degree>() <= 0.1);
         }
 
+        #[test]
+        fn body_wheel_steering_stays_locked_when_nose_steering_does_not_move() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .set_yellow_e_pump_a(false)
+                .start_eng1(Ratio::new::<percent>(80.))
+                .start_eng2(Ratio::new::<percent>(80.))
+                .set_anti_skid(false)
+                .run_one_tick();
+
+            test_bed = test_bed
+                .set_tiller_demand(Ratio::new::<ratio>(1.))
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert!(test_bed.is_body_wheel_steering_locked());
+            assert!(test_bed.get_body_wheel_steering_ratio().get::<ratio>() == 0.);
+        }
+
+        #[test]
+        fn body_wheel_steering_steers_opposite_to_nose_steering_at_full_tiller() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .set_yellow_e_pump_a(false)
+                .start_eng1(Ratio::new::<percent>(80.))
+                .start_eng2(Ratio::new::<percent>(80.))
+                .set_anti_skid(true)
+                .run_one_tick();
+
+            assert!(test_bed.is_body_wheel_steering_locked());
+
+            test_bed = test_bed
+                .set_tiller_demand(Ratio::new::<ratio>(1.))
+                .run_waiting_for(Duration::from_secs_f64(10.));
+
+            assert!(test_bed.nose_steering_position().get::<degree>() > 20.);
+            assert!(!test_bed.is_body_wheel_steering_locked());
+            assert!(test_bed.get_body_wheel_steering_ratio().get::<ratio>() < -0.5);
+
+            test_bed = test_bed
+                .set_tiller_demand(Ratio::new::<ratio>(-1.))
+                .run_waiting_for(Duration::from_secs_f64(15.));
+
+            assert!(test_bed.nose_steering_position().get::<degree>() < -20.);
+            assert!(!test_bed.is_body_wheel_steering_locked());
+            assert!(test_bed.get_body_wheel_steering_ratio().get::<ratio>() > 0.5);
+        }
+
         #[test]
         fn yellow_epump_has_cavitation_at_low_air_press() {
             let mut test_bed = test_bed_on_ground_with()
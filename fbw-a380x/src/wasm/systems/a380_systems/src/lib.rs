@@ -34,7 +34,14 @@ use icing::Icing;
 use navigation::A380RadioAltimeters;
 use payload::A380Payload;
 use power_consumption::A380PowerConsumption;
-use uom::si::{f64::Length, length::nautical_mile, quantities::Velocity, velocity::knot};
+use std::time::Duration;
+use uom::si::{
+    f64::{Length, Volume},
+    length::nautical_mile,
+    quantities::Velocity,
+    velocity::knot,
+    volume::liter,
+};
 
 use systems::{
     accept_iterable,
@@ -45,11 +52,18 @@ use systems::{
     electrical::{Electricity, ElectricitySource, ExternalPowerSource},
     engine::{trent_engine::TrentEngine, EngineFireOverheadPanel},
     enhanced_gpwc::EnhancedGroundProximityWarningComputer,
-    landing_gear::{LandingGear, LandingGearControlInterfaceUnitSet},
+    fire_protection::{
+        ApuFireProtection, FireDetectionZone, FireProtectionZone, SmokeDetectionControlUnit,
+        SmokeDetectionZone,
+    },
+    landing_gear::{
+        shock_strut::LandingGearCharacteristics, LandingGear, LandingGearControlInterfaceUnitSet,
+    },
     navigation::adirs::{
         AirDataInertialReferenceSystem, AirDataInertialReferenceSystemOverheadPanel,
     },
-    shared::{ElectricalBusType, MachNumber},
+    oxygen::{OxygenOverheadPanel, OxygenSystem},
+    shared::{ElectricalBusType, EngineFirePushButtons, MachNumber},
     simulation::{
         Aircraft, InitContext, SimulationElement, SimulationElementVisitor, UpdateContext,
     },
@@ -90,6 +104,11 @@ pub struct A380 {
     egpwc: EnhancedGroundProximityWarningComputer,
     icing_simulation: Icing,
     structural_flex: A380StructuralFlex,
+    oxygen: OxygenSystem,
+    oxygen_overhead: OxygenOverheadPanel,
+    engine_fire_protection: [FireProtectionZone; 4],
+    apu_fire_protection: ApuFireProtection,
+    sdcu: SmokeDetectionControlUnit,
 }
 impl A380 {
     pub fn new(context: &mut InitContext) -> A380 {
@@ -103,6 +122,16 @@ impl A380 {
                 context,
                 Velocity::new::<knot>(340.),
                 MachNumber(0.89),
+                [
+                    ElectricalBusType::AlternatingCurrentEssential,
+                    ElectricalBusType::AlternatingCurrent(4),
+                    ElectricalBusType::AlternatingCurrent(1),
+                ],
+                [
+                    ElectricalBusType::DirectCurrentHot(3),
+                    ElectricalBusType::DirectCurrentHot(2),
+                    ElectricalBusType::DirectCurrentHot(1),
+                ],
             ),
             adirs_overhead: AirDataInertialReferenceSystemOverheadPanel::new(context),
             air_conditioning: A380AirConditioning::new(context),
@@ -137,7 +166,7 @@ impl A380 {
             hydraulic: A380Hydraulic::new(context),
             hydraulic_overhead: A380HydraulicOverheadPanel::new(context),
             autobrake_panel: A380AutobrakePanel::new(context),
-            landing_gear: LandingGear::new(context),
+            landing_gear: LandingGear::new(context, LandingGearCharacteristics::a380()),
             pneumatic: A380Pneumatic::new(context),
             radio_altimeters: A380RadioAltimeters::new(context),
             cds: A380ControlDisplaySystem::new(context),
@@ -159,6 +188,39 @@ impl A380 {
 
             icing_simulation: Icing::new(context),
             structural_flex: A380StructuralFlex::new(context),
+            oxygen: OxygenSystem::new(
+                context,
+                Volume::new::<liter>(2. * 3256.),
+                Duration::from_secs(22 * 60),
+                ElectricalBusType::DirectCurrentEssential,
+            ),
+            oxygen_overhead: OxygenOverheadPanel::new(context),
+            engine_fire_protection: [1, 2, 3, 4].map(|number| {
+                FireProtectionZone::new(
+                    context,
+                    FireDetectionZone::Engine(number),
+                    ElectricalBusType::DirectCurrentEssential,
+                    &[
+                        ElectricalBusType::DirectCurrentEssential,
+                        ElectricalBusType::DirectCurrent(2),
+                    ],
+                )
+            }),
+            apu_fire_protection: ApuFireProtection::new(
+                context,
+                ElectricalBusType::DirectCurrentEssential,
+                ElectricalBusType::DirectCurrentHot(3),
+            ),
+            sdcu: SmokeDetectionControlUnit::new(
+                context,
+                &[
+                    SmokeDetectionZone::ForwardCargo,
+                    SmokeDetectionZone::AftCargo,
+                    SmokeDetectionZone::BulkCargo,
+                ],
+                ElectricalBusType::DirectCurrentEssential,
+                ElectricalBusType::DirectCurrentHot(3),
+            ),
         }
     }
 }
@@ -182,6 +244,7 @@ impl Aircraft for A380 {
                     && self.electrical_overhead.external_power_is_available(1)),
             self.pneumatic.apu_bleed_air_valve(),
             self.fuel.feed_one_tank_has_fuel(),
+            self.apu_fire_protection.fire_detected(),
         );
 
         self.electrical.update(
@@ -225,6 +288,7 @@ impl Aircraft for A380 {
 
         self.adcn.update();
         self.adcn_simvar_translation.update(&self.adcn);
+        self.landing_gear.update(context);
         self.lgcius.update(
             context,
             &self.landing_gear,
@@ -258,7 +322,7 @@ impl Aircraft for A380 {
         self.hydraulic_overhead.update(&self.hydraulic);
 
         self.adirs.update(context, &self.adirs_overhead);
-        self.adirs_overhead.update(context, &self.adirs);
+        self.adirs_overhead.update(&self.adirs);
 
         self.power_consumption.update(context);
 
@@ -275,12 +339,15 @@ impl Aircraft for A380 {
             &self.apu,
             &self.air_conditioning,
         );
+        self.sdcu.update(context);
+
         self.air_conditioning
             .mix_packs_air_update(self.pneumatic.packs());
         self.air_conditioning.update(
             context,
             &self.adirs,
             &self.hydraulic,
+            &self.sdcu,
             &self.adcn,
             [
                 &self.engine_1,
@@ -298,6 +365,16 @@ impl Aircraft for A380 {
 
         self.cds.update();
 
+        self.oxygen
+            .update(context, &self.oxygen_overhead, &self.air_conditioning);
+
+        for (index, engine_fire_protection) in self.engine_fire_protection.iter_mut().enumerate() {
+            engine_fire_protection
+                .update(context, self.engine_fire_overhead.is_released(index + 1));
+        }
+        self.apu_fire_protection
+            .update(context, self.apu_fire_overhead.fire_button_is_released());
+
         self.egpwc.update(&self.adirs, self.lgcius.lgciu1());
 
         self.structural_flex.update(
@@ -354,6 +431,11 @@ impl SimulationElement for A380 {
         self.egpwc.accept(visitor);
         self.icing_simulation.accept(visitor);
         self.structural_flex.accept(visitor);
+        self.oxygen.accept(visitor);
+        self.oxygen_overhead.accept(visitor);
+        accept_iterable!(self.engine_fire_protection, visitor);
+        self.apu_fire_protection.accept(visitor);
+        self.sdcu.accept(visitor);
 
         visitor.visit(self);
     }
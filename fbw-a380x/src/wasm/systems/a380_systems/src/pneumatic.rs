@@ -14,11 +14,13 @@ use systems::{
     air_conditioning::PackFlowControllers,
     overhead::{AutoOffFaultPushButton, OnOffFaultPushButton},
     pneumatic::{
-        valve::*, BleedMonitoringComputerIsAliveSignal, CompressionChamber,
-        ControllablePneumaticValve, CrossBleedValveSelectorKnob, CrossBleedValveSelectorMode,
-        DifferentialPressureTransducer, EngineCompressionChamberController, EngineModeSelector,
-        EngineState, PneumaticContainer, PneumaticPipe, PneumaticValveSignal, Precooler,
-        PressureTransducer, PressurisedReservoirWithExhaustValve, PressurizeableReservoir,
+        engine_anti_ice::{EngineAntiIce, EngineAntiIcePushButton},
+        valve::*,
+        BleedMonitoringComputerIsAliveSignal, CompressionChamber, ControllablePneumaticValve,
+        CrossBleedValveSelectorKnob, CrossBleedValveSelectorMode, DifferentialPressureTransducer,
+        EngineCompressionChamberController, EngineModeSelector, EngineState, PneumaticContainer,
+        PneumaticPipe, PneumaticValveSignal, Precooler, PressureTransducer,
+        PressurisedReservoirWithExhaustValve, PressurizeableReservoir,
         TargetPressureTemperatureSignal, VariableVolumeContainer,
     },
     shared::{
@@ -117,6 +119,8 @@ pub struct A380Pneumatic {
 
     cross_bleed_valves: [CrossBleedValve; 3],
 
+    engine_anti_ice: [EngineAntiIce; 4],
+
     fadec: FullAuthorityDigitalEngineControl,
     engine_starter_valve_controllers: [EngineStarterValveController; 4],
 
@@ -157,6 +161,12 @@ impl A380Pneumatic {
                 CrossBleedValve::new(context.get_identifier("PNEU_XBLEED_VALVE_C_OPEN".to_owned())),
                 CrossBleedValve::new(context.get_identifier("PNEU_XBLEED_VALVE_R_OPEN".to_owned())),
             ],
+            engine_anti_ice: [
+                EngineAntiIce::new(context, 1, ElectricalBusType::DirectCurrent(1)),
+                EngineAntiIce::new(context, 2, ElectricalBusType::DirectCurrent(1)),
+                EngineAntiIce::new(context, 3, ElectricalBusType::DirectCurrent(2)),
+                EngineAntiIce::new(context, 4, ElectricalBusType::DirectCurrent(2)),
+            ],
             fadec: FullAuthorityDigitalEngineControl::new(context),
             engine_starter_valve_controllers: [
                 EngineStarterValveController::new(1),
@@ -270,6 +280,20 @@ impl A380Pneumatic {
             );
         }
 
+        // Nacelle anti-ice air is tapped upstream of the pressure regulating valve
+        for (index, (engine_anti_ice, engine_system)) in self
+            .engine_anti_ice
+            .iter_mut()
+            .zip(self.engine_systems.iter_mut())
+            .enumerate()
+        {
+            engine_anti_ice.update(
+                context,
+                overhead_panel.engine_anti_ice_is_on(index + 1),
+                &mut engine_system.transfer_pressure_pipe,
+            );
+        }
+
         let [engine_1_system, engine_2_system, engine_3_system, engine_4_system] =
             &mut self.engine_systems;
         self.apu_bleed_air_valve.update_move_fluid(
@@ -407,6 +431,7 @@ impl SimulationElement for A380Pneumatic {
 
         accept_iterable!(self.cross_bleed_valves, visitor);
         accept_iterable!(self.engine_systems, visitor);
+        accept_iterable!(self.engine_anti_ice, visitor);
         accept_iterable!(self.packs, visitor);
 
         self.yellow_hydraulic_reservoir_with_valve.accept(visitor);
@@ -1080,6 +1105,7 @@ pub struct A380PneumaticOverheadPanel {
     engine_2_bleed: AutoOffFaultPushButton,
     engine_3_bleed: AutoOffFaultPushButton,
     engine_4_bleed: AutoOffFaultPushButton,
+    engine_anti_ice: [EngineAntiIcePushButton; 4],
 }
 impl A380PneumaticOverheadPanel {
     pub fn new(context: &mut InitContext) -> Self {
@@ -1090,6 +1116,12 @@ impl A380PneumaticOverheadPanel {
             engine_2_bleed: AutoOffFaultPushButton::new_auto(context, "PNEU_ENG_2_BLEED"),
             engine_3_bleed: AutoOffFaultPushButton::new_auto(context, "PNEU_ENG_3_BLEED"),
             engine_4_bleed: AutoOffFaultPushButton::new_auto(context, "PNEU_ENG_4_BLEED"),
+            engine_anti_ice: [
+                EngineAntiIcePushButton::new_off(context, 1),
+                EngineAntiIcePushButton::new_off(context, 2),
+                EngineAntiIcePushButton::new_off(context, 3),
+                EngineAntiIcePushButton::new_off(context, 4),
+            ],
         }
     }
 
@@ -1110,6 +1142,10 @@ impl A380PneumaticOverheadPanel {
             _ => panic!("Invalid engine number"),
         }
     }
+
+    pub fn engine_anti_ice_is_on(&self, engine_number: usize) -> bool {
+        self.engine_anti_ice[engine_number - 1].is_on()
+    }
 }
 impl EngineBleedPushbutton<4> for A380PneumaticOverheadPanel {
     fn engine_bleed_pushbuttons_are_auto(&self) -> [bool; 4] {
@@ -1129,6 +1165,7 @@ impl SimulationElement for A380PneumaticOverheadPanel {
         self.engine_2_bleed.accept(visitor);
         self.engine_3_bleed.accept(visitor);
         self.engine_4_bleed.accept(visitor);
+        accept_iterable!(self.engine_anti_ice, visitor);
 
         visitor.visit(self);
     }
@@ -3021,6 +3058,16 @@ mod tests {
         assert!(test_bed.contains_variable_with_name("PNEU_ENG_3_STARTER_VALVE_OPEN"));
         assert!(test_bed.contains_variable_with_name("PNEU_ENG_4_STARTER_VALVE_OPEN"));
 
+        assert!(test_bed.contains_variable_with_name("PNEU_ENG_1_ANTI_ICE_VALVE_OPEN"));
+        assert!(test_bed.contains_variable_with_name("PNEU_ENG_2_ANTI_ICE_VALVE_OPEN"));
+        assert!(test_bed.contains_variable_with_name("PNEU_ENG_3_ANTI_ICE_VALVE_OPEN"));
+        assert!(test_bed.contains_variable_with_name("PNEU_ENG_4_ANTI_ICE_VALVE_OPEN"));
+
+        assert!(test_bed.contains_variable_with_name("PNEU_ENG_1_ANTI_ICE_HAS_FAULT"));
+        assert!(test_bed.contains_variable_with_name("PNEU_ENG_2_ANTI_ICE_HAS_FAULT"));
+        assert!(test_bed.contains_variable_with_name("PNEU_ENG_3_ANTI_ICE_HAS_FAULT"));
+        assert!(test_bed.contains_variable_with_name("PNEU_ENG_4_ANTI_ICE_HAS_FAULT"));
+
         assert!(test_bed.contains_variable_with_name("PNEU_PACK_1_FLOW_VALVE_1_FLOW_RATE"));
         assert!(test_bed.contains_variable_with_name("PNEU_PACK_1_FLOW_VALVE_2_FLOW_RATE"));
         assert!(test_bed.contains_variable_with_name("PNEU_PACK_2_FLOW_VALVE_1_FLOW_RATE"));
@@ -1,346 +1,555 @@
 use systems::simulation::InitContext;
 use systems::{
-    electrical::consumption::{FlightPhasePowerConsumer, PowerConsumerFlightPhase},
+    electrical::consumption::{
+        EquipmentPowerConsumption, PowerConsumerCondition, PowerConsumerDefinition,
+        PowerConsumerFlightPhase,
+    },
     shared::ElectricalBusType,
     simulation::{SimulationElement, SimulationElementVisitor, UpdateContext},
 };
-use uom::si::{f64::*, power::watt};
 
-/// This type provides an aggregated form of power consumption.
-/// We haven't yet implemented all power consumers and thus need something to
-/// consume power, as otherwise electrical load is nearly 0.
+const ENGINES_RUNNING: [PowerConsumerFlightPhase; 5] = [
+    PowerConsumerFlightPhase::AfterStart,
+    PowerConsumerFlightPhase::Takeoff,
+    PowerConsumerFlightPhase::Flight,
+    PowerConsumerFlightPhase::Landing,
+    PowerConsumerFlightPhase::TaxiIn,
+];
+
+const TAKEOFF_AND_LANDING: [PowerConsumerFlightPhase; 2] = [
+    PowerConsumerFlightPhase::Takeoff,
+    PowerConsumerFlightPhase::Landing,
+];
+
+/// The equipment which isn't simulated by a system of its own. The wattages are derived
+/// from the per bus consumption figures provided by komp.
+const A380_POWER_CONSUMERS: [PowerConsumerDefinition; 72] = [
+    PowerConsumerDefinition::new(
+        "GALLEY 1",
+        ElectricalBusType::AlternatingCurrent(1),
+        14000.,
+        14000.,
+        PowerConsumerCondition::GalleyNotShed,
+    ),
+    PowerConsumerDefinition::new(
+        "IN FLIGHT ENTERTAINMENT 1",
+        ElectricalBusType::AlternatingCurrent(1),
+        6000.,
+        6000.,
+        PowerConsumerCondition::GalleyNotShed,
+    ),
+    PowerConsumerDefinition::new(
+        "CABIN LIGHTING 1",
+        ElectricalBusType::AlternatingCurrent(1),
+        3500.,
+        3800.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "LAVATORY WATER HEATERS 1",
+        ElectricalBusType::AlternatingCurrent(1),
+        1500.,
+        1500.,
+        PowerConsumerCondition::GalleyNotShed,
+    ),
+    PowerConsumerDefinition::new(
+        "RECIRCULATION FANS 1",
+        ElectricalBusType::AlternatingCurrent(1),
+        2800.,
+        4500.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "FEED TANK 1 MAIN PUMP",
+        ElectricalBusType::AlternatingCurrent(1),
+        1300.,
+        2600.,
+        PowerConsumerCondition::FlightPhases(&ENGINES_RUNNING),
+    ),
+    PowerConsumerDefinition::new(
+        "FEED TANK 1 STANDBY PUMP",
+        ElectricalBusType::AlternatingCurrent(1),
+        1300.,
+        2600.,
+        PowerConsumerCondition::FlightPhases(&TAKEOFF_AND_LANDING),
+    ),
+    PowerConsumerDefinition::new(
+        "AVIONICS 1",
+        ElectricalBusType::AlternatingCurrent(1),
+        5000.,
+        5000.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "AVIONICS VENTILATION 1",
+        ElectricalBusType::AlternatingCurrent(1),
+        1200.,
+        2000.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "CARGO HEAT AND VENTILATION 1",
+        ElectricalBusType::AlternatingCurrent(1),
+        1500.,
+        1500.,
+        PowerConsumerCondition::InFlight,
+    ),
+    PowerConsumerDefinition::new(
+        "GALLEY 2",
+        ElectricalBusType::AlternatingCurrent(2),
+        14000.,
+        14000.,
+        PowerConsumerCondition::GalleyNotShed,
+    ),
+    PowerConsumerDefinition::new(
+        "IN FLIGHT ENTERTAINMENT 2",
+        ElectricalBusType::AlternatingCurrent(2),
+        6000.,
+        6000.,
+        PowerConsumerCondition::GalleyNotShed,
+    ),
+    PowerConsumerDefinition::new(
+        "CABIN LIGHTING 2",
+        ElectricalBusType::AlternatingCurrent(2),
+        3500.,
+        3800.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "LAVATORY WATER HEATERS 2",
+        ElectricalBusType::AlternatingCurrent(2),
+        1500.,
+        1500.,
+        PowerConsumerCondition::GalleyNotShed,
+    ),
+    PowerConsumerDefinition::new(
+        "RECIRCULATION FANS 2",
+        ElectricalBusType::AlternatingCurrent(2),
+        2800.,
+        4500.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "FEED TANK 2 MAIN PUMP",
+        ElectricalBusType::AlternatingCurrent(2),
+        1300.,
+        2600.,
+        PowerConsumerCondition::FlightPhases(&ENGINES_RUNNING),
+    ),
+    PowerConsumerDefinition::new(
+        "FEED TANK 2 STANDBY PUMP",
+        ElectricalBusType::AlternatingCurrent(2),
+        1300.,
+        2600.,
+        PowerConsumerCondition::FlightPhases(&TAKEOFF_AND_LANDING),
+    ),
+    PowerConsumerDefinition::new(
+        "AVIONICS 2",
+        ElectricalBusType::AlternatingCurrent(2),
+        5000.,
+        5000.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "AVIONICS VENTILATION 2",
+        ElectricalBusType::AlternatingCurrent(2),
+        1200.,
+        2000.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "CARGO HEAT AND VENTILATION 2",
+        ElectricalBusType::AlternatingCurrent(2),
+        1500.,
+        1500.,
+        PowerConsumerCondition::InFlight,
+    ),
+    PowerConsumerDefinition::new(
+        "GALLEY 3",
+        ElectricalBusType::AlternatingCurrent(3),
+        14000.,
+        14000.,
+        PowerConsumerCondition::GalleyNotShed,
+    ),
+    PowerConsumerDefinition::new(
+        "IN FLIGHT ENTERTAINMENT 3",
+        ElectricalBusType::AlternatingCurrent(3),
+        6000.,
+        6000.,
+        PowerConsumerCondition::GalleyNotShed,
+    ),
+    PowerConsumerDefinition::new(
+        "CABIN LIGHTING 3",
+        ElectricalBusType::AlternatingCurrent(3),
+        3500.,
+        3800.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "LAVATORY WATER HEATERS 3",
+        ElectricalBusType::AlternatingCurrent(3),
+        1500.,
+        1500.,
+        PowerConsumerCondition::GalleyNotShed,
+    ),
+    PowerConsumerDefinition::new(
+        "RECIRCULATION FANS 3",
+        ElectricalBusType::AlternatingCurrent(3),
+        2800.,
+        4500.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "FEED TANK 3 MAIN PUMP",
+        ElectricalBusType::AlternatingCurrent(3),
+        1300.,
+        2600.,
+        PowerConsumerCondition::FlightPhases(&ENGINES_RUNNING),
+    ),
+    PowerConsumerDefinition::new(
+        "FEED TANK 3 STANDBY PUMP",
+        ElectricalBusType::AlternatingCurrent(3),
+        1300.,
+        2600.,
+        PowerConsumerCondition::FlightPhases(&TAKEOFF_AND_LANDING),
+    ),
+    PowerConsumerDefinition::new(
+        "AVIONICS 3",
+        ElectricalBusType::AlternatingCurrent(3),
+        5000.,
+        5000.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "AVIONICS VENTILATION 3",
+        ElectricalBusType::AlternatingCurrent(3),
+        1200.,
+        2000.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "CARGO HEAT AND VENTILATION 3",
+        ElectricalBusType::AlternatingCurrent(3),
+        1500.,
+        1500.,
+        PowerConsumerCondition::InFlight,
+    ),
+    PowerConsumerDefinition::new(
+        "GALLEY 4",
+        ElectricalBusType::AlternatingCurrent(4),
+        14000.,
+        14000.,
+        PowerConsumerCondition::GalleyNotShed,
+    ),
+    PowerConsumerDefinition::new(
+        "IN FLIGHT ENTERTAINMENT 4",
+        ElectricalBusType::AlternatingCurrent(4),
+        6000.,
+        6000.,
+        PowerConsumerCondition::GalleyNotShed,
+    ),
+    PowerConsumerDefinition::new(
+        "CABIN LIGHTING 4",
+        ElectricalBusType::AlternatingCurrent(4),
+        3500.,
+        3800.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "LAVATORY WATER HEATERS 4",
+        ElectricalBusType::AlternatingCurrent(4),
+        1500.,
+        1500.,
+        PowerConsumerCondition::GalleyNotShed,
+    ),
+    PowerConsumerDefinition::new(
+        "RECIRCULATION FANS 4",
+        ElectricalBusType::AlternatingCurrent(4),
+        2800.,
+        4500.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "FEED TANK 4 MAIN PUMP",
+        ElectricalBusType::AlternatingCurrent(4),
+        1300.,
+        2600.,
+        PowerConsumerCondition::FlightPhases(&ENGINES_RUNNING),
+    ),
+    PowerConsumerDefinition::new(
+        "FEED TANK 4 STANDBY PUMP",
+        ElectricalBusType::AlternatingCurrent(4),
+        1300.,
+        2600.,
+        PowerConsumerCondition::FlightPhases(&TAKEOFF_AND_LANDING),
+    ),
+    PowerConsumerDefinition::new(
+        "AVIONICS 4",
+        ElectricalBusType::AlternatingCurrent(4),
+        5000.,
+        5000.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "AVIONICS VENTILATION 4",
+        ElectricalBusType::AlternatingCurrent(4),
+        1200.,
+        2000.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "CARGO HEAT AND VENTILATION 4",
+        ElectricalBusType::AlternatingCurrent(4),
+        1500.,
+        1500.,
+        PowerConsumerCondition::InFlight,
+    ),
+    PowerConsumerDefinition::new(
+        "WINDSHIELD HEAT CAPT",
+        ElectricalBusType::AlternatingCurrent(1),
+        3000.,
+        4000.,
+        PowerConsumerCondition::FlightPhases(&ENGINES_RUNNING),
+    ),
+    PowerConsumerDefinition::new(
+        "WINDSHIELD HEAT F/O",
+        ElectricalBusType::AlternatingCurrent(4),
+        3000.,
+        4000.,
+        PowerConsumerCondition::FlightPhases(&ENGINES_RUNNING),
+    ),
+    PowerConsumerDefinition::new(
+        "WINDOW HEAT CAPT",
+        ElectricalBusType::AlternatingCurrent(2),
+        800.,
+        1000.,
+        PowerConsumerCondition::FlightPhases(&ENGINES_RUNNING),
+    ),
+    PowerConsumerDefinition::new(
+        "WINDOW HEAT F/O",
+        ElectricalBusType::AlternatingCurrent(3),
+        800.,
+        1000.,
+        PowerConsumerCondition::FlightPhases(&ENGINES_RUNNING),
+    ),
+    PowerConsumerDefinition::new(
+        "LANDING LIGHT L",
+        ElectricalBusType::AlternatingCurrent(2),
+        600.,
+        900.,
+        PowerConsumerCondition::FlightPhases(&TAKEOFF_AND_LANDING),
+    ),
+    PowerConsumerDefinition::new(
+        "LANDING LIGHT R",
+        ElectricalBusType::AlternatingCurrent(3),
+        600.,
+        900.,
+        PowerConsumerCondition::FlightPhases(&TAKEOFF_AND_LANDING),
+    ),
+    PowerConsumerDefinition::new(
+        "NAVIGATION AND LOGO LIGHTS",
+        ElectricalBusType::AlternatingCurrent(2),
+        600.,
+        600.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "WING ILLUMINATION AND STROBES",
+        ElectricalBusType::AlternatingCurrent(3),
+        700.,
+        700.,
+        PowerConsumerCondition::FlightPhases(&ENGINES_RUNNING),
+    ),
+    PowerConsumerDefinition::new(
+        "DRAIN MAST HEAT",
+        ElectricalBusType::AlternatingCurrent(3),
+        600.,
+        600.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "COCKPIT AND ANNUNCIATOR LIGHTING",
+        ElectricalBusType::AlternatingCurrent(1),
+        700.,
+        700.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "AVIONICS ESSENTIAL",
+        ElectricalBusType::AlternatingCurrentEssential,
+        900.,
+        900.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "PROBE HEAT CAPT",
+        ElectricalBusType::AlternatingCurrentEssential,
+        450.,
+        450.,
+        PowerConsumerCondition::FlightPhases(&ENGINES_RUNNING),
+    ),
+    PowerConsumerDefinition::new(
+        "AVIONICS ESSENTIAL SHED",
+        ElectricalBusType::AlternatingCurrentEssentialShed,
+        820.,
+        820.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "PROBE HEAT F/O",
+        ElectricalBusType::AlternatingCurrentEssentialShed,
+        450.,
+        450.,
+        PowerConsumerCondition::FlightPhases(&ENGINES_RUNNING),
+    ),
+    PowerConsumerDefinition::new(
+        "EHA AVIONICS",
+        ElectricalBusType::AlternatingCurrentNamed("247XP"),
+        300.,
+        300.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "CABIN SERVICE LIGHTING",
+        ElectricalBusType::AlternatingCurrentGndFltService,
+        1800.,
+        1800.,
+        PowerConsumerCondition::OnGround,
+    ),
+    PowerConsumerDefinition::new(
+        "POTABLE WATER COMPRESSORS",
+        ElectricalBusType::AlternatingCurrentGndFltService,
+        2000.,
+        3000.,
+        PowerConsumerCondition::OnGround,
+    ),
+    PowerConsumerDefinition::new(
+        "CARGO LIGHTING AND SERVICE OUTLETS",
+        ElectricalBusType::AlternatingCurrentGndFltService,
+        3500.,
+        3500.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "DC AVIONICS 1",
+        ElectricalBusType::DirectCurrent(1),
+        420.,
+        420.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "CARGO SMOKE DETECTION",
+        ElectricalBusType::DirectCurrent(1),
+        56.,
+        56.,
+        PowerConsumerCondition::InFlight,
+    ),
+    PowerConsumerDefinition::new(
+        "DC AVIONICS 2",
+        ElectricalBusType::DirectCurrent(2),
+        480.,
+        480.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "ENGINE INTERFACE UNITS",
+        ElectricalBusType::DirectCurrent(2),
+        112.,
+        168.,
+        PowerConsumerCondition::FlightPhases(&ENGINES_RUNNING),
+    ),
+    PowerConsumerDefinition::new(
+        "DC ESSENTIAL AVIONICS",
+        ElectricalBusType::DirectCurrentEssential,
+        200.,
+        250.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "DC ESSENTIAL IN FLIGHT AVIONICS",
+        ElectricalBusType::DirectCurrentNamed("108PH"),
+        150.,
+        150.,
+        PowerConsumerCondition::InFlight,
+    ),
+    PowerConsumerDefinition::new(
+        "EHA CONTROL",
+        ElectricalBusType::DirectCurrentNamed("247PP"),
+        84.,
+        84.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "APU ELECTRONIC CONTROL BOX MONITORING",
+        ElectricalBusType::DirectCurrentNamed("309PP"),
+        28.,
+        28.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "ENGINE FIRE DETECTION 1",
+        ElectricalBusType::DirectCurrentHot(1),
+        15.,
+        108.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "ENGINE FIRE DETECTION 2",
+        ElectricalBusType::DirectCurrentHot(2),
+        15.,
+        24.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "ADIRU ON BATTERY MONITORING",
+        ElectricalBusType::DirectCurrentHot(3),
+        5.,
+        5.,
+        PowerConsumerCondition::InFlight,
+    ),
+    PowerConsumerDefinition::new(
+        "APU FIRE DETECTION",
+        ElectricalBusType::DirectCurrentHot(4),
+        12.,
+        24.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "SERVICE INTERPHONE AND LIGHTS",
+        ElectricalBusType::DirectCurrentGndFltService,
+        112.,
+        168.,
+        PowerConsumerCondition::Always,
+    ),
+    PowerConsumerDefinition::new(
+        "CARGO DOOR OPERATION",
+        ElectricalBusType::DirectCurrentGndFltService,
+        112.,
+        112.,
+        PowerConsumerCondition::OnGround,
+    ),
+];
+
+/// This type provides the power consumption of equipment which isn't simulated
+/// by a system of its own. Each piece of equipment consumes power from its own bus,
+/// thus shedding a bus removes the demand of the equipment it powers.
 pub(super) struct A380PowerConsumption {
-    ac_bus_1_consumer: FlightPhasePowerConsumer,
-    ac_bus_2_consumer: FlightPhasePowerConsumer,
-    ac_ess_bus_consumer: FlightPhasePowerConsumer,
-    ac_ess_shed_bus_consumer: FlightPhasePowerConsumer,
-    ac_stat_inv_bus_consumer: FlightPhasePowerConsumer,
-    ac_gnd_flt_service_consumer: FlightPhasePowerConsumer,
-    dc_bus_1_consumer: FlightPhasePowerConsumer,
-    dc_bus_2_consumer: FlightPhasePowerConsumer,
-    dc_ess_bus_consumer: FlightPhasePowerConsumer,
-    dc_ess_shed_bus_consumer: FlightPhasePowerConsumer,
-    dc_bat_bus_consumer: FlightPhasePowerConsumer,
-    dc_hot_bus_1_consumer: FlightPhasePowerConsumer,
-    dc_hot_bus_2_consumer: FlightPhasePowerConsumer,
-    dc_gnd_flt_service_consumer: FlightPhasePowerConsumer,
+    equipment: EquipmentPowerConsumption,
 }
 impl A380PowerConsumption {
     pub fn new(context: &mut InitContext) -> Self {
-        // The watts in this function are all provided by komp.
         Self {
-            ac_bus_1_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::AlternatingCurrent(1),
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(26816.3),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(30350.1),
-                ),
-                (
-                    PowerConsumerFlightPhase::Takeoff,
-                    Power::new::<watt>(33797.3),
-                ),
-                (
-                    PowerConsumerFlightPhase::Flight,
-                    Power::new::<watt>(39032.5),
-                ),
-                (
-                    PowerConsumerFlightPhase::Landing,
-                    Power::new::<watt>(30733.3),
-                ),
-                (
-                    PowerConsumerFlightPhase::TaxiIn,
-                    Power::new::<watt>(30243.1),
-                ),
-            ]),
-            ac_bus_2_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::AlternatingCurrent(2),
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(26960.2),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(21735.8),
-                ),
-                (
-                    PowerConsumerFlightPhase::Takeoff,
-                    Power::new::<watt>(25183.),
-                ),
-                (
-                    PowerConsumerFlightPhase::Flight,
-                    Power::new::<watt>(29777.4),
-                ),
-                (
-                    PowerConsumerFlightPhase::Landing,
-                    Power::new::<watt>(22119.),
-                ),
-                (
-                    PowerConsumerFlightPhase::TaxiIn,
-                    Power::new::<watt>(24475.8),
-                ),
-            ]),
-            ac_ess_bus_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::AlternatingCurrentEssential,
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(455.7),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(715.7),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(875.7)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(875.7)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(715.7)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(715.7)),
-            ]),
-            ac_ess_shed_bus_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::AlternatingCurrentEssentialShed,
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(560.5),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(823.5),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(823.5)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(823.5)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(823.5)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(823.5)),
-            ]),
-            ac_stat_inv_bus_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::AlternatingCurrentStaticInverter,
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(135.),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(135.),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(135.)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(135.)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(135.)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(135.)),
-            ]),
-            ac_gnd_flt_service_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::AlternatingCurrentGndFltService,
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(4718.),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(3663.),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(2628.)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(2628.)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(2628.)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(3663.)),
-            ]),
-            dc_bus_1_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::DirectCurrent(1),
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(252.),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(308.),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(364.)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(280.)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(364.)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(336.)),
-            ]),
-            dc_bus_2_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::DirectCurrent(2),
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(532.),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(448.),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(392.)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(392.)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(392.)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(448.)),
-            ]),
-            dc_ess_bus_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::DirectCurrentEssential,
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(168.),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(140.),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(168.)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(140.)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(168.)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(140.)),
-            ]),
-            dc_ess_shed_bus_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::DirectCurrentEssentialShed,
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(224.),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(168.),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(196.)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(196.)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(196.)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(168.)),
-            ]),
-            dc_bat_bus_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::DirectCurrentBattery,
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(0.),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(28.),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(28.)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(28.)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(28.)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(28.)),
-            ]),
-            dc_hot_bus_1_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::DirectCurrentHot(1),
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(108.),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(11.),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(15.3)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(15.3)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(15.3)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(11.)),
-            ]),
-            dc_hot_bus_2_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::DirectCurrentHot(2),
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(24.3),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(24.3),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(24.3)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(24.3)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(24.3)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(24.3)),
-            ]),
-            dc_gnd_flt_service_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::DirectCurrentGndFltService,
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(168.),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(84.),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(84.)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(84.)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(112.)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(84.)),
-            ]),
+            equipment: EquipmentPowerConsumption::new(context, &A380_POWER_CONSUMERS),
         }
     }
 
     pub fn update(&mut self, context: &UpdateContext) {
-        self.ac_bus_1_consumer.update(context);
-        self.ac_bus_2_consumer.update(context);
-        self.ac_ess_bus_consumer.update(context);
-        self.ac_ess_shed_bus_consumer.update(context);
-        self.ac_stat_inv_bus_consumer.update(context);
-        self.ac_gnd_flt_service_consumer.update(context);
-        self.dc_bus_1_consumer.update(context);
-        self.dc_bus_2_consumer.update(context);
-        self.dc_ess_bus_consumer.update(context);
-        self.dc_ess_shed_bus_consumer.update(context);
-        self.dc_bat_bus_consumer.update(context);
-        self.dc_hot_bus_1_consumer.update(context);
-        self.dc_hot_bus_2_consumer.update(context);
-        self.dc_gnd_flt_service_consumer.update(context);
+        self.equipment.update(context);
     }
 }
 impl SimulationElement for A380PowerConsumption {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
-        self.ac_bus_1_consumer.accept(visitor);
-        self.ac_bus_2_consumer.accept(visitor);
-        self.ac_ess_bus_consumer.accept(visitor);
-        self.ac_ess_shed_bus_consumer.accept(visitor);
-        self.ac_stat_inv_bus_consumer.accept(visitor);
-        self.ac_gnd_flt_service_consumer.accept(visitor);
-        self.dc_bus_1_consumer.accept(visitor);
-        self.dc_bus_2_consumer.accept(visitor);
-        self.dc_ess_bus_consumer.accept(visitor);
-        self.dc_ess_shed_bus_consumer.accept(visitor);
-        self.dc_bat_bus_consumer.accept(visitor);
-        self.dc_hot_bus_1_consumer.accept(visitor);
-        self.dc_hot_bus_2_consumer.accept(visitor);
-        self.dc_gnd_flt_service_consumer.accept(visitor);
+        self.equipment.accept(visitor);
 
         visitor.visit(self);
     }
@@ -7,6 +7,8 @@ use systems::{
     },
     structural_flex::elevator_flex::FlexibleElevators,
     structural_flex::engine_wobble::EnginesFlexiblePhysics,
+    structural_flex::load_monitoring::{StructuralLimits, StructuralLoadMonitor},
+    structural_flex::turbulence::TurbulenceGenerator,
     structural_flex::wing_flex::{
         FlexPhysicsNG, WingAnimationMapper, WingFuelNodeMapper, WingLift, WingRootAcceleration,
     },
@@ -15,7 +17,7 @@ use systems::{
 
 use crate::fuel::A380FuelTankType;
 
-use uom::si::{f64::*, force::newton, mass::kilogram, ratio::ratio};
+use uom::si::{area::square_meter, f64::*, force::newton, mass::kilogram, ratio::ratio};
 
 use nalgebra::{Vector3, Vector5};
 
@@ -27,8 +29,14 @@ pub struct A380StructuralFlex {
     wing_flex: WingFlexA380,
 
     surface_vibrations: SurfaceVibrationGenerator,
+    turbulence: TurbulenceGenerator,
+
+    load_monitor: StructuralLoadMonitor<4>,
 }
 impl A380StructuralFlex {
+    const WING_AREA_SQUARE_METER: f64 = 845.;
+    const WING_LIFT_CURVE_SLOPE: f64 = 5.;
+
     pub fn new(context: &mut InitContext) -> Self {
         Self {
             ground_weight_ratio_id: context
@@ -39,6 +47,13 @@ impl A380StructuralFlex {
             wing_flex: WingFlexA380::new(context),
 
             surface_vibrations: SurfaceVibrationGenerator::default_generator(),
+            turbulence: TurbulenceGenerator::new(
+                context,
+                Area::new::<square_meter>(Self::WING_AREA_SQUARE_METER),
+                Self::WING_LIFT_CURVE_SLOPE,
+            ),
+
+            load_monitor: StructuralLoadMonitor::new(context, StructuralLimits::a380()),
         }
     }
 
@@ -50,25 +65,44 @@ impl A380StructuralFlex {
         surfaces_positions: &impl SurfacesPositions,
         fuel_mass: &impl FuelPayload,
     ) {
+        self.turbulence.update(context);
+
+        let external_acceleration = self.surface_vibrations.surface_vibration_acceleration()
+            + self.turbulence.gust_acceleration();
+
         self.elevators_flex_physics.update(
             context,
             outer_inner_elevator_aero_torques,
             up_down_rudder_aero_torques,
-            self.surface_vibrations.surface_vibration_acceleration(),
+            external_acceleration,
         );
 
         self.wing_flex.update(
             context,
-            self.surface_vibrations.surface_vibration_acceleration(),
+            external_acceleration,
+            self.turbulence.gust_lift(),
             surfaces_positions,
             fuel_mass,
         );
 
+        // Gust excitation is already part of the wing motion at the pylons
+        let pylons_accelerations = self.wing_flex.accelerations_at_engines_pylons();
+
         self.engines_flex_physics
-            .update(context, self.wing_flex.accelerations_at_engines_pylons());
+            .update(context, pylons_accelerations);
 
         self.surface_vibrations
             .update(context, self.wing_flex.ground_weight_ratio());
+
+        self.load_monitor.update(
+            context,
+            surfaces_positions
+                .left_flaps_position()
+                .max(surfaces_positions.right_flaps_position()),
+            self.wing_flex.wing_root_bending_moments(),
+            pylons_accelerations,
+            self.turbulence.gust_acceleration(),
+        );
     }
 }
 impl SimulationElement for A380StructuralFlex {
@@ -76,6 +110,8 @@ impl SimulationElement for A380StructuralFlex {
         self.elevators_flex_physics.accept(visitor);
         self.engines_flex_physics.accept(visitor);
         self.wing_flex.accept(visitor);
+        self.turbulence.accept(visitor);
+        self.load_monitor.accept(visitor);
 
         visitor.visit(self);
     }
@@ -331,11 +367,12 @@ impl WingFlexA380 {
     pub fn update(
         &mut self,
         context: &UpdateContext,
-        surface_vibration_acceleration: Acceleration,
+        external_acceleration: Acceleration,
+        turbulence_lift: Force,
         surfaces_positions: &impl SurfacesPositions,
         fuel_mass: &impl FuelPayload,
     ) {
-        self.wing_lift.update(context);
+        self.wing_lift.update(context, turbulence_lift);
         self.wing_lift_dynamic
             .update(self.wing_lift.total_plane_lift(), surfaces_positions);
 
@@ -350,7 +387,7 @@ impl WingFlexA380 {
                 .per_node_lift_left_wing_newton()
                 .as_slice(),
             self.fuel_mapper.fuel_masses(self.left_wing_fuel_mass),
-            surface_vibration_acceleration + self.left_right_wing_root_position[0].acceleration(),
+            external_acceleration + self.left_right_wing_root_position[0].acceleration(),
         );
 
         self.flex_physics[1].update(
@@ -359,7 +396,7 @@ impl WingFlexA380 {
                 .per_node_lift_right_wing_newton()
                 .as_slice(),
             self.fuel_mapper.fuel_masses(self.right_wing_fuel_mass),
-            surface_vibration_acceleration + self.left_right_wing_root_position[1].acceleration(),
+            external_acceleration + self.left_right_wing_root_position[1].acceleration(),
         );
     }
 
@@ -374,6 +411,13 @@ impl WingFlexA380 {
         })
     }
 
+    // Bending moments at left and right wing roots
+    pub fn wing_root_bending_moments(&self) -> [Torque; 2] {
+        [0, 1].map(|phys_idx| {
+            self.flex_physics[phys_idx].root_bending_moment(Self::WING_NODES_X_COORDINATES)
+        })
+    }
+
     #[cfg(test)]
     fn left_node_position(&self, node_id: usize) -> f64 {
         self.flex_physics[0].nodes_height_meters()[node_id]
@@ -424,7 +468,9 @@ mod tests {
     use std::time::Duration;
 
     use systems::simulation::test::ReadByName;
-    use uom::si::{angle::degree, velocity::knot};
+    use uom::si::{
+        angle::degree, mass_density::kilogram_per_cubic_meter, torque::newton_meter, velocity::knot,
+    };
 
     use ntest::assert_about_eq;
 
@@ -549,6 +595,7 @@ mod tests {
             self.wing_flex.update(
                 context,
                 Acceleration::default(),
+                Force::default(),
                 &self.surfaces_position,
                 &self.fuel_payload,
             );
@@ -616,6 +663,10 @@ mod tests {
             self.query(|a| a.wing_flex.wing_lift_dynamic.right_wing_lift)
         }
 
+        fn wing_root_bending_moments(&self) -> [Torque; 2] {
+            self.query(|a| a.wing_flex.wing_root_bending_moments())
+        }
+
         fn with_nominal_weight(mut self) -> Self {
             self.write_by_name(
                 "TOTAL WEIGHT",
@@ -747,6 +798,94 @@ mod tests {
         }
     }
 
+    struct StructuralFlexTestAircraft {
+        structural_flex: A380StructuralFlex,
+        surfaces_position: TestSurfacesPositions,
+        fuel_payload: TestFuelPayload,
+    }
+    impl StructuralFlexTestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                structural_flex: A380StructuralFlex::new(context),
+                surfaces_position: TestSurfacesPositions::default(),
+                fuel_payload: TestFuelPayload::default(),
+            }
+        }
+    }
+    impl Aircraft for StructuralFlexTestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.structural_flex.update(
+                context,
+                [(Torque::default(), Torque::default()); 2],
+                (Torque::default(), Torque::default()),
+                &self.surfaces_position,
+                &self.fuel_payload,
+            );
+        }
+    }
+    impl SimulationElement for StructuralFlexTestAircraft {
+        fn accept<V: SimulationElementVisitor>(&mut self, visitor: &mut V) {
+            self.structural_flex.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    // Flies 1g cruise for one minute with given dev turbulence intensity, returns the wing root bending moments
+    //      history and final wing fatigue damage
+    fn cruise_in_turbulence(intensity: f64, seed: f64) -> (Vec<[Torque; 2]>, [f64; 2]) {
+        let mut test_bed = SimulationTestBed::new(StructuralFlexTestAircraft::new);
+
+        test_bed.set_on_ground(false);
+        test_bed.set_true_airspeed(Velocity::new::<knot>(250.));
+        test_bed.set_ambient_air_density(MassDensity::new::<kilogram_per_cubic_meter>(0.9));
+        test_bed.write_by_name("TOTAL WEIGHT", Mass::new::<kilogram>(400000.));
+        for contact_point in [
+            "CONTACT POINT COMPRESSION",
+            "CONTACT POINT COMPRESSION:1",
+            "CONTACT POINT COMPRESSION:2",
+            "CONTACT POINT COMPRESSION:3",
+            "CONTACT POINT COMPRESSION:4",
+        ] {
+            test_bed.write_by_name(contact_point, 0.);
+        }
+
+        test_bed.write_by_name("STRUCTURAL_FLEX_DEV_TURBULENCE_INTENSITY", intensity);
+        test_bed.write_by_name("STRUCTURAL_FLEX_DEV_TURBULENCE_SEED", seed);
+
+        let moments = (0..600)
+            .map(|_| {
+                test_bed.run_with_delta(Duration::from_millis(100));
+                test_bed.query(|a| a.structural_flex.wing_flex.wing_root_bending_moments())
+            })
+            .collect();
+
+        let fatigue_damage =
+            test_bed.query(|a| a.structural_flex.load_monitor.wing_fatigue_damage());
+
+        (moments, fatigue_damage)
+    }
+
+    #[test]
+    fn same_turbulence_seed_gives_same_wing_response() {
+        let (first_run_moments, _) = cruise_in_turbulence(2., 12.);
+        let (second_run_moments, _) = cruise_in_turbulence(2., 12.);
+
+        assert!(first_run_moments == second_run_moments);
+    }
+
+    #[test]
+    fn wing_fatigue_increases_with_turbulence_intensity() {
+        let (_, no_turbulence_damage) = cruise_in_turbulence(0., 12.);
+        let (_, light_turbulence_damage) = cruise_in_turbulence(1., 12.);
+        let (_, severe_turbulence_damage) = cruise_in_turbulence(3., 12.);
+
+        for wing_idx in 0..2 {
+            assert!(light_turbulence_damage[wing_idx] > no_turbulence_damage[wing_idx]);
+            assert!(severe_turbulence_damage[wing_idx] > 10. * light_turbulence_damage[wing_idx]);
+        }
+    }
+
     #[test]
     fn fuel_mapping_tanks_1_2_left_wing() {
         let mut test_bed = SimulationTestBed::new(WingFlexTestAircraft::new);
@@ -1161,6 +1300,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn wing_root_bending_moment_is_upward_in_flight_and_downward_on_ground() {
+        let mut test_bed = WingFlexTestBed::new()
+            .with_nominal_weight()
+            .with_max_fuel()
+            .steady_on_ground();
+
+        test_bed = test_bed.run_waiting_for(Duration::from_secs(3));
+
+        let on_ground_moments = test_bed.wing_root_bending_moments();
+        assert!(on_ground_moments[0].get::<newton_meter>() < 0.);
+        assert!(on_ground_moments[1].get::<newton_meter>() < 0.);
+
+        test_bed = test_bed.in_1g_flight();
+        test_bed = test_bed.run_waiting_for(Duration::from_secs(3));
+
+        let in_flight_moments = test_bed.wing_root_bending_moments();
+        assert!(in_flight_moments[0].get::<newton_meter>() > 0.);
+        assert!(in_flight_moments[1].get::<newton_meter>() > 0.);
+    }
+
     #[test]
     fn in_left_turn_flight_has_more_right_lift() {
         let mut test_bed = WingFlexTestBed::new()
@@ -29,8 +29,10 @@ use rudder::rudder;
 use spoilers::spoilers;
 use std::error::Error;
 use systems::failures::FailureType;
+use systems::fire_protection::{FireDetectionLoopId, FireDetectionZone, SmokeDetectionZone};
 use systems::shared::{
-    ElectricalBusType, GearActuatorId, HydraulicColor, LgciuId, ProximityDetectorId,
+    ElectricalBusType, FlapSlatTransmissionId, FlightControlActuatorId, FlightControlSurfaceId,
+    GearActuatorId, HydraulicColor, LgciuId, ProximityDetectorId,
 };
 
 use systems_wasm::{MsfsSimulationBuilder, Variable};
@@ -68,6 +70,9 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .with_engine_anti_ice(4)?
     .with_wing_anti_ice()?
     .with_failures(vec![
+        (21_020, FailureType::CabinDoorSealLeak),
+        (21_021, FailureType::CabinWindowCrack),
+        (21_022, FailureType::CargoDoorUnsealed),
         (24_000, FailureType::TransformerRectifier(1)),
         (24_001, FailureType::TransformerRectifier(2)),
         (24_002, FailureType::TransformerRectifier(3)),
@@ -150,6 +155,1219 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             24_117,
             FailureType::ElectricalBus(ElectricalBusType::DirectCurrentGndFltService),
         ),
+        (26_000, FailureType::EngineFire(1)),
+        (26_001, FailureType::EngineFire(2)),
+        (26_002, FailureType::EngineFire(3)),
+        (26_003, FailureType::EngineFire(4)),
+        (26_004, FailureType::ApuFire),
+        (
+            26_010,
+            FailureType::FireDetectionLoop(FireDetectionZone::Engine(1), FireDetectionLoopId::A),
+        ),
+        (
+            26_011,
+            FailureType::FireDetectionLoop(FireDetectionZone::Engine(1), FireDetectionLoopId::B),
+        ),
+        (
+            26_012,
+            FailureType::FireDetectionLoop(FireDetectionZone::Engine(2), FireDetectionLoopId::A),
+        ),
+        (
+            26_013,
+            FailureType::FireDetectionLoop(FireDetectionZone::Engine(2), FireDetectionLoopId::B),
+        ),
+        (
+            26_014,
+            FailureType::FireDetectionLoop(FireDetectionZone::Engine(3), FireDetectionLoopId::A),
+        ),
+        (
+            26_015,
+            FailureType::FireDetectionLoop(FireDetectionZone::Engine(3), FireDetectionLoopId::B),
+        ),
+        (
+            26_016,
+            FailureType::FireDetectionLoop(FireDetectionZone::Engine(4), FireDetectionLoopId::A),
+        ),
+        (
+            26_017,
+            FailureType::FireDetectionLoop(FireDetectionZone::Engine(4), FireDetectionLoopId::B),
+        ),
+        (
+            26_018,
+            FailureType::FireDetectionLoop(FireDetectionZone::Apu, FireDetectionLoopId::A),
+        ),
+        (
+            26_019,
+            FailureType::FireDetectionLoop(FireDetectionZone::Apu, FireDetectionLoopId::B),
+        ),
+        (26_100, FailureType::Smoke(SmokeDetectionZone::ForwardCargo)),
+        (26_101, FailureType::Smoke(SmokeDetectionZone::AftCargo)),
+        (26_102, FailureType::Smoke(SmokeDetectionZone::BulkCargo)),
+        (26_103, FailureType::Smoke(SmokeDetectionZone::Lavatory)),
+        (
+            26_110,
+            FailureType::SmokeDetector(SmokeDetectionZone::ForwardCargo, 1),
+        ),
+        (
+            26_111,
+            FailureType::SmokeDetector(SmokeDetectionZone::ForwardCargo, 2),
+        ),
+        (
+            26_112,
+            FailureType::SmokeDetector(SmokeDetectionZone::AftCargo, 1),
+        ),
+        (
+            26_113,
+            FailureType::SmokeDetector(SmokeDetectionZone::AftCargo, 2),
+        ),
+        (
+            26_114,
+            FailureType::SmokeDetector(SmokeDetectionZone::BulkCargo, 1),
+        ),
+        (
+            26_115,
+            FailureType::SmokeDetector(SmokeDetectionZone::BulkCargo, 2),
+        ),
+        (
+            26_116,
+            FailureType::SmokeDetector(SmokeDetectionZone::Lavatory, 1),
+        ),
+        (
+            27_100,
+            FailureType::FlapSlatJam(FlapSlatTransmissionId::FlapsLeft),
+        ),
+        (
+            27_101,
+            FailureType::FlapSlatJam(FlapSlatTransmissionId::FlapsRight),
+        ),
+        (
+            27_102,
+            FailureType::FlapSlatJam(FlapSlatTransmissionId::SlatsLeft),
+        ),
+        (
+            27_103,
+            FailureType::FlapSlatJam(FlapSlatTransmissionId::SlatsRight),
+        ),
+        (
+            27_200,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftAileron(1),
+                1,
+            )),
+        ),
+        (
+            27_201,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftAileron(1),
+                2,
+            )),
+        ),
+        (
+            27_202,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftAileron(2),
+                1,
+            )),
+        ),
+        (
+            27_203,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftAileron(2),
+                2,
+            )),
+        ),
+        (
+            27_204,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftAileron(3),
+                1,
+            )),
+        ),
+        (
+            27_205,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftAileron(3),
+                2,
+            )),
+        ),
+        (
+            27_206,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightAileron(1),
+                1,
+            )),
+        ),
+        (
+            27_207,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightAileron(1),
+                2,
+            )),
+        ),
+        (
+            27_208,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightAileron(2),
+                1,
+            )),
+        ),
+        (
+            27_209,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightAileron(2),
+                2,
+            )),
+        ),
+        (
+            27_210,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightAileron(3),
+                1,
+            )),
+        ),
+        (
+            27_211,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightAileron(3),
+                2,
+            )),
+        ),
+        (
+            27_212,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftElevator(1),
+                1,
+            )),
+        ),
+        (
+            27_213,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftElevator(1),
+                2,
+            )),
+        ),
+        (
+            27_214,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftElevator(2),
+                1,
+            )),
+        ),
+        (
+            27_215,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftElevator(2),
+                2,
+            )),
+        ),
+        (
+            27_216,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightElevator(1),
+                1,
+            )),
+        ),
+        (
+            27_217,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightElevator(1),
+                2,
+            )),
+        ),
+        (
+            27_218,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightElevator(2),
+                1,
+            )),
+        ),
+        (
+            27_219,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightElevator(2),
+                2,
+            )),
+        ),
+        (
+            27_220,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::Rudder(1),
+                1,
+            )),
+        ),
+        (
+            27_221,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::Rudder(1),
+                2,
+            )),
+        ),
+        (
+            27_222,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::Rudder(2),
+                1,
+            )),
+        ),
+        (
+            27_223,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::Rudder(2),
+                2,
+            )),
+        ),
+        (
+            27_224,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(1),
+                1,
+            )),
+        ),
+        (
+            27_225,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(2),
+                1,
+            )),
+        ),
+        (
+            27_226,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(3),
+                1,
+            )),
+        ),
+        (
+            27_227,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(4),
+                1,
+            )),
+        ),
+        (
+            27_228,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(5),
+                1,
+            )),
+        ),
+        (
+            27_229,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(6),
+                1,
+            )),
+        ),
+        (
+            27_230,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(7),
+                1,
+            )),
+        ),
+        (
+            27_231,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(8),
+                1,
+            )),
+        ),
+        (
+            27_232,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(1),
+                1,
+            )),
+        ),
+        (
+            27_233,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(2),
+                1,
+            )),
+        ),
+        (
+            27_234,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(3),
+                1,
+            )),
+        ),
+        (
+            27_235,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(4),
+                1,
+            )),
+        ),
+        (
+            27_236,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(5),
+                1,
+            )),
+        ),
+        (
+            27_237,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(6),
+                1,
+            )),
+        ),
+        (
+            27_238,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(7),
+                1,
+            )),
+        ),
+        (
+            27_239,
+            FailureType::FlightControlActuatorJam(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(8),
+                1,
+            )),
+        ),
+        (
+            27_300,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftAileron(1),
+                1,
+            )),
+        ),
+        (
+            27_301,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftAileron(1),
+                2,
+            )),
+        ),
+        (
+            27_302,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftAileron(2),
+                1,
+            )),
+        ),
+        (
+            27_303,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftAileron(2),
+                2,
+            )),
+        ),
+        (
+            27_304,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftAileron(3),
+                1,
+            )),
+        ),
+        (
+            27_305,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftAileron(3),
+                2,
+            )),
+        ),
+        (
+            27_306,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightAileron(1),
+                1,
+            )),
+        ),
+        (
+            27_307,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightAileron(1),
+                2,
+            )),
+        ),
+        (
+            27_308,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightAileron(2),
+                1,
+            )),
+        ),
+        (
+            27_309,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightAileron(2),
+                2,
+            )),
+        ),
+        (
+            27_310,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightAileron(3),
+                1,
+            )),
+        ),
+        (
+            27_311,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightAileron(3),
+                2,
+            )),
+        ),
+        (
+            27_312,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftElevator(1),
+                1,
+            )),
+        ),
+        (
+            27_313,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftElevator(1),
+                2,
+            )),
+        ),
+        (
+            27_314,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftElevator(2),
+                1,
+            )),
+        ),
+        (
+            27_315,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftElevator(2),
+                2,
+            )),
+        ),
+        (
+            27_316,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightElevator(1),
+                1,
+            )),
+        ),
+        (
+            27_317,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightElevator(1),
+                2,
+            )),
+        ),
+        (
+            27_318,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightElevator(2),
+                1,
+            )),
+        ),
+        (
+            27_319,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightElevator(2),
+                2,
+            )),
+        ),
+        (
+            27_320,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::Rudder(1),
+                1,
+            )),
+        ),
+        (
+            27_321,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::Rudder(1),
+                2,
+            )),
+        ),
+        (
+            27_322,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::Rudder(2),
+                1,
+            )),
+        ),
+        (
+            27_323,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::Rudder(2),
+                2,
+            )),
+        ),
+        (
+            27_324,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(1),
+                1,
+            )),
+        ),
+        (
+            27_325,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(2),
+                1,
+            )),
+        ),
+        (
+            27_326,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(3),
+                1,
+            )),
+        ),
+        (
+            27_327,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(4),
+                1,
+            )),
+        ),
+        (
+            27_328,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(5),
+                1,
+            )),
+        ),
+        (
+            27_329,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(6),
+                1,
+            )),
+        ),
+        (
+            27_330,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(7),
+                1,
+            )),
+        ),
+        (
+            27_331,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(8),
+                1,
+            )),
+        ),
+        (
+            27_332,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(1),
+                1,
+            )),
+        ),
+        (
+            27_333,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(2),
+                1,
+            )),
+        ),
+        (
+            27_334,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(3),
+                1,
+            )),
+        ),
+        (
+            27_335,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(4),
+                1,
+            )),
+        ),
+        (
+            27_336,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(5),
+                1,
+            )),
+        ),
+        (
+            27_337,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(6),
+                1,
+            )),
+        ),
+        (
+            27_338,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(7),
+                1,
+            )),
+        ),
+        (
+            27_339,
+            FailureType::FlightControlActuatorRunaway(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(8),
+                1,
+            )),
+        ),
+        (
+            27_400,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftAileron(1),
+                1,
+            )),
+        ),
+        (
+            27_401,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftAileron(1),
+                2,
+            )),
+        ),
+        (
+            27_402,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftAileron(2),
+                1,
+            )),
+        ),
+        (
+            27_403,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftAileron(2),
+                2,
+            )),
+        ),
+        (
+            27_404,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftAileron(3),
+                1,
+            )),
+        ),
+        (
+            27_405,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftAileron(3),
+                2,
+            )),
+        ),
+        (
+            27_406,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightAileron(1),
+                1,
+            )),
+        ),
+        (
+            27_407,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightAileron(1),
+                2,
+            )),
+        ),
+        (
+            27_408,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightAileron(2),
+                1,
+            )),
+        ),
+        (
+            27_409,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightAileron(2),
+                2,
+            )),
+        ),
+        (
+            27_410,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightAileron(3),
+                1,
+            )),
+        ),
+        (
+            27_411,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightAileron(3),
+                2,
+            )),
+        ),
+        (
+            27_412,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftElevator(1),
+                1,
+            )),
+        ),
+        (
+            27_413,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftElevator(1),
+                2,
+            )),
+        ),
+        (
+            27_414,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftElevator(2),
+                1,
+            )),
+        ),
+        (
+            27_415,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftElevator(2),
+                2,
+            )),
+        ),
+        (
+            27_416,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightElevator(1),
+                1,
+            )),
+        ),
+        (
+            27_417,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightElevator(1),
+                2,
+            )),
+        ),
+        (
+            27_418,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightElevator(2),
+                1,
+            )),
+        ),
+        (
+            27_419,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightElevator(2),
+                2,
+            )),
+        ),
+        (
+            27_420,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::Rudder(1),
+                1,
+            )),
+        ),
+        (
+            27_421,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::Rudder(1),
+                2,
+            )),
+        ),
+        (
+            27_422,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::Rudder(2),
+                1,
+            )),
+        ),
+        (
+            27_423,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::Rudder(2),
+                2,
+            )),
+        ),
+        (
+            27_424,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(1),
+                1,
+            )),
+        ),
+        (
+            27_425,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(2),
+                1,
+            )),
+        ),
+        (
+            27_426,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(3),
+                1,
+            )),
+        ),
+        (
+            27_427,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(4),
+                1,
+            )),
+        ),
+        (
+            27_428,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(5),
+                1,
+            )),
+        ),
+        (
+            27_429,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(6),
+                1,
+            )),
+        ),
+        (
+            27_430,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(7),
+                1,
+            )),
+        ),
+        (
+            27_431,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(8),
+                1,
+            )),
+        ),
+        (
+            27_432,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(1),
+                1,
+            )),
+        ),
+        (
+            27_433,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(2),
+                1,
+            )),
+        ),
+        (
+            27_434,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(3),
+                1,
+            )),
+        ),
+        (
+            27_435,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(4),
+                1,
+            )),
+        ),
+        (
+            27_436,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(5),
+                1,
+            )),
+        ),
+        (
+            27_437,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(6),
+                1,
+            )),
+        ),
+        (
+            27_438,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(7),
+                1,
+            )),
+        ),
+        (
+            27_439,
+            FailureType::FlightControlActuatorDisconnect(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(8),
+                1,
+            )),
+        ),
+        (
+            27_500,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftAileron(1),
+                1,
+            )),
+        ),
+        (
+            27_501,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftAileron(1),
+                2,
+            )),
+        ),
+        (
+            27_502,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftAileron(2),
+                1,
+            )),
+        ),
+        (
+            27_503,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftAileron(2),
+                2,
+            )),
+        ),
+        (
+            27_504,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftAileron(3),
+                1,
+            )),
+        ),
+        (
+            27_505,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftAileron(3),
+                2,
+            )),
+        ),
+        (
+            27_506,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightAileron(1),
+                1,
+            )),
+        ),
+        (
+            27_507,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightAileron(1),
+                2,
+            )),
+        ),
+        (
+            27_508,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightAileron(2),
+                1,
+            )),
+        ),
+        (
+            27_509,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightAileron(2),
+                2,
+            )),
+        ),
+        (
+            27_510,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightAileron(3),
+                1,
+            )),
+        ),
+        (
+            27_511,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightAileron(3),
+                2,
+            )),
+        ),
+        (
+            27_512,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftElevator(1),
+                1,
+            )),
+        ),
+        (
+            27_513,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftElevator(1),
+                2,
+            )),
+        ),
+        (
+            27_514,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftElevator(2),
+                1,
+            )),
+        ),
+        (
+            27_515,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftElevator(2),
+                2,
+            )),
+        ),
+        (
+            27_516,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightElevator(1),
+                1,
+            )),
+        ),
+        (
+            27_517,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightElevator(1),
+                2,
+            )),
+        ),
+        (
+            27_518,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightElevator(2),
+                1,
+            )),
+        ),
+        (
+            27_519,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightElevator(2),
+                2,
+            )),
+        ),
+        (
+            27_520,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::Rudder(1),
+                1,
+            )),
+        ),
+        (
+            27_521,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::Rudder(1),
+                2,
+            )),
+        ),
+        (
+            27_522,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::Rudder(2),
+                1,
+            )),
+        ),
+        (
+            27_523,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::Rudder(2),
+                2,
+            )),
+        ),
+        (
+            27_524,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(1),
+                1,
+            )),
+        ),
+        (
+            27_525,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(2),
+                1,
+            )),
+        ),
+        (
+            27_526,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(3),
+                1,
+            )),
+        ),
+        (
+            27_527,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(4),
+                1,
+            )),
+        ),
+        (
+            27_528,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(5),
+                1,
+            )),
+        ),
+        (
+            27_529,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(6),
+                1,
+            )),
+        ),
+        (
+            27_530,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(7),
+                1,
+            )),
+        ),
+        (
+            27_531,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::LeftSpoiler(8),
+                1,
+            )),
+        ),
+        (
+            27_532,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(1),
+                1,
+            )),
+        ),
+        (
+            27_533,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(2),
+                1,
+            )),
+        ),
+        (
+            27_534,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(3),
+                1,
+            )),
+        ),
+        (
+            27_535,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(4),
+                1,
+            )),
+        ),
+        (
+            27_536,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(5),
+                1,
+            )),
+        ),
+        (
+            27_537,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(6),
+                1,
+            )),
+        ),
+        (
+            27_538,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(7),
+                1,
+            )),
+        ),
+        (
+            27_539,
+            FailureType::FlightControlActuatorRodEndFracture(FlightControlActuatorId::new(
+                FlightControlSurfaceId::RightSpoiler(8),
+                1,
+            )),
+        ),
         (29_000, FailureType::ReservoirLeak(HydraulicColor::Green)),
         (29_001, FailureType::ReservoirLeak(HydraulicColor::Blue)),
         (29_002, FailureType::ReservoirLeak(HydraulicColor::Yellow)),
@@ -171,6 +1389,70 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             29_008,
             FailureType::ReservoirReturnLeak(HydraulicColor::Yellow),
         ),
+        (
+            29_013,
+            FailureType::FireShutoffValveStuck(HydraulicColor::Green, 1),
+        ),
+        (
+            29_014,
+            FailureType::FireShutoffValveStuck(HydraulicColor::Green, 2),
+        ),
+        (
+            29_015,
+            FailureType::FireShutoffValveStuck(HydraulicColor::Green, 3),
+        ),
+        (
+            29_016,
+            FailureType::FireShutoffValveStuck(HydraulicColor::Green, 4),
+        ),
+        (
+            29_017,
+            FailureType::FireShutoffValveStuck(HydraulicColor::Yellow, 1),
+        ),
+        (
+            29_018,
+            FailureType::FireShutoffValveStuck(HydraulicColor::Yellow, 2),
+        ),
+        (
+            29_019,
+            FailureType::FireShutoffValveStuck(HydraulicColor::Yellow, 3),
+        ),
+        (
+            29_020,
+            FailureType::FireShutoffValveStuck(HydraulicColor::Yellow, 4),
+        ),
+        (
+            29_021,
+            FailureType::PriorityValveStuck(HydraulicColor::Green),
+        ),
+        (
+            29_022,
+            FailureType::PriorityValveStuck(HydraulicColor::Yellow),
+        ),
+        (
+            29_023,
+            FailureType::LeakMeasurementValveStuck(HydraulicColor::Green),
+        ),
+        (
+            29_024,
+            FailureType::LeakMeasurementValveStuck(HydraulicColor::Yellow),
+        ),
+        (
+            29_025,
+            FailureType::AccumulatorPrechargeLoss(HydraulicColor::Green),
+        ),
+        (
+            29_026,
+            FailureType::AccumulatorPrechargeLoss(HydraulicColor::Yellow),
+        ),
+        (30_000, FailureType::EngineAntiIceValveStuckClosed(1)),
+        (30_001, FailureType::EngineAntiIceValveStuckClosed(2)),
+        (30_002, FailureType::EngineAntiIceValveStuckClosed(3)),
+        (30_003, FailureType::EngineAntiIceValveStuckClosed(4)),
+        (30_004, FailureType::EngineAntiIcePressureSwitch(1)),
+        (30_005, FailureType::EngineAntiIcePressureSwitch(2)),
+        (30_006, FailureType::EngineAntiIcePressureSwitch(3)),
+        (30_007, FailureType::EngineAntiIcePressureSwitch(4)),
         (32_000, FailureType::LgciuPowerSupply(LgciuId::Lgciu1)),
         (32_001, FailureType::LgciuPowerSupply(LgciuId::Lgciu2)),
         (32_002, FailureType::LgciuInternalError(LgciuId::Lgciu1)),
@@ -247,9 +1529,53 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             32_025,
             FailureType::GearActuatorJammed(GearActuatorId::GearDoorRight),
         ),
+        (32_030, FailureType::BodyWheelSteering),
+        (
+            32_040,
+            FailureType::GearUplockJammed(GearActuatorId::GearNose),
+        ),
+        (
+            32_041,
+            FailureType::GearUplockJammed(GearActuatorId::GearLeft),
+        ),
+        (
+            32_042,
+            FailureType::GearUplockJammed(GearActuatorId::GearRight),
+        ),
+        (
+            32_043,
+            FailureType::GearUplockJammed(GearActuatorId::GearDoorNose),
+        ),
+        (
+            32_044,
+            FailureType::GearUplockJammed(GearActuatorId::GearDoorLeft),
+        ),
+        (
+            32_045,
+            FailureType::GearUplockJammed(GearActuatorId::GearDoorRight),
+        ),
+        (
+            32_050,
+            FailureType::GearDoorJammed(GearActuatorId::GearDoorNose),
+        ),
+        (
+            32_051,
+            FailureType::GearDoorJammed(GearActuatorId::GearDoorLeft),
+        ),
+        (
+            32_052,
+            FailureType::GearDoorJammed(GearActuatorId::GearDoorRight),
+        ),
+        (32_060, FailureType::GearSelectorValveStuck),
         (34_000, FailureType::RadioAltimeter(1)),
         (34_001, FailureType::RadioAltimeter(2)),
         (34_002, FailureType::RadioAltimeter(3)),
+        (49_000, FailureType::ApuEgtSensor),
+        (49_001, FailureType::ApuSpeedSensor),
+        (49_002, FailureType::ApuInletPressureSensor),
+        (49_003, FailureType::ApuFuelControlUnit),
+        (49_004, FailureType::ApuOilLowPressure),
+        (49_005, FailureType::ApuAirIntakeFlapStuck),
     ])
     .provides_aircraft_variable("ACCELERATION BODY X", "feet per second squared", 0)?
     .provides_aircraft_variable("ACCELERATION BODY Y", "feet per second squared", 0)?
@@ -274,6 +1600,10 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .provides_aircraft_variable("CONTACT POINT COMPRESSION", "Percent", 2)?
     .provides_aircraft_variable("CONTACT POINT COMPRESSION", "Percent", 3)?
     .provides_aircraft_variable("CONTACT POINT COMPRESSION", "Percent", 4)?
+    .provides_aircraft_variable("ENG ON FIRE", "Bool", 1)?
+    .provides_aircraft_variable("ENG ON FIRE", "Bool", 2)?
+    .provides_aircraft_variable("ENG ON FIRE", "Bool", 3)?
+    .provides_aircraft_variable("ENG ON FIRE", "Bool", 4)?
     .provides_aircraft_variable("EXTERNAL POWER AVAILABLE", "Bool", 1)?
     .provides_aircraft_variable("FUEL TOTAL QUANTITY WEIGHT", "Pounds", 0)?
     .provides_aircraft_variable("FUELSYSTEM TANK QUANTITY", "gallons", 1)?
@@ -385,6 +1715,7 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .provides_named_variable("FSDT_GSX_BOARDING_CARGO_PERCENT")?
     .provides_named_variable("FSDT_GSX_DEBOARDING_CARGO_PERCENT")?
     .provides_named_variable("FSDT_GSX_BYPASS_PIN")?
+    .provides_named_variable("PUSH_OVHD_OXYGEN_CREW")?
     .with_aspect(|builder| {
         builder.copy(
             Variable::named("FSDT_GSX_BYPASS_PIN"),
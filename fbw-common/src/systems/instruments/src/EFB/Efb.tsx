@@ -26,7 +26,7 @@ import { ToastContainer } from 'react-toastify';
 import { distanceTo } from 'msfs-geo';
 import { ErrorBoundary } from 'react-error-boundary';
 import { MemoryRouter as Router } from 'react-router';
-import { migrateSettings, readSettingsFromPersistentStorage, setAirframeInfo, setCabinInfo, setFlypadInfo } from '@flybywiresim/flypad';
+import { migrateSettings, persistSimVars, readSettingsFromPersistentStorage, setAirframeInfo, setCabinInfo, setFlypadInfo } from '@flybywiresim/flypad';
 import { Error as ErrorIcon } from './Assets/Error';
 import { FailuresOrchestratorProvider } from './failures-orchestrator-provider';
 import { AlertModal, ModalContainer, ModalProvider, useModals } from './UtilComponents/Modals/Modals';
@@ -81,7 +81,10 @@ export const EfbWrapper: React.FC<EfbWrapperProps> = ({ failures }) => {
         UniversalConfigProvider.fetchFlypadInfo(
             process.env.AIRCRAFT_PROJECT_PREFIX,
             process.env.AIRCRAFT_VARIANT,
-        ).then((info) => store.dispatch(setFlypadInfo(info)));
+        ).then((info) => {
+            store.dispatch(setFlypadInfo(info));
+            persistSimVars(info.persistedSimVars);
+        });
 
         UniversalConfigProvider.fetchCabinInfo(
             process.env.AIRCRAFT_PROJECT_PREFIX,
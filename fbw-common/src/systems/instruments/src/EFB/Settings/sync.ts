@@ -1,17 +1,28 @@
 // Copyright (c) 2023-2024 FlyByWire Simulations
 // SPDX-License-Identifier: GPL-3.0
 
-import { NXDataStore, DEFAULT_RADIO_AUTO_CALL_OUTS } from '@flybywiresim/fbw-sdk';
+import { NXDataStore, DEFAULT_RADIO_AUTO_CALL_OUTS, PersistedSimVarFlypadInfo } from '@flybywiresim/fbw-sdk';
 
 type SimVar = [name: string, type: string, defaultValue: string];
 type SimVarEnum = [name: string, type: string, defaultValue: string, map: Map<string, number>];
 
+const PERSISTED_SIMVARS_SAVE_INTERVAL_MS = 30_000;
+
 function syncSetting(simVar: SimVar, propertyName: string) {
     NXDataStore.getAndSubscribe(propertyName, (prop, value) => {
         SimVar.SetSimVarValue(simVar[0], simVar[1], parseInt(value)).catch((e) => console.log(propertyName, e));
     }, simVar[2]);
 }
 
+function persistSimVar(simVar: PersistedSimVarFlypadInfo) {
+    const storedValue = parseFloat(NXDataStore.get(simVar.property, simVar.defaultValue.toString()));
+    SimVar.SetSimVarValue(simVar.simVar, 'number', storedValue).catch((e) => console.log(simVar.property, e));
+
+    setInterval(() => {
+        NXDataStore.set(simVar.property, SimVar.GetSimVarValue(simVar.simVar, 'number').toString());
+    }, PERSISTED_SIMVARS_SAVE_INTERVAL_MS);
+}
+
 function syncEnumSetting(simVarEnum: SimVarEnum, propertyName: string) {
     NXDataStore.getAndSubscribe(propertyName, (prop, value) => {
         const mapValue = simVarEnum[3].get(value);
@@ -65,3 +76,11 @@ export function readSettingsFromPersistentStorage() {
     settingsToSync.forEach((simVar, propertyName) => syncSetting(simVar, propertyName));
     settingEnumToSync.forEach((simVarEnum, propertyName) => syncEnumSetting(simVarEnum, propertyName));
 }
+
+/**
+ * Restores the aircraft specific simvars describing long term aircraft condition and saves them back periodically,
+ * so that the condition carries over from one flight to the next
+ */
+export function persistSimVars(simVars: PersistedSimVarFlypadInfo[]) {
+    simVars.forEach((simVar) => persistSimVar(simVar));
+}
@@ -82,6 +82,7 @@ export class UniversalConfigProvider {
                 },
                 throttle: jsonMisc._throttle,
                 pushback: jsonMisc._pushback,
+                persistedSimVars: jsonMisc._persistedSimVars ?? [],
             }
         );
 
@@ -55,6 +55,7 @@ export interface FlypadInfo {
     payload: PayloadFlypadInfo;
     throttle: ThrottleFlypadInfo;
     pushback: PushbackFlypadInfo;
+    persistedSimVars: PersistedSimVarFlypadInfo[];
 }
 
 export interface ThrottleFlypadInfo {
@@ -74,6 +75,15 @@ export interface PushbackFlypadInfo {
     turnIndicatorTuningDefault: number;
 }
 
+/**
+ * A simvar describing long term aircraft condition, restored on plane load and saved back periodically
+ */
+export interface PersistedSimVarFlypadInfo {
+    property: string;
+    simVar: string;
+    defaultValue: number;
+}
+
 export interface PayloadFlypadInfo {
     planeCanvas: PayloadPlaneCanvas;
     chartLimits: PayloadChartLimits;
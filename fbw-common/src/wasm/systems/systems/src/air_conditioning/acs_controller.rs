@@ -13,9 +13,10 @@ use crate::{
 };
 
 use super::{
-    AdirsToAirCondInterface, AirConditioningOverheadShared, CabinFansSignal, Channel,
-    DuctTemperature, OperatingChannel, OverheadFlowSelector, PackFlow, PackFlowControllers,
-    PackFlowValveSignal, PressurizationOverheadShared, TrimAirControllers, TrimAirSystem, ZoneType,
+    AdirsToAirCondInterface, AdiruSourceSelector, AirConditioningOverheadShared, CabinFansSignal,
+    Channel, DuctTemperature, OperatingChannel, OverheadFlowSelector, PackFlow,
+    PackFlowControllers, PackFlowValveSignal, PressurizationOverheadShared, TrimAirControllers,
+    TrimAirSystem, ZoneType,
 };
 
 use std::{fmt::Display, time::Duration};
@@ -45,6 +46,16 @@ impl From<AcscId> for usize {
     }
 }
 
+impl AcscId {
+    /// Each ACSC checks its own side ADIRU first
+    fn adiru_check_order(&self) -> [usize; 3] {
+        match self {
+            AcscId::Acsc1(_) => [1, 2, 3],
+            AcscId::Acsc2(_) => [2, 1, 3],
+        }
+    }
+}
+
 impl Display for AcscId {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -66,6 +77,7 @@ pub struct AirConditioningSystemController<const ZONES: usize, const ENGINES: us
     active_channel: OperatingChannel,
     stand_by_channel: OperatingChannel,
 
+    adiru_source_selector: AdiruSourceSelector,
     aircraft_state: AirConditioningStateManager,
     zone_controller: Vec<ZoneController>,
     pack_flow_controller: PackFlowController<ENGINES>,
@@ -99,6 +111,7 @@ impl<const ZONES: usize, const ENGINES: usize> AirConditioningSystemController<Z
             active_channel: OperatingChannel::new(1, Some(failure_types[0]), &powered_by[0]),
             stand_by_channel: OperatingChannel::new(2, Some(failure_types[1]), &powered_by[1]),
 
+            adiru_source_selector: AdiruSourceSelector::new(id.adiru_check_order()),
             aircraft_state: AirConditioningStateManager::new(),
             zone_controller: Self::zone_controller_initiation(id, cabin_zone_ids),
             pack_flow_controller: PackFlowController::new(context, Pack(id.into())),
@@ -140,6 +153,8 @@ impl<const ZONES: usize, const ENGINES: usize> AirConditioningSystemController<Z
     ) {
         self.fault_determination();
 
+        self.adiru_source_selector
+            .update(|adiru_number| adirs.ground_speed(adiru_number));
         let ground_speed = self.ground_speed(adirs).unwrap_or_default();
         self.aircraft_state = self
             .aircraft_state
@@ -229,10 +244,13 @@ impl<const ZONES: usize, const ENGINES: usize> AirConditioningSystemController<Z
     }
 
     fn ground_speed(&self, adirs: &impl AdirsToAirCondInterface) -> Option<Velocity> {
-        // TODO: Verify ADIRU check order
-        [1, 2, 3]
-            .iter()
-            .find_map(|&adiru_number| adirs.ground_speed(adiru_number).normal_value())
+        self.adiru_source_selector
+            .selected_adiru()
+            .and_then(|adiru_number| adirs.ground_speed(adiru_number).normal_value())
+    }
+
+    pub fn selected_adiru(&self) -> Option<usize> {
+        self.adiru_source_selector.selected_adiru()
     }
 
     pub fn pack_fault_determination(&self) -> bool {
@@ -1541,21 +1559,39 @@ mod acs_controller_tests {
 
     struct TestAdirs {
         ground_speed: Velocity,
+        failed_adirus: [bool; 3],
+        aligning_adirus: [bool; 3],
     }
     impl TestAdirs {
         fn new() -> Self {
             Self {
                 ground_speed: Velocity::default(),
+                failed_adirus: [false; 3],
+                aligning_adirus: [false; 3],
             }
         }
 
         fn set_ground_speed(&mut self, ground_speed: Velocity) {
             self.ground_speed = ground_speed;
         }
+
+        fn set_adiru_failed(&mut self, adiru_number: usize, is_failed: bool) {
+            self.failed_adirus[adiru_number - 1] = is_failed;
+        }
+
+        fn set_adiru_aligning(&mut self, adiru_number: usize, is_aligning: bool) {
+            self.aligning_adirus[adiru_number - 1] = is_aligning;
+        }
     }
     impl AdirsToAirCondInterface for TestAdirs {
-        fn ground_speed(&self, __adiru_number: usize) -> Arinc429Word<Velocity> {
-            Arinc429Word::new(self.ground_speed, SignStatus::NormalOperation)
+        fn ground_speed(&self, adiru_number: usize) -> Arinc429Word<Velocity> {
+            if self.failed_adirus[adiru_number - 1] {
+                Arinc429Word::new(Velocity::default(), SignStatus::FailureWarning)
+            } else if self.aligning_adirus[adiru_number - 1] {
+                Arinc429Word::new(Velocity::default(), SignStatus::NoComputedData)
+            } else {
+                Arinc429Word::new(self.ground_speed, SignStatus::NormalOperation)
+            }
         }
         fn true_airspeed(&self, _adiru_number: usize) -> Arinc429Word<Velocity> {
             Arinc429Word::new(Velocity::default(), SignStatus::NoComputedData)
@@ -2364,6 +2400,14 @@ mod acs_controller_tests {
             self.adirs.set_ground_speed(ground_speed);
         }
 
+        fn set_adiru_failed(&mut self, adiru_number: usize, is_failed: bool) {
+            self.adirs.set_adiru_failed(adiru_number, is_failed);
+        }
+
+        fn set_adiru_aligning(&mut self, adiru_number: usize, is_aligning: bool) {
+            self.adirs.set_adiru_aligning(adiru_number, is_aligning);
+        }
+
         fn set_engine_n1(&mut self, n: Ratio) {
             self.engine_1.set_engine_n1(n);
             self.engine_2.set_engine_n1(n);
@@ -2840,6 +2884,18 @@ mod acs_controller_tests {
             self.command(|a| a.set_ground_speed(ground_speed));
         }
 
+        fn command_adiru_failed(&mut self, adiru_number: usize, is_failed: bool) {
+            self.command(|a| a.set_adiru_failed(adiru_number, is_failed));
+        }
+
+        fn command_adiru_aligning(&mut self, adiru_number: usize, is_aligning: bool) {
+            self.command(|a| a.set_adiru_aligning(adiru_number, is_aligning));
+        }
+
+        fn acsc_selected_adiru(&self, acsc_number: usize) -> Option<usize> {
+            self.query(|a| a.acsc[acsc_number - 1].selected_adiru())
+        }
+
         fn measured_temperature(&mut self) -> ThermodynamicTemperature {
             self.read_by_name("COND_FWD_TEMP")
         }
@@ -3168,6 +3224,81 @@ mod acs_controller_tests {
     mod air_conditioning_system_controller_tests {
         use super::*;
 
+        #[test]
+        fn each_acsc_selects_its_own_side_adiru() {
+            let test_bed = test_bed().and_run();
+
+            assert_eq!(test_bed.acsc_selected_adiru(1), Some(1));
+            assert_eq!(test_bed.acsc_selected_adiru(2), Some(2));
+        }
+
+        #[test]
+        fn acsc_reverts_to_next_adiru_when_selected_adiru_fails() {
+            let mut test_bed = test_bed();
+
+            test_bed.command_adiru_failed(1, true);
+            test_bed.run();
+
+            assert_eq!(test_bed.acsc_selected_adiru(1), Some(2));
+            assert_eq!(test_bed.acsc_selected_adiru(2), Some(2));
+
+            test_bed.command_adiru_failed(2, true);
+            test_bed.run();
+
+            assert_eq!(test_bed.acsc_selected_adiru(1), Some(3));
+            assert_eq!(test_bed.acsc_selected_adiru(2), Some(3));
+        }
+
+        #[test]
+        fn acsc_reverts_to_next_adiru_while_selected_adiru_is_aligning() {
+            let mut test_bed = test_bed();
+
+            test_bed.command_adiru_aligning(1, true);
+            test_bed.run();
+
+            assert_eq!(test_bed.acsc_selected_adiru(1), Some(2));
+
+            test_bed.command_adiru_aligning(1, false);
+            test_bed.run();
+
+            assert_eq!(test_bed.acsc_selected_adiru(1), Some(1));
+        }
+
+        #[test]
+        fn acsc_returns_to_own_side_adiru_when_it_recovers() {
+            let mut test_bed = test_bed();
+
+            test_bed.command_adiru_failed(2, true);
+            test_bed.run();
+
+            assert_eq!(test_bed.acsc_selected_adiru(2), Some(1));
+
+            test_bed.command_adiru_failed(2, false);
+            test_bed.run();
+
+            assert_eq!(test_bed.acsc_selected_adiru(2), Some(2));
+        }
+
+        #[test]
+        fn acsc_uses_ground_speed_of_reverted_adiru() {
+            let mut test_bed = test_bed()
+                .on_ground()
+                .with()
+                .landing_gear_compressed()
+                .and()
+                .engine_in_take_off()
+                .and_run();
+
+            test_bed.command_adiru_failed(1, true);
+            test_bed.command_ground_speed(Velocity::new::<knot>(71.));
+            test_bed.run();
+
+            test_bed = test_bed.landing_gear_not_compressed();
+            test_bed.run();
+
+            assert!(test_bed.ac_state_is_in_flight());
+        }
+
         #[test]
         fn trim_air_achieves_selected_temperature() {
             let mut test_bed = test_bed()
@@ -3,8 +3,8 @@ use crate::{
     failures::{Failure, FailureType},
     shared::{AverageExt, CabinSimulation},
     simulation::{
-        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
-        VariableIdentifier, Write,
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
     },
 };
 use num_traits::Pow;
@@ -14,7 +14,7 @@ use uom::si::{
     mass_density::kilogram_per_cubic_meter,
     mass_rate::kilogram_per_second,
     power::{kilowatt, watt},
-    pressure::{hectopascal, pascal},
+    pressure::{hectopascal, pascal, psi},
     ratio::ratio,
     thermodynamic_temperature::{degree_celsius, kelvin},
     velocity::meter_per_second,
@@ -40,6 +40,8 @@ pub struct CabinAirSimulation<C, const ZONES: usize> {
     cabin_zones: [CabinZone<C>; ZONES],
 
     hull_breach: Failure,
+    cabin_leakage: CabinLeakage<C>,
+    delta_pressure_monitor: FuselageDeltaPressureMonitor<C>,
     constants: PhantomData<C>,
 }
 
@@ -72,6 +74,8 @@ impl<C: PressurizationConstants, const ZONES: usize> CabinAirSimulation<C, ZONES
                 }),
 
             hull_breach: Failure::new(FailureType::RapidDecompression),
+            cabin_leakage: CabinLeakage::new(context),
+            delta_pressure_monitor: FuselageDeltaPressureMonitor::new(context),
             constants: PhantomData,
         }
     }
@@ -151,6 +155,9 @@ impl<C: PressurizationConstants, const ZONES: usize> CabinAirSimulation<C, ZONES
         self.internal_air.set_flow_rate(MassRate::default());
         self.internal_air
             .set_pressure(self.internal_air.pressure() + pressure_change);
+
+        self.delta_pressure_monitor
+            .update(self.internal_air.pressure() - self.filtered_exterior_pressure);
     }
 
     fn initialize_cabin_pressure(
@@ -158,13 +165,15 @@ impl<C: PressurizationConstants, const ZONES: usize> CabinAirSimulation<C, ZONES
         context: &UpdateContext,
         lgciu_gear_compressed: bool,
     ) -> Pressure {
+        self.previous_exterior_pressure =
+            BoundedVecDeque::from_iter(vec![context.ambient_pressure(); 20], 20);
+        self.filtered_exterior_pressure = context.ambient_pressure();
+
         if lgciu_gear_compressed {
             context.ambient_pressure()
         } else {
             // Formula to simulate pressure start state if starting in flight
             let ambient_pressure: f64 = context.ambient_pressure().get::<hectopascal>();
-            self.previous_exterior_pressure =
-                BoundedVecDeque::from_iter(vec![context.ambient_pressure(); 20], 20);
             Pressure::new::<hectopascal>(
                 -0.0002 * ambient_pressure.powf(2.) + 0.5463 * ambient_pressure + 658.85,
             )
@@ -319,6 +328,7 @@ impl<C: PressurizationConstants, const ZONES: usize> CabinAirSimulation<C, ZONES
         let leakage_area = C::CABIN_LEAKAGE_AREA
             + C::SAFETY_VALVE_SIZE * safety_valve_open_amount.get::<ratio>()
             + number_of_open_doors as f64 * C::DOOR_OPENING_AREA
+            + self.cabin_leakage.leakage_area()
             + Self::HULL_BREACH_AREA * self.hull_breach.is_active() as u32 as f64; // sq m
 
         let pressure_ratio =
@@ -357,6 +367,10 @@ impl<C: PressurizationConstants, const ZONES: usize> CabinAirSimulation<C, ZONES
             .iter_mut()
             .for_each(|zone| zone.set_zone_air_temperature(temperature));
     }
+
+    pub fn structural_delta_p_limit_exceeded(&self) -> bool {
+        self.delta_pressure_monitor.structural_limit_exceeded()
+    }
 }
 
 impl<C: PressurizationConstants, const ZONES: usize> CabinSimulation
@@ -383,9 +397,163 @@ impl<C: PressurizationConstants, const ZONES: usize> SimulationElement
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         accept_iterable!(self.cabin_zones, visitor);
         self.hull_breach.accept(visitor);
+        self.cabin_leakage.accept(visitor);
+        self.delta_pressure_monitor.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+/// Leaks through the fuselage in addition to its normal leakage. Unlike a hull breach,
+/// these are small enough to result in a slow depressurisation of the cabin.
+struct CabinLeakage<C> {
+    door_seal_leak: CabinLeak,
+    window_crack: CabinLeak,
+    cargo_door_unsealed: CabinLeak,
+
+    constants: PhantomData<C>,
+}
+
+impl<C: PressurizationConstants> CabinLeakage<C> {
+    fn new(context: &mut InitContext) -> Self {
+        Self {
+            door_seal_leak: CabinLeak::new(
+                context,
+                FailureType::CabinDoorSealLeak,
+                "DOOR_SEAL",
+                C::DOOR_SEAL_LEAK_AREA,
+            ),
+            window_crack: CabinLeak::new(
+                context,
+                FailureType::CabinWindowCrack,
+                "WINDOW_CRACK",
+                C::WINDOW_CRACK_LEAK_AREA,
+            ),
+            cargo_door_unsealed: CabinLeak::new(
+                context,
+                FailureType::CargoDoorUnsealed,
+                "CARGO_DOOR",
+                C::CARGO_DOOR_UNSEALED_LEAK_AREA,
+            ),
+
+            constants: PhantomData,
+        }
+    }
+
+    /// Additional leakage area in sq m
+    fn leakage_area(&self) -> f64 {
+        self.door_seal_leak.area() + self.window_crack.area() + self.cargo_door_unsealed.area()
+    }
+}
+
+impl<C> SimulationElement for CabinLeakage<C> {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.door_seal_leak.accept(visitor);
+        self.window_crack.accept(visitor);
+        self.cargo_door_unsealed.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+/// A single leak failure. Its area is a failure parameter, so partial leaks can be simulated.
+/// When no area has been configured, the default area of the leak is used.
+struct CabinLeak {
+    area_id: VariableIdentifier,
+
+    failure: Failure,
+    configured_area: f64,
+    default_area: f64,
+}
+
+impl CabinLeak {
+    fn new(
+        context: &mut InitContext,
+        failure_type: FailureType,
+        name: &str,
+        default_area: f64,
+    ) -> Self {
+        Self {
+            area_id: context.get_identifier(format!("PRESS_{}_LEAK_AREA", name)),
+
+            failure: Failure::new(failure_type),
+            configured_area: 0.,
+            default_area,
+        }
+    }
+
+    /// Leakage area in sq m
+    fn area(&self) -> f64 {
+        if !self.failure.is_active() {
+            0.
+        } else if self.configured_area > 0. {
+            self.configured_area
+        } else {
+            self.default_area
+        }
+    }
+}
+
+impl SimulationElement for CabinLeak {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
 
         visitor.visit(self);
     }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.configured_area = reader.read(&self.area_id);
+    }
+}
+
+/// Records the highest and lowest differential pressure the fuselage has been exposed to,
+/// so an exceedance of the structural limits remains known after the event.
+struct FuselageDeltaPressureMonitor<C> {
+    max_delta_p_id: VariableIdentifier,
+    min_delta_p_id: VariableIdentifier,
+    structural_limit_exceeded_id: VariableIdentifier,
+
+    max_delta_p: Pressure,
+    min_delta_p: Pressure,
+
+    constants: PhantomData<C>,
+}
+
+impl<C: PressurizationConstants> FuselageDeltaPressureMonitor<C> {
+    fn new(context: &mut InitContext) -> Self {
+        Self {
+            max_delta_p_id: context.get_identifier("PRESS_MAX_RECORDED_DELTA_P".to_owned()),
+            min_delta_p_id: context.get_identifier("PRESS_MIN_RECORDED_DELTA_P".to_owned()),
+            structural_limit_exceeded_id: context
+                .get_identifier("PRESS_STRUCTURAL_LIMIT_EXCEEDED".to_owned()),
+
+            max_delta_p: Pressure::default(),
+            min_delta_p: Pressure::default(),
+
+            constants: PhantomData,
+        }
+    }
+
+    fn update(&mut self, cabin_delta_p: Pressure) {
+        self.max_delta_p = self.max_delta_p.max(cabin_delta_p);
+        self.min_delta_p = self.min_delta_p.min(cabin_delta_p);
+    }
+
+    fn structural_limit_exceeded(&self) -> bool {
+        self.max_delta_p > Pressure::new::<psi>(C::MAX_STRUCTURAL_DELTA_P)
+            || self.min_delta_p < Pressure::new::<psi>(C::MIN_STRUCTURAL_DELTA_P)
+    }
+}
+
+impl<C: PressurizationConstants> SimulationElement for FuselageDeltaPressureMonitor<C> {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.max_delta_p_id, self.max_delta_p);
+        writer.write(&self.min_delta_p_id, self.min_delta_p);
+        writer.write(
+            &self.structural_limit_exceeded_id,
+            self.structural_limit_exceeded(),
+        );
+    }
 }
 
 pub struct CabinZone<C> {
@@ -716,7 +884,7 @@ mod cabin_air_tests {
         air_conditioning::PackFlow,
         shared::InternationalStandardAtmosphere,
         simulation::{
-            test::{SimulationTestBed, TestBed, WriteByName},
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
             Aircraft, SimulationElement, SimulationElementVisitor, UpdateContext,
         },
     };
@@ -945,6 +1113,10 @@ mod cabin_air_tests {
             self.query(|a| a.cabin_air_simulation.cabin_temperature()[1])
         }
 
+        fn structural_delta_p_limit_exceeded(&mut self) -> bool {
+            self.read_by_name("PRESS_STRUCTURAL_LIMIT_EXCEEDED")
+        }
+
         fn memorize_cabin_temperature(mut self) -> Self {
             self.stored_temperature = Some(self.cabin_temperature());
             self
@@ -1154,4 +1326,112 @@ mod cabin_air_tests {
 
         assert!(first_temperature_differential < second_temperature_differential);
     }
+
+    fn cabin_pressure_after_one_minute_at_cruise_with(failure: Option<FailureType>) -> Pressure {
+        let mut test_bed = test_bed_with()
+            .ambient_temperature_of(ThermodynamicTemperature::new::<degree_celsius>(-50.))
+            .ambient_pressure_of(InternationalStandardAtmosphere::pressure_at_altitude(
+                Length::new::<foot>(39000.),
+            ))
+            .flying(true)
+            .iterate(1);
+
+        if let Some(failure) = failure {
+            test_bed.fail(failure);
+        }
+
+        test_bed
+            .iterate_with_delta(120, Duration::from_millis(500))
+            .cabin_pressure()
+    }
+
+    fn cabin_pressure_after_one_minute_at_cruise_with_leak_area(area: f64) -> Pressure {
+        let mut test_bed = test_bed_with()
+            .ambient_temperature_of(ThermodynamicTemperature::new::<degree_celsius>(-50.))
+            .ambient_pressure_of(InternationalStandardAtmosphere::pressure_at_altitude(
+                Length::new::<foot>(39000.),
+            ))
+            .flying(true)
+            .iterate(1);
+
+        test_bed.write_by_name("PRESS_DOOR_SEAL_LEAK_AREA", area);
+        test_bed.fail(FailureType::CabinDoorSealLeak);
+
+        test_bed
+            .iterate_with_delta(120, Duration::from_millis(500))
+            .cabin_pressure()
+    }
+
+    #[test]
+    fn cabin_leak_failures_depressurise_the_cabin() {
+        let no_failure_pressure = cabin_pressure_after_one_minute_at_cruise_with(None);
+
+        for failure in [
+            FailureType::CabinDoorSealLeak,
+            FailureType::CabinWindowCrack,
+            FailureType::CargoDoorUnsealed,
+        ] {
+            assert!(
+                cabin_pressure_after_one_minute_at_cruise_with(Some(failure)) < no_failure_pressure
+            );
+        }
+    }
+
+    #[test]
+    fn cabin_leak_failures_depressurise_slower_than_rapid_decompression() {
+        let rapid_decompression_pressure =
+            cabin_pressure_after_one_minute_at_cruise_with(Some(FailureType::RapidDecompression));
+
+        for failure in [
+            FailureType::CabinDoorSealLeak,
+            FailureType::CabinWindowCrack,
+            FailureType::CargoDoorUnsealed,
+        ] {
+            assert!(
+                cabin_pressure_after_one_minute_at_cruise_with(Some(failure))
+                    > rapid_decompression_pressure
+            );
+        }
+    }
+
+    #[test]
+    fn larger_configured_leak_area_depressurises_the_cabin_faster() {
+        let small_leak_pressure = cabin_pressure_after_one_minute_at_cruise_with_leak_area(0.001);
+        let large_leak_pressure = cabin_pressure_after_one_minute_at_cruise_with_leak_area(0.01);
+
+        assert!(large_leak_pressure < small_leak_pressure);
+    }
+
+    #[test]
+    fn structural_delta_p_limit_is_not_exceeded_in_normal_operation() {
+        let mut test_bed = test_bed_with()
+            .ambient_temperature_of(ThermodynamicTemperature::new::<degree_celsius>(-50.))
+            .ambient_pressure_of(InternationalStandardAtmosphere::pressure_at_altitude(
+                Length::new::<foot>(39000.),
+            ))
+            .flying(true)
+            .iterate(20);
+
+        assert!(!test_bed.structural_delta_p_limit_exceeded());
+    }
+
+    #[test]
+    fn structural_delta_p_limit_exceedance_is_latched() {
+        let mut test_bed = test_bed_with()
+            .ambient_pressure_of(Pressure::new::<hectopascal>(1013.25))
+            .iterate(1)
+            .then()
+            .ambient_pressure_of(InternationalStandardAtmosphere::pressure_at_altitude(
+                Length::new::<foot>(30000.),
+            ))
+            .iterate(20);
+
+        assert!(test_bed.structural_delta_p_limit_exceeded());
+
+        test_bed = test_bed
+            .ambient_pressure_of(Pressure::new::<hectopascal>(1013.25))
+            .iterate(20);
+
+        assert!(test_bed.structural_delta_p_limit_exceeded());
+    }
 }
@@ -14,7 +14,7 @@ use crate::{
 
 use super::{
     pressure_valve::{OutflowValve, PressureValveSignal, SafetyValve},
-    AdirsToAirCondInterface, OutflowValveSignal, PressurizationConstants,
+    AdirsToAirCondInterface, AdiruSourceSelector, OutflowValveSignal, PressurizationConstants,
     PressurizationOverheadShared,
 };
 
@@ -33,6 +33,16 @@ pub enum CpcId {
     Cpc2,
 }
 
+impl CpcId {
+    /// Each CPC checks its own side ADIRU first
+    fn adiru_check_order(&self) -> [usize; 3] {
+        match self {
+            Self::Cpc1 => [1, 2, 3],
+            Self::Cpc2 => [2, 1, 3],
+        }
+    }
+}
+
 impl Display for CpcId {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -53,6 +63,7 @@ pub struct CabinPressureController<C: PressurizationConstants> {
     auto_landing_elevation_id: VariableIdentifier,
     destination_qnh_id: VariableIdentifier,
 
+    adiru_source_selector: AdiruSourceSelector,
     pressure_schedule_manager: Option<PressureScheduleManager>,
     manual_partition: Option<CpcManualPartition>,
     outflow_valve_controller: OutflowValveController,
@@ -114,6 +125,7 @@ impl<C: PressurizationConstants> CabinPressureController<C> {
             auto_landing_elevation_id: context.get_identifier("FM1_LANDING_ELEVATION".to_owned()),
             destination_qnh_id: context.get_identifier("DESTINATION_QNH".to_owned()),
 
+            adiru_source_selector: AdiruSourceSelector::new(id.adiru_check_order()),
             pressure_schedule_manager: Some(PressureScheduleManager::new()),
             manual_partition: if id == CpcId::Cpc1 {
                 Some(CpcManualPartition::new(context))
@@ -172,6 +184,7 @@ impl<C: PressurizationConstants> CabinPressureController<C> {
         safety_valve: &SafetyValve,
         is_active: bool,
     ) {
+        self.update_adiru_source(adirs);
         let (adirs_airspeed, _) = self.adirs_values_calculation(adirs);
 
         self.cabin_pressure = cabin_simulation.cabin_pressure();
@@ -252,6 +265,7 @@ impl<C: PressurizationConstants> CabinPressureController<C> {
         context: &UpdateContext,
         adirs: &impl AdirsToAirCondInterface,
     ) {
+        self.update_adiru_source(adirs);
         let (_, adirs_ambient_pressure) = self.adirs_values_calculation(adirs);
         let new_exterior_altitude: Length;
 
@@ -288,19 +302,26 @@ impl<C: PressurizationConstants> CabinPressureController<C> {
         self.previous_reference_pressure = self.reference_pressure;
     }
 
+    fn update_adiru_source(&mut self, adirs: &impl AdirsToAirCondInterface) {
+        self.adiru_source_selector
+            .update(|adiru_number| adirs.ambient_static_pressure(adiru_number));
+    }
+
     fn adirs_values_calculation(
         &self,
         adirs: &impl AdirsToAirCondInterface,
     ) -> (Option<Velocity>, Option<Pressure>) {
-        // TODO: Each CPC has a different order for checking the ADIRS
-        let adiru_check_order = [1, 2, 3];
-        let adirs_airspeed = adiru_check_order
-            .iter()
-            .find_map(|&adiru_number| adirs.true_airspeed(adiru_number).normal_value());
-        let adirs_ambient_pressure = adiru_check_order
-            .iter()
-            .find_map(|&adiru_number| adirs.ambient_static_pressure(adiru_number).normal_value());
-        (adirs_airspeed, adirs_ambient_pressure)
+        match self.adiru_source_selector.selected_adiru() {
+            Some(adiru_number) => (
+                adirs.true_airspeed(adiru_number).normal_value(),
+                adirs.ambient_static_pressure(adiru_number).normal_value(),
+            ),
+            None => (None, None),
+        }
+    }
+
+    pub fn selected_adiru(&self) -> Option<usize> {
+        self.adiru_source_selector.selected_adiru()
     }
 
     fn calculate_cabin_target_vs(&mut self) -> Velocity {
@@ -400,10 +421,10 @@ impl<C: PressurizationConstants> CabinPressureController<C> {
             return Pressure::new::<hectopascal>(Self::P_0);
         }
 
-        // TODO: Each CPC has a different order for checking the ADIRS - CPC1 1-2-3, CPC2 2-1-3
-        let altimeter_setting = [1, 2, 3]
-            .iter()
-            .find_map(|&adiru_number| adirs.baro_correction(adiru_number).normal_value());
+        let altimeter_setting = self
+            .adiru_source_selector
+            .selected_adiru()
+            .and_then(|adiru_number| adirs.baro_correction(adiru_number).normal_value());
 
         if matches!(
             self.pressure_schedule_manager,
@@ -1253,12 +1274,31 @@ mod tests {
     struct TestAdirs {
         true_airspeed: Velocity,
         ambient_pressure: Pressure,
+        failed_adirus: [bool; 3],
+        no_computed_data_adirus: [bool; 3],
     }
     impl TestAdirs {
         fn new() -> Self {
             Self {
                 true_airspeed: Velocity::default(),
                 ambient_pressure: Pressure::new::<hectopascal>(1013.25),
+                failed_adirus: [false; 3],
+                no_computed_data_adirus: [false; 3],
+            }
+        }
+        fn set_adiru_failed(&mut self, adiru_number: usize, is_failed: bool) {
+            self.failed_adirus[adiru_number - 1] = is_failed;
+        }
+        fn set_adiru_no_computed_data(&mut self, adiru_number: usize, is_ncd: bool) {
+            self.no_computed_data_adirus[adiru_number - 1] = is_ncd;
+        }
+        fn ssm(&self, adiru_number: usize) -> SignStatus {
+            if self.failed_adirus[adiru_number - 1] {
+                SignStatus::FailureWarning
+            } else if self.no_computed_data_adirus[adiru_number - 1] {
+                SignStatus::NoComputedData
+            } else {
+                SignStatus::NormalOperation
             }
         }
         fn set_true_airspeed(&mut self, airspeed: Velocity) {
@@ -1272,14 +1312,14 @@ mod tests {
         fn ground_speed(&self, _adiru_number: usize) -> Arinc429Word<Velocity> {
             Arinc429Word::new(Velocity::default(), SignStatus::NormalOperation)
         }
-        fn true_airspeed(&self, _adiru_number: usize) -> Arinc429Word<Velocity> {
-            Arinc429Word::new(self.true_airspeed, SignStatus::NormalOperation)
+        fn true_airspeed(&self, adiru_number: usize) -> Arinc429Word<Velocity> {
+            Arinc429Word::new(self.true_airspeed, self.ssm(adiru_number))
         }
         fn baro_correction(&self, _adiru_number: usize) -> Arinc429Word<Pressure> {
             Arinc429Word::new(Pressure::default(), SignStatus::NoComputedData)
         }
-        fn ambient_static_pressure(&self, _adiru_number: usize) -> Arinc429Word<Pressure> {
-            Arinc429Word::new(self.ambient_pressure, SignStatus::NormalOperation)
+        fn ambient_static_pressure(&self, adiru_number: usize) -> Arinc429Word<Pressure> {
+            Arinc429Word::new(self.ambient_pressure, self.ssm(adiru_number))
         }
     }
 
@@ -1506,11 +1546,11 @@ mod tests {
         const P_0: f64 = 1013.25; // ISA standard pressure at sea level - hPa
         const L: f64 = -0.00651; // Adiabatic lapse rate - K/m
 
-        fn new(context: &mut InitContext) -> Self {
+        fn new(context: &mut InitContext, cpc_id: CpcId) -> Self {
             let mut test_aircraft = Self {
                 adirs: TestAdirs::new(),
                 air_conditioning_system: TestAirConditioningSystem::new(),
-                cpc: CabinPressureController::new(context, CpcId::Cpc1),
+                cpc: CabinPressureController::new(context, cpc_id),
                 cabin_air_simulation: CabinAirSimulation::new(
                     context,
                     &[ZoneType::Cockpit, ZoneType::Cabin(1), ZoneType::Cabin(2)],
@@ -1548,6 +1588,14 @@ mod tests {
             self.adirs.set_true_airspeed(airspeed);
         }
 
+        fn set_adiru_failed(&mut self, adiru_number: usize, is_failed: bool) {
+            self.adirs.set_adiru_failed(adiru_number, is_failed);
+        }
+
+        fn set_adiru_no_computed_data(&mut self, adiru_number: usize, is_ncd: bool) {
+            self.adirs.set_adiru_no_computed_data(adiru_number, is_ncd);
+        }
+
         fn run_with_vertical_speed_of(&mut self, delta: Duration, vertical_speed: Velocity) {
             let distance: Length = Length::new::<meter>(
                 vertical_speed.get::<meter_per_second>() * delta.as_secs_f64(),
@@ -1659,9 +1707,9 @@ mod tests {
         vertical_speed: Velocity,
     }
     impl CabinPressureControllerTestBed {
-        fn new() -> Self {
+        fn new(cpc_id: CpcId) -> Self {
             Self {
-                test_bed: SimulationTestBed::new(TestAircraft::new),
+                test_bed: SimulationTestBed::new(|context| TestAircraft::new(context, cpc_id)),
                 vertical_speed: Velocity::default(),
             }
         }
@@ -1693,7 +1741,11 @@ mod tests {
         }
     }
     fn test_bed() -> CabinPressureControllerTestBed {
-        CabinPressureControllerTestBed::new()
+        CabinPressureControllerTestBed::new(CpcId::Cpc1)
+    }
+
+    fn test_bed_with_cpc_2() -> CabinPressureControllerTestBed {
+        CabinPressureControllerTestBed::new(CpcId::Cpc2)
     }
 
     #[test]
@@ -1732,6 +1784,22 @@ mod tests {
         assert!(test_bed.query(|a| a.is_climb()));
     }
 
+    #[test]
+    fn schedule_changes_from_ground_to_climb_with_own_side_adiru_failed() {
+        let mut test_bed = test_bed();
+
+        test_bed.command(|a| a.set_adiru_failed(1, true));
+        test_bed.command(|a| a.set_on_ground(true));
+        test_bed.command(|a| a.set_true_airspeed(Velocity::new::<knot>(101.)));
+
+        test_bed.run();
+
+        test_bed.command(|a| a.set_on_ground(false));
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.is_climb()));
+    }
+
     #[test]
     fn schedule_changes_from_takeoff_to_climb() {
         let mut test_bed = test_bed();
@@ -2101,4 +2169,77 @@ mod tests {
 
         assert!(test_bed.query(|a| a.is_climb()));
     }
+
+    #[test]
+    fn cpc_1_selects_adiru_1() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        assert_eq!(test_bed.query(|a| a.cpc.selected_adiru()), Some(1));
+    }
+
+    #[test]
+    fn cpc_reverts_to_next_adiru_when_selected_adiru_fails() {
+        let mut test_bed = test_bed();
+
+        test_bed.command(|a| a.set_adiru_failed(1, true));
+        test_bed.run();
+
+        assert_eq!(test_bed.query(|a| a.cpc.selected_adiru()), Some(2));
+
+        test_bed.command(|a| a.set_adiru_failed(2, true));
+        test_bed.run();
+
+        assert_eq!(test_bed.query(|a| a.cpc.selected_adiru()), Some(3));
+
+        test_bed.command(|a| a.set_adiru_failed(1, false));
+        test_bed.run();
+
+        assert_eq!(test_bed.query(|a| a.cpc.selected_adiru()), Some(1));
+    }
+
+    #[test]
+    fn cpc_has_no_adiru_source_when_all_adirus_fail() {
+        let mut test_bed = test_bed();
+
+        for adiru_number in 1..=3 {
+            test_bed.command(|a| a.set_adiru_failed(adiru_number, true));
+        }
+        test_bed.run();
+
+        assert_eq!(test_bed.query(|a| a.cpc.selected_adiru()), None);
+    }
+
+    #[test]
+    fn cpc_2_checks_adiru_2_first() {
+        let mut test_bed = test_bed_with_cpc_2();
+        test_bed.run();
+
+        assert_eq!(test_bed.query(|a| a.cpc.selected_adiru()), Some(2));
+
+        test_bed.command(|a| a.set_adiru_failed(2, true));
+        test_bed.run();
+
+        assert_eq!(test_bed.query(|a| a.cpc.selected_adiru()), Some(1));
+
+        test_bed.command(|a| a.set_adiru_failed(1, true));
+        test_bed.run();
+
+        assert_eq!(test_bed.query(|a| a.cpc.selected_adiru()), Some(3));
+    }
+
+    #[test]
+    fn cpc_2_reverts_to_next_adiru_when_selected_adiru_has_no_computed_data() {
+        let mut test_bed = test_bed_with_cpc_2();
+
+        test_bed.command(|a| a.set_adiru_no_computed_data(2, true));
+        test_bed.run();
+
+        assert_eq!(test_bed.query(|a| a.cpc.selected_adiru()), Some(1));
+
+        test_bed.command(|a| a.set_adiru_no_computed_data(2, false));
+        test_bed.run();
+
+        assert_eq!(test_bed.query(|a| a.cpc.selected_adiru()), Some(2));
+    }
 }
@@ -107,6 +107,37 @@ pub trait AdirsToAirCondInterface {
     fn ambient_static_pressure(&self, adiru_number: usize) -> Arinc429Word<Pressure>;
 }
 
+/// Selects the ADIRU a controller takes its air data from. The ADIRUs are checked in the
+/// controller's own order, so it reverts to the next ADIRU as soon as the selected one
+/// doesn't provide valid data and goes back to its preferred ADIRU once that one is valid again.
+pub struct AdiruSourceSelector {
+    check_order: [usize; 3],
+    selected_adiru: Option<usize>,
+}
+
+impl AdiruSourceSelector {
+    pub fn new(check_order: [usize; 3]) -> Self {
+        Self {
+            check_order,
+            selected_adiru: None,
+        }
+    }
+
+    /// Updates the selection using the validity of the given monitored word of each ADIRU.
+    /// Failed as well as no computed data words (e.g. IR still aligning) trigger a reversion.
+    pub fn update<T: Copy>(&mut self, monitored_word: impl Fn(usize) -> Arinc429Word<T>) {
+        self.selected_adiru = self
+            .check_order
+            .iter()
+            .copied()
+            .find(|&adiru_number| monitored_word(adiru_number).is_normal_operation());
+    }
+
+    pub fn selected_adiru(&self) -> Option<usize> {
+        self.selected_adiru
+    }
+}
+
 pub trait AirConditioningOverheadShared {
     fn selected_cabin_temperature(&self, zone_id: usize) -> ThermodynamicTemperature;
     fn selected_cargo_temperature(&self, _zone_id: ZoneType) -> ThermodynamicTemperature {
@@ -383,6 +414,9 @@ pub trait PressurizationConstants {
     const OUTFLOW_VALVE_SIZE: f64;
     const SAFETY_VALVE_SIZE: f64;
     const DOOR_OPENING_AREA: f64;
+    const DOOR_SEAL_LEAK_AREA: f64 = 0.003; // m2
+    const WINDOW_CRACK_LEAK_AREA: f64 = 0.001; // m2
+    const CARGO_DOOR_UNSEALED_LEAK_AREA: f64 = 0.008; // m2
 
     const MAX_CLIMB_RATE: f64;
     const MAX_CLIMB_RATE_IN_DESCENT: f64;
@@ -393,6 +427,8 @@ pub trait PressurizationConstants {
     const MAX_CLIMB_CABIN_ALTITUDE: f64;
     const MAX_SAFETY_DELTA_P: f64;
     const MIN_SAFETY_DELTA_P: f64;
+    const MAX_STRUCTURAL_DELTA_P: f64 = 9.; // PSI
+    const MIN_STRUCTURAL_DELTA_P: f64 = -1.; // PSI
     const TAKEOFF_RATE: f64;
     const DEPRESS_RATE: f64;
     const EXCESSIVE_ALT_WARNING: f64;
@@ -1,8 +1,8 @@
 use std::time::Duration;
 
 use uom::si::{
-    electric_potential::volt, f64::*, frequency::hertz, power::watt, pressure::psi, ratio::percent,
-    temperature_interval, thermodynamic_temperature::degree_celsius,
+    electric_potential::volt, f64::*, frequency::hertz, length::foot, power::watt, pressure::psi,
+    ratio::percent, ratio::ratio, temperature_interval, thermodynamic_temperature::degree_celsius,
 };
 
 use crate::{
@@ -19,7 +19,9 @@ use crate::{
     simulation::{InitContext, SimulationElement, SimulatorWriter, UpdateContext},
 };
 
-use super::{ApuConstants, ApuGenerator, ApuStartMotor, Turbine, TurbineSignal, TurbineState};
+use super::{
+    performance, ApuConstants, ApuGenerator, ApuStartMotor, Turbine, TurbineSignal, TurbineState,
+};
 
 pub struct Aps3200Constants;
 
@@ -30,6 +32,8 @@ impl ApuConstants for Aps3200Constants {
     const AIR_INTAKE_FLAP_CLOSURE_PERCENT: f64 = 7.;
     const SHOULD_BE_AVAILABLE_DURING_SHUTDOWN: bool = true;
     const FUEL_LINE_ID: u8 = 18;
+    const SPEED_SENSOR_FALLBACK_TO_N2: bool = false;
+    const MAXIMUM_START_ALTITUDE_FEET: f64 = 39000.;
 }
 
 pub struct ShutdownAps3200Turbine {
@@ -52,6 +56,7 @@ impl Turbine for ShutdownAps3200Turbine {
         context: &UpdateContext,
         _: bool,
         _: bool,
+        _: ElectricPotential,
         controller: &dyn ControllerSignal<TurbineSignal>,
     ) -> Box<dyn Turbine> {
         self.egt = calculate_towards_ambient_egt(self.egt, context);
@@ -84,14 +89,21 @@ struct Starting {
     n: Ratio,
     egt: ThermodynamicTemperature,
     ignore_calculated_egt: bool,
+    has_lit_off: bool,
 }
 impl Starting {
+    const START_IGNITION_AFTER: Duration = Duration::from_millis(1500);
+    // Without light-off the start motor only cranks the APU up to this point in the
+    // start sequence.
+    const CRANKING_UNTIL: Duration = Duration::from_secs(8);
+
     fn new(egt: ThermodynamicTemperature) -> Starting {
         Starting {
             since: Duration::from_secs(0),
             n: Ratio::new::<percent>(0.),
             egt,
             ignore_calculated_egt: true,
+            has_lit_off: false,
         }
     }
 
@@ -129,14 +141,14 @@ impl Starting {
                 + (APU_N_TEMP_X11 * n.powi(11))
                 + (APU_N_TEMP_X12 * n.powi(12))
                 + (APU_N_TEMP_X13 * n.powi(13)),
-        );
+        ) + performance::starting_egt_delta(context);
 
         // The above calculated EGT can be lower than the ambient temperature,
         // or the current APU EGT (when cooling down). To prevent sudden changes
         // in temperature, we ignore the calculated EGT until it exceeds the current
         // EGT.
         let towards_ambient_egt = calculate_towards_ambient_egt(self.egt, context);
-        if temperature > towards_ambient_egt {
+        if self.has_lit_off && temperature > towards_ambient_egt {
             self.ignore_calculated_egt = false;
         }
 
@@ -165,9 +177,9 @@ impl Starting {
 
         // Protect against the formula returning decreasing results after this value.
         const TIME_LIMIT: f64 = 45.12;
-        const START_IGNITION_AFTER_SECONDS: f64 = 1.5;
-        let ignition_turned_on_secs =
-            (self.since.as_secs_f64() - START_IGNITION_AFTER_SECONDS).min(TIME_LIMIT);
+        let ignition_turned_on_secs = (self.since.as_secs_f64()
+            - Starting::START_IGNITION_AFTER.as_secs_f64())
+        .min(TIME_LIMIT);
 
         if ignition_turned_on_secs > 0. {
             let n = (APU_N_CONST
@@ -199,9 +211,29 @@ impl Turbine for Starting {
         context: &UpdateContext,
         _: bool,
         _: bool,
+        start_motor_potential: ElectricPotential,
         controller: &dyn ControllerSignal<TurbineSignal>,
     ) -> Box<dyn Turbine> {
-        self.since += context.delta();
+        let start_acceleration_factor =
+            performance::start_acceleration_factor(context, start_motor_potential);
+        self.since += if start_acceleration_factor < 1. {
+            context.delta().mul_f64(start_acceleration_factor)
+        } else {
+            context.delta()
+        };
+
+        if !self.has_lit_off && self.since >= Starting::START_IGNITION_AFTER {
+            self.has_lit_off = performance::light_off_is_possible(
+                context,
+                start_acceleration_factor,
+                Length::new::<foot>(Aps3200Constants::MAXIMUM_START_ALTITUDE_FEET),
+            );
+
+            if !self.has_lit_off {
+                self.since = self.since.min(Starting::CRANKING_UNTIL);
+            }
+        }
+
         self.n = self.calculate_n();
         self.egt = self.calculate_egt(context);
 
@@ -210,7 +242,7 @@ impl Turbine for Starting {
             Some(TurbineSignal::StartOrContinue)
                 if { (self.n.get::<percent>() - 100.).abs() < f64::EPSILON } =>
             {
-                Box::new(Running::new(self.egt))
+                Box::new(Running::new(context, self.egt))
             }
             Some(TurbineSignal::StartOrContinue) => self,
         }
@@ -343,9 +375,13 @@ struct Running {
     base_egt_deviation: TemperatureInterval,
     bleed_air_usage: BleedAirUsageEgtDelta,
     apu_gen_usage: ApuGenUsageEgtDelta,
+    bleed_air_pressure: Pressure,
 }
 impl Running {
-    fn new(egt: ThermodynamicTemperature) -> Running {
+    // TODO: Figure out what value this is supposed to be.
+    const SEA_LEVEL_BLEED_AIR_PRESSURE_PSI: f64 = 50.;
+
+    fn new(context: &UpdateContext, egt: ThermodynamicTemperature) -> Running {
         let base_egt = 340. + ((random_number() % 11) as f64);
         Running {
             egt,
@@ -358,9 +394,17 @@ impl Running {
             ),
             bleed_air_usage: BleedAirUsageEgtDelta::new(),
             apu_gen_usage: ApuGenUsageEgtDelta::new(),
+            bleed_air_pressure: Running::calculate_bleed_air_pressure(context),
         }
     }
 
+    fn calculate_bleed_air_pressure(context: &UpdateContext) -> Pressure {
+        performance::bleed_air_pressure(
+            context,
+            Pressure::new::<psi>(Running::SEA_LEVEL_BLEED_AIR_PRESSURE_PSI),
+        )
+    }
+
     fn calculate_egt(
         &mut self,
         context: &UpdateContext,
@@ -375,7 +419,8 @@ impl Running {
             ),
         );
 
-        let mut target = self.base_egt + self.base_egt_deviation;
+        let mut target =
+            self.base_egt + self.base_egt_deviation + performance::running_egt_delta(context);
         self.apu_gen_usage.update(context, apu_gen_is_used);
         target += self.apu_gen_usage.egt_delta();
 
@@ -391,9 +436,11 @@ impl Turbine for Running {
         context: &UpdateContext,
         apu_bleed_is_used: bool,
         apu_gen_is_used: bool,
+        _: ElectricPotential,
         controller: &dyn ControllerSignal<TurbineSignal>,
     ) -> Box<dyn Turbine> {
         self.egt = self.calculate_egt(context, apu_gen_is_used, apu_bleed_is_used);
+        self.bleed_air_pressure = Running::calculate_bleed_air_pressure(context);
 
         match controller.signal() {
             Some(TurbineSignal::StartOrContinue) => self,
@@ -416,8 +463,7 @@ impl Turbine for Running {
     }
 
     fn bleed_air_pressure(&self) -> Pressure {
-        // TODO: Figure out what value this is supposed to be.
-        Pressure::new::<psi>(50.)
+        self.bleed_air_pressure
     }
 }
 
@@ -526,6 +572,7 @@ impl Turbine for Stopping {
         context: &UpdateContext,
         _: bool,
         _: bool,
+        _: ElectricPotential,
         _: &dyn ControllerSignal<TurbineSignal>,
     ) -> Box<dyn Turbine> {
         self.since += context.delta();
@@ -702,7 +749,7 @@ impl SimulationElement for Aps3200ApuGenerator {
 
     fn process_power_consumption_report<T: PowerConsumptionReport>(
         &mut self,
-        _: &UpdateContext,
+        context: &UpdateContext,
         report: &T,
     ) {
         self.output_potential = if self.should_provide_output() {
@@ -721,7 +768,7 @@ impl SimulationElement for Aps3200ApuGenerator {
             .total_consumption_of(PotentialOrigin::ApuGenerator(self.number))
             .get::<watt>();
         let power_factor_correction = 0.8;
-        let maximum_load = 90000.;
+        let maximum_load = 90000. * performance::generator_load_capability(context).get::<ratio>();
         self.load = Ratio::new::<percent>(
             (power_consumption * power_factor_correction / maximum_load) * 100.,
         );
@@ -736,6 +783,7 @@ pub struct Aps3200StartMotor {
     /// potential coming in via those contactors.
     powered_by: ElectricalBusType,
     is_powered: bool,
+    potential: ElectricPotential,
     powered_since: Duration,
 }
 impl Aps3200StartMotor {
@@ -743,6 +791,7 @@ impl Aps3200StartMotor {
         Aps3200StartMotor {
             powered_by,
             is_powered: false,
+            potential: ElectricPotential::new::<volt>(0.),
             powered_since: Duration::from_secs(0),
         }
     }
@@ -751,10 +800,15 @@ impl ApuStartMotor for Aps3200StartMotor {
     fn is_powered(&self) -> bool {
         self.is_powered
     }
+
+    fn potential(&self) -> ElectricPotential {
+        self.potential
+    }
 }
 impl SimulationElement for Aps3200StartMotor {
     fn receive_power(&mut self, buses: &impl ElectricalBuses) {
         self.is_powered = buses.is_powered(self.powered_by);
+        self.potential = buses.potential_of(self.powered_by).raw();
     }
 
     fn consume_power<T: ConsumePower>(&mut self, context: &UpdateContext, consumption: &mut T) {
@@ -7,12 +7,15 @@ use super::{
 use crate::shared::{EngineCorrectedN1, LgciuWeightOnWheels};
 use crate::simulation::{InitContext, SimulatorReader, VariableIdentifier};
 use crate::{
+    failures::{Failure, FailureType},
     pneumatic::PneumaticValveSignal,
     shared::{
         arinc429::SignStatus, ApuBleedAirValveSignal, ApuMaster, ApuStart, ConsumePower,
         ContactorSignal, ControllerSignal, ElectricalBusType, ElectricalBuses, PneumaticValve,
     },
-    simulation::{Read, SimulationElement, SimulatorWriter, UpdateContext, Write},
+    simulation::{
+        Read, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext, Write,
+    },
 };
 use std::marker::PhantomData;
 use std::time::Duration;
@@ -35,6 +38,7 @@ pub(super) struct ElectronicControlBox<C: ApuConstants> {
     apu_is_auto_shutdown_id: VariableIdentifier,
     apu_is_emergency_shutdown_id: VariableIdentifier,
     apu_bleed_air_pressure_id: VariableIdentifier,
+    apu_ecb_fault_code_id: VariableIdentifier,
 
     apu_fuel_line_flow_id: VariableIdentifier,
 
@@ -62,12 +66,27 @@ pub(super) struct ElectronicControlBox<C: ApuConstants> {
     on_ground: bool,
     /** Absolute air pressure sensor in the air intake assembly. */
     inlet_pressure: Pressure,
+    fire_detected: bool,
+    air_intake_flap_not_open_for: Duration,
+    starting_for: Duration,
+
+    egt_sensor_failure: Failure,
+    speed_sensor_failure: Failure,
+    inlet_pressure_sensor_failure: Failure,
+    fuel_control_unit_failure: Failure,
+    oil_low_pressure_failure: Failure,
 
     constants: PhantomData<C>,
 }
 impl<C: ApuConstants> ElectronicControlBox<C> {
     const START_MOTOR_POWERED_UNTIL_N: f64 = 55.;
     const JET_A_1_DENSITY: f64 = 0.804; // Kilograms per Liter
+    const AIR_INTAKE_FLAP_NOT_OPEN_TIMEOUT: Duration = Duration::from_secs(20);
+    const OIL_PRESSURE_MONITORING_ABOVE_N: f64 = 95.;
+    const FALLBACK_INLET_PRESSURE_PSI: f64 = 14.696;
+    /** N2 at which N reaches 100% with the APU running without load. */
+    const FALLBACK_N2_AT_RATED_N_PERCENT: f64 = 85.;
+    const START_NO_ACCELERATION_TIMEOUT: Duration = Duration::from_secs(120);
 
     pub fn new(context: &mut InitContext, powered_by: ElectricalBusType) -> Self {
         ElectronicControlBox {
@@ -86,6 +105,7 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
             apu_is_emergency_shutdown_id: context
                 .get_identifier("APU_IS_EMERGENCY_SHUTDOWN".to_owned()),
             apu_bleed_air_pressure_id: context.get_identifier("APU_BLEED_AIR_PRESSURE".to_owned()),
+            apu_ecb_fault_code_id: context.get_identifier("APU_ECB_FAULT_CODE".to_owned()),
 
             apu_fuel_line_flow_id: context
                 .get_identifier(format!("FUELSYSTEM LINE FUEL FLOW:{}", C::FUEL_LINE_ID)),
@@ -115,6 +135,15 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
             engines_on: false,
             on_ground: false,
             inlet_pressure: Pressure::new::<bar>(0.94),
+            fire_detected: false,
+            air_intake_flap_not_open_for: Duration::ZERO,
+            starting_for: Duration::ZERO,
+
+            egt_sensor_failure: Failure::new(FailureType::ApuEgtSensor),
+            speed_sensor_failure: Failure::new(FailureType::ApuSpeedSensor),
+            inlet_pressure_sensor_failure: Failure::new(FailureType::ApuInletPressureSensor),
+            fuel_control_unit_failure: Failure::new(FailureType::ApuFuelControlUnit),
+            oil_low_pressure_failure: Failure::new(FailureType::ApuOilLowPressure),
 
             constants: PhantomData,
         }
@@ -136,11 +165,13 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
         overhead: &AuxiliaryPowerUnitOverheadPanel,
         fire_overhead: &AuxiliaryPowerUnitFireOverheadPanel,
         apu_bleed_is_on: bool,
+        fire_detected: bool,
     ) {
         self.master_is_on = overhead.master_sw_is_on();
         self.start_is_on = overhead.start_is_on();
         self.bleed_is_on = apu_bleed_is_on;
         self.fire_button_is_released = fire_overhead.fire_button_is_released();
+        self.fire_detected = fire_detected;
         if fire_overhead.fire_button_is_released() {
             self.fault = Some(ApuFault::ApuFire);
         }
@@ -151,8 +182,13 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
     }
 
     pub fn update_air_intake_state(&mut self, context: &UpdateContext) {
-        // FIXME simulate sensor failure (with fallback value logic)
-        self.inlet_pressure = context.ambient_pressure();
+        // When the inlet pressure sensor fails, the ECB falls back to the sea level pressure.
+        // This selects the lower (more restrictive) starting EGT limit at any altitude.
+        self.inlet_pressure = if self.inlet_pressure_sensor_failure.is_active() {
+            Pressure::new::<psi>(Self::FALLBACK_INLET_PRESSURE_PSI)
+        } else {
+            context.ambient_pressure()
+        };
     }
 
     pub fn update_start_motor_state(&mut self, start_motor: &impl ApuStartMotor) {
@@ -172,8 +208,11 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
         self.update_fuel_used(context);
 
         self.n2 = turbine.n2();
-        self.n = turbine.n();
-        self.egt = turbine.egt();
+        self.n = self.measured_n(turbine);
+        // Without a valid EGT signal the ECB retains the last valid value.
+        if !self.egt_sensor_failure.is_active() {
+            self.egt = turbine.egt();
+        }
         self.turbine_state = turbine.state();
         self.bleed_air_pressure = turbine.bleed_air_pressure();
 
@@ -184,6 +223,24 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
             self.n_above_95_duration = Duration::from_secs(0);
         }
 
+        if self.master_is_on
+            && self.start_is_on
+            && self.turbine_state == TurbineState::Shutdown
+            && !self.air_intake_flap_is_fully_open()
+        {
+            self.air_intake_flap_not_open_for += context.delta();
+        } else {
+            self.air_intake_flap_not_open_for = Duration::ZERO;
+        }
+
+        if self.turbine_state == TurbineState::Starting {
+            self.starting_for += context.delta();
+        } else {
+            self.starting_for = Duration::ZERO;
+        }
+
+        self.monitor_faults();
+
         if !self.is_on() {
             self.fault = None;
         }
@@ -195,6 +252,64 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
         }
     }
 
+    fn measured_n(&self, turbine: &dyn Turbine) -> Ratio {
+        if !self.speed_sensor_failure.is_active() {
+            turbine.n()
+        } else if C::SPEED_SENSOR_FALLBACK_TO_N2 {
+            // N is derived from the N2 signal, which runs at a fixed ratio below N.
+            Ratio::new::<percent>(
+                (self.n2.get::<percent>() / Self::FALLBACK_N2_AT_RATED_N_PERCENT * 100.).min(100.),
+            )
+        } else {
+            // Without any speed signal the ECB cannot consider the turbine to be turning.
+            Ratio::default()
+        }
+    }
+
+    fn monitor_faults(&mut self) {
+        // On ground, a detected fire causes an emergency shutdown without crew action.
+        if self.fire_detected && self.on_ground {
+            self.fault = Some(ApuFault::ApuFire);
+        }
+
+        if self.fault.is_some() || !self.is_on() {
+            return;
+        }
+
+        let turbine_is_turning = matches!(
+            self.turbine_state,
+            TurbineState::Starting | TurbineState::Running
+        );
+
+        self.fault = if turbine_is_turning && self.fuel_control_unit_failure.is_active() {
+            Some(ApuFault::FuelControlUnit)
+        } else if turbine_is_turning
+            && self.speed_sensor_failure.is_active()
+            && !C::SPEED_SENSOR_FALLBACK_TO_N2
+        {
+            Some(ApuFault::SpeedSensor)
+        } else if self.turbine_state == TurbineState::Starting
+            && self.egt_sensor_failure.is_active()
+        {
+            // Overtemperature protection during the start sequence isn't possible without EGT.
+            Some(ApuFault::EgtSensor)
+        } else if turbine_is_turning
+            && self.n.get::<percent>() > Self::OIL_PRESSURE_MONITORING_ABOVE_N
+            && self.oil_low_pressure_failure.is_active()
+        {
+            Some(ApuFault::OilLowPressure)
+        } else if self.air_intake_flap_not_open_for >= Self::AIR_INTAKE_FLAP_NOT_OPEN_TIMEOUT {
+            Some(ApuFault::AirIntakeFlapNotOpen)
+        } else if self.starting_for >= Self::START_NO_ACCELERATION_TIMEOUT
+            && self.n.get::<percent>() < Self::START_MOTOR_POWERED_UNTIL_N
+        {
+            // The APU didn't light off or accelerates too slowly to become self-sustaining.
+            Some(ApuFault::NoAcceleration)
+        } else {
+            None
+        };
+    }
+
     pub fn update_bleed_air_valve_state(
         &mut self,
         context: &UpdateContext,
@@ -295,6 +410,30 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
         self.has_fault() || self.fire_button_is_released
     }
 
+    fn fault_code(&self) -> u8 {
+        self.fault.map_or(0, |fault| fault.code())
+    }
+
+    fn egt_sensor_ssm(&self) -> SignStatus {
+        if !self.is_on() {
+            SignStatus::FailureWarning
+        } else if self.egt_sensor_failure.is_active() {
+            SignStatus::NoComputedData
+        } else {
+            SignStatus::NormalOperation
+        }
+    }
+
+    fn speed_sensor_ssm(&self) -> SignStatus {
+        if !self.is_on() {
+            SignStatus::FailureWarning
+        } else if self.speed_sensor_failure.is_active() && !C::SPEED_SENSOR_FALLBACK_TO_N2 {
+            SignStatus::NoComputedData
+        } else {
+            SignStatus::NormalOperation
+        }
+    }
+
     pub fn has_fuel_low_pressure_fault(&self) -> bool {
         if let Some(fault) = self.fault {
             fault == ApuFault::FuelLowPressure
@@ -432,6 +571,8 @@ impl<C: ApuConstants> ControllerSignal<ApuBleedAirValveSignal> for ElectronicCon
             && self.master_is_on
             && self.n.get::<percent>() > 95.
             && self.bleed_is_on
+            // The bleed load is shed when EGT can no longer be monitored.
+            && !self.egt_sensor_failure.is_active()
         {
             Some(ApuBleedAirValveSignal::new_open())
         } else {
@@ -440,6 +581,16 @@ impl<C: ApuConstants> ControllerSignal<ApuBleedAirValveSignal> for ElectronicCon
     }
 }
 impl<C: ApuConstants> SimulationElement for ElectronicControlBox<C> {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.egt_sensor_failure.accept(visitor);
+        self.speed_sensor_failure.accept(visitor);
+        self.inlet_pressure_sensor_failure.accept(visitor);
+        self.fuel_control_unit_failure.accept(visitor);
+        self.oil_low_pressure_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         let ssm = if self.is_on() {
             SignStatus::NormalOperation
@@ -450,9 +601,9 @@ impl<C: ApuConstants> SimulationElement for ElectronicControlBox<C> {
         // For sound and effects.
         writer.write(&self.apu_n_raw_id, self.n());
 
-        writer.write_arinc429(&self.apu_n_id, self.n(), ssm);
+        writer.write_arinc429(&self.apu_n_id, self.n(), self.speed_sensor_ssm());
         writer.write_arinc429(&self.apu_n2_id, self.n2(), ssm);
-        writer.write_arinc429(&self.apu_egt_id, self.egt, ssm);
+        writer.write_arinc429(&self.apu_egt_id, self.egt, self.egt_sensor_ssm());
         writer.write_arinc429(
             &self.apu_egt_caution_id,
             self.egt_caution_temperature(),
@@ -483,6 +634,7 @@ impl<C: ApuConstants> SimulationElement for ElectronicControlBox<C> {
             &self.apu_is_emergency_shutdown_id,
             self.is_emergency_shutdown(),
         );
+        writer.write(&self.apu_ecb_fault_code_id, self.fault_code());
     }
 
     fn read(&mut self, reader: &mut SimulatorReader) {
@@ -506,4 +658,26 @@ enum ApuFault {
     ApuFire,
     FuelLowPressure,
     DcPowerLoss,
+    EgtSensor,
+    SpeedSensor,
+    FuelControlUnit,
+    OilLowPressure,
+    AirIntakeFlapNotOpen,
+    NoAcceleration,
+}
+impl ApuFault {
+    /// The code as reported by the ECB to the ECAM.
+    fn code(&self) -> u8 {
+        match self {
+            ApuFault::ApuFire => 1,
+            ApuFault::FuelLowPressure => 2,
+            ApuFault::DcPowerLoss => 3,
+            ApuFault::EgtSensor => 4,
+            ApuFault::SpeedSensor => 5,
+            ApuFault::FuelControlUnit => 6,
+            ApuFault::OilLowPressure => 7,
+            ApuFault::AirIntakeFlapNotOpen => 8,
+            ApuFault::NoAcceleration => 9,
+        }
+    }
 }
@@ -27,6 +27,7 @@ pub use aps3200::{Aps3200ApuGenerator, Aps3200Constants, Aps3200StartMotor};
 pub use pw980::{Pw980ApuGenerator, Pw980Constants, Pw980StartMotor};
 
 mod electronic_control_box;
+mod performance;
 
 pub struct AuxiliaryPowerUnitFactory {}
 impl AuxiliaryPowerUnitFactory {
@@ -68,6 +69,7 @@ impl AuxiliaryPowerUnitFactory {
 
 pub trait ApuStartMotor: SimulationElement {
     fn is_powered(&self) -> bool;
+    fn potential(&self) -> ElectricPotential;
 }
 
 /// Komp: There is a pressure switch between the fuel valve and the APU.
@@ -108,6 +110,7 @@ pub struct AuxiliaryPowerUnit<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants
     start_motor: U,
     air_intake_flap: AirIntakeFlap,
     fuel_pressure_switch: FuelPressureSwitch,
+    bleed_air_temperature: ThermodynamicTemperature,
 }
 impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
     AuxiliaryPowerUnit<T, U, C, N>
@@ -130,6 +133,7 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
             start_motor,
             air_intake_flap: AirIntakeFlap::new(air_intake_flap_powered_by),
             fuel_pressure_switch: FuelPressureSwitch::new(),
+            bleed_air_temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
         }
     }
 
@@ -142,9 +146,14 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
         apu_gen_is_used: bool,
         bleed_air_valve: &mut impl ControllablePneumaticValve,
         has_fuel_remaining: bool,
+        fire_detected: bool,
     ) {
-        self.ecb
-            .update_overhead_panel_state(overhead, fire_overhead, apu_bleed_is_on);
+        self.ecb.update_overhead_panel_state(
+            overhead,
+            fire_overhead,
+            apu_bleed_is_on,
+            fire_detected,
+        );
         self.fuel_pressure_switch.update(has_fuel_remaining);
         self.ecb
             .update_fuel_pressure_switch_state(&self.fuel_pressure_switch);
@@ -159,10 +168,13 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
                 context,
                 bleed_air_valve.is_open(),
                 apu_gen_is_used,
+                self.start_motor.potential(),
                 &self.ecb,
             );
 
             self.ecb.update(context, updated_turbine.as_ref());
+            self.bleed_air_temperature =
+                performance::bleed_air_temperature(context, updated_turbine.bleed_air_pressure());
 
             self.turbine = Some(updated_turbine);
         }
@@ -246,13 +258,8 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
     ControllerSignal<TargetPressureTemperatureSignal> for AuxiliaryPowerUnit<T, U, C, N>
 {
     fn signal(&self) -> Option<TargetPressureTemperatureSignal> {
-        // TODO: Calculate the temperature depending on environmental conditions.
-        // Currently the temperature is precalculated for a bleed pressure of 42 psi.
         self.turbine.as_ref().map(|s| {
-            TargetPressureTemperatureSignal::new(
-                s.bleed_air_pressure(),
-                ThermodynamicTemperature::new::<degree_celsius>(165.),
-            )
+            TargetPressureTemperatureSignal::new(s.bleed_air_pressure(), self.bleed_air_temperature)
         })
     }
 }
@@ -282,6 +289,7 @@ pub trait Turbine {
         context: &UpdateContext,
         apu_bleed_is_used: bool,
         apu_gen_is_used: bool,
+        start_motor_potential: ElectricPotential,
         controller: &dyn ControllerSignal<TurbineSignal>,
     ) -> Box<dyn Turbine>;
     fn n(&self) -> Ratio;
@@ -315,6 +323,11 @@ pub trait ApuConstants {
     const AIR_INTAKE_FLAP_CLOSURE_PERCENT: f64;
     const SHOULD_BE_AVAILABLE_DURING_SHUTDOWN: bool;
     const FUEL_LINE_ID: u8;
+    /// Whether the ECB can continue operating on the N2 speed signal
+    /// when the N speed sensor fails.
+    const SPEED_SENSOR_FALLBACK_TO_N2: bool;
+    /// The pressure altitude above which the APU cannot light off.
+    const MAXIMUM_START_ALTITUDE_FEET: f64;
 }
 
 pub struct AuxiliaryPowerUnitFireOverheadPanel {
@@ -327,7 +340,7 @@ impl AuxiliaryPowerUnitFireOverheadPanel {
         }
     }
 
-    fn fire_button_is_released(&self) -> bool {
+    pub fn fire_button_is_released(&self) -> bool {
         self.apu_fire_button.is_released()
     }
 }
@@ -404,6 +417,7 @@ mod tests {
     };
 
     use super::*;
+    use crate::failures::FailureType;
     use crate::simulation::test::{ReadByName, WriteByName};
     use crate::simulation::InitContext;
     use rstest::rstest;
@@ -452,6 +466,7 @@ mod tests {
             _: &UpdateContext,
             _: bool,
             _: bool,
+            _: ElectricPotential,
             _: &dyn ControllerSignal<TurbineSignal>,
         ) -> Box<dyn Turbine> {
             self
@@ -567,6 +582,7 @@ mod tests {
         lgciu1: TestLgciu,
         lgciu2: TestLgciu,
         has_fuel_remaining: bool,
+        fire_detected: bool,
         power_consumer: PowerConsumer,
         cut_start_motor_power: bool,
         power_consumption: Power,
@@ -605,6 +621,7 @@ mod tests {
                 lgciu1: TestLgciu::new(false),
                 lgciu2: TestLgciu::new(false),
                 has_fuel_remaining: true,
+                fire_detected: false,
                 cut_start_motor_power: false,
                 power_consumption: Power::new::<watt>(0.),
                 apu_generator_output_within_normal_parameters_before_processing_power_consumption_report: false,
@@ -632,6 +649,7 @@ mod tests {
                 lgciu1: TestLgciu::new(false),
                 lgciu2: TestLgciu::new(false),
                 has_fuel_remaining: true,
+                fire_detected: false,
                 cut_start_motor_power: false,
                 power_consumption: Power::new::<watt>(0.),
                 apu_generator_output_within_normal_parameters_before_processing_power_consumption_report: false,
@@ -651,6 +669,10 @@ mod tests {
             self.has_fuel_remaining = value;
         }
 
+        fn set_fire_detected(&mut self, value: bool) {
+            self.fire_detected = value;
+        }
+
         fn set_turbine_infinitely_running_at(&mut self, n: Ratio) {
             self.apu
                 .set_turbine(Some(Box::new(InfinitelyAtNTestTurbine::new(n))));
@@ -668,6 +690,10 @@ mod tests {
             self.apu.signal()
         }
 
+        fn bleed_air_signal(&self) -> Option<TargetPressureTemperatureSignal> {
+            self.apu.signal()
+        }
+
         fn cut_start_motor_power(&mut self) {
             self.cut_start_motor_power = true;
         }
@@ -680,6 +706,10 @@ mod tests {
             self.dc_bat_bus_electricity_source.power();
         }
 
+        fn set_dc_bat_bus_potential(&mut self, potential: ElectricPotential) {
+            self.dc_bat_bus_electricity_source.set_potential(potential);
+        }
+
         fn apu_generator_output_within_normal_parameters_after_processing_power_consumption_report(
             &self,
         ) -> bool {
@@ -722,6 +752,7 @@ mod tests {
                 self.apu_gen_is_used,
                 self.pneumatic.bleed_air_valve(),
                 self.has_fuel_remaining,
+                self.fire_detected,
             );
 
             self.apu_generator_output_within_normal_parameters_before_processing_power_consumption_report = self.apu.generator(1).output_within_normal_parameters();
@@ -892,6 +923,16 @@ mod tests {
             self
         }
 
+        fn failed(mut self, failure_type: FailureType) -> Self {
+            self.fail(failure_type);
+            self
+        }
+
+        fn fire_detected(mut self) -> Self {
+            self.command(|a| a.set_fire_detected(true));
+            self
+        }
+
         pub fn released_apu_fire_pb(mut self) -> Self {
             self.write_by_name("FIRE_BUTTON_APU", true);
             self
@@ -992,6 +1033,11 @@ mod tests {
             self
         }
 
+        fn dc_bat_bus_potential_of(mut self, potential: ElectricPotential) -> Self {
+            self.command(|a| a.set_dc_bat_bus_potential(potential));
+            self
+        }
+
         pub fn and(self) -> Self {
             self
         }
@@ -1151,6 +1197,12 @@ mod tests {
             self.query(|a| a.close_start_contactors_signal())
         }
 
+        fn bleed_air_temperature(&self) -> ThermodynamicTemperature {
+            self.query(|a| a.bleed_air_signal())
+                .unwrap()
+                .target_temperature()
+        }
+
         fn has_fuel_low_pressure_fault(&mut self) -> Arinc429Word<bool> {
             self.read_arinc429_by_name("APU_LOW_FUEL_PRESSURE_FAULT")
         }
@@ -1163,6 +1215,10 @@ mod tests {
             self.read_by_name("APU_IS_EMERGENCY_SHUTDOWN")
         }
 
+        fn ecb_fault_code(&mut self) -> u8 {
+            self.read_by_name("APU_ECB_FAULT_CODE")
+        }
+
         fn is_inoperable(&mut self) -> bool {
             self.read_by_name("ECAM_INOP_SYS_APU")
         }
@@ -1212,8 +1268,12 @@ mod tests {
     #[cfg(test)]
     mod apu_tests {
         use super::*;
+        use crate::shared::InternationalStandardAtmosphere;
         use ntest::{assert_about_eq, timeout};
-        use uom::si::{mass::kilogram, power::watt};
+        use uom::si::{
+            electric_potential::volt, length::foot, mass::kilogram, power::watt,
+            temperature_interval,
+        };
 
         const APPROXIMATE_STARTUP_TIME: u64 = 49;
 
@@ -3373,5 +3433,503 @@ mod tests {
                     < 1.
             );
         }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn when_egt_sensor_fails_during_start_apu_auto_shuts_down<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .starting_apu()
+                .and()
+                .failed(FailureType::ApuEgtSensor)
+                .run(Duration::from_secs(1));
+
+            assert!(test_bed.is_auto_shutdown());
+            assert!(test_bed.master_has_fault());
+            assert_eq!(test_bed.ecb_fault_code(), 4);
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn when_egt_sensor_fails_while_running_apu_remains_available_without_bleed<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .running_apu_with_bleed_air()
+                .and()
+                .failed(FailureType::ApuEgtSensor)
+                .run(Duration::from_secs(10));
+
+            assert!(test_bed.apu_is_available());
+            assert!(test_bed.egt().is_no_computed_data());
+            assert!(!test_bed.bleed_air_valve_is_open());
+            assert_eq!(test_bed.ecb_fault_code(), 0);
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        fn when_speed_sensor_fails_single_shaft_apu_auto_shuts_down<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .running_apu()
+                .and()
+                .failed(FailureType::ApuSpeedSensor)
+                .run(Duration::from_secs(1));
+
+            assert!(test_bed.is_auto_shutdown());
+            assert!(test_bed.n().is_no_computed_data());
+            assert_eq!(test_bed.ecb_fault_code(), 5);
+        }
+
+        #[rstest]
+        #[case::pw980(test_bed_pw980())]
+        fn when_speed_sensor_fails_apu_with_n2_fallback_remains_available<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .running_apu()
+                .and()
+                .failed(FailureType::ApuSpeedSensor)
+                .run(Duration::from_secs(10));
+
+            assert!(!test_bed.is_auto_shutdown());
+            assert!(test_bed.apu_is_available());
+            assert!(test_bed.n().is_normal_operation());
+        }
+
+        #[rstest]
+        #[case::pw980(test_bed_pw980())]
+        fn when_speed_sensor_fails_apu_with_n2_fallback_indicates_n_derived_from_n2<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .running_apu_without_bleed_air()
+                .and()
+                .failed(FailureType::ApuSpeedSensor)
+                .run(Duration::from_secs(1));
+
+            assert_about_eq!(test_bed.n_raw().get::<percent>(), 100.);
+            assert_about_eq!(test_bed.n().normal_value().unwrap().get::<percent>(), 100.);
+        }
+
+        #[rstest]
+        #[case::pw980(test_bed_pw980())]
+        fn when_speed_sensor_failed_before_start_apu_with_n2_fallback_starts_and_becomes_available<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .failed(FailureType::ApuSpeedSensor)
+                .and()
+                .starting_apu();
+
+            for _ in 0..120 {
+                test_bed = test_bed.run(Duration::from_secs(1));
+                if test_bed.apu_is_available() {
+                    break;
+                }
+            }
+
+            assert!(test_bed.apu_is_available());
+            assert!(!test_bed.should_close_start_contactors_commanded());
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        fn when_speed_sensor_fails_apu_without_n2_fallback_indicates_no_speed<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .running_apu()
+                .and()
+                .failed(FailureType::ApuSpeedSensor)
+                .run(Duration::from_secs(1));
+
+            assert_about_eq!(test_bed.n_raw().get::<percent>(), 0.);
+            assert!(!test_bed.apu_is_available());
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn when_fuel_control_unit_fails_apu_auto_shuts_down<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .running_apu()
+                .and()
+                .failed(FailureType::ApuFuelControlUnit)
+                .run_until_n_decreases(Duration::from_millis(50));
+
+            assert!(test_bed.is_auto_shutdown());
+            assert_eq!(test_bed.ecb_fault_code(), 6);
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn when_oil_pressure_low_while_running_apu_auto_shuts_down<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .running_apu()
+                .and()
+                .failed(FailureType::ApuOilLowPressure)
+                .run_until_n_decreases(Duration::from_millis(50));
+
+            assert!(test_bed.is_auto_shutdown());
+            assert!(test_bed.master_has_fault());
+            assert_eq!(test_bed.ecb_fault_code(), 7);
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn when_air_intake_flap_stuck_closed_start_is_aborted<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .failed(FailureType::ApuAirIntakeFlapStuck)
+                .master_on()
+                .run(Duration::from_millis(1))
+                .then_continue_with()
+                .start_on()
+                .run(Duration::from_secs(25));
+
+            assert_about_eq!(test_bed.n().normal_value().unwrap().get::<percent>(), 0.);
+            assert!(test_bed.master_has_fault());
+            assert!(!test_bed.start_is_on());
+            assert_eq!(test_bed.ecb_fault_code(), 8);
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn when_inlet_pressure_sensor_fails_max_starting_egt_falls_back_to_900_degrees<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .starting_apu()
+                .and()
+                .ambient_pressure(Pressure::new::<psi>(5.44))
+                .failed(FailureType::ApuInletPressureSensor)
+                .run(Duration::from_secs(1));
+
+            assert_about_eq!(
+                test_bed
+                    .egt_warning_temperature()
+                    .normal_value()
+                    .unwrap()
+                    .get::<degree_celsius>(),
+                900.
+            );
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn when_fire_detected_on_ground_apu_is_emergency_shutdown<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .on_ground(true)
+                .running_apu()
+                .and()
+                .fire_detected()
+                .run(Duration::from_secs(1));
+
+            assert!(test_bed.is_emergency_shutdown());
+            assert_eq!(test_bed.ecb_fault_code(), 1);
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn when_fire_detected_in_flight_apu_continues_running<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .on_ground(false)
+                .running_apu()
+                .and()
+                .fire_detected()
+                .run(Duration::from_secs(1));
+
+            assert!(!test_bed.is_emergency_shutdown());
+            assert!(test_bed.apu_is_available());
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200(), test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980(), test_bed_pw980())]
+        fn start_is_slower_at_high_altitude<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] reference_bed: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut reference_bed = reference_bed.starting_apu().run(Duration::from_secs(25));
+            let mut test_bed = bed_with
+                .ambient_pressure(InternationalStandardAtmosphere::pressure_at_altitude(
+                    Length::new::<foot>(20000.),
+                ))
+                .starting_apu()
+                .run(Duration::from_secs(25));
+
+            assert!(
+                test_bed.n().normal_value().unwrap() < reference_bed.n().normal_value().unwrap()
+            );
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200(), test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980(), test_bed_pw980())]
+        fn start_is_slower_when_cold<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] reference_bed: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut reference_bed = reference_bed.starting_apu().run(Duration::from_secs(25));
+            let mut test_bed = bed_with
+                .ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-30.))
+                .starting_apu()
+                .run(Duration::from_secs(25));
+
+            assert!(
+                test_bed.n().normal_value().unwrap() < reference_bed.n().normal_value().unwrap()
+            );
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200(), test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980(), test_bed_pw980())]
+        fn start_is_slower_with_low_start_motor_voltage<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] reference_bed: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut reference_bed = reference_bed.starting_apu().run(Duration::from_secs(25));
+            let mut test_bed = bed_with
+                .dc_bat_bus_potential_of(ElectricPotential::new::<volt>(23.))
+                .starting_apu()
+                .run(Duration::from_secs(25));
+
+            assert!(
+                test_bed.n().normal_value().unwrap() < reference_bed.n().normal_value().unwrap()
+            );
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn apu_does_not_light_off_above_maximum_start_altitude<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let ambient_temperature = ThermodynamicTemperature::new::<degree_celsius>(-56.5);
+            let mut test_bed = bed_with
+                .ambient_pressure(InternationalStandardAtmosphere::pressure_at_altitude(
+                    Length::new::<foot>(C::MAXIMUM_START_ALTITUDE_FEET + 2000.),
+                ))
+                .ambient_temperature(ambient_temperature)
+                .run(Duration::from_secs(500))
+                .then_continue_with()
+                .starting_apu()
+                .run(Duration::from_secs(60));
+
+            assert!(test_bed.n().normal_value().unwrap() < Ratio::new::<percent>(55.));
+            assert!(
+                test_bed.egt().normal_value().unwrap()
+                    < ambient_temperature
+                        + TemperatureInterval::new::<temperature_interval::degree_celsius>(1.)
+            );
+            assert!(!test_bed.apu_is_available());
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn apu_without_acceleration_during_start_auto_shuts_down<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .ambient_pressure(InternationalStandardAtmosphere::pressure_at_altitude(
+                    Length::new::<foot>(C::MAXIMUM_START_ALTITUDE_FEET + 2000.),
+                ))
+                .starting_apu()
+                .run(Duration::from_secs(121));
+
+            assert!(test_bed.is_auto_shutdown());
+            assert!(test_bed.master_has_fault());
+            assert_eq!(test_bed.ecb_fault_code(), 9);
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn running_apu_has_less_bleed_air_pressure_at_high_altitude<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with.running_apu_with_bleed_air();
+            let sea_level_pressure = test_bed.bleed_air_pressure().normal_value().unwrap();
+
+            test_bed = test_bed
+                .ambient_pressure(InternationalStandardAtmosphere::pressure_at_altitude(
+                    Length::new::<foot>(20000.),
+                ))
+                .run(Duration::from_secs(1));
+
+            assert!(test_bed.bleed_air_pressure().normal_value().unwrap() < sea_level_pressure);
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn running_apu_bleed_air_temperature_increases_with_ambient_temperature<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with.running_apu_with_bleed_air();
+            let cold_temperature = test_bed.bleed_air_temperature();
+
+            test_bed = test_bed
+                .ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(35.))
+                .run(Duration::from_secs(1));
+
+            assert!(test_bed.bleed_air_temperature() > cold_temperature);
+        }
+
+        #[test]
+        fn running_aps3200_delivers_bleed_air_at_165_degrees_on_a_standard_day() {
+            let test_bed = test_bed_aps3200()
+                .ambient_pressure(InternationalStandardAtmosphere::ground_pressure())
+                .ambient_temperature(InternationalStandardAtmosphere::ground_temperature())
+                .running_apu_with_bleed_air();
+
+            assert!((test_bed.bleed_air_temperature().get::<degree_celsius>() - 165.).abs() < 2.);
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn generator_load_is_higher_at_high_altitude<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .running_apu()
+                .power_demand(Power::new::<watt>(50000.))
+                .run(Duration::from_secs(1));
+            let sea_level_load = test_bed.load();
+
+            test_bed = test_bed
+                .ambient_pressure(InternationalStandardAtmosphere::pressure_at_altitude(
+                    Length::new::<foot>(35000.),
+                ))
+                .run(Duration::from_secs(1));
+
+            assert!(test_bed.load() > sea_level_load);
+        }
     }
 }
@@ -0,0 +1,166 @@
+//! Effects of the environment on APU performance.
+//!
+//! The start and running models of the APUs are based on data recorded on a standard day
+//! at sea level with a fully charged battery. The functions in this module describe how
+//! altitude, outside air temperature and start motor voltage deviate from that reference.
+//! They are written such that they have no effect in reference conditions.
+use uom::si::{
+    electric_potential::volt, f64::*, length::foot, pressure::psi, ratio::ratio,
+    temperature_interval, thermodynamic_temperature::degree_celsius,
+    thermodynamic_temperature::kelvin,
+};
+
+use crate::{shared::InternationalStandardAtmosphere, simulation::UpdateContext};
+
+/// Below this pressure altitude the air is dense enough for the start to proceed as in
+/// reference conditions.
+const START_PENALTY_ABOVE_ALTITUDE_FEET: f64 = 10000.;
+const START_FACTOR_REDUCTION_PER_1000_FEET: f64 = 0.015;
+const START_FACTOR_REDUCTION_PER_DEGREE_BELOW_ZERO: f64 = 0.005;
+/// The start motor delivers its full torque at or above this voltage.
+const START_MOTOR_FULL_TORQUE_VOLTAGE: f64 = 25.5;
+/// The start motor no longer delivers meaningful torque at or below this voltage.
+const START_MOTOR_NO_TORQUE_VOLTAGE: f64 = 18.;
+/// Below this start acceleration factor the combustion chamber doesn't receive
+/// enough air for light-off.
+const MINIMUM_LIGHT_OFF_START_FACTOR: f64 = 0.35;
+
+const STARTING_EGT_INCREASE_PER_DEGREE_ABOVE_ISA: f64 = 1.;
+const STARTING_EGT_INCREASE_PER_1000_FEET: f64 = 4.;
+const RUNNING_EGT_INCREASE_PER_DEGREE_ABOVE_ISA: f64 = 0.5;
+const RUNNING_EGT_INCREASE_PER_1000_FEET: f64 = 2.;
+const EGT_PENALTY_ABOVE_ALTITUDE_FEET: f64 = 10000.;
+
+const BLEED_PRESSURE_RATIO_REDUCTION_PER_DEGREE_ABOVE_ISA: f64 = 0.003;
+/// The isentropic efficiency of the load compressor, chosen such that
+/// the APS3200 delivers its bleed air at 165 °C on a standard day at sea level.
+const LOAD_COMPRESSOR_EFFICIENCY: f64 = 0.8;
+const HEAT_CAPACITY_RATIO_AIR: f64 = 1.4;
+
+const GENERATOR_FULL_LOAD_UP_TO_ALTITUDE_FEET: f64 = 25000.;
+const GENERATOR_LOAD_REDUCTION_PER_1000_FEET: f64 = 0.02;
+const GENERATOR_FULL_LOAD_UP_TO_DEGREES_ABOVE_ISA: f64 = 15.;
+const GENERATOR_LOAD_REDUCTION_PER_DEGREE_ABOVE_ISA: f64 = 0.01;
+const MINIMUM_GENERATOR_LOAD_CAPABILITY: f64 = 0.5;
+
+fn pressure_altitude_feet(context: &UpdateContext) -> f64 {
+    InternationalStandardAtmosphere::altitude_from_pressure(context.ambient_pressure())
+        .get::<foot>()
+}
+
+/// Degrees the outside air temperature is above the ISA temperature at the current
+/// pressure altitude. Negative on a day colder than ISA.
+fn isa_deviation(context: &UpdateContext) -> f64 {
+    let isa_temperature = InternationalStandardAtmosphere::temperature_at_altitude(
+        InternationalStandardAtmosphere::altitude_from_pressure(context.ambient_pressure()),
+    );
+
+    context.ambient_temperature().get::<kelvin>() - isa_temperature.get::<kelvin>()
+}
+
+fn thousands_of_feet_above(context: &UpdateContext, altitude_feet: f64) -> f64 {
+    ((pressure_altitude_feet(context) - altitude_feet) / 1000.).max(0.)
+}
+
+/// The rate at which the start sequence progresses compared to the reference start.
+/// Thinner air at altitude, a cold soaked APU and a low start motor voltage all slow
+/// the acceleration down. When the start motor isn't powered (anymore), its voltage
+/// has no effect.
+pub(super) fn start_acceleration_factor(
+    context: &UpdateContext,
+    start_motor_potential: ElectricPotential,
+) -> f64 {
+    let altitude_factor = 1.
+        - START_FACTOR_REDUCTION_PER_1000_FEET
+            * thousands_of_feet_above(context, START_PENALTY_ABOVE_ALTITUDE_FEET);
+
+    let cold_factor = 1.
+        - START_FACTOR_REDUCTION_PER_DEGREE_BELOW_ZERO
+            * (-context.ambient_temperature().get::<degree_celsius>()).max(0.);
+
+    let voltage_factor = if start_motor_potential.get::<volt>() > 0. {
+        ((start_motor_potential.get::<volt>() - START_MOTOR_NO_TORQUE_VOLTAGE)
+            / (START_MOTOR_FULL_TORQUE_VOLTAGE - START_MOTOR_NO_TORQUE_VOLTAGE))
+            .clamp(0., 1.)
+    } else {
+        1.
+    };
+
+    (altitude_factor * cold_factor * voltage_factor).clamp(0., 1.)
+}
+
+/// Whether the APU can light off within its start envelope. Above the maximum start
+/// altitude or when the start acceleration is too weak, the APU only cranks.
+pub(super) fn light_off_is_possible(
+    context: &UpdateContext,
+    start_acceleration_factor: f64,
+    maximum_start_altitude: Length,
+) -> bool {
+    pressure_altitude_feet(context) <= maximum_start_altitude.get::<foot>()
+        && start_acceleration_factor >= MINIMUM_LIGHT_OFF_START_FACTOR
+}
+
+/// The increase of the EGT peak during the start on a hot day or at altitude.
+pub(super) fn starting_egt_delta(context: &UpdateContext) -> TemperatureInterval {
+    TemperatureInterval::new::<temperature_interval::degree_celsius>(
+        STARTING_EGT_INCREASE_PER_DEGREE_ABOVE_ISA * isa_deviation(context).max(0.)
+            + STARTING_EGT_INCREASE_PER_1000_FEET
+                * thousands_of_feet_above(context, EGT_PENALTY_ABOVE_ALTITUDE_FEET),
+    )
+}
+
+/// The increase of the running EGT on a hot day or at altitude.
+pub(super) fn running_egt_delta(context: &UpdateContext) -> TemperatureInterval {
+    TemperatureInterval::new::<temperature_interval::degree_celsius>(
+        RUNNING_EGT_INCREASE_PER_DEGREE_ABOVE_ISA * isa_deviation(context).max(0.)
+            + RUNNING_EGT_INCREASE_PER_1000_FEET
+                * thousands_of_feet_above(context, EGT_PENALTY_ABOVE_ALTITUDE_FEET),
+    )
+}
+
+/// The absolute bleed air pressure available from the load compressor. The compressor
+/// delivers a roughly constant pressure ratio, thus the delivered pressure drops with
+/// the ambient pressure. On a hot day the pressure ratio is lower.
+pub(super) fn bleed_air_pressure(
+    context: &UpdateContext,
+    sea_level_bleed_air_pressure: Pressure,
+) -> Pressure {
+    let sea_level_pressure_ratio = sea_level_bleed_air_pressure.get::<psi>()
+        / InternationalStandardAtmosphere::ground_pressure().get::<psi>();
+    let pressure_ratio = 1.
+        + (sea_level_pressure_ratio - 1.)
+            * (1.
+                - BLEED_PRESSURE_RATIO_REDUCTION_PER_DEGREE_ABOVE_ISA
+                    * isa_deviation(context).max(0.));
+
+    context.ambient_pressure() * pressure_ratio.max(1.)
+}
+
+/// The temperature of the bleed air leaving the load compressor.
+pub(super) fn bleed_air_temperature(
+    context: &UpdateContext,
+    bleed_air_pressure: Pressure,
+) -> ThermodynamicTemperature {
+    let pressure_ratio = (bleed_air_pressure / context.ambient_pressure())
+        .get::<ratio>()
+        .max(1.);
+    let ambient = context.ambient_temperature().get::<kelvin>();
+    let isentropic_temperature_rise = ambient
+        * (pressure_ratio.powf((HEAT_CAPACITY_RATIO_AIR - 1.) / HEAT_CAPACITY_RATIO_AIR) - 1.);
+
+    ThermodynamicTemperature::new::<kelvin>(
+        ambient + isentropic_temperature_rise / LOAD_COMPRESSOR_EFFICIENCY,
+    )
+}
+
+/// The share of the generator's rated load which can be supplied. The APU's shaft power
+/// decreases at high altitude and on a hot day, which limits the generator load.
+pub(super) fn generator_load_capability(context: &UpdateContext) -> Ratio {
+    let capability = 1.
+        - GENERATOR_LOAD_REDUCTION_PER_1000_FEET
+            * thousands_of_feet_above(context, GENERATOR_FULL_LOAD_UP_TO_ALTITUDE_FEET)
+        - GENERATOR_LOAD_REDUCTION_PER_DEGREE_ABOVE_ISA
+            * (isa_deviation(context) - GENERATOR_FULL_LOAD_UP_TO_DEGREES_ABOVE_ISA).max(0.);
+
+    Ratio::new::<ratio>(capability.clamp(MINIMUM_GENERATOR_LOAD_CAPABILITY, 1.))
+}
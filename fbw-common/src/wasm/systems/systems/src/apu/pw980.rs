@@ -1,11 +1,8 @@
 use std::time::Duration;
 
-use uom::{
-    si::{
-        electric_potential::volt, f64::*, frequency::hertz, power::watt, pressure::psi,
-        ratio::percent, temperature_interval, thermodynamic_temperature::degree_celsius,
-    },
-    ConstZero,
+use uom::si::{
+    electric_potential::volt, f64::*, frequency::hertz, length::foot, power::watt, pressure::psi,
+    ratio::percent, ratio::ratio, temperature_interval, thermodynamic_temperature::degree_celsius,
 };
 
 use crate::{
@@ -23,7 +20,9 @@ use crate::{
     simulation::{InitContext, SimulationElement, SimulatorWriter, UpdateContext},
 };
 
-use super::{ApuConstants, ApuGenerator, ApuStartMotor, Turbine, TurbineSignal, TurbineState};
+use super::{
+    performance, ApuConstants, ApuGenerator, ApuStartMotor, Turbine, TurbineSignal, TurbineState,
+};
 
 pub struct Pw980Constants;
 
@@ -34,6 +33,8 @@ impl ApuConstants for Pw980Constants {
     const AIR_INTAKE_FLAP_CLOSURE_PERCENT: f64 = 8.;
     const SHOULD_BE_AVAILABLE_DURING_SHUTDOWN: bool = false;
     const FUEL_LINE_ID: u8 = 141;
+    const SPEED_SENSOR_FALLBACK_TO_N2: bool = true;
+    const MAXIMUM_START_ALTITUDE_FEET: f64 = 41000.;
 }
 
 pub struct ShutdownPw980Turbine {
@@ -56,6 +57,7 @@ impl Turbine for ShutdownPw980Turbine {
         context: &UpdateContext,
         _: bool,
         _: bool,
+        _: ElectricPotential,
         controller: &dyn ControllerSignal<TurbineSignal>,
     ) -> Box<dyn Turbine> {
         self.egt = calculate_towards_ambient_egt(self.egt, context);
@@ -93,8 +95,14 @@ struct Starting {
     n2: Ratio,
     egt: ThermodynamicTemperature,
     ignore_calculated_egt: bool,
+    has_lit_off: bool,
 }
 impl Starting {
+    const START_IGNITION_AFTER: Duration = Duration::from_secs(6);
+    // Without light-off the start motor only cranks the APU up to this point in the
+    // start sequence.
+    const CRANKING_UNTIL: Duration = Duration::from_secs(8);
+
     fn new(egt: ThermodynamicTemperature) -> Starting {
         Starting {
             since: Duration::from_secs(0),
@@ -102,6 +110,7 @@ impl Starting {
             n2: Ratio::default(),
             egt,
             ignore_calculated_egt: true,
+            has_lit_off: false,
         }
     }
 
@@ -140,14 +149,14 @@ impl Starting {
                 + (APU_N_TEMP_X11 * n.powi(11))
                 + (APU_N_TEMP_X12 * n.powi(12))
                 + (APU_N_TEMP_X13 * n.powi(13)),
-        );
+        ) + performance::starting_egt_delta(context);
 
         // The above calculated EGT can be lower than the ambient temperature,
         // or the current APU EGT (when cooling down). To prevent sudden changes
         // in temperature, we ignore the calculated EGT until it exceeds the current
         // EGT.
         let towards_ambient_egt = calculate_towards_ambient_egt(self.egt, context);
-        if temperature > towards_ambient_egt {
+        if self.has_lit_off && temperature > towards_ambient_egt {
             self.ignore_calculated_egt = false;
         }
 
@@ -286,9 +295,29 @@ impl Turbine for Starting {
         context: &UpdateContext,
         _: bool,
         _: bool,
+        start_motor_potential: ElectricPotential,
         controller: &dyn ControllerSignal<TurbineSignal>,
     ) -> Box<dyn Turbine> {
-        self.since += context.delta();
+        let start_acceleration_factor =
+            performance::start_acceleration_factor(context, start_motor_potential);
+        self.since += if start_acceleration_factor < 1. {
+            context.delta().mul_f64(start_acceleration_factor)
+        } else {
+            context.delta()
+        };
+
+        if !self.has_lit_off && self.since >= Starting::START_IGNITION_AFTER {
+            self.has_lit_off = performance::light_off_is_possible(
+                context,
+                start_acceleration_factor,
+                Length::new::<foot>(Pw980Constants::MAXIMUM_START_ALTITUDE_FEET),
+            );
+
+            if !self.has_lit_off {
+                self.since = self.since.min(Starting::CRANKING_UNTIL);
+            }
+        }
+
         self.n2 = self.calculate_n2();
         self.n = self.calculate_n();
         self.egt = self.calculate_egt(context);
@@ -298,7 +327,7 @@ impl Turbine for Starting {
             Some(TurbineSignal::StartOrContinue)
                 if { (self.n.get::<percent>() - 100.).abs() < f64::EPSILON } =>
             {
-                Box::new(Running::new(self.egt))
+                Box::new(Running::new(context, self.egt))
             }
             Some(TurbineSignal::StartOrContinue) => self,
         }
@@ -468,9 +497,13 @@ struct Running {
     apu_gen_usage: ApuGenUsageEgtDelta,
     n2: Ratio,
     bleed_air_n2_delta: ApuBleedUsageN2Delta,
+    bleed_air_pressure: Pressure,
 }
 impl Running {
-    fn new(egt: ThermodynamicTemperature) -> Running {
+    // Value from refs, this is the pressure above the unpressurized system at sea level.
+    const SEA_LEVEL_BLEED_AIR_GAUGE_PRESSURE_PSI: f64 = 22.;
+
+    fn new(context: &UpdateContext, egt: ThermodynamicTemperature) -> Running {
         let base_egt = 480. + ((random_number() % 11) as f64);
         Running {
             egt,
@@ -483,9 +516,18 @@ impl Running {
             apu_gen_usage: ApuGenUsageEgtDelta::new(),
             n2: Ratio::default(),
             bleed_air_n2_delta: ApuBleedUsageN2Delta::new(),
+            bleed_air_pressure: Running::calculate_bleed_air_pressure(context),
         }
     }
 
+    fn calculate_bleed_air_pressure(context: &UpdateContext) -> Pressure {
+        performance::bleed_air_pressure(
+            context,
+            Pressure::new::<psi>(Running::SEA_LEVEL_BLEED_AIR_GAUGE_PRESSURE_PSI)
+                + InternationalStandardAtmosphere::ground_pressure(),
+        )
+    }
+
     fn calculate_egt(
         &mut self,
         context: &UpdateContext,
@@ -500,7 +542,8 @@ impl Running {
             ),
         );
 
-        let mut target = self.base_egt + self.base_egt_deviation;
+        let mut target =
+            self.base_egt + self.base_egt_deviation + performance::running_egt_delta(context);
         self.apu_gen_usage.update(context, apu_gen_is_used);
         target += self.apu_gen_usage.egt_delta();
 
@@ -526,10 +569,12 @@ impl Turbine for Running {
         context: &UpdateContext,
         apu_bleed_is_used: bool,
         apu_gen_is_used: bool,
+        _: ElectricPotential,
         controller: &dyn ControllerSignal<TurbineSignal>,
     ) -> Box<dyn Turbine> {
         self.egt = self.calculate_egt(context, apu_gen_is_used, apu_bleed_is_used);
         self.n2 = self.calculate_n2(context, apu_bleed_is_used);
+        self.bleed_air_pressure = Running::calculate_bleed_air_pressure(context);
 
         match controller.signal() {
             Some(TurbineSignal::StartOrContinue) => self,
@@ -558,9 +603,7 @@ impl Turbine for Running {
     }
 
     fn bleed_air_pressure(&self) -> Pressure {
-        // Value from refs, we add standard pressure at sea level as state of unpressurized system
-        Pressure::new::<psi>(22.)
-            + InternationalStandardAtmosphere::pressure_at_altitude(Length::ZERO)
+        self.bleed_air_pressure
     }
 }
 
@@ -744,6 +787,7 @@ impl Turbine for Stopping {
         context: &UpdateContext,
         _: bool,
         _: bool,
+        _: ElectricPotential,
         _: &dyn ControllerSignal<TurbineSignal>,
     ) -> Box<dyn Turbine> {
         self.since += context.delta();
@@ -924,7 +968,7 @@ impl SimulationElement for Pw980ApuGenerator {
 
     fn process_power_consumption_report<T: PowerConsumptionReport>(
         &mut self,
-        _: &UpdateContext,
+        context: &UpdateContext,
         report: &T,
     ) {
         self.output_potential = if self.should_provide_output() {
@@ -943,7 +987,7 @@ impl SimulationElement for Pw980ApuGenerator {
             .total_consumption_of(PotentialOrigin::ApuGenerator(self.number))
             .get::<watt>();
         let power_factor_correction = 0.8;
-        let maximum_load = 120000.;
+        let maximum_load = 120000. * performance::generator_load_capability(context).get::<ratio>();
         self.load = Ratio::new::<percent>(
             (power_consumption * power_factor_correction / maximum_load) * 100.,
         );
@@ -958,6 +1002,7 @@ pub struct Pw980StartMotor {
     /// potential coming in via those contactors.
     powered_by: ElectricalBusType,
     is_powered: bool,
+    potential: ElectricPotential,
     powered_since: Duration,
 }
 impl Pw980StartMotor {
@@ -965,6 +1010,7 @@ impl Pw980StartMotor {
         Pw980StartMotor {
             powered_by,
             is_powered: false,
+            potential: ElectricPotential::default(),
             powered_since: Duration::ZERO,
         }
     }
@@ -973,10 +1019,15 @@ impl ApuStartMotor for Pw980StartMotor {
     fn is_powered(&self) -> bool {
         self.is_powered
     }
+
+    fn potential(&self) -> ElectricPotential {
+        self.potential
+    }
 }
 impl SimulationElement for Pw980StartMotor {
     fn receive_power(&mut self, buses: &impl ElectricalBuses) {
         self.is_powered = buses.is_powered(self.powered_by);
+        self.potential = buses.potential_of(self.powered_by).raw();
     }
 
     fn consume_power<T: ConsumePower>(&mut self, context: &UpdateContext, consumption: &mut T) {
@@ -19,7 +19,7 @@
 use super::ElectricalBusType;
 use crate::simulation::{InitContext, VariableIdentifier};
 use crate::{
-    shared::{random_number, ConsumePower, ElectricalBuses, FwcFlightPhase},
+    shared::{ConsumePower, ElectricalBuses, FwcFlightPhase},
     simulation::{
         Read, SimulationElement, SimulationElementVisitor, SimulatorReader, UpdateContext,
     },
@@ -59,54 +59,169 @@ impl SimulationElement for PowerConsumer {
     }
 }
 
-/// A special type of power consumer which changes its consumption
-/// based on the phase of the flight.
-pub struct FlightPhasePowerConsumer {
+/// The condition under which a piece of equipment declared in a
+/// [PowerConsumerDefinition] is switched on.
+#[derive(Copy, Clone, Debug)]
+pub enum PowerConsumerCondition {
+    Always,
+    OnGround,
+    InFlight,
+    FlightPhases(&'static [PowerConsumerFlightPhase]),
+    /// Galley equipment which is switched off when the electrical system sheds the galley.
+    GalleyNotShed,
+}
+
+/// Declares a single piece of equipment, the bus it is powered by and the power it consumes.
+/// When the equipment is switched on it consumes its peak power for a short duration
+/// (inrush), after which it settles on its nominal power.
+#[derive(Copy, Clone, Debug)]
+pub struct PowerConsumerDefinition {
+    name: &'static str,
+    bus_type: ElectricalBusType,
+    nominal_watts: f64,
+    peak_watts: f64,
+    condition: PowerConsumerCondition,
+}
+impl PowerConsumerDefinition {
+    pub const fn new(
+        name: &'static str,
+        bus_type: ElectricalBusType,
+        nominal_watts: f64,
+        peak_watts: f64,
+        condition: PowerConsumerCondition,
+    ) -> Self {
+        Self {
+            name,
+            bus_type,
+            nominal_watts,
+            peak_watts,
+            condition,
+        }
+    }
+}
+
+struct EquipmentPowerConsumer {
+    definition: PowerConsumerDefinition,
+    is_powered: bool,
+    is_on: bool,
+    on_duration: Duration,
+}
+impl EquipmentPowerConsumer {
+    const INRUSH_DURATION: Duration = Duration::from_secs(2);
+
+    fn new(definition: PowerConsumerDefinition) -> Self {
+        Self {
+            definition,
+            is_powered: false,
+            is_on: false,
+            on_duration: Duration::from_secs(0),
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        flight_phase: PowerConsumerFlightPhase,
+        galley_is_shed: bool,
+    ) {
+        self.is_on = match self.definition.condition {
+            PowerConsumerCondition::Always => true,
+            PowerConsumerCondition::OnGround => context.is_on_ground(),
+            PowerConsumerCondition::InFlight => !context.is_on_ground(),
+            PowerConsumerCondition::FlightPhases(phases) => phases.contains(&flight_phase),
+            PowerConsumerCondition::GalleyNotShed => !galley_is_shed,
+        };
+
+        if self.is_consuming() {
+            self.on_duration += context.delta();
+        } else {
+            self.on_duration = Duration::from_secs(0);
+        }
+    }
+
+    fn is_consuming(&self) -> bool {
+        self.is_powered && self.is_on
+    }
+
+    fn demand(&self) -> Power {
+        if !self.is_consuming() {
+            Power::new::<watt>(0.)
+        } else if self.on_duration <= Self::INRUSH_DURATION {
+            Power::new::<watt>(self.definition.peak_watts)
+        } else {
+            Power::new::<watt>(self.definition.nominal_watts)
+        }
+    }
+}
+impl SimulationElement for EquipmentPowerConsumer {
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.definition.bus_type);
+    }
+
+    fn consume_power<T: ConsumePower>(&mut self, _: &UpdateContext, consumption: &mut T) {
+        consumption.consume_from_bus(self.definition.bus_type, self.demand());
+    }
+}
+
+/// Consumes power for each piece of equipment declared in a table of [PowerConsumerDefinition].
+/// Equipment only consumes power while its bus is powered and its condition is met. Thus
+/// shedding a bus removes the demand of all equipment powered by it.
+pub struct EquipmentPowerConsumption {
     fwc_flight_phase_id: VariableIdentifier,
+    galley_is_shed_id: VariableIdentifier,
 
-    consumer: PowerConsumer,
-    base_demand: [Power; PowerConsumerFlightPhase::TaxiIn as usize + 1],
     current_flight_phase: PowerConsumerFlightPhase,
-    update_after: Duration,
+    galley_is_shed: bool,
+    consumers: Vec<EquipmentPowerConsumer>,
 }
-impl FlightPhasePowerConsumer {
-    pub fn new(context: &mut InitContext, bus_type: ElectricalBusType) -> Self {
+impl EquipmentPowerConsumption {
+    pub fn new(context: &mut InitContext, definitions: &[PowerConsumerDefinition]) -> Self {
         Self {
             fwc_flight_phase_id: context.get_identifier("FWC_FLIGHT_PHASE".to_owned()),
+            galley_is_shed_id: context.get_identifier("ELEC_GALLEY_IS_SHED".to_owned()),
 
-            consumer: PowerConsumer::from(bus_type),
-            base_demand: Default::default(),
             current_flight_phase: PowerConsumerFlightPhase::BeforeStart,
-            update_after: Duration::from_secs(0),
+            galley_is_shed: false,
+            consumers: definitions
+                .iter()
+                .map(|definition| EquipmentPowerConsumer::new(*definition))
+                .collect(),
         }
     }
 
     pub fn update(&mut self, context: &UpdateContext) {
-        if self.update_after <= context.delta() {
-            self.update_after = Duration::from_secs_f64(5. + ((random_number() % 26) as f64));
-            let base_demand = self.base_demand[self.current_flight_phase as usize].get::<watt>();
-            self.consumer.demand(Power::new::<watt>(
-                base_demand * ((90. + ((random_number() % 21) as f64)) / 100.),
-            ));
-        } else {
-            self.update_after -= context.delta();
+        for consumer in &mut self.consumers {
+            consumer.update(context, self.current_flight_phase, self.galley_is_shed);
         }
     }
 
-    pub fn demand(
-        mut self,
-        demand: [(PowerConsumerFlightPhase, Power); PowerConsumerFlightPhase::TaxiIn as usize + 1],
-    ) -> Self {
-        for (phase, power) in &demand {
-            self.base_demand[*phase as usize] = *power;
-        }
+    /// The power currently demanded by the equipment with the given name.
+    pub fn demand_of(&self, name: &str) -> Option<Power> {
+        self.consumers
+            .iter()
+            .find(|consumer| consumer.definition.name == name)
+            .map(|consumer| consumer.demand())
+    }
+
+    /// The power currently demanded by all equipment powered by the given bus.
+    pub fn demand_from_bus(&self, bus_type: ElectricalBusType) -> Power {
+        self.consumers
+            .iter()
+            .filter(|consumer| consumer.definition.bus_type == bus_type)
+            .map(|consumer| consumer.demand())
+            .fold(Power::new::<watt>(0.), |total, demand| total + demand)
+    }
 
-        self
+    pub fn total_demand(&self) -> Power {
+        self.consumers
+            .iter()
+            .map(|consumer| consumer.demand())
+            .fold(Power::new::<watt>(0.), |total, demand| total + demand)
     }
 }
-impl SimulationElement for FlightPhasePowerConsumer {
+impl SimulationElement for EquipmentPowerConsumption {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
-        self.consumer.accept(visitor);
+        accept_iterable!(self.consumers, visitor);
 
         visitor.visit(self);
     }
@@ -117,10 +232,12 @@ impl SimulationElement for FlightPhasePowerConsumer {
         if let Some(phase) = flight_phase {
             self.current_flight_phase = PowerConsumerFlightPhase::from(phase);
         }
+
+        self.galley_is_shed = reader.read(&self.galley_is_shed_id);
     }
 }
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum PowerConsumerFlightPhase {
     BeforeStart = 0,
     AfterStart = 1,
@@ -152,39 +269,70 @@ mod tests {
     use crate::shared::{PotentialOrigin, PowerConsumptionReport};
 
     #[cfg(test)]
-    mod flight_phase_power_consumer_tests {
+    mod equipment_power_consumption_tests {
         use crate::{
             electrical::{test::TestElectricitySource, ElectricalBus},
             simulation::{
-                test::{SimulationTestBed, TestBed},
-                Aircraft,
+                test::{SimulationTestBed, TestBed, WriteByName},
+                Aircraft, InitContext,
             },
         };
 
         use super::*;
-        use crate::simulation::test::WriteByName;
-        use crate::simulation::InitContext;
-
-        struct FlightPhasePowerConsumerTestAircraft {
+        use std::time::Duration;
+
+        const BUS: ElectricalBusType = ElectricalBusType::AlternatingCurrent(1);
+
+        const CONSUMERS: [PowerConsumerDefinition; 4] = [
+            PowerConsumerDefinition::new(
+                "AVIONICS",
+                BUS,
+                1000.,
+                1500.,
+                PowerConsumerCondition::Always,
+            ),
+            PowerConsumerDefinition::new(
+                "GALLEY",
+                BUS,
+                5000.,
+                6000.,
+                PowerConsumerCondition::GalleyNotShed,
+            ),
+            PowerConsumerDefinition::new(
+                "PROBE HEAT",
+                BUS,
+                300.,
+                300.,
+                PowerConsumerCondition::InFlight,
+            ),
+            PowerConsumerDefinition::new(
+                "TAXI LIGHT",
+                BUS,
+                250.,
+                400.,
+                PowerConsumerCondition::FlightPhases(&[
+                    PowerConsumerFlightPhase::AfterStart,
+                    PowerConsumerFlightPhase::TaxiIn,
+                ]),
+            ),
+        ];
+
+        struct EquipmentPowerConsumptionTestAircraft {
             electricity_source: TestElectricitySource,
             apu_generator_consumption: Option<Power>,
-            consumer: FlightPhasePowerConsumer,
-            bus_with_demand: ElectricalBus,
+            consumption: EquipmentPowerConsumption,
+            bus: ElectricalBus,
         }
-        impl FlightPhasePowerConsumerTestAircraft {
-            fn new(
-                consumer: FlightPhasePowerConsumer,
-                bus_with_demand: ElectricalBusType,
-                context: &mut InitContext,
-            ) -> Self {
+        impl EquipmentPowerConsumptionTestAircraft {
+            fn new(context: &mut InitContext) -> Self {
                 Self {
                     electricity_source: TestElectricitySource::unpowered(
                         context,
                         PotentialOrigin::ApuGenerator(1),
                     ),
                     apu_generator_consumption: None,
-                    consumer,
-                    bus_with_demand: ElectricalBus::new(context, bus_with_demand),
+                    consumption: EquipmentPowerConsumption::new(context, &CONSUMERS),
+                    bus: ElectricalBus::new(context, BUS),
                 }
             }
 
@@ -192,37 +340,32 @@ mod tests {
                 self.electricity_source.power()
             }
 
-            fn consumption_equals(&self, expected: Power) -> bool {
-                match self.apu_generator_consumption {
-                    Some(consumption) => consumption == expected,
-                    None => false,
-                }
+            fn demand_of(&self, name: &str) -> Power {
+                self.consumption.demand_of(name).unwrap()
             }
 
-            fn consumption_within_range(&self, min: Power, max: Power) -> bool {
-                match self.apu_generator_consumption {
-                    Some(consumption) => min <= consumption && consumption <= max,
-                    None => false,
-                }
+            fn consumption(&self) -> Power {
+                self.apu_generator_consumption
+                    .unwrap_or_else(|| Power::new::<watt>(0.))
             }
         }
-        impl Aircraft for FlightPhasePowerConsumerTestAircraft {
+        impl Aircraft for EquipmentPowerConsumptionTestAircraft {
             fn update_before_power_distribution(
                 &mut self,
                 _: &UpdateContext,
                 electricity: &mut crate::electrical::Electricity,
             ) {
                 electricity.supplied_by(&self.electricity_source);
-                electricity.flow(&self.electricity_source, &self.bus_with_demand);
+                electricity.flow(&self.electricity_source, &self.bus);
             }
 
             fn update_after_power_distribution(&mut self, context: &UpdateContext) {
-                self.consumer.update(context);
+                self.consumption.update(context);
             }
         }
-        impl SimulationElement for FlightPhasePowerConsumerTestAircraft {
+        impl SimulationElement for EquipmentPowerConsumptionTestAircraft {
             fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
-                self.consumer.accept(visitor);
+                self.consumption.accept(visitor);
 
                 visitor.visit(self);
             }
@@ -237,118 +380,116 @@ mod tests {
             }
         }
 
-        fn apply_flight_phase(
-            test_bed: &mut SimulationTestBed<FlightPhasePowerConsumerTestAircraft>,
-            phase: FwcFlightPhase,
+        fn test_bed() -> SimulationTestBed<EquipmentPowerConsumptionTestAircraft> {
+            SimulationTestBed::new(EquipmentPowerConsumptionTestAircraft::new)
+        }
+
+        fn run_past_inrush(
+            test_bed: &mut SimulationTestBed<EquipmentPowerConsumptionTestAircraft>,
         ) {
-            test_bed.write_by_name("FWC_FLIGHT_PHASE", phase as i32 as f64);
+            test_bed.run_with_delta(Duration::from_secs(5));
+            test_bed.run_with_delta(Duration::from_secs(5));
         }
 
         #[test]
-        fn when_flight_phase_doesnt_have_demand_usage_is_zero() {
-            let mut test_bed = SimulationTestBed::new(|context| {
-                FlightPhasePowerConsumerTestAircraft::new(
-                    FlightPhasePowerConsumer::new(
-                        context,
-                        ElectricalBusType::AlternatingCurrent(1),
-                    )
-                    .demand([
-                        (
-                            PowerConsumerFlightPhase::BeforeStart,
-                            Power::new::<watt>(0.),
-                        ),
-                        (PowerConsumerFlightPhase::AfterStart, Power::new::<watt>(0.)),
-                        (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(0.)),
-                        (PowerConsumerFlightPhase::Flight, Power::new::<watt>(0.)),
-                        (PowerConsumerFlightPhase::Landing, Power::new::<watt>(0.)),
-                        (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(0.)),
-                    ]),
-                    ElectricalBusType::AlternatingCurrent(1),
-                    context,
-                )
-            });
+        fn when_bus_unpowered_equipment_does_not_consume_power() {
+            let mut test_bed = test_bed();
+            run_past_inrush(&mut test_bed);
+
+            assert_eq!(test_bed.query(|a| a.consumption()), Power::new::<watt>(0.));
+            assert_eq!(
+                test_bed.query(|a| a.demand_of("AVIONICS")),
+                Power::new::<watt>(0.)
+            );
+        }
 
+        #[test]
+        fn equipment_consumes_peak_power_when_switched_on() {
+            let mut test_bed = test_bed();
             test_bed.command(|a| a.power());
+            test_bed.run_with_delta(Duration::from_secs(1));
 
-            apply_flight_phase(&mut test_bed, FwcFlightPhase::FirstEngineStarted);
+            assert_eq!(
+                test_bed.query(|a| a.demand_of("AVIONICS")),
+                Power::new::<watt>(1500.)
+            );
+        }
 
-            test_bed.run();
+        #[test]
+        fn equipment_consumes_nominal_power_after_inrush() {
+            let mut test_bed = test_bed();
+            test_bed.command(|a| a.power());
+            run_past_inrush(&mut test_bed);
 
-            assert!(test_bed.query(|a| a.consumption_equals(Power::new::<watt>(0.))));
+            assert_eq!(
+                test_bed.query(|a| a.demand_of("AVIONICS")),
+                Power::new::<watt>(1000.)
+            );
         }
 
         #[test]
-        fn when_flight_phase_does_have_demand_usage_is_close_to_demand() {
-            let input = Power::new::<watt>(20000.);
-            let mut test_bed = SimulationTestBed::new(|context| {
-                FlightPhasePowerConsumerTestAircraft::new(
-                    FlightPhasePowerConsumer::new(
-                        context,
-                        ElectricalBusType::AlternatingCurrent(1),
-                    )
-                    .demand([
-                        (
-                            PowerConsumerFlightPhase::BeforeStart,
-                            Power::new::<watt>(0.),
-                        ),
-                        (PowerConsumerFlightPhase::AfterStart, Power::new::<watt>(0.)),
-                        (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(0.)),
-                        (PowerConsumerFlightPhase::Flight, input),
-                        (PowerConsumerFlightPhase::Landing, Power::new::<watt>(0.)),
-                        (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(0.)),
-                    ]),
-                    ElectricalBusType::AlternatingCurrent(1),
-                    context,
-                )
-            });
+        fn consumption_is_the_sum_of_all_equipment_which_is_on() {
+            let mut test_bed = test_bed();
+            test_bed.command(|a| a.power());
+            run_past_inrush(&mut test_bed);
 
+            assert_eq!(
+                test_bed.query(|a| a.consumption()),
+                Power::new::<watt>(1000. + 5000. + 300.)
+            );
+        }
+
+        #[test]
+        fn shed_galley_does_not_consume_power() {
+            let mut test_bed = test_bed();
             test_bed.command(|a| a.power());
+            test_bed.write_by_name("ELEC_GALLEY_IS_SHED", true);
+            run_past_inrush(&mut test_bed);
 
-            apply_flight_phase(&mut test_bed, FwcFlightPhase::AtOrAbove1500Feet);
+            assert_eq!(
+                test_bed.query(|a| a.demand_of("GALLEY")),
+                Power::new::<watt>(0.)
+            );
+        }
 
-            test_bed.run();
+        #[test]
+        fn in_flight_equipment_does_not_consume_power_on_ground() {
+            let mut test_bed = test_bed();
+            test_bed.command(|a| a.power());
+            test_bed.set_on_ground(true);
+            run_past_inrush(&mut test_bed);
 
-            assert!(test_bed.query(|a| a.consumption_within_range(input * 0.9, input * 1.1)));
+            assert_eq!(
+                test_bed.query(|a| a.demand_of("PROBE HEAT")),
+                Power::new::<watt>(0.)
+            );
         }
 
         #[test]
-        fn when_flight_phase_does_have_demand_but_consumer_unpowered_usage_is_zero() {
-            let mut test_bed = SimulationTestBed::new(|context| {
-                FlightPhasePowerConsumerTestAircraft::new(
-                    FlightPhasePowerConsumer::new(
-                        context,
-                        ElectricalBusType::AlternatingCurrent(1),
-                    )
-                    .demand([
-                        (
-                            PowerConsumerFlightPhase::BeforeStart,
-                            Power::new::<watt>(20000.),
-                        ),
-                        (
-                            PowerConsumerFlightPhase::AfterStart,
-                            Power::new::<watt>(20000.),
-                        ),
-                        (
-                            PowerConsumerFlightPhase::Takeoff,
-                            Power::new::<watt>(20000.),
-                        ),
-                        (PowerConsumerFlightPhase::Flight, Power::new::<watt>(20000.)),
-                        (
-                            PowerConsumerFlightPhase::Landing,
-                            Power::new::<watt>(20000.),
-                        ),
-                        (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(20000.)),
-                    ]),
-                    ElectricalBusType::AlternatingCurrent(1),
-                    context,
-                )
-            });
-
-            apply_flight_phase(&mut test_bed, FwcFlightPhase::FirstEngineStarted);
-
-            test_bed.run();
-
-            assert!(test_bed.query(|a| a.consumption_equals(Power::new::<watt>(0.))));
+        fn flight_phase_equipment_only_consumes_power_in_its_flight_phases() {
+            let mut test_bed = test_bed();
+            test_bed.command(|a| a.power());
+            test_bed.write_by_name(
+                "FWC_FLIGHT_PHASE",
+                FwcFlightPhase::AtOrAbove1500Feet as i32 as f64,
+            );
+            run_past_inrush(&mut test_bed);
+
+            assert_eq!(
+                test_bed.query(|a| a.demand_of("TAXI LIGHT")),
+                Power::new::<watt>(0.)
+            );
+
+            test_bed.write_by_name(
+                "FWC_FLIGHT_PHASE",
+                FwcFlightPhase::AtOrBelowEightyKnots as i32 as f64,
+            );
+            run_past_inrush(&mut test_bed);
+
+            assert_eq!(
+                test_bed.query(|a| a.demand_of("TAXI LIGHT")),
+                Power::new::<watt>(250.)
+            );
         }
     }
 }
@@ -24,7 +24,7 @@ use crate::{
 use crate::{
     shared::{
         ConsumePower, ElectricalBusType, ElectricalBuses, EmergencyElectricalState,
-        PotentialOrigin, PowerConsumptionReport,
+        EngineUncorrectedN3, PotentialOrigin, PowerConsumptionReport,
     },
     simulation::{
         SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext, Write,
@@ -42,7 +42,7 @@ pub use external_power_source::ExternalPowerSource;
 use fxhash::{FxHashMap, FxHashSet};
 pub use static_inverter::StaticInverter;
 pub use transformer_rectifier::TransformerRectifier;
-use uom::si::{electric_potential::volt, f64::*, power::watt, velocity::knot};
+use uom::si::{electric_potential::volt, f64::*, power::watt, ratio::percent, velocity::knot};
 
 pub use ram_air_turbine::{GeneratorControlUnit, RamAirTurbine};
 
@@ -279,11 +279,15 @@ pub trait ProvideLoad {
 /// Determines if and for how long the aircraft is in an emergency electrical situation.
 pub struct EmergencyElectrical {
     is_active_for_duration: Duration,
+    total_engine_flameout: bool,
 }
 impl EmergencyElectrical {
+    const TOTAL_ENGINE_FLAMEOUT_N3_THRESHOLD_PERCENT: f64 = 50.;
+
     pub fn new() -> Self {
         Self {
             is_active_for_duration: Duration::from_secs(0),
+            total_engine_flameout: false,
         }
     }
 
@@ -293,10 +297,38 @@ impl EmergencyElectrical {
         electricity: &Electricity,
         ac_electrical_system: &impl AlternatingCurrentElectricalSystem,
     ) {
-        // TODO A380 has also total flame out condition: all engines N3 under 50% throws emergency elec
-        if !ac_electrical_system.any_non_essential_bus_powered(electricity)
-            && context.indicated_airspeed() > Velocity::new::<knot>(100.)
-        {
+        self.update_active_duration(
+            context,
+            !ac_electrical_system.any_non_essential_bus_powered(electricity),
+        );
+    }
+
+    /// Updates the emergency electrical state for aircraft which also enter
+    /// the emergency electrical configuration on a total engine flame out (TEFO):
+    /// all engines N3 under 50% while in flight, even when some non essential
+    /// buses are still powered by other sources.
+    pub fn update_with_total_engine_flameout(
+        &mut self,
+        context: &UpdateContext,
+        electricity: &Electricity,
+        ac_electrical_system: &impl AlternatingCurrentElectricalSystem,
+        engines: &[&impl EngineUncorrectedN3],
+    ) {
+        self.total_engine_flameout = !context.is_on_ground()
+            && engines.iter().all(|engine| {
+                engine.uncorrected_n3()
+                    < Ratio::new::<percent>(Self::TOTAL_ENGINE_FLAMEOUT_N3_THRESHOLD_PERCENT)
+            });
+
+        self.update_active_duration(
+            context,
+            self.total_engine_flameout
+                || !ac_electrical_system.any_non_essential_bus_powered(electricity),
+        );
+    }
+
+    fn update_active_duration(&mut self, context: &UpdateContext, condition_met: bool) {
+        if condition_met && context.indicated_airspeed() > Velocity::new::<knot>(100.) {
             self.is_active_for_duration += context.delta();
         } else {
             self.is_active_for_duration = Duration::from_secs(0)
@@ -307,6 +339,12 @@ impl EmergencyElectrical {
         self.is_active_for_duration > Duration::from_secs(0)
     }
 
+    /// Indicates whether all engines are flamed out in flight. Only computed
+    /// when updating through [EmergencyElectrical::update_with_total_engine_flameout].
+    pub fn is_total_engine_flameout(&self) -> bool {
+        self.total_engine_flameout
+    }
+
     fn active_duration(&self) -> Duration {
         self.is_active_for_duration
     }
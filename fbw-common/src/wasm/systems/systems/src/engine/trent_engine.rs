@@ -1,7 +1,7 @@
 use uom::si::{angular_velocity::revolution_per_minute, f64::*, pressure::psi, ratio::percent};
 
 use crate::{
-    shared::{EngineCorrectedN1, EngineCorrectedN2, EngineUncorrectedN2},
+    shared::{EngineCorrectedN1, EngineCorrectedN2, EngineUncorrectedN2, EngineUncorrectedN3},
     simulation::{Read, SimulationElement, SimulatorReader, UpdateContext},
 };
 
@@ -101,6 +101,11 @@ impl EngineUncorrectedN2 for TrentEngine {
         self.uncorrected_n2
     }
 }
+impl EngineUncorrectedN3 for TrentEngine {
+    fn uncorrected_n3(&self) -> Ratio {
+        self.uncorrected_n3
+    }
+}
 impl Engine for TrentEngine {
     fn hydraulic_pump_output_speed(&self) -> AngularVelocity {
         self.hydraulic_pump_output_speed
@@ -1,7 +1,9 @@
 use crate::air_conditioning::{acs_controller::AcscId, cabin_pressure_controller::CpcId, ZoneType};
+use crate::fire_protection::{FireDetectionLoopId, FireDetectionZone, SmokeDetectionZone};
 use crate::shared::{
-    AirbusElectricPumpId, AirbusEngineDrivenPumpId, ElectricalBusType, GearActuatorId,
-    HydraulicColor, LgciuId, ProximityDetectorId,
+    AirbusElectricPumpId, AirbusEngineDrivenPumpId, BleedDuctId, ElectricalBusType,
+    FlapSlatTransmissionId, FlightControlActuatorId, GearActuatorId, HydraulicColor, LgciuId,
+    ProximityDetectorId, ReverserDoorId,
 };
 use crate::simulation::SimulationElement;
 
@@ -18,8 +20,17 @@ pub enum FailureType {
     OutflowValveFault,
     SafetyValveFault,
     RapidDecompression,
+    CabinDoorSealLeak,
+    CabinWindowCrack,
+    CargoDoorUnsealed,
     Generator(usize),
     ApuGenerator(usize),
+    ApuEgtSensor,
+    ApuSpeedSensor,
+    ApuInletPressureSensor,
+    ApuFuelControlUnit,
+    ApuOilLowPressure,
+    ApuAirIntakeFlapStuck,
     TransformerRectifier(usize),
     StaticInverter,
     ElectricalBus(ElectricalBusType),
@@ -28,13 +39,42 @@ pub enum FailureType {
     ReservoirReturnLeak(HydraulicColor),
     EnginePumpOverheat(AirbusEngineDrivenPumpId),
     ElecPumpOverheat(AirbusElectricPumpId),
+    FireShutoffValveStuck(HydraulicColor, usize),
+    PriorityValveStuck(HydraulicColor),
+    LeakMeasurementValveStuck(HydraulicColor),
+    AccumulatorPrechargeLoss(HydraulicColor),
+    PowerTransferUnitFailure,
+    PowerTransferUnitJam,
     LgciuPowerSupply(LgciuId),
     LgciuInternalError(LgciuId),
     GearProxSensorDamage(ProximityDetectorId),
     GearActuatorJammed(GearActuatorId),
+    GearUplockJammed(GearActuatorId),
+    GearDoorJammed(GearActuatorId),
+    GearSelectorValveStuck,
+    BodyWheelSteering,
     BrakeHydraulicLeak(HydraulicColor),
     BrakeAccumulatorGasLeak,
+    FlapSlatJam(FlapSlatTransmissionId),
+    ReverserDoorUnlocked(ReverserDoorId),
+    FlightControlActuatorJam(FlightControlActuatorId),
+    FlightControlActuatorRunaway(FlightControlActuatorId),
+    FlightControlActuatorDisconnect(FlightControlActuatorId),
+    FlightControlActuatorRodEndFracture(FlightControlActuatorId),
     RadioAltimeter(usize),
+    BleedDuctLeak(BleedDuctId),
+    PressureRegulatingValveStuckOpen(usize),
+    PressureRegulatingValveStuckClosed(usize),
+    HighPressureValveStuckOpen(usize),
+    CrossBleedValveStuck,
+    PrecoolerClogged(usize),
+    EngineAntiIceValveStuckClosed(usize),
+    EngineAntiIcePressureSwitch(usize),
+    EngineFire(usize),
+    ApuFire,
+    FireDetectionLoop(FireDetectionZone, FireDetectionLoopId),
+    Smoke(SmokeDetectionZone),
+    SmokeDetector(SmokeDetectionZone, usize),
 }
 
 pub struct Failure {
@@ -0,0 +1,1078 @@
+use crate::{
+    failures::{Failure, FailureType},
+    overhead::MomentaryPushButton,
+    shared::{CargoSmokeDetection, ElectricalBusType, ElectricalBuses},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    },
+};
+
+use std::{fmt::Display, time::Duration};
+
+use uom::si::{f64::*, pressure::psi};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FireDetectionZone {
+    Engine(usize),
+    Apu,
+}
+impl Display for FireDetectionZone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FireDetectionZone::Engine(number) => write!(f, "ENG{}", number),
+            FireDetectionZone::Apu => write!(f, "APU"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FireDetectionLoopId {
+    A,
+    B,
+}
+impl Display for FireDetectionLoopId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FireDetectionLoopId::A => write!(f, "A"),
+            FireDetectionLoopId::B => write!(f, "B"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SmokeDetectionZone {
+    ForwardCargo,
+    AftCargo,
+    BulkCargo,
+    Lavatory,
+}
+impl Display for SmokeDetectionZone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SmokeDetectionZone::ForwardCargo => write!(f, "FWD_CARGO"),
+            SmokeDetectionZone::AftCargo => write!(f, "AFT_CARGO"),
+            SmokeDetectionZone::BulkCargo => write!(f, "BULK_CARGO"),
+            SmokeDetectionZone::Lavatory => write!(f, "LAVATORY"),
+        }
+    }
+}
+
+/// A fire or smoke source, started by its failure or, for engines, by a fire set by the sim.
+/// Once extinguished it stays out until the fire condition clears.
+struct FireSource {
+    sim_fire_id: Option<VariableIdentifier>,
+
+    failure: Failure,
+    sim_fire_is_active: bool,
+    is_extinguished: bool,
+}
+impl FireSource {
+    fn new(failure_type: FailureType) -> Self {
+        Self {
+            sim_fire_id: None,
+
+            failure: Failure::new(failure_type),
+            sim_fire_is_active: false,
+            is_extinguished: false,
+        }
+    }
+
+    fn new_with_sim_fire(
+        context: &mut InitContext,
+        failure_type: FailureType,
+        sim_fire_name: String,
+    ) -> Self {
+        Self {
+            sim_fire_id: Some(context.get_identifier(sim_fire_name)),
+            ..Self::new(failure_type)
+        }
+    }
+
+    fn update(&mut self) {
+        if !self.is_active() {
+            self.is_extinguished = false;
+        }
+    }
+
+    fn extinguish(&mut self) {
+        if self.is_active() {
+            self.is_extinguished = true;
+        }
+    }
+
+    fn is_active(&self) -> bool {
+        self.failure.is_active() || self.sim_fire_is_active
+    }
+
+    fn is_burning(&self) -> bool {
+        self.is_active() && !self.is_extinguished
+    }
+}
+impl SimulationElement for FireSource {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        if let Some(sim_fire_id) = &self.sim_fire_id {
+            self.sim_fire_is_active = reader.read(sim_fire_id);
+        }
+    }
+}
+
+struct FireDetectionLoop {
+    failure: Failure,
+    fault_duration: Duration,
+}
+impl FireDetectionLoop {
+    fn new(zone: FireDetectionZone, id: FireDetectionLoopId) -> Self {
+        Self {
+            failure: Failure::new(FailureType::FireDetectionLoop(zone, id)),
+            fault_duration: Duration::ZERO,
+        }
+    }
+
+    fn update(&mut self, context: &UpdateContext) {
+        if self.is_faulty() {
+            self.fault_duration += context.delta();
+        } else {
+            self.fault_duration = Duration::ZERO;
+        }
+    }
+
+    fn is_faulty(&self) -> bool {
+        self.failure.is_active()
+    }
+
+    fn detects_fire(&self, fire_is_burning: bool) -> bool {
+        fire_is_burning && !self.is_faulty()
+    }
+}
+impl SimulationElement for FireDetectionLoop {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+/// The fire detection unit monitors the two detection loops of a zone with AND logic.
+/// A single faulty loop is ignored so the remaining loop can still trigger the warning.
+/// Both loops failing within a short time of each other is interpreted as a burn through
+/// and also triggers the warning.
+struct FireDetectionUnit {
+    fire_detected_id: VariableIdentifier,
+    loop_a_fault_id: VariableIdentifier,
+    loop_b_fault_id: VariableIdentifier,
+    detection_fault_id: VariableIdentifier,
+    test_id: VariableIdentifier,
+
+    loop_a: FireDetectionLoop,
+    loop_b: FireDetectionLoop,
+    test_is_pressed: bool,
+    fire_detected: bool,
+
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+}
+impl FireDetectionUnit {
+    const SIMULTANEOUS_LOOP_FAULT_MAX_INTERVAL: Duration = Duration::from_secs(5);
+
+    fn new(
+        context: &mut InitContext,
+        zone: FireDetectionZone,
+        powered_by: ElectricalBusType,
+    ) -> Self {
+        Self {
+            fire_detected_id: context.get_identifier(format!("FIRE_{}_DETECTED", zone)),
+            loop_a_fault_id: context.get_identifier(format!("FIRE_{}_LOOP_A_FAULT", zone)),
+            loop_b_fault_id: context.get_identifier(format!("FIRE_{}_LOOP_B_FAULT", zone)),
+            detection_fault_id: context.get_identifier(format!("FIRE_{}_DETECTION_FAULT", zone)),
+            test_id: context.get_identifier(format!("FIRE_TEST_{}", zone)),
+
+            loop_a: FireDetectionLoop::new(zone, FireDetectionLoopId::A),
+            loop_b: FireDetectionLoop::new(zone, FireDetectionLoopId::B),
+            test_is_pressed: false,
+            fire_detected: false,
+
+            powered_by,
+            is_powered: false,
+        }
+    }
+
+    fn update(&mut self, context: &UpdateContext, fire_is_burning: bool) {
+        self.loop_a.update(context);
+        self.loop_b.update(context);
+
+        let loop_a_detects = self.loop_a.detects_fire(fire_is_burning);
+        let loop_b_detects = self.loop_b.detects_fire(fire_is_burning);
+
+        self.fire_detected = self.is_powered
+            && (self.test_is_pressed
+                || (loop_a_detects && loop_b_detects)
+                || (loop_a_detects && self.loop_b.is_faulty())
+                || (loop_b_detects && self.loop_a.is_faulty())
+                || self.loops_failed_simultaneously());
+    }
+
+    fn loops_failed_simultaneously(&self) -> bool {
+        let (a, b) = (self.loop_a.fault_duration, self.loop_b.fault_duration);
+
+        self.loop_a.is_faulty()
+            && self.loop_b.is_faulty()
+            && a.max(b) - a.min(b) < Self::SIMULTANEOUS_LOOP_FAULT_MAX_INTERVAL
+    }
+
+    fn loop_a_has_fault(&self) -> bool {
+        self.is_powered && self.loop_a.is_faulty()
+    }
+
+    fn loop_b_has_fault(&self) -> bool {
+        self.is_powered && self.loop_b.is_faulty()
+    }
+
+    fn has_detection_fault(&self) -> bool {
+        self.loop_a_has_fault() && self.loop_b_has_fault() && !self.loops_failed_simultaneously()
+    }
+}
+impl SimulationElement for FireDetectionUnit {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.loop_a.accept(visitor);
+        self.loop_b.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.test_is_pressed = reader.read(&self.test_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.fire_detected_id, self.fire_detected);
+        writer.write(&self.loop_a_fault_id, self.loop_a_has_fault());
+        writer.write(&self.loop_b_fault_id, self.loop_b_has_fault());
+        writer.write(&self.detection_fault_id, self.has_detection_fault());
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+}
+
+/// A pressurised extinguishing agent bottle. Once its squib is fired the agent
+/// is released within about a second and the bottle cannot be used again.
+pub struct FireExtinguisherBottle {
+    pressure_id: VariableIdentifier,
+    is_discharged_id: VariableIdentifier,
+
+    squib_is_fired: bool,
+    pressure: Pressure,
+
+    squib_powered_by: ElectricalBusType,
+    squib_is_powered: bool,
+}
+impl FireExtinguisherBottle {
+    const CHARGED_PRESSURE_PSI: f64 = 600.;
+    const LOW_PRESSURE_PSI: f64 = 50.;
+    const DISCHARGE_TIME_CONSTANT_SECONDS: f64 = 0.3;
+
+    fn new(context: &mut InitContext, name: &str, squib_powered_by: ElectricalBusType) -> Self {
+        Self {
+            pressure_id: context.get_identifier(format!("{}_PRESSURE", name)),
+            is_discharged_id: context.get_identifier(format!("{}_IS_DISCHARGED", name)),
+
+            squib_is_fired: false,
+            pressure: Pressure::new::<psi>(Self::CHARGED_PRESSURE_PSI),
+
+            squib_powered_by,
+            squib_is_powered: false,
+        }
+    }
+
+    fn update(&mut self, context: &UpdateContext) {
+        if self.squib_is_fired {
+            self.pressure *=
+                (-context.delta_as_secs_f64() / Self::DISCHARGE_TIME_CONSTANT_SECONDS).exp();
+        }
+    }
+
+    fn fire_squib(&mut self) {
+        if self.squib_is_powered {
+            self.squib_is_fired = true;
+        }
+    }
+
+    /// The agent is flowing into the protected zone
+    fn is_discharging(&self) -> bool {
+        self.squib_is_fired && !self.is_discharged()
+    }
+
+    pub fn pressure(&self) -> Pressure {
+        self.pressure
+    }
+
+    pub fn is_discharged(&self) -> bool {
+        self.pressure < Pressure::new::<psi>(Self::LOW_PRESSURE_PSI)
+    }
+}
+impl SimulationElement for FireExtinguisherBottle {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.pressure_id, self.pressure);
+        writer.write(&self.is_discharged_id, self.is_discharged());
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.squib_is_powered = buses.is_powered(self.squib_powered_by);
+    }
+}
+
+/// Fire protection of an engine nacelle or of the APU compartment: a fire detection unit
+/// with its two loops and the extinguisher bottles discharging into the zone.
+/// The bottle squibs are only armed once the zone's fire push button is released.
+pub struct FireProtectionZone {
+    agent_discharge_ids: Vec<VariableIdentifier>,
+
+    fire: FireSource,
+    detection: FireDetectionUnit,
+    bottles: Vec<FireExtinguisherBottle>,
+    agent_discharge_is_commanded: Vec<bool>,
+}
+impl FireProtectionZone {
+    pub fn new(
+        context: &mut InitContext,
+        zone: FireDetectionZone,
+        detection_powered_by: ElectricalBusType,
+        squibs_powered_by: &[ElectricalBusType],
+    ) -> Self {
+        let fire = match zone {
+            FireDetectionZone::Engine(number) => FireSource::new_with_sim_fire(
+                context,
+                FailureType::EngineFire(number),
+                format!("ENG ON FIRE:{}", number),
+            ),
+            FireDetectionZone::Apu => FireSource::new(FailureType::ApuFire),
+        };
+
+        Self {
+            agent_discharge_ids: (1..=squibs_powered_by.len())
+                .map(|number| {
+                    context.get_identifier(format!("FIRE_{}_AGENT{}_Discharge", zone, number))
+                })
+                .collect(),
+
+            fire,
+            detection: FireDetectionUnit::new(context, zone, detection_powered_by),
+            bottles: squibs_powered_by
+                .iter()
+                .enumerate()
+                .map(|(index, &powered_by)| {
+                    FireExtinguisherBottle::new(
+                        context,
+                        &format!("FIRE_{}_AGENT{}", zone, index + 1),
+                        powered_by,
+                    )
+                })
+                .collect(),
+            agent_discharge_is_commanded: vec![false; squibs_powered_by.len()],
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, fire_push_button_is_released: bool) {
+        self.fire.update();
+
+        for (bottle, &commanded) in self
+            .bottles
+            .iter_mut()
+            .zip(&self.agent_discharge_is_commanded)
+        {
+            if commanded && fire_push_button_is_released {
+                bottle.fire_squib();
+            }
+            bottle.update(context);
+        }
+
+        if self.bottles.iter().any(|bottle| bottle.is_discharging()) {
+            self.fire.extinguish();
+        }
+
+        self.detection.update(context, self.fire.is_burning());
+    }
+
+    fn discharge_all(&mut self) {
+        self.bottles
+            .iter_mut()
+            .for_each(|bottle| bottle.fire_squib());
+    }
+
+    pub fn fire_is_burning(&self) -> bool {
+        self.fire.is_burning()
+    }
+
+    pub fn fire_detected(&self) -> bool {
+        self.detection.fire_detected
+    }
+
+    pub fn has_detection_fault(&self) -> bool {
+        self.detection.has_detection_fault()
+    }
+
+    pub fn bottle(&self, number: usize) -> &FireExtinguisherBottle {
+        &self.bottles[number - 1]
+    }
+}
+impl SimulationElement for FireProtectionZone {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.fire.accept(visitor);
+        self.detection.accept(visitor);
+        accept_iterable!(self.bottles, visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        for (id, commanded) in self
+            .agent_discharge_ids
+            .iter()
+            .zip(&mut self.agent_discharge_is_commanded)
+        {
+            *commanded = reader.read(id);
+        }
+    }
+}
+
+/// On ground an APU fire automatically discharges the APU bottle after a short delay,
+/// without any crew action.
+pub struct ApuFireProtection {
+    zone: FireProtectionZone,
+    fire_detected_on_ground_duration: Duration,
+}
+impl ApuFireProtection {
+    const AUTOMATIC_DISCHARGE_DELAY: Duration = Duration::from_secs(3);
+
+    pub fn new(
+        context: &mut InitContext,
+        detection_powered_by: ElectricalBusType,
+        squib_powered_by: ElectricalBusType,
+    ) -> Self {
+        Self {
+            zone: FireProtectionZone::new(
+                context,
+                FireDetectionZone::Apu,
+                detection_powered_by,
+                &[squib_powered_by],
+            ),
+            fire_detected_on_ground_duration: Duration::ZERO,
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, fire_push_button_is_released: bool) {
+        if self.zone.fire_detected() && context.is_on_ground() {
+            self.fire_detected_on_ground_duration += context.delta();
+        } else {
+            self.fire_detected_on_ground_duration = Duration::ZERO;
+        }
+
+        if self.fire_detected_on_ground_duration >= Self::AUTOMATIC_DISCHARGE_DELAY {
+            self.zone.discharge_all();
+        }
+
+        self.zone.update(context, fire_push_button_is_released);
+    }
+
+    pub fn fire_detected(&self) -> bool {
+        self.zone.fire_detected()
+    }
+
+    pub fn fire_is_burning(&self) -> bool {
+        self.zone.fire_is_burning()
+    }
+
+    pub fn bottle_is_discharged(&self) -> bool {
+        self.zone.bottle(1).is_discharged()
+    }
+}
+impl SimulationElement for ApuFireProtection {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.zone.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+struct SmokeDetector {
+    failure: Failure,
+}
+impl SmokeDetector {
+    fn new(zone: SmokeDetectionZone, number: usize) -> Self {
+        Self {
+            failure: Failure::new(FailureType::SmokeDetector(zone, number)),
+        }
+    }
+
+    fn is_faulty(&self) -> bool {
+        self.failure.is_active()
+    }
+
+    fn detects_smoke(&self, smoke_is_present: bool) -> bool {
+        smoke_is_present && !self.is_faulty()
+    }
+}
+impl SimulationElement for SmokeDetector {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+struct SmokeDetectionCompartment {
+    smoke_detected_id: VariableIdentifier,
+    detector_fault_ids: Vec<VariableIdentifier>,
+
+    zone: SmokeDetectionZone,
+    smoke: FireSource,
+    detectors: Vec<SmokeDetector>,
+    smoke_detected: bool,
+}
+impl SmokeDetectionCompartment {
+    fn new(context: &mut InitContext, zone: SmokeDetectionZone, detectors: usize) -> Self {
+        Self {
+            smoke_detected_id: context.get_identifier(format!("SMOKE_{}_DETECTED", zone)),
+            detector_fault_ids: (1..=detectors)
+                .map(|number| {
+                    context.get_identifier(format!("SMOKE_{}_DETECTOR_{}_FAULT", zone, number))
+                })
+                .collect(),
+
+            zone,
+            smoke: FireSource::new(FailureType::Smoke(zone)),
+            detectors: (1..=detectors)
+                .map(|number| SmokeDetector::new(zone, number))
+                .collect(),
+            smoke_detected: false,
+        }
+    }
+
+    /// All healthy detectors of the compartment have to agree before smoke is reported.
+    fn update(&mut self, is_powered: bool, test_is_pressed: bool) {
+        self.smoke.update();
+
+        let smoke_is_present = self.smoke.is_burning();
+        let healthy_detectors: Vec<_> = self.detectors.iter().filter(|d| !d.is_faulty()).collect();
+
+        self.smoke_detected = is_powered
+            && (test_is_pressed
+                || (!healthy_detectors.is_empty()
+                    && healthy_detectors
+                        .iter()
+                        .all(|d| d.detects_smoke(smoke_is_present))));
+    }
+}
+impl SimulationElement for SmokeDetectionCompartment {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.smoke.accept(visitor);
+        accept_iterable!(self.detectors, visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.smoke_detected_id, self.smoke_detected);
+        self.detector_fault_ids
+            .iter()
+            .zip(&self.detectors)
+            .for_each(|(id, detector)| writer.write(id, detector.is_faulty()));
+    }
+}
+
+/// The smoke detection control unit (SDCU) processes the cargo and lavatory smoke
+/// detectors. Cargo smoke closes the ventilation isolation valves of the affected
+/// compartment. The cargo bottle can be discharged into any cargo compartment.
+pub struct SmokeDetectionControlUnit {
+    test_id: VariableIdentifier,
+    discharged_ids: Vec<VariableIdentifier>,
+
+    compartments: Vec<SmokeDetectionCompartment>,
+    discharge_push_buttons: Vec<MomentaryPushButton>,
+    cargo_bottle: FireExtinguisherBottle,
+    discharged_into: Vec<bool>,
+    test_is_pressed: bool,
+
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+}
+impl SmokeDetectionControlUnit {
+    const CARGO_SMOKE_DETECTORS_PER_COMPARTMENT: usize = 2;
+
+    pub fn new(
+        context: &mut InitContext,
+        cargo_compartments: &[SmokeDetectionZone],
+        powered_by: ElectricalBusType,
+        cargo_squib_powered_by: ElectricalBusType,
+    ) -> Self {
+        let mut compartments: Vec<_> = cargo_compartments
+            .iter()
+            .map(|&zone| {
+                SmokeDetectionCompartment::new(
+                    context,
+                    zone,
+                    Self::CARGO_SMOKE_DETECTORS_PER_COMPARTMENT,
+                )
+            })
+            .collect();
+        compartments.push(SmokeDetectionCompartment::new(
+            context,
+            SmokeDetectionZone::Lavatory,
+            1,
+        ));
+
+        Self {
+            test_id: context.get_identifier("FIRE_TEST_CARGO".to_owned()),
+            discharged_ids: cargo_compartments
+                .iter()
+                .map(|zone| {
+                    context.get_identifier(format!(
+                        "CARGOSMOKE_{}_DISCHARGED",
+                        Self::compartment_name(zone)
+                    ))
+                })
+                .collect(),
+
+            compartments,
+            discharge_push_buttons: cargo_compartments
+                .iter()
+                .map(|zone| {
+                    MomentaryPushButton::new(
+                        context,
+                        &format!("CARGO_SMOKE_{}_DISCH", Self::compartment_name(zone)),
+                    )
+                })
+                .collect(),
+            cargo_bottle: FireExtinguisherBottle::new(
+                context,
+                "FIRE_CARGO_AGENT1",
+                cargo_squib_powered_by,
+            ),
+            discharged_into: vec![false; cargo_compartments.len()],
+            test_is_pressed: false,
+
+            powered_by,
+            is_powered: false,
+        }
+    }
+
+    fn compartment_name(zone: &SmokeDetectionZone) -> &'static str {
+        match zone {
+            SmokeDetectionZone::ForwardCargo => "FWD",
+            SmokeDetectionZone::AftCargo => "AFT",
+            SmokeDetectionZone::BulkCargo => "BULK",
+            SmokeDetectionZone::Lavatory => "LAV",
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext) {
+        for (index, push_button) in self.discharge_push_buttons.iter().enumerate() {
+            if push_button.is_pressed() && !self.cargo_bottle.squib_is_fired {
+                self.cargo_bottle.fire_squib();
+                self.discharged_into[index] = self.cargo_bottle.squib_is_fired;
+            }
+        }
+        self.cargo_bottle.update(context);
+
+        if self.cargo_bottle.is_discharging() {
+            for (compartment, &discharged) in
+                self.compartments.iter_mut().zip(&self.discharged_into)
+            {
+                if discharged {
+                    compartment.smoke.extinguish();
+                }
+            }
+        }
+
+        for compartment in &mut self.compartments {
+            let test_is_pressed =
+                self.test_is_pressed && compartment.zone != SmokeDetectionZone::Lavatory;
+            compartment.update(self.is_powered, test_is_pressed);
+        }
+    }
+
+    pub fn smoke_detected(&self, zone: SmokeDetectionZone) -> bool {
+        self.compartments
+            .iter()
+            .any(|compartment| compartment.zone == zone && compartment.smoke_detected)
+    }
+
+    pub fn cargo_bottle(&self) -> &FireExtinguisherBottle {
+        &self.cargo_bottle
+    }
+}
+impl CargoSmokeDetection for SmokeDetectionControlUnit {
+    fn fwd_cargo_smoke_detected(&self) -> bool {
+        self.smoke_detected(SmokeDetectionZone::ForwardCargo)
+    }
+
+    fn aft_cargo_smoke_detected(&self) -> bool {
+        self.smoke_detected(SmokeDetectionZone::AftCargo)
+    }
+
+    fn bulk_cargo_smoke_detected(&self) -> bool {
+        self.smoke_detected(SmokeDetectionZone::BulkCargo)
+    }
+}
+impl SimulationElement for SmokeDetectionControlUnit {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.compartments, visitor);
+        accept_iterable!(self.discharge_push_buttons, visitor);
+        self.cargo_bottle.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.test_is_pressed = reader.read(&self.test_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        self.discharged_ids
+            .iter()
+            .zip(&self.discharged_into)
+            .for_each(|(id, &discharged)| writer.write(id, discharged));
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        shared::PotentialOrigin,
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+            Aircraft,
+        },
+    };
+
+    struct TestAircraft {
+        engine_fire_protection: FireProtectionZone,
+        apu_fire_protection: ApuFireProtection,
+        sdcu: SmokeDetectionControlUnit,
+        fire_push_buttons_released: bool,
+
+        powered_source: TestElectricitySource,
+        dc_bat_bus: ElectricalBus,
+        dc_2_bus: ElectricalBus,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                engine_fire_protection: FireProtectionZone::new(
+                    context,
+                    FireDetectionZone::Engine(1),
+                    ElectricalBusType::DirectCurrentBattery,
+                    &[
+                        ElectricalBusType::DirectCurrentBattery,
+                        ElectricalBusType::DirectCurrent(2),
+                    ],
+                ),
+                apu_fire_protection: ApuFireProtection::new(
+                    context,
+                    ElectricalBusType::DirectCurrentBattery,
+                    ElectricalBusType::DirectCurrentBattery,
+                ),
+                sdcu: SmokeDetectionControlUnit::new(
+                    context,
+                    &[
+                        SmokeDetectionZone::ForwardCargo,
+                        SmokeDetectionZone::AftCargo,
+                    ],
+                    ElectricalBusType::DirectCurrentBattery,
+                    ElectricalBusType::DirectCurrentBattery,
+                ),
+                fire_push_buttons_released: false,
+
+                powered_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::Battery(1),
+                ),
+                dc_bat_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrentBattery),
+                dc_2_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(2)),
+            }
+        }
+
+        fn release_fire_push_buttons(&mut self) {
+            self.fire_push_buttons_released = true;
+        }
+
+        fn unpower(&mut self) {
+            self.powered_source.unpower();
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            electricity.supplied_by(&self.powered_source);
+            electricity.flow(&self.powered_source, &self.dc_bat_bus);
+            electricity.flow(&self.powered_source, &self.dc_2_bus);
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.engine_fire_protection
+                .update(context, self.fire_push_buttons_released);
+            self.apu_fire_protection
+                .update(context, self.fire_push_buttons_released);
+            self.sdcu.update(context);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.engine_fire_protection.accept(visitor);
+            self.apu_fire_protection.accept(visitor);
+            self.sdcu.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        SimulationTestBed::new(TestAircraft::new)
+    }
+
+    fn loop_failure(id: FireDetectionLoopId) -> FailureType {
+        FailureType::FireDetectionLoop(FireDetectionZone::Engine(1), id)
+    }
+
+    #[test]
+    fn no_fire_is_detected_without_fire() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.engine_fire_protection.fire_detected()));
+        assert!(!test_bed.query(|a| a.apu_fire_protection.fire_detected()));
+        assert!(!test_bed.query(|a| a.sdcu.fwd_cargo_smoke_detected()));
+    }
+
+    #[test]
+    fn engine_fire_is_detected_by_both_loops() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::EngineFire(1));
+        test_bed.run();
+
+        let detected: bool = test_bed.read_by_name("FIRE_ENG1_DETECTED");
+        assert!(detected);
+    }
+
+    #[test]
+    fn engine_fire_set_by_the_sim_is_detected() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("ENG ON FIRE:1", true);
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.engine_fire_protection.fire_detected()));
+    }
+
+    #[test]
+    fn agent_discharge_extinguishes_engine_fire_set_by_the_sim() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("ENG ON FIRE:1", true);
+        test_bed.command(|a| a.release_fire_push_buttons());
+        test_bed.run();
+
+        test_bed.write_by_name("FIRE_ENG1_AGENT1_Discharge", true);
+        test_bed.run_multiple_frames(Duration::from_secs(5));
+
+        assert!(!test_bed.query(|a| a.engine_fire_protection.fire_is_burning()));
+        assert!(!test_bed.query(|a| a.engine_fire_protection.fire_detected()));
+    }
+
+    #[test]
+    fn engine_fire_is_detected_with_one_faulty_loop() {
+        let mut test_bed = test_bed();
+        test_bed.fail(loop_failure(FireDetectionLoopId::A));
+        test_bed.fail(FailureType::EngineFire(1));
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.engine_fire_protection.fire_detected()));
+
+        let loop_a_fault: bool = test_bed.read_by_name("FIRE_ENG1_LOOP_A_FAULT");
+        let loop_b_fault: bool = test_bed.read_by_name("FIRE_ENG1_LOOP_B_FAULT");
+        assert!(loop_a_fault);
+        assert!(!loop_b_fault);
+    }
+
+    #[test]
+    fn both_loops_failing_simultaneously_triggers_fire_warning() {
+        let mut test_bed = test_bed();
+        test_bed.fail(loop_failure(FireDetectionLoopId::A));
+        test_bed.fail(loop_failure(FireDetectionLoopId::B));
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.engine_fire_protection.fire_detected()));
+        assert!(!test_bed.query(|a| a.engine_fire_protection.has_detection_fault()));
+    }
+
+    #[test]
+    fn both_loops_failing_apart_is_a_detection_fault() {
+        let mut test_bed = test_bed();
+        test_bed.fail(loop_failure(FireDetectionLoopId::A));
+        test_bed.run_multiple_frames(Duration::from_secs(10));
+        test_bed.fail(loop_failure(FireDetectionLoopId::B));
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.engine_fire_protection.fire_detected()));
+        assert!(test_bed.query(|a| a.engine_fire_protection.has_detection_fault()));
+    }
+
+    #[test]
+    fn fire_test_triggers_fire_warning() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("FIRE_TEST_ENG1", true);
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.engine_fire_protection.fire_detected()));
+    }
+
+    #[test]
+    fn unpowered_detection_unit_does_not_detect_fire() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.unpower());
+        test_bed.fail(FailureType::EngineFire(1));
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.engine_fire_protection.fire_detected()));
+    }
+
+    #[test]
+    fn agent_is_not_discharged_before_fire_push_button_is_released() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("FIRE_ENG1_AGENT1_Discharge", true);
+        test_bed.run_multiple_frames(Duration::from_secs(5));
+
+        assert!(!test_bed.query(|a| a.engine_fire_protection.bottle(1).is_discharged()));
+    }
+
+    #[test]
+    fn agent_discharge_empties_bottle_and_extinguishes_fire() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::EngineFire(1));
+        test_bed.command(|a| a.release_fire_push_buttons());
+        test_bed.run();
+        assert!(test_bed.query(|a| a.engine_fire_protection.fire_detected()));
+
+        test_bed.write_by_name("FIRE_ENG1_AGENT1_Discharge", true);
+        test_bed.run_multiple_frames(Duration::from_secs(5));
+
+        let is_discharged: bool = test_bed.read_by_name("FIRE_ENG1_AGENT1_IS_DISCHARGED");
+        assert!(is_discharged);
+        assert!(!test_bed.query(|a| a.engine_fire_protection.bottle(2).is_discharged()));
+        assert!(!test_bed.query(|a| a.engine_fire_protection.fire_is_burning()));
+        assert!(!test_bed.query(|a| a.engine_fire_protection.fire_detected()));
+    }
+
+    #[test]
+    fn apu_bottle_is_discharged_automatically_on_ground() {
+        let mut test_bed = test_bed();
+        test_bed.set_on_ground(true);
+        test_bed.fail(FailureType::ApuFire);
+        test_bed.run_multiple_frames(Duration::from_secs(1));
+        assert!(!test_bed.query(|a| a.apu_fire_protection.bottle_is_discharged()));
+
+        test_bed.run_multiple_frames(Duration::from_secs(5));
+        assert!(test_bed.query(|a| a.apu_fire_protection.bottle_is_discharged()));
+        assert!(!test_bed.query(|a| a.apu_fire_protection.fire_is_burning()));
+    }
+
+    #[test]
+    fn apu_bottle_is_not_discharged_automatically_in_flight() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::ApuFire);
+        test_bed.run_multiple_frames(Duration::from_secs(10));
+
+        assert!(!test_bed.query(|a| a.apu_fire_protection.bottle_is_discharged()));
+        assert!(test_bed.query(|a| a.apu_fire_protection.fire_detected()));
+    }
+
+    #[test]
+    fn cargo_smoke_is_detected() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::Smoke(SmokeDetectionZone::ForwardCargo));
+        test_bed.run();
+
+        let detected: bool = test_bed.read_by_name("SMOKE_FWD_CARGO_DETECTED");
+        assert!(detected);
+        assert!(!test_bed.query(|a| a.sdcu.aft_cargo_smoke_detected()));
+    }
+
+    #[test]
+    fn cargo_smoke_is_detected_with_one_faulty_detector() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::SmokeDetector(SmokeDetectionZone::AftCargo, 2));
+        test_bed.fail(FailureType::Smoke(SmokeDetectionZone::AftCargo));
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.sdcu.aft_cargo_smoke_detected()));
+
+        let detector_fault: bool = test_bed.read_by_name("SMOKE_AFT_CARGO_DETECTOR_2_FAULT");
+        assert!(detector_fault);
+    }
+
+    #[test]
+    fn cargo_smoke_is_not_detected_with_all_detectors_faulty() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::SmokeDetector(SmokeDetectionZone::AftCargo, 1));
+        test_bed.fail(FailureType::SmokeDetector(SmokeDetectionZone::AftCargo, 2));
+        test_bed.fail(FailureType::Smoke(SmokeDetectionZone::AftCargo));
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.sdcu.aft_cargo_smoke_detected()));
+    }
+
+    #[test]
+    fn cargo_discharge_extinguishes_smoke_in_selected_compartment() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::Smoke(SmokeDetectionZone::ForwardCargo));
+        test_bed.run();
+
+        test_bed.write_by_name("OVHD_CARGO_SMOKE_FWD_DISCH_IS_PRESSED", true);
+        test_bed.run();
+        test_bed.write_by_name("OVHD_CARGO_SMOKE_FWD_DISCH_IS_PRESSED", false);
+        test_bed.run_multiple_frames(Duration::from_secs(5));
+
+        let fwd_discharged: bool = test_bed.read_by_name("CARGOSMOKE_FWD_DISCHARGED");
+        let aft_discharged: bool = test_bed.read_by_name("CARGOSMOKE_AFT_DISCHARGED");
+        assert!(fwd_discharged);
+        assert!(!aft_discharged);
+        assert!(test_bed.query(|a| a.sdcu.cargo_bottle().is_discharged()));
+        assert!(!test_bed.query(|a| a.sdcu.fwd_cargo_smoke_detected()));
+    }
+
+    #[test]
+    fn cargo_test_does_not_trigger_lavatory_smoke() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("FIRE_TEST_CARGO", true);
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.sdcu.fwd_cargo_smoke_detected()));
+        assert!(test_bed.query(|a| a.sdcu.aft_cargo_smoke_detected()));
+        assert!(!test_bed.query(|a| a.sdcu.smoke_detected(SmokeDetectionZone::Lavatory)));
+    }
+
+    #[test]
+    fn lavatory_smoke_is_detected() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::Smoke(SmokeDetectionZone::Lavatory));
+        test_bed.run();
+
+        let detected: bool = test_bed.read_by_name("SMOKE_LAVATORY_DETECTED");
+        assert!(detected);
+    }
+}
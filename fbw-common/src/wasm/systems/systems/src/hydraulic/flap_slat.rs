@@ -1,9 +1,12 @@
 use super::linear_actuator::Actuator;
+use crate::failures::{Failure, FailureType};
 use crate::shared::{
-    interpolation, low_pass_filter::LowPassFilter, FeedbackPositionPickoffUnit, SectionPressure,
+    interpolation, low_pass_filter::LowPassFilter, AsymmetryPositionPickoffUnits,
+    FeedbackPositionPickoffUnit, FlapSlatTransmissionId, SectionPressure,
 };
 use crate::simulation::{
-    InitContext, SimulationElement, SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
+    VariableIdentifier, Write,
 };
 
 use uom::si::{
@@ -109,6 +112,45 @@ impl Actuator for FlapSlatHydraulicMotor {
     }
 }
 
+/// The transmission of one wing, driven by the torque shaft of the power control unit.
+/// A jammed transmission doesn't follow the torque shaft anymore while the other wing keeps
+/// moving, which results in an asymmetry between left and right surfaces.
+struct FlapSlatTransmission {
+    offset_from_torque_shaft: Angle,
+    jam_failure: Failure,
+}
+impl FlapSlatTransmission {
+    fn new(id: FlapSlatTransmissionId) -> Self {
+        Self {
+            offset_from_torque_shaft: Angle::new::<radian>(0.),
+            jam_failure: Failure::new(FailureType::FlapSlatJam(id)),
+        }
+    }
+
+    fn update(&mut self, torque_shaft_movement: Angle) {
+        if self.is_jammed() {
+            self.offset_from_torque_shaft -= torque_shaft_movement;
+        }
+    }
+
+    fn is_jammed(&self) -> bool {
+        self.jam_failure.is_active()
+    }
+
+    fn position(&self, torque_shaft_position: Angle, max_position: Angle) -> Angle {
+        (torque_shaft_position + self.offset_from_torque_shaft)
+            .max(Angle::new::<radian>(0.))
+            .min(max_position)
+    }
+}
+impl SimulationElement for FlapSlatTransmission {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.jam_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
 pub struct FlapSlatAssembly {
     position_left_percent_id: VariableIdentifier,
     position_right_percent_id: VariableIdentifier,
@@ -132,6 +174,10 @@ pub struct FlapSlatAssembly {
     left_motor: FlapSlatHydraulicMotor,
     right_motor: FlapSlatHydraulicMotor,
 
+    left_transmission: FlapSlatTransmission,
+    right_transmission: FlapSlatTransmission,
+    wingtip_brakes_engaged: bool,
+
     synchro_gear_breakpoints: [f64; 12],
     final_surface_angle_carac: [f64; 12],
 
@@ -152,6 +198,8 @@ impl FlapSlatAssembly {
     pub fn new(
         context: &mut InitContext,
         id: &str,
+        left_transmission_id: FlapSlatTransmissionId,
+        right_transmission_id: FlapSlatTransmissionId,
         motor_displacement: Volume,
         full_pressure_max_speed: AngularVelocity,
         max_synchro_gear_position: Angle,
@@ -186,6 +234,9 @@ impl FlapSlatAssembly {
             surface_gear_ratio,
             left_motor: FlapSlatHydraulicMotor::new(motor_displacement),
             right_motor: FlapSlatHydraulicMotor::new(motor_displacement),
+            left_transmission: FlapSlatTransmission::new(left_transmission_id),
+            right_transmission: FlapSlatTransmission::new(right_transmission_id),
+            wingtip_brakes_engaged: false,
             synchro_gear_breakpoints,
             final_surface_angle_carac,
             circuit_target_pressure,
@@ -203,9 +254,12 @@ impl FlapSlatAssembly {
         context: &UpdateContext,
         sfcc1_surface_position_request: Option<Angle>,
         sfcc2_surface_position_request: Option<Angle>,
+        wingtip_brakes_engaged: bool,
         left_pressure: &impl SectionPressure,
         right_pressure: &impl SectionPressure,
     ) {
+        self.wingtip_brakes_engaged = wingtip_brakes_engaged;
+
         self.update_final_ffpu_angle_request(
             sfcc1_surface_position_request,
             sfcc2_surface_position_request,
@@ -233,7 +287,11 @@ impl FlapSlatAssembly {
     }
 
     fn update_speed_and_position(&mut self, context: &UpdateContext) {
-        if self.final_requested_synchro_gear_position > self.position_feedback() {
+        let previous_position = self.surface_control_arm_position;
+
+        if self.is_torque_shaft_locked() {
+            self.speed = AngularVelocity::new::<radian_per_second>(0.);
+        } else if self.final_requested_synchro_gear_position > self.position_feedback() {
             self.surface_control_arm_position += Angle::new::<radian>(
                 self.max_speed().get::<radian_per_second>() * context.delta_as_secs_f64(),
             );
@@ -260,6 +318,24 @@ impl FlapSlatAssembly {
             .surface_control_arm_position
             .max(Angle::new::<radian>(0.))
             .min(self.synchro_angle_to_surface_angle(self.max_synchro_gear_position));
+
+        let torque_shaft_movement = self.surface_control_arm_position - previous_position;
+        self.left_transmission.update(torque_shaft_movement);
+        self.right_transmission.update(torque_shaft_movement);
+    }
+
+    fn transmission_position(&self, transmission: &FlapSlatTransmission) -> Angle {
+        transmission.position(
+            self.surface_control_arm_position,
+            self.synchro_angle_to_surface_angle(self.max_synchro_gear_position),
+        )
+    }
+
+    /// The torque shaft cannot turn when both wingtip brakes are engaged
+    /// or when both transmissions are jammed.
+    fn is_torque_shaft_locked(&self) -> bool {
+        self.wingtip_brakes_engaged
+            || self.left_transmission.is_jammed() && self.right_transmission.is_jammed()
     }
 
     fn update_final_ffpu_angle_request(
@@ -398,20 +474,10 @@ impl FlapSlatAssembly {
     }
 
     fn update_position_ratios(&mut self) {
-        self.left_position = Ratio::new::<ratio>(
-            interpolation(
-                &self.synchro_gear_breakpoints,
-                &self.final_surface_angle_carac,
-                self.position_feedback().get::<degree>(),
-            ) / interpolation(
-                &self.synchro_gear_breakpoints,
-                &self.final_surface_angle_carac,
-                self.max_synchro_gear_position.get::<degree>(),
-            ),
-        );
+        let max_surface_angle = self.surface_angle(self.max_synchro_gear_position);
 
-        // TODO update when left and right are simulated separatly
-        self.right_position = self.left_position;
+        self.left_position = self.surface_angle(self.left_angle()) / max_surface_angle;
+        self.right_position = self.surface_angle(self.right_angle()) / max_surface_angle;
     }
 
     fn is_approaching_requested_position(&self, synchro_gear_angle_request: Angle) -> bool {
@@ -449,10 +515,15 @@ impl FlapSlatAssembly {
 
     /// Gets flap surface angle from current Feedback Position Pickup Unit (FPPU) position
     fn flap_surface_angle(&self) -> Angle {
+        self.surface_angle(self.position_feedback())
+    }
+
+    /// Gets surface angle from a position in the synchro gear reference
+    fn surface_angle(&self, synchro_gear_angle: Angle) -> Angle {
         Angle::new::<degree>(interpolation(
             &self.synchro_gear_breakpoints,
             &self.final_surface_angle_carac,
-            self.position_feedback().get::<degree>(),
+            synchro_gear_angle.get::<degree>(),
         ))
     }
 
@@ -488,6 +559,13 @@ impl FlapSlatAssembly {
     }
 }
 impl SimulationElement for FlapSlatAssembly {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.left_transmission.accept(visitor);
+        self.right_transmission.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(
             &self.position_left_percent_id,
@@ -498,9 +576,14 @@ impl SimulationElement for FlapSlatAssembly {
             self.right_position.get::<percent>(),
         );
 
-        let flaps_surface_angle = self.flap_surface_angle();
-        writer.write(&self.angle_left_id, flaps_surface_angle.get::<degree>());
-        writer.write(&self.angle_right_id, flaps_surface_angle.get::<degree>());
+        writer.write(
+            &self.angle_left_id,
+            self.surface_angle(self.left_angle()).get::<degree>(),
+        );
+        writer.write(
+            &self.angle_right_id,
+            self.surface_angle(self.right_angle()).get::<degree>(),
+        );
 
         writer.write(&self.is_moving_id, self.is_surface_moving());
     }
@@ -510,6 +593,108 @@ impl FeedbackPositionPickoffUnit for FlapSlatAssembly {
         self.position_feedback()
     }
 }
+impl AsymmetryPositionPickoffUnits for FlapSlatAssembly {
+    fn left_angle(&self) -> Angle {
+        self.transmission_position(&self.left_transmission)
+            * self.surface_to_synchro_gear_ratio.get::<ratio>()
+    }
+
+    fn right_angle(&self) -> Angle {
+        self.transmission_position(&self.right_transmission)
+            * self.surface_to_synchro_gear_ratio.get::<ratio>()
+    }
+}
+
+/// Monitors the left and right transmissions of a surface and engages the wingtip brakes (WTB)
+/// when an asymmetry between both wings or a runaway of the system is detected.
+/// Once engaged, the wingtip brakes stay engaged and the surface is locked
+/// until a maintenance reset is performed on ground.
+pub struct WingtipBrakeMonitor {
+    asymmetry_detected: bool,
+    runaway_detected: bool,
+    angle_when_stopped: Option<Angle>,
+    closest_angle_to_demand: Option<(Angle, Angle)>,
+}
+
+impl WingtipBrakeMonitor {
+    const ASYMMETRY_THRESHOLD_DEGREE: f64 = 6.;
+    const RUNAWAY_THRESHOLD_DEGREE: f64 = 6.;
+
+    pub fn new() -> Self {
+        Self {
+            asymmetry_detected: false,
+            runaway_detected: false,
+            angle_when_stopped: None,
+            closest_angle_to_demand: None,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        demanded_angle: Option<Angle>,
+        feedback: &(impl FeedbackPositionPickoffUnit + AsymmetryPositionPickoffUnits),
+    ) {
+        if (feedback.left_angle() - feedback.right_angle())
+            .get::<degree>()
+            .abs()
+            > Self::ASYMMETRY_THRESHOLD_DEGREE
+        {
+            self.asymmetry_detected = true;
+        }
+
+        let fppu_angle = feedback.angle();
+        match demanded_angle {
+            // Without demand, the surface shall not move from where it stopped.
+            None => {
+                self.closest_angle_to_demand = None;
+                let angle_when_stopped = *self.angle_when_stopped.get_or_insert(fppu_angle);
+
+                if (fppu_angle - angle_when_stopped).get::<degree>().abs()
+                    > Self::RUNAWAY_THRESHOLD_DEGREE
+                {
+                    self.runaway_detected = true;
+                }
+            }
+            // With a demand, the surface shall not move away from the demanded position.
+            Some(demanded_angle) => {
+                self.angle_when_stopped = None;
+                let distance = (demanded_angle - fppu_angle).abs();
+
+                match self.closest_angle_to_demand {
+                    Some((previous_demand, closest_distance))
+                        if previous_demand == demanded_angle =>
+                    {
+                        if (distance - closest_distance).get::<degree>()
+                            > Self::RUNAWAY_THRESHOLD_DEGREE
+                        {
+                            self.runaway_detected = true;
+                        }
+                        self.closest_angle_to_demand =
+                            Some((demanded_angle, closest_distance.min(distance)));
+                    }
+                    _ => self.closest_angle_to_demand = Some((demanded_angle, distance)),
+                }
+            }
+        }
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    pub fn wingtip_brakes_engaged(&self) -> bool {
+        self.asymmetry_detected || self.runaway_detected
+    }
+
+    pub fn asymmetry_detected(&self) -> bool {
+        self.asymmetry_detected
+    }
+}
+impl Default for WingtipBrakeMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -561,6 +746,7 @@ mod tests {
 
         left_motor_angle_request: Option<Angle>,
         right_motor_angle_request: Option<Angle>,
+        wingtip_brakes_engaged: bool,
 
         left_motor_pressure: TestHydraulicSection,
         right_motor_pressure: TestHydraulicSection,
@@ -572,6 +758,7 @@ mod tests {
                 flaps_slats: flap_system(context, max_speed),
                 left_motor_angle_request: None,
                 right_motor_angle_request: None,
+                wingtip_brakes_engaged: false,
                 left_motor_pressure: TestHydraulicSection::default(),
                 right_motor_pressure: TestHydraulicSection::default(),
             }
@@ -601,6 +788,10 @@ mod tests {
             self.right_motor_angle_request =
                 flap_fppu_from_surface_angle(surface_angle_request_sfcc2);
         }
+
+        fn set_wingtip_brakes_engaged(&mut self, engaged: bool) {
+            self.wingtip_brakes_engaged = engaged;
+        }
     }
     impl Aircraft for TestAircraft {
         fn update_after_power_distribution(&mut self, context: &UpdateContext) {
@@ -611,6 +802,7 @@ mod tests {
                     &context.with_delta(cur_time_step),
                     self.left_motor_angle_request,
                     self.right_motor_angle_request,
+                    self.wingtip_brakes_engaged,
                     &self.left_motor_pressure,
                     &self.right_motor_pressure,
                 );
@@ -1087,10 +1279,191 @@ mod tests {
         }
     }
 
+    #[test]
+    fn flap_slat_assembly_left_and_right_follow_the_torque_shaft() {
+        let max_speed = AngularVelocity::new::<radian_per_second>(0.11);
+        let mut test_bed = SimulationTestBed::new(|context| TestAircraft::new(context, max_speed));
+
+        test_bed.command(|a| a.set_angle_request(Some(Angle::new::<degree>(20.))));
+        test_bed.command(|a| {
+            a.set_current_pressure(
+                Pressure::new::<psi>(MAX_CIRCUIT_PRESSURE_PSI),
+                Pressure::new::<psi>(MAX_CIRCUIT_PRESSURE_PSI),
+            )
+        });
+
+        test_bed.run_multiple_frames(Duration::from_millis(35000));
+
+        let feedback = test_bed.query(|a| a.flaps_slats.position_feedback());
+        assert!(test_bed.query(|a| a.flaps_slats.left_angle()) == feedback);
+        assert!(test_bed.query(|a| a.flaps_slats.right_angle()) == feedback);
+        assert!(
+            test_bed.query(|a| a.flaps_slats.left_position())
+                == test_bed.query(|a| a.flaps_slats.right_position())
+        );
+    }
+
+    #[test]
+    fn flap_slat_assembly_jammed_transmission_creates_asymmetry() {
+        let max_speed = AngularVelocity::new::<radian_per_second>(0.11);
+        let mut test_bed = SimulationTestBed::new(|context| TestAircraft::new(context, max_speed));
+
+        test_bed.command(|a| a.set_angle_request(Some(Angle::new::<degree>(20.))));
+        test_bed.command(|a| {
+            a.set_current_pressure(
+                Pressure::new::<psi>(MAX_CIRCUIT_PRESSURE_PSI),
+                Pressure::new::<psi>(MAX_CIRCUIT_PRESSURE_PSI),
+            )
+        });
+        test_bed.run_multiple_frames(Duration::from_millis(2000));
+
+        test_bed.fail(FailureType::FlapSlatJam(FlapSlatTransmissionId::FlapsLeft));
+        test_bed.run_multiple_frames(Duration::from_millis(100));
+        let jammed_angle = test_bed.query(|a| a.flaps_slats.left_angle());
+
+        test_bed.run_multiple_frames(Duration::from_millis(5000));
+
+        assert!(
+            (test_bed.query(|a| a.flaps_slats.left_angle()) - jammed_angle).abs()
+                < Angle::new::<degree>(0.001)
+        );
+        assert!(test_bed.query(|a| a.flaps_slats.right_angle()) > jammed_angle);
+        assert!(
+            test_bed.query(|a| a.flaps_slats.right_angle())
+                == test_bed.query(|a| a.flaps_slats.position_feedback())
+        );
+        assert!(
+            test_bed.query(|a| a.flaps_slats.left_position())
+                < test_bed.query(|a| a.flaps_slats.right_position())
+        );
+    }
+
+    #[test]
+    fn flap_slat_assembly_does_not_move_with_wingtip_brakes_engaged() {
+        let max_speed = AngularVelocity::new::<radian_per_second>(0.11);
+        let mut test_bed = SimulationTestBed::new(|context| TestAircraft::new(context, max_speed));
+
+        test_bed.command(|a| a.set_angle_request(Some(Angle::new::<degree>(20.))));
+        test_bed.command(|a| {
+            a.set_current_pressure(
+                Pressure::new::<psi>(MAX_CIRCUIT_PRESSURE_PSI),
+                Pressure::new::<psi>(MAX_CIRCUIT_PRESSURE_PSI),
+            )
+        });
+        test_bed.run_multiple_frames(Duration::from_millis(2000));
+
+        test_bed.command(|a| a.set_wingtip_brakes_engaged(true));
+        test_bed.run_multiple_frames(Duration::from_millis(100));
+        let locked_angle = test_bed.query(|a| a.flaps_slats.position_feedback());
+
+        test_bed.run_multiple_frames(Duration::from_millis(5000));
+
+        assert!(test_bed.query(|a| a.flaps_slats.position_feedback()) == locked_angle);
+        assert!(test_bed.query(|a| a.flaps_slats.left_angle()) == locked_angle);
+        assert!(test_bed.query(|a| a.flaps_slats.right_angle()) == locked_angle);
+        assert!(
+            test_bed.query(|a| a.flaps_slats.speed)
+                == AngularVelocity::new::<radian_per_second>(0.)
+        );
+    }
+
+    struct TestPickoffUnits {
+        left_angle: Angle,
+        right_angle: Angle,
+    }
+    impl TestPickoffUnits {
+        fn new(left_angle: f64, right_angle: f64) -> Self {
+            Self {
+                left_angle: Angle::new::<degree>(left_angle),
+                right_angle: Angle::new::<degree>(right_angle),
+            }
+        }
+    }
+    impl FeedbackPositionPickoffUnit for TestPickoffUnits {
+        fn angle(&self) -> Angle {
+            self.right_angle
+        }
+    }
+    impl AsymmetryPositionPickoffUnits for TestPickoffUnits {
+        fn left_angle(&self) -> Angle {
+            self.left_angle
+        }
+
+        fn right_angle(&self) -> Angle {
+            self.right_angle
+        }
+    }
+
+    #[test]
+    fn wingtip_brake_monitor_not_engaged_when_following_demand() {
+        let mut monitor = WingtipBrakeMonitor::new();
+        let demand = Some(Angle::new::<degree>(120.));
+
+        monitor.update(demand, &TestPickoffUnits::new(0., 0.));
+        monitor.update(demand, &TestPickoffUnits::new(60., 60.));
+        monitor.update(demand, &TestPickoffUnits::new(120., 120.));
+        monitor.update(None, &TestPickoffUnits::new(120., 120.));
+
+        assert!(!monitor.wingtip_brakes_engaged());
+    }
+
+    #[test]
+    fn wingtip_brake_monitor_engages_on_asymmetry() {
+        let mut monitor = WingtipBrakeMonitor::new();
+
+        monitor.update(
+            Some(Angle::new::<degree>(120.)),
+            &TestPickoffUnits::new(50., 60.),
+        );
+
+        assert!(monitor.wingtip_brakes_engaged());
+        assert!(monitor.asymmetry_detected());
+    }
+
+    #[test]
+    fn wingtip_brake_monitor_engages_when_moving_without_demand() {
+        let mut monitor = WingtipBrakeMonitor::new();
+
+        monitor.update(None, &TestPickoffUnits::new(0., 0.));
+        monitor.update(None, &TestPickoffUnits::new(10., 10.));
+
+        assert!(monitor.wingtip_brakes_engaged());
+        assert!(!monitor.asymmetry_detected());
+    }
+
+    #[test]
+    fn wingtip_brake_monitor_engages_when_moving_away_from_demand() {
+        let mut monitor = WingtipBrakeMonitor::new();
+        let demand = Some(Angle::new::<degree>(120.));
+
+        monitor.update(demand, &TestPickoffUnits::new(60., 60.));
+        monitor.update(demand, &TestPickoffUnits::new(50., 50.));
+
+        assert!(monitor.wingtip_brakes_engaged());
+        assert!(!monitor.asymmetry_detected());
+    }
+
+    #[test]
+    fn wingtip_brake_monitor_stays_engaged_until_reset() {
+        let mut monitor = WingtipBrakeMonitor::new();
+
+        monitor.update(None, &TestPickoffUnits::new(0., 0.));
+        monitor.update(None, &TestPickoffUnits::new(10., 10.));
+        monitor.update(None, &TestPickoffUnits::new(0., 0.));
+
+        assert!(monitor.wingtip_brakes_engaged());
+
+        monitor.reset();
+
+        assert!(!monitor.wingtip_brakes_engaged());
+    }
+
     fn flap_system(context: &mut InitContext, max_speed: AngularVelocity) -> FlapSlatAssembly {
         FlapSlatAssembly::new(
             context,
             "FLAPS",
+            FlapSlatTransmissionId::FlapsLeft,
+            FlapSlatTransmissionId::FlapsRight,
             Volume::new::<cubic_inch>(0.32),
             max_speed,
             Angle::new::<degree>(251.97),
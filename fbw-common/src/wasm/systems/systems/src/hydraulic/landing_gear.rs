@@ -20,7 +20,7 @@ use super::{
     HydraulicValve, HydraulicValveType,
 };
 
-use uom::si::{f64::*, pressure::psi, ratio::ratio};
+use uom::si::{f64::*, pressure::psi, ratio::ratio, torque::newton_meter};
 
 pub trait GearGravityExtension {
     fn extension_handle_number_of_turns(&self) -> u8;
@@ -193,18 +193,21 @@ impl HydraulicGearSystem {
             lgciu_controller,
             valves_controller,
             current_pressure,
+            true,
         );
         self.left_door_assembly.update(
             context,
             lgciu_controller,
             valves_controller,
             current_pressure,
+            true,
         );
         self.right_door_assembly.update(
             context,
             lgciu_controller,
             valves_controller,
             current_pressure,
+            true,
         );
 
         self.nose_gear_assembly.update(
@@ -212,18 +215,21 @@ impl HydraulicGearSystem {
             lgciu_controller,
             valves_controller,
             current_pressure,
+            self.nose_door_assembly.is_opened_for_gear_clearance(),
         );
         self.left_gear_assembly.update(
             context,
             lgciu_controller,
             valves_controller,
             current_pressure,
+            self.left_door_assembly.is_opened_for_gear_clearance(),
         );
         self.right_gear_assembly.update(
             context,
             lgciu_controller,
             valves_controller,
             current_pressure,
+            self.right_door_assembly.is_opened_for_gear_clearance(),
         );
     }
 
@@ -331,6 +337,9 @@ struct GearSystemHydraulicSupply {
     safety_valve: HydraulicValve,
     cutoff_valve: HydraulicValve,
     gear_and_door_selector_valve: HydraulicValve,
+    selector_valve_commanded_open: bool,
+
+    selector_valve_stuck_failure: Failure,
 }
 impl GearSystemHydraulicSupply {
     fn new() -> Self {
@@ -350,6 +359,9 @@ impl GearSystemHydraulicSupply {
                     ElectricalBusType::DirectCurrentGndFltService,
                 ]),
             ),
+            selector_valve_commanded_open: false,
+
+            selector_valve_stuck_failure: Failure::new(FailureType::GearSelectorValveStuck),
         }
     }
 
@@ -370,9 +382,15 @@ impl GearSystemHydraulicSupply {
             valves_controller.shut_off_valve_should_open(),
             self.safety_valve.pressure_output(),
         );
+
+        // A stuck selector valve ignores LGCIU commands and stays where it was when the failure occurred
+        if !self.selector_valve_stuck_failure.is_active() {
+            self.selector_valve_commanded_open = gear_controller.control_active();
+        }
+
         self.gear_and_door_selector_valve.update(
             context,
-            gear_controller.control_active(),
+            self.selector_valve_commanded_open,
             self.cutoff_valve.pressure_output(),
         );
     }
@@ -385,6 +403,7 @@ impl SimulationElement for GearSystemHydraulicSupply {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.safety_valve.accept(visitor);
         self.gear_and_door_selector_valve.accept(visitor);
+        self.selector_valve_stuck_failure.accept(visitor);
 
         visitor.visit(self);
     }
@@ -422,6 +441,8 @@ struct GearSystemComponentAssembly {
     hydraulic_uplock: HydraulicLock,
     hydraulic_downlock: Option<HydraulicLock>,
 
+    uplock_jammed_failure: Failure,
+
     aerodynamic_model: AerodynamicModel,
 }
 impl GearSystemComponentAssembly {
@@ -431,6 +452,9 @@ impl GearSystemComponentAssembly {
     const UPLOCKED_PROXIMITY_DETECTOR_MOUNTING_POSITION_RATIO: f64 = 0.;
     const UPLOCKED_PROXIMITY_DETECTOR_TRIG_DISTANCE_RATIO: f64 = 0.01;
 
+    // Gear leg can only leave its uplock once its door has swung far enough out of the way
+    const DOOR_OPENED_FOR_GEAR_CLEARANCE_RATIO: f64 = 0.9;
+
     fn new(
         id: GearActuatorId,
         is_inverted_control: bool,
@@ -479,6 +503,7 @@ impl GearSystemComponentAssembly {
             } else {
                 None
             },
+            uplock_jammed_failure: Failure::new(FailureType::GearUplockJammed(id)),
             aerodynamic_model,
         };
 
@@ -493,10 +518,17 @@ impl GearSystemComponentAssembly {
         gear_system_controller: &impl LgciuGearControl,
         valves_controller: &impl GearSystemController,
         current_pressure: Pressure,
+        is_travel_path_clear: bool,
     ) {
         self.update_proximity_detectors();
 
-        self.update_hydraulic_control(gear_system_controller, valves_controller, current_pressure);
+        self.update_hydraulic_control(
+            context,
+            gear_system_controller,
+            valves_controller,
+            current_pressure,
+            is_travel_path_clear,
+        );
 
         self.aerodynamic_model
             .update_body(context, self.hydraulic_assembly.body());
@@ -521,9 +553,11 @@ impl GearSystemComponentAssembly {
 
     fn update_hydraulic_control(
         &mut self,
+        context: &UpdateContext,
         gear_system_controller: &impl LgciuGearControl,
         valves_controller: &impl GearSystemController,
         current_pressure: Pressure,
+        is_travel_path_clear: bool,
     ) {
         let should_hydraulically_open = match self.component_id {
             GearSysComponentId::Door => gear_system_controller.should_open_doors(),
@@ -538,16 +572,28 @@ impl GearSystemComponentAssembly {
             }
         };
 
+        // A jammed uplock hook can't be released by its hydraulic actuator, only the
+        // gravity extension cable has enough mechanical advantage to break it free
         self.hydraulic_uplock.update(
-            should_hydraulically_open,
-            should_mechanically_open,
+            context,
+            should_hydraulically_open
+                && !self.uplock_jammed_failure.is_active()
+                && is_travel_path_clear,
+            should_mechanically_open && is_travel_path_clear,
             current_pressure,
+            self.hydraulic_assembly.aerodynamic_torque(),
         );
 
         let mut should_lock_down = false;
 
         if let Some(hyd_lock) = &mut self.hydraulic_downlock {
-            hyd_lock.update(!should_hydraulically_open, false, current_pressure);
+            hyd_lock.update(
+                context,
+                !should_hydraulically_open,
+                false,
+                current_pressure,
+                Torque::default(),
+            );
             should_lock_down = hyd_lock.is_locked_or_ready_to_latch();
         }
 
@@ -567,6 +613,10 @@ impl GearSystemComponentAssembly {
         }
     }
 
+    fn is_opened_for_gear_clearance(&self) -> bool {
+        self.position_normalized().get::<ratio>() > Self::DOOR_OPENED_FOR_GEAR_CLEARANCE_RATIO
+    }
+
     fn actuator(&mut self) -> &mut impl Actuator {
         self.hydraulic_assembly.actuator(0)
     }
@@ -594,6 +644,7 @@ impl SimulationElement for GearSystemComponentAssembly {
         accept_iterable!(self.uplock_proximity_detectors, visitor);
         accept_iterable!(self.fully_opened_proximity_detectors, visitor);
         self.hydraulic_controller.accept(visitor);
+        self.uplock_jammed_failure.accept(visitor);
 
         visitor.visit(self);
     }
@@ -612,6 +663,8 @@ struct GearSystemComponentHydraulicController {
     jamming_position: Ratio,
     jamming_is_effective: bool,
 
+    door_jammed_failure: Option<Failure>,
+
     soft_downlock_is_active: bool,
 }
 impl GearSystemComponentHydraulicController {
@@ -626,6 +679,10 @@ impl GearSystemComponentHydraulicController {
             jammed_actuator_failure: Failure::new(FailureType::GearActuatorJammed(id)),
             jamming_position: Ratio::new::<ratio>(random_from_range(0., 1.)),
             jamming_is_effective: false,
+            door_jammed_failure: match GearSysComponentId::from(id) {
+                GearSysComponentId::Door => Some(Failure::new(FailureType::GearDoorJammed(id))),
+                GearSysComponentId::Gear => None,
+            },
             soft_downlock_is_active: false,
         }
     }
@@ -678,6 +735,13 @@ impl GearSystemComponentHydraulicController {
         }
     }
 
+    // Door structure or hinges are jammed: door is held wherever it was regardless of hydraulic or gravity action
+    fn door_is_jammed(&self) -> bool {
+        self.door_jammed_failure
+            .as_ref()
+            .map_or(false, |failure| failure.is_active())
+    }
+
     fn update_soft_downlock(&mut self) {
         if self.is_soft_downlock {
             if (!self.is_inverted_control
@@ -704,7 +768,7 @@ impl GearSystemComponentHydraulicController {
 }
 impl HydraulicAssemblyController for GearSystemComponentHydraulicController {
     fn requested_mode(&self) -> LinearActuatorMode {
-        if self.jamming_is_effective {
+        if self.jamming_is_effective || self.door_is_jammed() {
             return LinearActuatorMode::ClosedValves;
         }
 
@@ -740,6 +804,9 @@ impl ElectroHydrostaticPowered for GearSystemComponentHydraulicController {}
 impl SimulationElement for GearSystemComponentHydraulicController {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.jammed_actuator_failure.accept(visitor);
+        if let Some(failure) = &mut self.door_jammed_failure {
+            failure.accept(visitor);
+        }
 
         visitor.visit(self);
     }
@@ -747,23 +814,63 @@ impl SimulationElement for GearSystemComponentHydraulicController {
 
 struct HydraulicLock {
     is_unlocked: bool,
+
+    mechanical_release_progress: Ratio,
 }
 impl HydraulicLock {
     const UNLOCK_MIN_PRESS_PSI: f64 = 1000.;
 
+    // Time for the release cable to pull the hook open when nothing is pressing on it
+    const MECHANICAL_RELEASE_TIME_UNLOADED_S: f64 = 0.5;
+    // Aerodynamic torque pressing the component on its hook that doubles the release time
+    const MECHANICAL_RELEASE_TORQUE_FOR_DOUBLE_TIME_NM: f64 = 5000.;
+
     fn new() -> Self {
-        Self { is_unlocked: false }
+        Self {
+            is_unlocked: false,
+            mechanical_release_progress: Ratio::default(),
+        }
     }
 
     fn update(
         &mut self,
+        context: &UpdateContext,
         should_unlock: bool,
         should_manually_unlock: bool,
         current_pressure: Pressure,
+        hook_load: Torque,
     ) {
+        self.update_mechanical_release(context, should_manually_unlock, hook_load);
+
         self.is_unlocked = should_unlock
             && current_pressure.get::<psi>() > Self::UNLOCK_MIN_PRESS_PSI
-            || should_manually_unlock
+            || self.is_mechanically_released();
+    }
+
+    /// Mechanical release is not instantaneous: the more air loads press the component
+    /// against its hook, the longer it takes for the cable to drag the hook open.
+    fn update_mechanical_release(
+        &mut self,
+        context: &UpdateContext,
+        should_manually_unlock: bool,
+        hook_load: Torque,
+    ) {
+        if should_manually_unlock {
+            let release_time_s = Self::MECHANICAL_RELEASE_TIME_UNLOADED_S
+                * (1.
+                    + hook_load.get::<newton_meter>().abs()
+                        / Self::MECHANICAL_RELEASE_TORQUE_FOR_DOUBLE_TIME_NM);
+
+            self.mechanical_release_progress = (self.mechanical_release_progress
+                + Ratio::new::<ratio>(context.delta_as_secs_f64() / release_time_s))
+            .min(Ratio::new::<ratio>(1.));
+        } else {
+            self.mechanical_release_progress = Ratio::default();
+        }
+    }
+
+    fn is_mechanically_released(&self) -> bool {
+        self.mechanical_release_progress.get::<ratio>() >= 1.
     }
 
     fn is_locked_or_ready_to_latch(&self) -> bool {
@@ -844,12 +951,14 @@ mod tests {
     struct TestGearValvesController {
         safety_valve_should_open: bool,
         shut_off_valve_should_open: bool,
+        uplocks_should_mechanically_unlock: bool,
     }
     impl TestGearValvesController {
         fn with_safety_and_shutoff_opened() -> Self {
             Self {
                 safety_valve_should_open: true,
                 shut_off_valve_should_open: true,
+                ..Default::default()
             }
         }
 
@@ -857,6 +966,7 @@ mod tests {
             Self {
                 safety_valve_should_open: true,
                 shut_off_valve_should_open: false,
+                ..Default::default()
             }
         }
 
@@ -864,6 +974,7 @@ mod tests {
             Self {
                 safety_valve_should_open: false,
                 shut_off_valve_should_open: true,
+                ..Default::default()
             }
         }
 
@@ -871,8 +982,13 @@ mod tests {
             Self {
                 safety_valve_should_open: false,
                 shut_off_valve_should_open: false,
+                ..Default::default()
             }
         }
+
+        fn set_uplocks_mechanical_release(&mut self, release: bool) {
+            self.uplocks_should_mechanically_unlock = release;
+        }
     }
     impl GearSystemController for TestGearValvesController {
         fn safety_valve_should_open(&self) -> bool {
@@ -888,11 +1004,11 @@ mod tests {
         }
 
         fn doors_uplocks_should_mechanically_unlock(&self) -> bool {
-            false
+            self.uplocks_should_mechanically_unlock
         }
 
         fn gears_uplocks_should_mechanically_unlock(&self) -> bool {
-            false
+            self.uplocks_should_mechanically_unlock
         }
     }
 
@@ -1022,6 +1138,7 @@ mod tests {
         gear_assembly: GearSystemComponentAssembly,
 
         component_controller: TestGearSystemController,
+        valves_controller: TestGearValvesController,
 
         pressure: Pressure,
     }
@@ -1066,6 +1183,7 @@ mod tests {
                 ),
 
                 component_controller: TestGearSystemController::new(),
+                valves_controller: TestGearValvesController::with_safety_and_shutoff_opened(),
 
                 pressure: Pressure::new::<psi>(3000.),
             }
@@ -1091,19 +1209,25 @@ mod tests {
             self.component_controller.set_gears_extending(false);
         }
 
+        fn command_gravity_extension(&mut self) {
+            self.valves_controller.set_uplocks_mechanical_release(true);
+        }
+
         fn update(&mut self, context: &UpdateContext) {
             self.door_assembly.update(
                 context,
                 &self.component_controller,
-                &TestGearValvesController::with_safety_and_shutoff_opened(),
+                &self.valves_controller,
                 self.pressure,
+                true,
             );
 
             self.gear_assembly.update(
                 context,
                 &self.component_controller,
-                &TestGearValvesController::with_safety_and_shutoff_opened(),
+                &self.valves_controller,
                 self.pressure,
+                self.door_assembly.is_opened_for_gear_clearance(),
             );
 
             println!(
@@ -1296,6 +1420,23 @@ mod tests {
         assert!(test_bed.query(|e| e.gear_system_manifold_pressure()) < Pressure::new::<psi>(100.));
     }
 
+    #[test]
+    fn hydraulic_manifold_do_not_receive_pressure_with_selector_valve_stuck_closed() {
+        let mut test_bed = SimulationTestBed::new(|context| {
+            TestHydraulicManifoldAircraft::new(
+                context,
+                TestGearValvesController::with_safety_and_shutoff_opened(),
+                TestGearSystemController::new(),
+            )
+        });
+        test_bed.fail(FailureType::GearSelectorValveStuck);
+        test_bed.command(|a| a.set_current_pressure(Pressure::new::<psi>(3000.)));
+
+        test_bed.run_with_delta(Duration::from_millis(100));
+
+        assert!(test_bed.query(|e| e.gear_system_manifold_pressure()) < Pressure::new::<psi>(100.));
+    }
+
     #[test]
     fn door_assembly_init_uplocked() {
         let mut test_bed = SimulationTestBed::new(|context| {
@@ -1497,6 +1638,79 @@ mod tests {
         assert!(test_bed.query(|a| a.is_door_sensor_uplock(LgciuId::Lgciu2)));
     }
 
+    #[test]
+    fn jammed_gear_uplock_keeps_gear_up_on_hydraulic_extension() {
+        let mut test_bed = test_bed_with_gear_retracted();
+
+        test_bed.fail(FailureType::GearUplockJammed(GearActuatorId::GearNose));
+
+        test_bed.command(|a| a.command_doors_opening());
+        test_bed.run_with_delta(Duration::from_millis(5000));
+        test_bed.command(|a| a.command_gears_extending());
+        test_bed.run_with_delta(Duration::from_millis(12000));
+
+        assert!(test_bed.query(|a| a.is_door_sensor_fully_opened(LgciuId::Lgciu1)));
+        assert!(test_bed.query(|a| a.is_gear_physically_locked()));
+        assert!(test_bed.query(|a| a.is_gear_sensor_uplock(LgciuId::Lgciu1)));
+        assert!(!test_bed.query(|a| a.is_gear_sensor_fully_opened(LgciuId::Lgciu1)));
+    }
+
+    #[test]
+    fn jammed_gear_uplock_is_released_by_gravity_extension() {
+        let mut test_bed = test_bed_with_gear_retracted();
+
+        test_bed.fail(FailureType::GearUplockJammed(GearActuatorId::GearNose));
+
+        test_bed.command(|a| a.set_pressure(Pressure::default()));
+        test_bed.command(|a| a.command_gravity_extension());
+        test_bed.run_with_delta(Duration::from_millis(10000));
+
+        assert!(!test_bed.query(|a| a.is_gear_sensor_uplock(LgciuId::Lgciu1)));
+        assert!(!test_bed.query(|a| a.is_gear_sensor_uplock(LgciuId::Lgciu2)));
+    }
+
+    #[test]
+    fn jammed_door_blocks_gear_extension() {
+        let mut test_bed = test_bed_with_gear_retracted();
+
+        test_bed.fail(FailureType::GearDoorJammed(GearActuatorId::GearDoorNose));
+
+        test_bed.command(|a| a.command_doors_opening());
+        test_bed.run_with_delta(Duration::from_millis(5000));
+        test_bed.command(|a| a.command_gears_extending());
+        test_bed.run_with_delta(Duration::from_millis(12000));
+
+        assert!(!test_bed.query(|a| a.is_door_sensor_fully_opened(LgciuId::Lgciu1)));
+
+        assert!(test_bed.query(|a| a.is_gear_sensor_uplock(LgciuId::Lgciu1)));
+        assert!(!test_bed.query(|a| a.is_gear_sensor_fully_opened(LgciuId::Lgciu1)));
+    }
+
+    fn test_bed_with_gear_retracted() -> SimulationTestBed<TestSingleGearAircraft> {
+        let mut test_bed = SimulationTestBed::new(|context| {
+            let gear_door = main_gear_door_right_assembly(context);
+
+            TestSingleGearAircraft::new(
+                Duration::from_millis(10),
+                gear_door,
+                main_gear_right_assembly(context, true),
+            )
+        });
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        test_bed.command(|a| a.command_doors_opening());
+        test_bed.run_with_delta(Duration::from_millis(4000));
+        test_bed.command(|a| a.command_gears_retracting());
+        test_bed.run_with_delta(Duration::from_millis(10000));
+        test_bed.command(|a| a.command_doors_closing());
+        test_bed.run_with_delta(Duration::from_millis(6000));
+
+        assert!(test_bed.query(|a| a.is_gear_sensor_uplock(LgciuId::Lgciu1)));
+        assert!(test_bed.query(|a| a.is_door_sensor_uplock(LgciuId::Lgciu1)));
+
+        test_bed
+    }
+
     fn main_gear_door_right_assembly(
         context: &mut InitContext,
     ) -> HydraulicLinearActuatorAssembly<1> {
@@ -19,10 +19,11 @@ use uom::si::{
 };
 
 use crate::{
+    failures::{Failure, FailureType},
     shared::{
         interpolation, low_pass_filter::LowPassFilter, pid::PidController,
         random_from_normal_distribution, random_from_range, ConsumePower, ElectricalBusType,
-        ElectricalBuses,
+        ElectricalBuses, FlightControlActuatorId,
     },
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -39,6 +40,9 @@ pub trait Actuator {
     fn used_volume(&self) -> Volume;
     fn reservoir_return(&self) -> Volume;
     fn reset_volumes(&mut self);
+
+    /// Share of its nominal flow the actuator can receive at current fluid viscosity
+    fn set_fluid_viscosity_efficiency(&mut self, _efficiency: Ratio) {}
 }
 
 /// Trait linked to anything moving bounded between a minimum and maximum position.
@@ -398,6 +402,7 @@ struct CoreHydraulicForce {
     max_flow: VolumeRate,
     min_flow: VolumeRate,
     flow_error_prev: VolumeRate,
+    fluid_viscosity_efficiency: Ratio,
 
     bore_side_area: Area,
     rod_side_area: Area,
@@ -489,6 +494,7 @@ impl CoreHydraulicForce {
             max_flow,
             min_flow,
             flow_error_prev: VolumeRate::new::<gallon_per_second>(0.),
+            fluid_viscosity_efficiency: Ratio::new::<ratio>(1.),
             bore_side_area,
             rod_side_area,
             last_control_force: Force::new::<newton>(0.),
@@ -743,14 +749,18 @@ impl CoreHydraulicForce {
     fn open_loop_flow(&self, required_position: Ratio, position_normalized: Ratio) -> VolumeRate {
         let position_error = required_position - position_normalized;
 
+        // Cold thick fluid can't flow as fast through the actuator valves
+        let max_flow = self.max_flow * self.fluid_viscosity_efficiency.get::<ratio>();
+        let min_flow = self.min_flow * self.fluid_viscosity_efficiency.get::<ratio>();
+
         let open_loop_flow_target = if position_error.get::<ratio>() >= 0. {
             VolumeRate::new::<gallon_per_second>(
-                position_error.get::<ratio>().powi(2) * self.max_flow.get::<gallon_per_second>()
+                position_error.get::<ratio>().powi(2) * max_flow.get::<gallon_per_second>()
                     / Self::OPEN_LOOP_POSITION_ERROR_FOR_MAX_FLOW.powi(2),
             )
         } else {
             VolumeRate::new::<gallon_per_second>(
-                position_error.get::<ratio>().powi(2) * -self.max_flow.get::<gallon_per_second>()
+                position_error.get::<ratio>().powi(2) * -max_flow.get::<gallon_per_second>()
                     / Self::OPEN_LOOP_POSITION_ERROR_FOR_MAX_FLOW.powi(2),
             )
         };
@@ -769,8 +779,7 @@ impl CoreHydraulicForce {
             )
         };
 
-        (open_loop_flow_target.min(self.max_flow).max(self.min_flow))
-            * open_loop_modifier_from_position
+        (open_loop_flow_target.min(max_flow).max(min_flow)) * open_loop_modifier_from_position
     }
 
     fn update_force_min_max(
@@ -1082,6 +1091,7 @@ impl LinearActuator {
         connected_body: &mut LinearActuatedRigidBodyOnHingeAxis,
         controller: &(impl HydraulicAssemblyController + HydraulicLocking + ElectroHydrostaticPowered),
         current_input_pressure: Pressure,
+        failures: Option<&FlightControlActuatorFailures>,
     ) {
         let mut can_move_using_aircraft_hydraulic_pressure = true;
 
@@ -1112,24 +1122,39 @@ impl LinearActuator {
             Pressure::default()
         };
 
+        let requested_mode = failures.map_or(controller.requested_mode(), |failures| {
+            failures.requested_mode(controller.requested_mode())
+        });
+
         self.core_hydraulics.update_force(
             context,
             self.requested_position,
-            controller.requested_mode(),
+            requested_mode,
             self.position_normalized,
             internal_actuator_pressure,
             self.signed_flow,
             self.speed,
         );
-        connected_body.apply_control_arm_force(self.core_hydraulics.force());
+
+        if failures.map_or(true, |failures| failures.is_connected_to_body()) {
+            connected_body.apply_control_arm_force(self.core_hydraulics.force());
+        }
     }
 
     fn update_after_rigid_body(
         &mut self,
         context: &UpdateContext,
         connected_body: &LinearActuatedRigidBodyOnHingeAxis,
+        is_connected_to_body: bool,
     ) {
-        self.update_speed_position(context, connected_body);
+        if is_connected_to_body {
+            self.update_speed_position(context, connected_body);
+        } else {
+            // Rod end is broken: body moves without dragging the actuator rod
+            self.last_position = self.position;
+            self.delta_displacement = Length::default();
+            self.speed = Velocity::default();
+        }
 
         self.update_fluid_displacements(context);
     }
@@ -1237,6 +1262,10 @@ impl Actuator for LinearActuator {
             eha.reset_volumes();
         }
     }
+
+    fn set_fluid_viscosity_efficiency(&mut self, efficiency: Ratio) {
+        self.core_hydraulics.fluid_viscosity_efficiency = efficiency;
+    }
 }
 impl HydraulicLocking for LinearActuator {
     fn should_soft_lock(&self) -> bool {
@@ -1308,9 +1337,100 @@ pub fn get_more_restrictive_soft_lock_velocities(
     )
 }
 
+/// Failures of a flight control surface actuator
+///
+/// - Jam: actuator rod is mechanically stuck, surface is held where the jam occurred
+/// - Runaway: servo valve is stuck fully open, actuator drives the surface hardover to one of its stops
+/// - Disconnect: servo valve lost its input, actuator only dampens a free floating surface
+/// - Rod end fracture: actuator is not linked to the surface anymore, it neither moves it nor uses fluid
+struct FlightControlActuatorFailures {
+    jam: Failure,
+    runaway: Failure,
+    disconnect: Failure,
+    rod_end_fracture: Failure,
+
+    jammed_position: Option<Ratio>,
+    runaway_position: Ratio,
+}
+impl FlightControlActuatorFailures {
+    fn new(id: FlightControlActuatorId) -> Self {
+        Self {
+            jam: Failure::new(FailureType::FlightControlActuatorJam(id)),
+            runaway: Failure::new(FailureType::FlightControlActuatorRunaway(id)),
+            disconnect: Failure::new(FailureType::FlightControlActuatorDisconnect(id)),
+            rod_end_fracture: Failure::new(FailureType::FlightControlActuatorRodEndFracture(id)),
+
+            jammed_position: None,
+            runaway_position: Self::random_runaway_position(),
+        }
+    }
+
+    fn random_runaway_position() -> Ratio {
+        if random_from_range(0., 1.) < 0.5 {
+            Ratio::new::<ratio>(0.)
+        } else {
+            Ratio::new::<ratio>(1.)
+        }
+    }
+
+    fn update(&mut self, body_position_normalized: Ratio) {
+        if !self.jam.is_active() {
+            self.jammed_position = None;
+        } else if self.jammed_position.is_none() {
+            self.jammed_position = Some(body_position_normalized);
+        }
+
+        // Taking a new random hardover direction while runaway is not active
+        if !self.runaway.is_active() {
+            self.runaway_position = Self::random_runaway_position();
+        }
+    }
+
+    fn requested_position(&self, requested_position: Ratio) -> Ratio {
+        if self.runaway.is_active() {
+            self.runaway_position
+        } else {
+            requested_position
+        }
+    }
+
+    fn requested_mode(&self, requested_mode: LinearActuatorMode) -> LinearActuatorMode {
+        if self.runaway.is_active() {
+            LinearActuatorMode::PositionControl
+        } else if self.disconnect.is_active() {
+            LinearActuatorMode::ActiveDamping
+        } else {
+            requested_mode
+        }
+    }
+
+    fn jammed_position(&self) -> Option<Ratio> {
+        if self.is_connected_to_body() {
+            self.jammed_position
+        } else {
+            None
+        }
+    }
+
+    fn is_connected_to_body(&self) -> bool {
+        !self.rod_end_fracture.is_active()
+    }
+}
+impl SimulationElement for FlightControlActuatorFailures {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.jam.accept(visitor);
+        self.runaway.accept(visitor);
+        self.disconnect.accept(visitor);
+        self.rod_end_fracture.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
 pub struct HydraulicLinearActuatorAssembly<const N: usize> {
     linear_actuators: [LinearActuator; N],
     rigid_body: LinearActuatedRigidBodyOnHingeAxis,
+    actuator_failures: Vec<FlightControlActuatorFailures>,
 }
 impl<const N: usize> HydraulicLinearActuatorAssembly<N> {
     pub fn new(
@@ -1320,6 +1440,24 @@ impl<const N: usize> HydraulicLinearActuatorAssembly<N> {
         Self {
             linear_actuators,
             rigid_body,
+            actuator_failures: vec![],
+        }
+    }
+
+    /// Assembly of a flight control surface, where each actuator can be failed
+    /// through its own jam, runaway, disconnect and rod end fracture failures
+    pub fn new_with_failures(
+        linear_actuators: [LinearActuator; N],
+        rigid_body: LinearActuatedRigidBodyOnHingeAxis,
+        actuator_ids: [FlightControlActuatorId; N],
+    ) -> Self {
+        Self {
+            linear_actuators,
+            rigid_body,
+            actuator_failures: actuator_ids
+                .iter()
+                .map(|id| FlightControlActuatorFailures::new(*id))
+                .collect(),
         }
     }
 
@@ -1344,11 +1482,23 @@ impl<const N: usize> HydraulicLinearActuatorAssembly<N> {
               + ElectroHydrostaticPowered],
         current_pressure: [Pressure; N],
     ) {
+        let body_position_normalized = self.rigid_body.position_normalized();
+        for failures in &mut self.actuator_failures {
+            failures.update(body_position_normalized);
+        }
+
         for (index, actuator) in self.linear_actuators.iter_mut().enumerate() {
+            let requested_position = self.actuator_failures.get(index).map_or(
+                assembly_controllers[index].requested_position(),
+                |failures| {
+                    failures.requested_position(assembly_controllers[index].requested_position())
+                },
+            );
+
             actuator.set_position_target(
                 self.rigid_body
                     .linear_actuator_pos_normalized_from_angular_position_normalized(
-                        assembly_controllers[index].requested_position(),
+                        requested_position,
                     ),
             );
         }
@@ -1364,13 +1514,20 @@ impl<const N: usize> HydraulicLinearActuatorAssembly<N> {
                     &mut self.rigid_body,
                     &assembly_controllers[index],
                     current_pressure[index],
+                    self.actuator_failures.get(index),
                 );
             }
 
             self.rigid_body.update(context);
 
-            for actuator in &mut self.linear_actuators {
-                actuator.update_after_rigid_body(context, &self.rigid_body);
+            for (index, actuator) in self.linear_actuators.iter_mut().enumerate() {
+                actuator.update_after_rigid_body(
+                    context,
+                    &self.rigid_body,
+                    self.actuator_failures
+                        .get(index)
+                        .map_or(true, |failures| failures.is_connected_to_body()),
+                );
             }
         } else {
             self.rigid_body.update(context);
@@ -1394,6 +1551,19 @@ impl<const N: usize> HydraulicLinearActuatorAssembly<N> {
             }
         }
 
+        // A jammed actuator still linked to the body holds it where the jam occurred
+        if no_lock {
+            if let Some(jammed_position) = self
+                .actuator_failures
+                .iter()
+                .find_map(|failures| failures.jammed_position())
+            {
+                self.rigid_body.lock_at_position_normalized(jammed_position);
+
+                no_lock = false;
+            }
+        }
+
         if no_lock {
             self.rigid_body.unlock();
         }
@@ -1462,6 +1632,7 @@ impl<const N: usize> HydraulicLinearActuatorAssembly<N> {
 impl SimulationElement for HydraulicLinearActuatorAssembly<1> {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         accept_iterable!(self.linear_actuators, visitor);
+        accept_iterable!(self.actuator_failures, visitor);
 
         visitor.visit(self);
     }
@@ -1478,6 +1649,7 @@ impl Debug for HydraulicLinearActuatorAssembly<1> {
 impl SimulationElement for HydraulicLinearActuatorAssembly<2> {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         accept_iterable!(self.linear_actuators, visitor);
+        accept_iterable!(self.actuator_failures, visitor);
 
         visitor.visit(self);
     }
@@ -1494,6 +1666,7 @@ impl Debug for HydraulicLinearActuatorAssembly<2> {
 impl SimulationElement for HydraulicLinearActuatorAssembly<3> {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         accept_iterable!(self.linear_actuators, visitor);
+        accept_iterable!(self.actuator_failures, visitor);
 
         visitor.visit(self);
     }
@@ -1958,8 +2131,8 @@ mod tests {
     use crate::electrical::ElectricalBus;
     use crate::electrical::Electricity;
 
-    use crate::shared::PowerConsumptionReport;
     use crate::shared::{update_iterator::MaxStepLoop, PotentialOrigin};
+    use crate::shared::{FlightControlSurfaceId, PowerConsumptionReport};
     use crate::simulation::test::{ElementCtorFn, SimulationTestBed, TestBed, WriteByName};
     use crate::simulation::{Aircraft, InitContext, SimulationElement};
     use std::time::Duration;
@@ -3480,6 +3653,136 @@ mod tests {
         assert!(test_bed.query(|a| a.body_position()) > Ratio::new::<ratio>(0.95));
     }
 
+    #[test]
+    fn aileron_jammed_actuator_holds_surface_in_place() {
+        let mut test_bed = SimulationTestBed::new(|context| {
+            let tested_object = aileron_assembly_with_failures(context, false);
+            TestAircraft::new(context, tested_object)
+        });
+
+        test_bed.command(|a| a.command_unlock());
+        test_bed.command(|a| {
+            a.set_pressures([Pressure::new::<psi>(3000.), Pressure::new::<psi>(3000.)])
+        });
+        test_bed.command(|a| a.command_active_damping_mode(0));
+        test_bed.command(|a| a.command_position_control(Ratio::new::<ratio>(0.5), 1));
+        test_bed.run_with_delta(Duration::from_secs_f64(1.));
+
+        test_bed.fail(FailureType::FlightControlActuatorJam(
+            LEFT_AILERON_ACTUATOR_IDS[1],
+        ));
+        test_bed.command(|a| a.command_position_control(Ratio::new::<ratio>(1.), 1));
+        test_bed.run_with_delta(Duration::from_secs_f64(2.));
+
+        assert!(test_bed.query(|a| a.is_locked()));
+        assert!(test_bed.query(|a| a.body_position()) > Ratio::new::<ratio>(0.45));
+        assert!(test_bed.query(|a| a.body_position()) < Ratio::new::<ratio>(0.55));
+
+        test_bed.unfail(FailureType::FlightControlActuatorJam(
+            LEFT_AILERON_ACTUATOR_IDS[1],
+        ));
+        test_bed.run_with_delta(Duration::from_secs_f64(1.));
+
+        assert!(test_bed.query(|a| a.body_position()) > Ratio::new::<ratio>(0.95));
+    }
+
+    #[test]
+    fn aileron_runaway_actuator_drives_surface_hardover() {
+        let mut test_bed = SimulationTestBed::new(|context| {
+            let tested_object = aileron_assembly_with_failures(context, false);
+            TestAircraft::new(context, tested_object)
+        });
+
+        test_bed.command(|a| a.command_unlock());
+        test_bed.command(|a| {
+            a.set_pressures([Pressure::new::<psi>(3000.), Pressure::new::<psi>(3000.)])
+        });
+        test_bed.command(|a| a.command_active_damping_mode(0));
+        test_bed.command(|a| a.command_position_control(Ratio::new::<ratio>(0.5), 1));
+        test_bed.run_with_delta(Duration::from_secs_f64(1.));
+
+        test_bed.fail(FailureType::FlightControlActuatorRunaway(
+            LEFT_AILERON_ACTUATOR_IDS[1],
+        ));
+        test_bed.run_with_delta(Duration::from_secs_f64(1.));
+
+        let position = test_bed.query(|a| a.body_position());
+        assert!(position < Ratio::new::<ratio>(0.05) || position > Ratio::new::<ratio>(0.95));
+    }
+
+    #[test]
+    fn aileron_runaway_actuator_takes_control_from_damping_mode() {
+        let mut test_bed = SimulationTestBed::new(|context| {
+            let tested_object = aileron_assembly_with_failures(context, false);
+            TestAircraft::new(context, tested_object)
+        });
+
+        test_bed.command(|a| a.command_unlock());
+        test_bed.command(|a| {
+            a.set_pressures([Pressure::new::<psi>(3000.), Pressure::new::<psi>(3000.)])
+        });
+        test_bed.command(|a| a.command_active_damping_mode(0));
+        test_bed.command(|a| a.command_active_damping_mode(1));
+        test_bed.fail(FailureType::FlightControlActuatorRunaway(
+            LEFT_AILERON_ACTUATOR_IDS[0],
+        ));
+        test_bed.run_with_delta(Duration::from_secs_f64(2.));
+
+        let position = test_bed.query(|a| a.body_position());
+        assert!(position < Ratio::new::<ratio>(0.05) || position > Ratio::new::<ratio>(0.95));
+    }
+
+    #[test]
+    fn aileron_floats_down_when_controlling_actuator_disconnected() {
+        let mut test_bed = SimulationTestBed::new(|context| {
+            let tested_object = aileron_assembly_with_failures(context, false);
+            TestAircraft::new(context, tested_object)
+        });
+
+        test_bed.command(|a| a.command_unlock());
+        test_bed.command(|a| {
+            a.set_pressures([Pressure::new::<psi>(3000.), Pressure::new::<psi>(3000.)])
+        });
+        test_bed.command(|a| a.command_active_damping_mode(0));
+        test_bed.command(|a| a.command_position_control(Ratio::new::<ratio>(0.8), 1));
+        test_bed.run_with_delta(Duration::from_secs_f64(1.));
+        assert!(test_bed.query(|a| a.body_position()) > Ratio::new::<ratio>(0.75));
+
+        test_bed.fail(FailureType::FlightControlActuatorDisconnect(
+            LEFT_AILERON_ACTUATOR_IDS[1],
+        ));
+        test_bed.run_with_delta(Duration::from_secs_f64(5.));
+
+        assert!(test_bed.query(|a| a.body_position()) < Ratio::new::<ratio>(0.1));
+    }
+
+    #[test]
+    fn aileron_actuator_with_broken_rod_end_does_not_move_surface_nor_use_fluid() {
+        let mut test_bed = SimulationTestBed::new(|context| {
+            let tested_object = aileron_assembly_with_failures(context, false);
+            TestAircraft::new(context, tested_object)
+        });
+
+        test_bed.command(|a| a.command_unlock());
+        test_bed.command(|a| {
+            a.set_pressures([Pressure::new::<psi>(3000.), Pressure::new::<psi>(3000.)])
+        });
+        test_bed.command(|a| a.command_active_damping_mode(0));
+        test_bed.command(|a| a.command_position_control(Ratio::new::<ratio>(0.8), 1));
+        test_bed.run_with_delta(Duration::from_secs_f64(1.));
+
+        test_bed.fail(FailureType::FlightControlActuatorRodEndFracture(
+            LEFT_AILERON_ACTUATOR_IDS[1],
+        ));
+        test_bed.run_with_delta(Duration::from_secs_f64(0.1));
+        let used_volume = test_bed.query(|a| a.actuator_used_volume(1));
+
+        test_bed.run_with_delta(Duration::from_secs_f64(5.));
+
+        assert!(test_bed.query(|a| a.body_position()) < Ratio::new::<ratio>(0.1));
+        assert!(test_bed.query(|a| a.actuator_used_volume(1)) == used_volume);
+    }
+
     #[test]
     fn elevator_position_control_is_stable_with_all_actuators_in_control() {
         let mut test_bed = SimulationTestBed::new(|context| {
@@ -4459,6 +4762,25 @@ mod tests {
         HydraulicLinearActuatorAssembly::new([actuator, actuator], rigid_body)
     }
 
+    const LEFT_AILERON_ACTUATOR_IDS: [FlightControlActuatorId; 2] = [
+        FlightControlActuatorId::new(FlightControlSurfaceId::LeftAileron(1), 1),
+        FlightControlActuatorId::new(FlightControlSurfaceId::LeftAileron(1), 2),
+    ];
+
+    fn aileron_assembly_with_failures(
+        context: &mut InitContext,
+        is_init_down: bool,
+    ) -> HydraulicLinearActuatorAssembly<2> {
+        let rigid_body = aileron_body(is_init_down);
+        let actuator = aileron_actuator(context, &rigid_body);
+
+        HydraulicLinearActuatorAssembly::new_with_failures(
+            [actuator, actuator],
+            rigid_body,
+            LEFT_AILERON_ACTUATOR_IDS,
+        )
+    }
+
     fn aileron_actuator(
         context: &mut InitContext,
         bounded_linear_length: &impl BoundedLinearLength,
@@ -2,16 +2,18 @@ use self::brake_circuit::BrakeAccumulatorCharacteristics;
 use self::linear_actuator::Actuator;
 use crate::failures::{Failure, FailureType};
 use crate::hydraulic::{
-    electrical_pump_physics::ElectricalPumpPhysics, pumps::PumpCharacteristics,
+    electrical_pump_physics::ElectricalPumpPhysics,
+    pumps::{PumpCharacteristics, PumpWear},
 };
 use crate::pneumatic::PressurizeableReservoir;
 use crate::wind_turbine::WindTurbine;
 
 use crate::physics::{GravityEffect, WobblePhysics};
 use crate::shared::{
-    interpolation, low_pass_filter::LowPassFilter, random_from_normal_distribution,
-    random_from_range, AirbusElectricPumpId, AirbusEngineDrivenPumpId, DelayedTrueLogicGate,
-    ElectricalBusType, ElectricalBuses, HydraulicColor, RamAirTurbineController, SectionPressure,
+    calculate_towards_target_temperature, interpolation, low_pass_filter::LowPassFilter,
+    random_from_normal_distribution, random_from_range, AirbusElectricPumpId,
+    AirbusEngineDrivenPumpId, DelayedTrueLogicGate, ElectricalBusType, ElectricalBuses,
+    HydraulicColor, RamAirTurbineController, SectionPressure,
 };
 use crate::simulation::{
     InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -27,6 +29,7 @@ use uom::si::{
     length::meter,
     pressure::{pascal, psi},
     ratio::ratio,
+    thermodynamic_temperature::degree_celsius,
     torque::{newton_meter, pound_force_inch},
     volume::{cubic_inch, cubic_meter, gallon},
     volume_rate::{gallon_per_minute, gallon_per_second},
@@ -81,9 +84,25 @@ pub trait PressureSource {
     fn displacement(&self) -> Volume;
 }
 
+/// State of the fluid of a hydraulic circuit that the pumps and actuators are sensitive to.
+pub trait HydraulicFluid: HeatingElement {
+    /// Share of the nominal flow that can pass through pumps and actuators at current fluid viscosity
+    fn viscosity_efficiency(&self) -> Ratio;
+
+    /// Amount of particles and degradation products in the fluid. 0 is clean, 1 fully contaminated
+    fn contamination(&self) -> Ratio;
+}
+
 pub struct Fluid {
+    temperature_id: VariableIdentifier,
+    contamination_id: VariableIdentifier,
+
     current_bulk: Pressure,
     heat_state: HeatingProperties,
+
+    temperature: ThermodynamicTemperature,
+    is_temperature_initialised: bool,
+    contamination: Ratio,
 }
 impl Fluid {
     const HEATING_TIME_CONSTANT_MEAN_S: f64 = 40.;
@@ -92,8 +111,25 @@ impl Fluid {
     const COOLING_TIME_CONSTANT: Duration = Duration::from_secs(60 * 3);
     const DAMAGE_TIME_CONSTANT: Duration = Duration::from_secs(60 * 3);
 
-    pub fn new(bulk: Pressure) -> Self {
+    // Temperature the fluid stabilises at above ambient when circulating, and when overheating
+    const OPERATING_TEMPERATURE_RISE_DEGREE_CELSIUS: f64 = 45.;
+    const OVERHEAT_TEMPERATURE_RISE_DEGREE_CELSIUS: f64 = 110.;
+
+    const WARMING_RATE_DEGREE_CELSIUS_PER_S: f64 = 0.1;
+    const COOLING_RATE_DEGREE_CELSIUS_PER_S: f64 = 0.03;
+
+    // Thick cold fluid restricts flow through pumps and actuators. Above 0°C viscosity has no effect
+    const VISCOSITY_TEMPERATURE_BREAKPOINTS_DEGREE_CELSIUS: [f64; 5] = [-60., -40., -20., -10., 0.];
+    const VISCOSITY_EFFICIENCY_MAP: [f64; 5] = [0.4, 0.6, 0.85, 0.95, 1.];
+
+    // Overheated fluid breaks down and pollutes the circuit
+    const CONTAMINATION_PER_HOUR_OVERHEATING: f64 = 0.5;
+
+    pub fn new(context: &mut InitContext, id: HydraulicColor, bulk: Pressure) -> Self {
         Self {
+            temperature_id: context.get_identifier(format!("HYD_{}_FLUID_TEMPERATURE", id)),
+            contamination_id: context.get_identifier(format!("HYD_{}_FLUID_CONTAMINATION", id)),
+
             current_bulk: bulk,
             heat_state: HeatingProperties::new(
                 Duration::from_secs_f64(
@@ -106,6 +142,10 @@ impl Fluid {
                 Self::COOLING_TIME_CONSTANT,
                 Self::DAMAGE_TIME_CONSTANT,
             ),
+
+            temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
+            is_temperature_initialised: false,
+            contamination: Ratio::default(),
         }
     }
 
@@ -113,8 +153,56 @@ impl Fluid {
         self.current_bulk
     }
 
-    fn update(&mut self, context: &UpdateContext, is_heating: bool) {
+    pub fn temperature(&self) -> ThermodynamicTemperature {
+        self.temperature
+    }
+
+    fn update(&mut self, context: &UpdateContext, is_heating: bool, is_circulating: bool) {
         self.heat_state.update(context, is_heating);
+
+        self.update_temperature(context, is_circulating);
+
+        if self.heat_state.is_overheating() {
+            self.contamination = Ratio::new::<ratio>(
+                (self.contamination.get::<ratio>()
+                    + Self::CONTAMINATION_PER_HOUR_OVERHEATING * context.delta_as_secs_f64()
+                        / 3600.)
+                    .min(1.),
+            );
+        }
+    }
+
+    fn update_temperature(&mut self, context: &UpdateContext, is_circulating: bool) {
+        let ambient = context.ambient_temperature();
+
+        // Fluid is cold soaked at ambient temperature when the aircraft is loaded
+        if !self.is_temperature_initialised {
+            self.temperature = ambient;
+            self.is_temperature_initialised = true;
+        }
+
+        let target_temperature = if self.heat_state.is_overheating() {
+            ThermodynamicTemperature::new::<degree_celsius>(
+                ambient.get::<degree_celsius>() + Self::OVERHEAT_TEMPERATURE_RISE_DEGREE_CELSIUS,
+            )
+        } else if is_circulating {
+            ThermodynamicTemperature::new::<degree_celsius>(
+                ambient.get::<degree_celsius>() + Self::OPERATING_TEMPERATURE_RISE_DEGREE_CELSIUS,
+            )
+        } else {
+            ambient
+        };
+
+        self.temperature = calculate_towards_target_temperature(
+            self.temperature,
+            target_temperature,
+            if target_temperature > self.temperature {
+                Self::WARMING_RATE_DEGREE_CELSIUS_PER_S
+            } else {
+                Self::COOLING_RATE_DEGREE_CELSIUS_PER_S
+            },
+            context.delta(),
+        );
     }
 }
 impl HeatingElement for Fluid {
@@ -126,6 +214,30 @@ impl HeatingElement for Fluid {
         self.heat_state.is_damaged()
     }
 }
+impl HydraulicFluid for Fluid {
+    fn viscosity_efficiency(&self) -> Ratio {
+        Ratio::new::<ratio>(interpolation(
+            &Self::VISCOSITY_TEMPERATURE_BREAKPOINTS_DEGREE_CELSIUS,
+            &Self::VISCOSITY_EFFICIENCY_MAP,
+            self.temperature.get::<degree_celsius>(),
+        ))
+    }
+
+    fn contamination(&self) -> Ratio {
+        self.contamination
+    }
+}
+impl SimulationElement for Fluid {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        let contamination: f64 = reader.read(&self.contamination_id);
+        self.contamination = Ratio::new::<ratio>(contamination.clamp(0., 1.));
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.temperature_id, self.temperature);
+        writer.write(&self.contamination_id, self.contamination.get::<ratio>());
+    }
+}
 
 #[derive(PartialEq, Eq, Clone, Copy)]
 pub enum PressureSwitchState {
@@ -348,6 +460,9 @@ pub struct PowerTransferUnit {
     efficiency: Ratio,
 
     heat_state: HeatingProperties,
+
+    failure: Failure,
+    jam_failure: Failure,
 }
 impl PowerTransferUnit {
     const MIN_SPEED_SIMULATION_RPM: f64 = 50.;
@@ -450,6 +565,9 @@ impl PowerTransferUnit {
                 Self::COOLING_TIME_CONSTANT,
                 Self::DAMAGE_TIME_CONSTANT,
             ),
+
+            failure: Failure::new(FailureType::PowerTransferUnitFailure),
+            jam_failure: Failure::new(FailureType::PowerTransferUnitJam),
         }
     }
 
@@ -476,7 +594,8 @@ impl PowerTransferUnit {
         loop_right_section: &impl SectionPressure,
         controller: &impl PowerTransferUnitController,
     ) {
-        self.is_enabled = controller.should_enable();
+        // Failed PTU control valve stays closed whatever the controller asks
+        self.is_enabled = controller.should_enable() && !self.failure.is_active();
 
         self.update_displacement(context, loop_left_section, loop_right_section);
         self.update_shaft_physics(context, loop_left_section, loop_right_section);
@@ -597,6 +716,7 @@ impl PowerTransferUnit {
         let total_torque = friction_torque + left_side_torque + right_side_torque;
 
         if !self.heat_state.is_damaged()
+            && !self.jam_failure.is_active()
             && (self.is_rotating()
                 || total_torque.abs().get::<newton_meter>() > Self::BREAKOUT_TORQUE_NM)
         {
@@ -718,6 +838,13 @@ impl PowerTransferUnit {
     }
 }
 impl SimulationElement for PowerTransferUnit {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+        self.jam_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.valve_opened_id, self.is_enabled());
         writer.write(
@@ -987,14 +1114,6 @@ impl HydraulicCircuit {
 
     const FLUID_BULK_MODULUS_PASCAL: f64 = 1450000000.0;
 
-    // TODO firevalves are actually powered by a sub-bus (401PP DC ESS)
-    const DEFAULT_FIRE_VALVE_POWERING_BUS: ElectricalBusType =
-        ElectricalBusType::DirectCurrentEssential;
-
-    // TODO leak meas valves are actually powered by a sub-bus (Bus 601PP)
-    const DEFAULT_LEAK_MEASUREMENT_VALVE_POWERING_BUS: ElectricalBusType =
-        ElectricalBusType::DirectCurrentGndFltService;
-
     pub fn new(
         context: &mut InitContext,
         id: HydraulicColor,
@@ -1016,6 +1135,9 @@ impl HydraulicCircuit {
         priority_valve: PriorityValve,
         system_accumulator_precharge: Pressure,
         system_accumulator_volume: Volume,
+
+        fire_valve_powering_bus: ElectricalBusType,
+        leak_measurement_valve_powering_bus: ElectricalBusType,
     ) -> Self {
         assert!(number_of_pump_sections > 0);
 
@@ -1029,7 +1151,7 @@ impl HydraulicCircuit {
                 context,
                 id,
                 pump_id,
-                Self::DEFAULT_FIRE_VALVE_POWERING_BUS,
+                fire_valve_powering_bus,
             ));
 
             pump_sections.push(Section::new(
@@ -1083,7 +1205,8 @@ impl HydraulicCircuit {
                 connected_to_ptu_left_side,
                 connected_to_ptu_right_side,
                 Some(LeakMeasurementValve::new(
-                    Self::DEFAULT_LEAK_MEASUREMENT_VALVE_POWERING_BUS,
+                    id,
+                    leak_measurement_valve_powering_bus,
                 )),
                 Some(priority_valve),
             ),
@@ -1112,7 +1235,11 @@ impl HydraulicCircuit {
             },
             pump_sections_check_valves: pump_to_system_check_valves,
             pump_section_routed_to_auxiliary_section: pump_section_to_auxiliary,
-            fluid: Fluid::new(Pressure::new::<pascal>(Self::FLUID_BULK_MODULUS_PASCAL)),
+            fluid: Fluid::new(
+                context,
+                id,
+                Pressure::new::<pascal>(Self::FLUID_BULK_MODULUS_PASCAL),
+            ),
             reservoir,
             circuit_target_pressure,
         }
@@ -1123,11 +1250,13 @@ impl HydraulicCircuit {
     }
 
     pub fn update_system_actuator_volumes(&mut self, actuator: &mut impl Actuator) {
+        actuator.set_fluid_viscosity_efficiency(self.fluid.viscosity_efficiency());
         self.system_section.update_actuator_volumes(actuator);
     }
 
     pub fn update_auxiliary_actuator_volumes(&mut self, actuator: &mut impl Actuator) {
         if let Some(auxiliary_section) = self.auxiliary_section.as_mut() {
+            actuator.set_fluid_viscosity_efficiency(self.fluid.viscosity_efficiency());
             auxiliary_section.update_actuator_volumes(actuator);
         } else {
             panic!("No auxiliary section available but an actuator was provided")
@@ -1145,28 +1274,35 @@ impl HydraulicCircuit {
         reservoir_pressure: Pressure,
     ) {
         let mut any_pump_is_overheating = false;
+        let mut any_pump_is_flowing = false;
         for pump in main_section_pumps.iter() {
-            if pump.flow().get::<gallon_per_second>() > 0.01 && pump.is_overheating() {
-                any_pump_is_overheating = true;
+            if pump.flow().get::<gallon_per_second>() > 0.01 {
+                any_pump_is_flowing = true;
+                any_pump_is_overheating |= pump.is_overheating();
             }
         }
 
         if let Some(pump) = system_section_pump.as_ref() {
-            if pump.flow().get::<gallon_per_second>() > 0.01 && pump.is_overheating() {
-                any_pump_is_overheating = true;
+            if pump.flow().get::<gallon_per_second>() > 0.01 {
+                any_pump_is_flowing = true;
+                any_pump_is_overheating |= pump.is_overheating();
             }
         }
 
         if let Some(pump) = auxiliary_section_pump.as_ref() {
-            if pump.flow().get::<gallon_per_second>() > 0.01 && pump.is_overheating() {
-                any_pump_is_overheating = true;
+            if pump.flow().get::<gallon_per_second>() > 0.01 {
+                any_pump_is_flowing = true;
+                any_pump_is_overheating |= pump.is_overheating();
             }
         }
 
         let ptu_overheats_fluid = ptu.map_or(false, |p| p.is_overheating() && p.is_rotating());
 
-        self.fluid
-            .update(context, ptu_overheats_fluid || any_pump_is_overheating);
+        self.fluid.update(
+            context,
+            ptu_overheats_fluid || any_pump_is_overheating,
+            any_pump_is_flowing || ptu.map_or(false, |p| p.is_rotating()),
+        );
 
         self.reservoir
             .update(context, reservoir_pressure, &self.fluid);
@@ -1463,6 +1599,7 @@ impl HydraulicCircuit {
 }
 impl SimulationElement for HydraulicCircuit {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.fluid.accept(visitor);
         self.reservoir.accept(visitor);
 
         for section in &mut self.pump_sections {
@@ -1510,6 +1647,8 @@ pub struct Section {
     fire_valve: Option<FireValve>,
 
     accumulator: Option<Accumulator>,
+    accumulator_precharge_loss_failure: Option<Failure>,
+    accumulator_precharge_loss_failure_active_previous_state: bool,
 
     connected_to_ptu_left_side: bool,
     connected_to_ptu_right_side: bool,
@@ -1529,6 +1668,9 @@ pub struct Section {
     total_actuator_returned_volume: Volume,
 }
 impl Section {
+    const ACCUMULATOR_PRECHARGE_LOSS_GRADIENT_PSI_PER_S: f64 = 20.;
+    const ACCUMULATOR_PRECHARGE_LOSS_MIN_PRESSURE_PSI: f64 = 50.;
+
     pub fn new(
         context: &mut InitContext,
         loop_id: HydraulicColor,
@@ -1562,6 +1704,12 @@ impl Section {
             current_pressure: Pressure::new::<psi>(14.7),
             current_flow: VolumeRate::new::<gallon_per_second>(0.),
             fire_valve,
+            accumulator_precharge_loss_failure: if accumulator.is_some() {
+                Some(Failure::new(FailureType::AccumulatorPrechargeLoss(loop_id)))
+            } else {
+                None
+            },
+            accumulator_precharge_loss_failure_active_previous_state: false,
             accumulator,
             connected_to_ptu_left_side,
             connected_to_ptu_right_side,
@@ -1653,6 +1801,8 @@ impl Section {
 
         reservoir.add_return_volume(static_leak);
 
+        self.update_accumulator_precharge(context);
+
         if let Some(accumulator) = &mut self.accumulator {
             accumulator.update(
                 context,
@@ -1674,6 +1824,33 @@ impl Section {
         self.reset_actuator_volumes();
     }
 
+    fn update_accumulator_precharge(&mut self, context: &UpdateContext) {
+        if let Some((precharge_failure, accumulator)) = self
+            .accumulator_precharge_loss_failure
+            .as_ref()
+            .zip(self.accumulator.as_mut())
+        {
+            if precharge_failure.is_active() {
+                let new_precharge_after_leak = (accumulator.gas_precharge_pressure()
+                    - Pressure::new::<psi>(
+                        context.delta_as_secs_f64()
+                            * Self::ACCUMULATOR_PRECHARGE_LOSS_GRADIENT_PSI_PER_S,
+                    ))
+                .max(Pressure::new::<psi>(
+                    Self::ACCUMULATOR_PRECHARGE_LOSS_MIN_PRESSURE_PSI,
+                ));
+
+                accumulator.set_gas_precharge_pressure(new_precharge_after_leak);
+            } else if self.accumulator_precharge_loss_failure_active_previous_state {
+                // Clearing the failure acts as a maintenance action recharging nitrogen to nominal
+                accumulator.reset_gas_precharge_pressure_to_nominal();
+            }
+
+            self.accumulator_precharge_loss_failure_active_previous_state =
+                precharge_failure.is_active();
+        }
+    }
+
     fn update_actuator_volumes(&mut self, actuator: &mut impl Actuator) {
         self.total_actuator_consumed_volume += actuator.used_volume();
         self.total_actuator_returned_volume += actuator.reservoir_return();
@@ -1815,6 +1992,14 @@ impl SimulationElement for Section {
             leak_meas_valve.accept(visitor);
         }
 
+        if let Some(priority_valve) = &mut self.priority_valve {
+            priority_valve.accept(visitor);
+        }
+
+        if let Some(failure) = &mut self.accumulator_precharge_loss_failure {
+            failure.accept(visitor);
+        }
+
         visitor.visit(self);
     }
 
@@ -1858,6 +2043,8 @@ pub struct FireValve {
     is_open: bool,
     bus_type: ElectricalBusType,
     is_powered: bool,
+
+    stuck_failure: Failure,
 }
 impl FireValve {
     fn new(
@@ -1876,13 +2063,15 @@ impl FireValve {
             is_open: true,
             bus_type,
             is_powered: false,
+
+            stuck_failure: Failure::new(FailureType::FireShutoffValveStuck(hyd_loop_id, pump_id)),
         }
     }
 
     /// Updates opening state:
-    /// A firevalve will move if powered, stay at current position if unpowered
+    /// A firevalve will move if powered, stay at current position if unpowered or if its motor failed
     fn update(&mut self, valve_open_command: bool) {
-        if self.is_powered {
+        if self.is_powered && !self.stuck_failure.is_active() {
             self.is_open = valve_open_command;
         }
     }
@@ -1892,12 +2081,17 @@ impl FireValve {
     }
 }
 impl SimulationElement for FireValve {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.stuck_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.opened_id, self.is_open());
     }
 
     fn receive_power(&mut self, buses: &impl ElectricalBuses) {
-        // TODO is actually powered by a sub-bus (401PP DC ESS)
         self.is_powered = buses.is_powered(self.bus_type);
     }
 }
@@ -1987,11 +2181,17 @@ pub struct PriorityValve {
 
     upstream_pressure: Pressure,
     downstream_pressure: Pressure,
+
+    stuck_failure: Failure,
 }
 impl PriorityValve {
     const VALVE_RESPONSE_TIME_CONSTANT: Duration = Duration::from_millis(5);
 
-    pub fn new(fully_closed_threshold: Pressure, fully_opened_threshold: Pressure) -> Self {
+    pub fn new(
+        hyd_loop_id: HydraulicColor,
+        fully_closed_threshold: Pressure,
+        fully_opened_threshold: Pressure,
+    ) -> Self {
         Self {
             open_ratio: LowPassFilter::<Ratio>::new(Self::VALVE_RESPONSE_TIME_CONSTANT),
 
@@ -2000,13 +2200,18 @@ impl PriorityValve {
 
             upstream_pressure: Pressure::default(),
             downstream_pressure: Pressure::default(),
+
+            stuck_failure: Failure::new(FailureType::PriorityValveStuck(hyd_loop_id)),
         }
     }
 
     fn update(&mut self, context: &UpdateContext, upstream_pressure: Pressure) {
         self.upstream_pressure = upstream_pressure;
 
-        self.update_open_state(context);
+        // A stuck spool keeps its last opening whatever the upstream pressure
+        if !self.stuck_failure.is_active() {
+            self.update_open_state(context);
+        }
 
         self.update_downstream_pressure();
     }
@@ -2032,6 +2237,13 @@ impl PriorityValve {
         self.downstream_pressure
     }
 }
+impl SimulationElement for PriorityValve {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.stuck_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
 
 pub struct LeakMeasurementValve {
     open_ratio: LowPassFilter<Ratio>,
@@ -2041,17 +2253,20 @@ pub struct LeakMeasurementValve {
 
     upstream_pressure: Pressure,
     downstream_pressure: Pressure,
+
+    stuck_failure: Failure,
 }
 impl LeakMeasurementValve {
     const VALVE_RESPONSE_TIME_CONSTANT: Duration = Duration::from_millis(500);
 
-    fn new(powered_by: ElectricalBusType) -> Self {
+    fn new(hyd_loop_id: HydraulicColor, powered_by: ElectricalBusType) -> Self {
         Self {
             open_ratio: LowPassFilter::<Ratio>::new(Self::VALVE_RESPONSE_TIME_CONSTANT),
             is_powered: false,
             powered_by,
             upstream_pressure: Pressure::default(),
             downstream_pressure: Pressure::default(),
+            stuck_failure: Failure::new(FailureType::LeakMeasurementValveStuck(hyd_loop_id)),
         }
     }
 
@@ -2063,7 +2278,9 @@ impl LeakMeasurementValve {
     ) {
         self.upstream_pressure = upstream_pressure;
 
-        self.update_open_state(context, valve_controller);
+        if !self.stuck_failure.is_active() {
+            self.update_open_state(context, valve_controller);
+        }
 
         self.update_downstream_pressure();
     }
@@ -2097,6 +2314,12 @@ impl LeakMeasurementValve {
     }
 }
 impl SimulationElement for LeakMeasurementValve {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.stuck_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn receive_power(&mut self, buses: &impl ElectricalBuses) {
         self.is_powered = buses.is_powered(self.powered_by);
     }
@@ -2238,6 +2461,9 @@ pub struct Reservoir {
 
     total_return_flow: VolumeRate,
     total_return_volume: Volume,
+
+    fluid_viscosity_efficiency: Ratio,
+    fluid_contamination: Ratio,
 }
 impl Reservoir {
     const MIN_USABLE_VOLUME_GAL: f64 = 0.2;
@@ -2293,6 +2519,9 @@ impl Reservoir {
             ),
             total_return_flow: VolumeRate::default(),
             total_return_volume: Volume::default(),
+
+            fluid_viscosity_efficiency: Ratio::new::<ratio>(1.),
+            fluid_contamination: Ratio::default(),
         }
     }
 
@@ -2300,9 +2529,11 @@ impl Reservoir {
         &mut self,
         context: &UpdateContext,
         air_pressure: Pressure,
-        fluid: &impl HeatingElement,
+        fluid: &impl HydraulicFluid,
     ) {
         self.air_pressure = air_pressure;
+        self.fluid_viscosity_efficiency = fluid.viscosity_efficiency();
+        self.fluid_contamination = fluid.contamination();
 
         self.update_return_flow(context);
         self.update_heat(context, fluid);
@@ -2401,6 +2632,14 @@ impl Reservoir {
         self.air_pressure
     }
 
+    fn fluid_viscosity_efficiency(&self) -> Ratio {
+        self.fluid_viscosity_efficiency
+    }
+
+    fn fluid_contamination(&self) -> Ratio {
+        self.fluid_contamination
+    }
+
     fn is_empty(&self) -> bool {
         self.fluid_level_reachable_by_pumps() <= Volume::new::<gallon>(0.01)
     }
@@ -2469,6 +2708,8 @@ pub struct Pump {
     speed: AngularVelocity,
 
     cavitation_efficiency: Ratio,
+
+    wear: Option<PumpWear>,
 }
 impl Pump {
     const SECONDS_PER_MINUTES: f64 = 60.;
@@ -2477,6 +2718,10 @@ impl Pump {
     const MAX_DISPLACEMENT_FILTER_TIME_CONSTANT: Duration = Duration::from_millis(150);
 
     fn new(pump_characteristics: PumpCharacteristics) -> Self {
+        Self::new_with_wear(pump_characteristics, None)
+    }
+
+    fn new_with_wear(pump_characteristics: PumpCharacteristics, wear: Option<PumpWear>) -> Self {
         Self {
             delta_vol_max: Volume::new::<gallon>(0.),
             current_displacement: Volume::new::<gallon>(0.),
@@ -2489,6 +2734,8 @@ impl Pump {
             speed: AngularVelocity::new::<revolution_per_minute>(0.),
 
             cavitation_efficiency: Ratio::new::<ratio>(1.),
+
+            wear,
         }
     }
 
@@ -2503,12 +2750,15 @@ impl Pump {
         self.speed = speed;
 
         self.update_cavitation(reservoir);
+        self.update_wear(context, reservoir);
 
         let theoretical_displacement = self.calculate_displacement(section, controller);
 
         self.current_max_displacement.update(
             context.delta(),
             self.cavitation_efficiency
+                * reservoir.fluid_viscosity_efficiency()
+                * self.wear_efficiency()
                 * theoretical_displacement
                 * controller.max_displacement_restriction(),
         );
@@ -2534,6 +2784,28 @@ impl Pump {
         };
     }
 
+    fn update_wear(&mut self, context: &UpdateContext, reservoir: &Reservoir) {
+        let is_operating = self.speed
+            > self
+                .pump_characteristics
+                .min_speed_for_non_zero_efficiency();
+
+        if let Some(wear) = self.wear.as_mut() {
+            wear.update(
+                context,
+                is_operating,
+                self.cavitation_efficiency,
+                reservoir.fluid_contamination(),
+            );
+        }
+    }
+
+    fn wear_efficiency(&self) -> Ratio {
+        self.wear.as_ref().map_or(Ratio::new::<ratio>(1.), |wear| {
+            wear.displacement_efficiency()
+        })
+    }
+
     fn calculate_displacement<T: PumpController>(
         &self,
         section: &impl SectionPressure,
@@ -2600,6 +2872,21 @@ impl Pump {
     fn cavitation_efficiency(&self) -> Ratio {
         self.cavitation_efficiency
     }
+
+    fn wear(&self) -> Ratio {
+        self.wear
+            .as_ref()
+            .map_or(Ratio::default(), |wear| wear.wear())
+    }
+}
+impl SimulationElement for Pump {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        if let Some(wear) = self.wear.as_mut() {
+            wear.accept(visitor);
+        }
+
+        visitor.visit(self);
+    }
 }
 impl PressureSource for Pump {
     fn delta_vol_max(&self) -> Volume {
@@ -2651,7 +2938,10 @@ impl ElectricPump {
         Self {
             cavitation_id: context.get_identifier(format!("HYD_{}_EPUMP_CAVITATION", id)),
             overheat_id: context.get_identifier(format!("HYD_{}_EPUMP_OVHT", id)),
-            pump: Pump::new(pump_characteristics),
+            pump: Pump::new_with_wear(
+                pump_characteristics,
+                Some(PumpWear::new(context, format!("HYD_{}_EPUMP_WEAR", id))),
+            ),
             pump_physics: ElectricalPumpPhysics::new(
                 context,
                 id,
@@ -2693,6 +2983,10 @@ impl ElectricPump {
     pub fn speed(&self) -> AngularVelocity {
         self.pump.speed
     }
+
+    pub fn wear(&self) -> Ratio {
+        self.pump.wear()
+    }
 }
 impl PressureSource for ElectricPump {
     fn delta_vol_max(&self) -> Volume {
@@ -2725,6 +3019,7 @@ impl PressureSource for ElectricPump {
 impl SimulationElement for ElectricPump {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.pump_physics.accept(visitor);
+        self.pump.accept(visitor);
 
         visitor.visit(self);
     }
@@ -2778,7 +3073,10 @@ impl EngineDrivenPump {
             active_id: context.get_identifier(format!("HYD_{}_EDPUMP_ACTIVE", id)),
             is_active: false,
             speed: AngularVelocity::new::<revolution_per_minute>(0.),
-            pump: Pump::new(pump_characteristics),
+            pump: Pump::new_with_wear(
+                pump_characteristics,
+                Some(PumpWear::new(context, format!("HYD_{}_EDPUMP_WEAR", id))),
+            ),
             overheat_failure: Failure::new(FailureType::EnginePumpOverheat(id)),
             heat_state: HeatingProperties::new(
                 Duration::from_secs_f64(
@@ -2824,6 +3122,10 @@ impl EngineDrivenPump {
 
         self.is_active = controller.should_pressurise();
     }
+
+    pub fn wear(&self) -> Ratio {
+        self.pump.wear()
+    }
 }
 impl PressureSource for EngineDrivenPump {
     fn delta_vol_max(&self) -> Volume {
@@ -2856,6 +3158,7 @@ impl PressureSource for EngineDrivenPump {
 impl SimulationElement for EngineDrivenPump {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.overheat_failure.accept(visitor);
+        self.pump.accept(visitor);
         visitor.visit(self);
     }
 
@@ -3234,12 +3537,15 @@ impl SimulationElement for HydraulicValve {
 #[cfg(test)]
 mod tests {
     use crate::simulation::test::{
-        ElementCtorFn, ReadByName, SimulationTestBed, TestBed, WriteByName,
+        ElementCtorFn, ReadByName, SimulationTestBed, TestAircraft, TestBed, WriteByName,
     };
     use crate::simulation::InitContext;
     use ntest::assert_about_eq;
 
-    use uom::si::{f64::*, pressure::psi, ratio::percent, volume::gallon};
+    use uom::si::{
+        f64::*, pressure::psi, ratio::percent, thermodynamic_temperature::degree_celsius,
+        volume::gallon,
+    };
 
     use super::*;
 
@@ -3260,8 +3566,94 @@ mod tests {
             self.is_hot
         }
     }
+    impl HydraulicFluid for TestFluid {
+        fn viscosity_efficiency(&self) -> Ratio {
+            Ratio::new::<ratio>(1.)
+        }
+
+        fn contamination(&self) -> Ratio {
+            Ratio::default()
+        }
+    }
+
+    struct TestHydraulicCircuitController {
+        should_open_leak_measurement_valve: bool,
+    }
+    impl TestHydraulicCircuitController {
+        fn with_leak_measurement_valve_open(should_open_leak_measurement_valve: bool) -> Self {
+            Self {
+                should_open_leak_measurement_valve,
+            }
+        }
+    }
+    impl HydraulicCircuitController for TestHydraulicCircuitController {
+        fn should_open_fire_shutoff_valve(&self, _: usize) -> bool {
+            true
+        }
 
-    impl SimulationElement for PriorityValve {}
+        fn should_open_leak_measurement_valve(&self) -> bool {
+            self.should_open_leak_measurement_valve
+        }
+    }
+
+    struct TestPowerTransferUnitController {
+        should_enable: bool,
+    }
+    impl TestPowerTransferUnitController {
+        fn enabled(should_enable: bool) -> Self {
+            Self { should_enable }
+        }
+    }
+    impl PowerTransferUnitController for TestPowerTransferUnitController {
+        fn should_enable(&self) -> bool {
+            self.should_enable
+        }
+    }
+
+    struct TestPowerTransferUnitCharacteristics;
+    impl PowerTransferUnitCharacteristics for TestPowerTransferUnitCharacteristics {
+        fn efficiency(&self) -> Ratio {
+            Ratio::new::<ratio>(0.8)
+        }
+
+        fn deactivation_delta_pressure(&self) -> Pressure {
+            Pressure::new::<psi>(200.)
+        }
+
+        fn activation_delta_pressure(&self) -> Pressure {
+            Pressure::new::<psi>(500.)
+        }
+
+        fn shot_to_shot_variability(&self) -> Ratio {
+            Ratio::default()
+        }
+    }
+
+    struct TestHydraulicSection {
+        pressure: Pressure,
+    }
+    impl TestHydraulicSection {
+        fn new(pressure: Pressure) -> Self {
+            Self { pressure }
+        }
+    }
+    impl SectionPressure for TestHydraulicSection {
+        fn pressure(&self) -> Pressure {
+            self.pressure
+        }
+
+        fn pressure_downstream_leak_valve(&self) -> Pressure {
+            self.pressure
+        }
+
+        fn pressure_downstream_priority_valve(&self) -> Pressure {
+            self.pressure
+        }
+
+        fn is_pressure_switch_pressurised(&self) -> bool {
+            self.pressure > Pressure::new::<psi>(1700.)
+        }
+    }
 
     #[test]
     fn section_writes_its_state() {
@@ -3349,7 +3741,10 @@ mod tests {
     #[test]
     fn leak_measurement_valve_init_with_zero_pressures() {
         let test_bed = SimulationTestBed::from(ElementCtorFn(|_| {
-            LeakMeasurementValve::new(ElectricalBusType::DirectCurrentEssential)
+            LeakMeasurementValve::new(
+                HydraulicColor::Green,
+                ElectricalBusType::DirectCurrentEssential,
+            )
         }));
 
         assert!(test_bed.query_element(|e| e.downstream_pressure == Pressure::new::<psi>(0.)));
@@ -3359,7 +3754,11 @@ mod tests {
     #[test]
     fn priority_valve_init_with_zero_pressures() {
         let test_bed = SimulationTestBed::from(ElementCtorFn(|_| {
-            PriorityValve::new(Pressure::new::<psi>(1500.), Pressure::new::<psi>(2000.))
+            PriorityValve::new(
+                HydraulicColor::Green,
+                Pressure::new::<psi>(1500.),
+                Pressure::new::<psi>(2000.),
+            )
         }));
 
         assert!(test_bed.query_element(|e| e.downstream_pressure() == Pressure::new::<psi>(0.)));
@@ -3369,7 +3768,11 @@ mod tests {
     #[test]
     fn priority_valve_opened_with_pressure() {
         let mut test_bed = SimulationTestBed::from(ElementCtorFn(|_| {
-            PriorityValve::new(Pressure::new::<psi>(1500.), Pressure::new::<psi>(2000.))
+            PriorityValve::new(
+                HydraulicColor::Green,
+                Pressure::new::<psi>(1500.),
+                Pressure::new::<psi>(2000.),
+            )
         }));
 
         test_bed.set_update_after_power_distribution(|valve, context| {
@@ -3384,7 +3787,11 @@ mod tests {
     #[test]
     fn priority_valve_not_fully_opened_with_lower_pressure() {
         let mut test_bed = SimulationTestBed::from(ElementCtorFn(|_| {
-            PriorityValve::new(Pressure::new::<psi>(1500.), Pressure::new::<psi>(2000.))
+            PriorityValve::new(
+                HydraulicColor::Green,
+                Pressure::new::<psi>(1500.),
+                Pressure::new::<psi>(2000.),
+            )
         }));
 
         test_bed.set_update_after_power_distribution(|valve, context| {
@@ -3400,7 +3807,11 @@ mod tests {
     #[test]
     fn priority_valve_closed_with_under_threshold_pressure() {
         let mut test_bed = SimulationTestBed::from(ElementCtorFn(|_| {
-            PriorityValve::new(Pressure::new::<psi>(1500.), Pressure::new::<psi>(2000.))
+            PriorityValve::new(
+                HydraulicColor::Green,
+                Pressure::new::<psi>(1500.),
+                Pressure::new::<psi>(2000.),
+            )
         }));
 
         test_bed.set_update_after_power_distribution(|valve, context| {
@@ -3413,6 +3824,274 @@ mod tests {
         assert!(test_bed.query_element(|e| e.downstream_pressure() >= Pressure::new::<psi>(0.)));
     }
 
+    #[test]
+    fn priority_valve_stuck_open_does_not_close_under_threshold_pressure() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(|_| {
+            PriorityValve::new(
+                HydraulicColor::Green,
+                Pressure::new::<psi>(1500.),
+                Pressure::new::<psi>(2000.),
+            )
+        }));
+
+        test_bed.set_update_after_power_distribution(|valve, context| {
+            valve.update(context, Pressure::new::<psi>(3000.))
+        });
+        test_bed.run_multiple_frames(Duration::from_secs(2));
+
+        test_bed.fail(FailureType::PriorityValveStuck(HydraulicColor::Green));
+        test_bed.set_update_after_power_distribution(|valve, context| {
+            valve.update(context, Pressure::new::<psi>(1450.))
+        });
+        test_bed.run_multiple_frames(Duration::from_secs(2));
+
+        assert!(test_bed.query_element(|e| e.downstream_pressure() >= Pressure::new::<psi>(1400.)));
+    }
+
+    #[test]
+    fn priority_valve_stuck_closed_does_not_open_with_pressure() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(|_| {
+            PriorityValve::new(
+                HydraulicColor::Green,
+                Pressure::new::<psi>(1500.),
+                Pressure::new::<psi>(2000.),
+            )
+        }));
+
+        test_bed.fail(FailureType::PriorityValveStuck(HydraulicColor::Green));
+        test_bed.set_update_after_power_distribution(|valve, context| {
+            valve.update(context, Pressure::new::<psi>(3000.))
+        });
+        test_bed.run_multiple_frames(Duration::from_secs(2));
+
+        assert!(test_bed.query_element(|e| e.downstream_pressure() <= Pressure::new::<psi>(50.)));
+    }
+
+    #[test]
+    fn fire_valve_closes_when_powered_and_commanded_closed() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(|context| {
+            FireValve::new(
+                context,
+                HydraulicColor::Green,
+                1,
+                ElectricalBusType::DirectCurrentEssential,
+            )
+        }));
+
+        test_bed.set_update_after_power_distribution(|valve, _| {
+            valve.is_powered = true;
+            valve.update(false)
+        });
+        test_bed.run();
+
+        assert!(!test_bed.query_element(|e| e.is_open()));
+    }
+
+    #[test]
+    fn fire_valve_stuck_open_stays_open_when_fire_pb_released() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(|context| {
+            FireValve::new(
+                context,
+                HydraulicColor::Green,
+                1,
+                ElectricalBusType::DirectCurrentEssential,
+            )
+        }));
+
+        test_bed.fail(FailureType::FireShutoffValveStuck(HydraulicColor::Green, 1));
+        test_bed.set_update_after_power_distribution(|valve, _| {
+            valve.is_powered = true;
+            valve.update(false)
+        });
+        test_bed.run();
+
+        assert!(test_bed.query_element(|e| e.is_open()));
+
+        let is_opened: bool = test_bed.read_by_name("HYD_GREEN_PUMP_1_FIRE_VALVE_OPENED");
+        assert!(is_opened);
+    }
+
+    #[test]
+    fn fire_valve_stuck_closed_does_not_reopen_when_fire_pb_pushed_back_in() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(|context| {
+            FireValve::new(
+                context,
+                HydraulicColor::Green,
+                1,
+                ElectricalBusType::DirectCurrentEssential,
+            )
+        }));
+
+        test_bed.set_update_after_power_distribution(|valve, _| {
+            valve.is_powered = true;
+            valve.update(false)
+        });
+        test_bed.run();
+
+        test_bed.fail(FailureType::FireShutoffValveStuck(HydraulicColor::Green, 1));
+        test_bed.set_update_after_power_distribution(|valve, _| {
+            valve.is_powered = true;
+            valve.update(true)
+        });
+        test_bed.run();
+
+        assert!(!test_bed.query_element(|e| e.is_open()));
+    }
+
+    #[test]
+    fn leak_measurement_valve_stuck_open_does_not_close_when_commanded() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(|_| {
+            LeakMeasurementValve::new(
+                HydraulicColor::Green,
+                ElectricalBusType::DirectCurrentGndFltService,
+            )
+        }));
+
+        test_bed.set_update_after_power_distribution(|valve, context| {
+            valve.is_powered = true;
+            valve.update(
+                context,
+                Pressure::new::<psi>(3000.),
+                &TestHydraulicCircuitController::with_leak_measurement_valve_open(true),
+            )
+        });
+        test_bed.run_multiple_frames(Duration::from_secs(2));
+
+        test_bed.fail(FailureType::LeakMeasurementValveStuck(
+            HydraulicColor::Green,
+        ));
+        test_bed.set_update_after_power_distribution(|valve, context| {
+            valve.is_powered = true;
+            valve.update(
+                context,
+                Pressure::new::<psi>(3000.),
+                &TestHydraulicCircuitController::with_leak_measurement_valve_open(false),
+            )
+        });
+        test_bed.run_multiple_frames(Duration::from_secs(2));
+
+        assert!(test_bed.query_element(|e| e.downstream_pressure() >= Pressure::new::<psi>(2800.)));
+    }
+
+    #[test]
+    fn leak_measurement_valve_stuck_closed_does_not_open_when_commanded() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(|_| {
+            LeakMeasurementValve::new(
+                HydraulicColor::Green,
+                ElectricalBusType::DirectCurrentGndFltService,
+            )
+        }));
+
+        test_bed.set_update_after_power_distribution(|valve, context| {
+            valve.is_powered = true;
+            valve.update(
+                context,
+                Pressure::new::<psi>(3000.),
+                &TestHydraulicCircuitController::with_leak_measurement_valve_open(false),
+            )
+        });
+        test_bed.run_multiple_frames(Duration::from_secs(2));
+
+        test_bed.fail(FailureType::LeakMeasurementValveStuck(
+            HydraulicColor::Green,
+        ));
+        test_bed.set_update_after_power_distribution(|valve, context| {
+            valve.is_powered = true;
+            valve.update(
+                context,
+                Pressure::new::<psi>(3000.),
+                &TestHydraulicCircuitController::with_leak_measurement_valve_open(true),
+            )
+        });
+        test_bed.run_multiple_frames(Duration::from_secs(2));
+
+        assert!(test_bed.query_element(|e| e.downstream_pressure() <= Pressure::new::<psi>(50.)));
+    }
+
+    #[test]
+    fn ptu_transfers_flow_with_delta_pressure() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(power_transfer_unit));
+
+        test_bed.set_update_after_power_distribution(|ptu, context| {
+            ptu.update(
+                context,
+                &TestHydraulicSection::new(Pressure::new::<psi>(3000.)),
+                &TestHydraulicSection::new(Pressure::new::<psi>(1000.)),
+                &TestPowerTransferUnitController::enabled(true),
+            )
+        });
+        test_bed.run_multiple_frames(Duration::from_secs(2));
+
+        assert!(test_bed.query_element(|e| e.flow() > VolumeRate::default()));
+        assert!(test_bed.query_element(|e| e.is_active_left_to_right()));
+    }
+
+    #[test]
+    fn failed_ptu_is_disabled_and_transfers_no_flow() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(power_transfer_unit));
+
+        test_bed.fail(FailureType::PowerTransferUnitFailure);
+        test_bed.set_update_after_power_distribution(|ptu, context| {
+            ptu.update(
+                context,
+                &TestHydraulicSection::new(Pressure::new::<psi>(3000.)),
+                &TestHydraulicSection::new(Pressure::new::<psi>(1000.)),
+                &TestPowerTransferUnitController::enabled(true),
+            )
+        });
+        test_bed.run_multiple_frames(Duration::from_secs(2));
+
+        assert!(!test_bed.query_element(|e| e.is_enabled()));
+        assert!(test_bed.query_element(|e| e.flow() == VolumeRate::default()));
+    }
+
+    #[test]
+    fn jammed_ptu_transfers_no_flow_and_shaft_stays_stopped() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(power_transfer_unit));
+
+        test_bed.fail(FailureType::PowerTransferUnitJam);
+        test_bed.set_update_after_power_distribution(|ptu, context| {
+            ptu.update(
+                context,
+                &TestHydraulicSection::new(Pressure::new::<psi>(3000.)),
+                &TestHydraulicSection::new(Pressure::new::<psi>(1000.)),
+                &TestPowerTransferUnitController::enabled(true),
+            )
+        });
+        test_bed.run_multiple_frames(Duration::from_secs(2));
+
+        assert!(test_bed.query_element(|e| e.is_enabled()));
+        assert!(test_bed.query_element(|e| e.flow() == VolumeRate::default()));
+        assert!(!test_bed.query_element(|e| e.is_active_left_to_right()));
+
+        let shaft_rpm: f64 = test_bed.read_by_name("HYD_PTU_SHAFT_RPM");
+        assert_about_eq!(shaft_rpm, 0.);
+    }
+
+    #[test]
+    fn accumulator_precharge_leaks_down_to_minimum_and_is_restored_when_failure_clears() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(|context| {
+            section_with_accumulator(context, HydraulicColor::Green)
+        }));
+        test_bed.set_update_after_power_distribution(|section, context| {
+            section.update_accumulator_precharge(context)
+        });
+
+        test_bed.fail(FailureType::AccumulatorPrechargeLoss(HydraulicColor::Green));
+        test_bed.run_multiple_frames(Duration::from_secs(10));
+
+        assert!((accumulator_precharge(&mut test_bed).get::<psi>() - 1685.).abs() < 1.);
+
+        test_bed.run_multiple_frames(Duration::from_secs(120));
+
+        assert_about_eq!(accumulator_precharge(&mut test_bed).get::<psi>(), 50.);
+
+        test_bed.unfail(FailureType::AccumulatorPrechargeLoss(HydraulicColor::Green));
+        test_bed.run();
+
+        assert_about_eq!(accumulator_precharge(&mut test_bed).get::<psi>(), 1885.);
+    }
+
     #[test]
     fn reservoir_reports_only_gaugeable_volume() {
         let mut test_bed = SimulationTestBed::from(ElementCtorFn(|context| {
@@ -3639,6 +4318,150 @@ mod tests {
         assert!(!is_overheating);
     }
 
+    #[test]
+    fn fluid_is_cold_soaked_at_ambient_temperature() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(fluid));
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-40.));
+        test_bed.set_update_after_power_distribution(|fluid, context| {
+            fluid.update(context, false, false)
+        });
+
+        test_bed.run();
+
+        let temperature: ThermodynamicTemperature =
+            test_bed.read_by_name("HYD_GREEN_FLUID_TEMPERATURE");
+        assert_about_eq!(temperature.get::<degree_celsius>(), -40.);
+        assert!(test_bed.query_element(|f| f.viscosity_efficiency()) < Ratio::new::<ratio>(0.7));
+    }
+
+    #[test]
+    fn fluid_has_full_viscosity_efficiency_above_zero_degree() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(fluid));
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+        test_bed.set_update_after_power_distribution(|fluid, context| {
+            fluid.update(context, false, false)
+        });
+
+        test_bed.run();
+
+        assert_about_eq!(
+            test_bed
+                .query_element(|f| f.viscosity_efficiency())
+                .get::<ratio>(),
+            1.
+        );
+    }
+
+    #[test]
+    fn circulating_cold_fluid_warms_up_to_full_viscosity_efficiency() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(fluid));
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-20.));
+        test_bed.set_update_after_power_distribution(|fluid, context| {
+            fluid.update(context, false, true)
+        });
+
+        test_bed.run();
+        assert!(test_bed.query_element(|f| f.viscosity_efficiency()) < Ratio::new::<ratio>(1.));
+
+        test_bed.run_multiple_frames(Duration::from_secs(60 * 10));
+
+        let temperature: ThermodynamicTemperature =
+            test_bed.read_by_name("HYD_GREEN_FLUID_TEMPERATURE");
+        assert!(temperature.get::<degree_celsius>() > 20.);
+        assert_about_eq!(
+            test_bed
+                .query_element(|f| f.viscosity_efficiency())
+                .get::<ratio>(),
+            1.
+        );
+    }
+
+    #[test]
+    fn overheating_fluid_gets_contaminated() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(fluid));
+        test_bed.set_update_after_power_distribution(|fluid, context| {
+            fluid.update(context, true, true)
+        });
+
+        test_bed.run_multiple_frames(Duration::from_secs(60 * 10));
+
+        let contamination: f64 = test_bed.read_by_name("HYD_GREEN_FLUID_CONTAMINATION");
+        assert!(contamination > 0.);
+    }
+
+    #[test]
+    fn fluid_contamination_is_read_back_from_simulation() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(fluid));
+        test_bed.set_update_after_power_distribution(|fluid, context| {
+            fluid.update(context, false, true)
+        });
+
+        test_bed.write_by_name("HYD_GREEN_FLUID_CONTAMINATION", 0.3);
+        test_bed.run();
+
+        assert_about_eq!(
+            test_bed.query_element(|f| f.contamination()).get::<ratio>(),
+            0.3
+        );
+    }
+
+    #[test]
+    fn pump_wear_builds_up_faster_with_contaminated_fluid() {
+        let mut clean_test_bed = SimulationTestBed::from(ElementCtorFn(pump_wear));
+        clean_test_bed.set_update_after_power_distribution(|wear, context| {
+            wear.update(context, true, Ratio::new::<ratio>(1.), Ratio::default())
+        });
+
+        let mut contaminated_test_bed = SimulationTestBed::from(ElementCtorFn(pump_wear));
+        contaminated_test_bed.set_update_after_power_distribution(|wear, context| {
+            wear.update(
+                context,
+                true,
+                Ratio::new::<ratio>(1.),
+                Ratio::new::<ratio>(1.),
+            )
+        });
+
+        clean_test_bed.run_with_delta(Duration::from_secs(3600));
+        contaminated_test_bed.run_with_delta(Duration::from_secs(3600));
+
+        let clean_wear: f64 = clean_test_bed.read_by_name("HYD_GREEN_EPUMP_WEAR");
+        let contaminated_wear: f64 = contaminated_test_bed.read_by_name("HYD_GREEN_EPUMP_WEAR");
+        assert!(clean_wear > 0.);
+        assert!(contaminated_wear > 10. * clean_wear);
+    }
+
+    #[test]
+    fn pump_wear_does_not_build_up_when_not_operating() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(pump_wear));
+        test_bed.set_update_after_power_distribution(|wear, context| {
+            wear.update(context, false, Ratio::default(), Ratio::new::<ratio>(1.))
+        });
+
+        test_bed.run_with_delta(Duration::from_secs(3600));
+
+        let wear: f64 = test_bed.read_by_name("HYD_GREEN_EPUMP_WEAR");
+        assert_about_eq!(wear, 0.);
+    }
+
+    #[test]
+    fn worn_pump_read_from_simulation_has_reduced_displacement_efficiency() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(pump_wear));
+        test_bed.set_update_after_power_distribution(|wear, context| {
+            wear.update(context, false, Ratio::new::<ratio>(1.), Ratio::default())
+        });
+
+        test_bed.write_by_name("HYD_GREEN_EPUMP_WEAR", 1.);
+        test_bed.run();
+
+        assert_about_eq!(
+            test_bed
+                .query_element(|w| w.displacement_efficiency())
+                .get::<ratio>(),
+            0.7
+        );
+    }
+
     fn section(
         context: &mut InitContext,
         loop_id: HydraulicColor,
@@ -3649,7 +4472,7 @@ mod tests {
             context,
             loop_id,
             pump_id,
-            HydraulicCircuit::DEFAULT_FIRE_VALVE_POWERING_BUS,
+            ElectricalBusType::DirectCurrentEssential,
         ));
         Section::new(
             context,
@@ -3668,15 +4491,64 @@ mod tests {
             false,
             false,
             Some(LeakMeasurementValve::new(
-                HydraulicCircuit::DEFAULT_LEAK_MEASUREMENT_VALVE_POWERING_BUS,
+                loop_id,
+                ElectricalBusType::DirectCurrentGndFltService,
             )),
             Some(PriorityValve::new(
+                loop_id,
                 Pressure::new::<psi>(1500.),
                 Pressure::new::<psi>(2000.),
             )),
         )
     }
 
+    fn section_with_accumulator(context: &mut InitContext, loop_id: HydraulicColor) -> Section {
+        Section::new(
+            context,
+            loop_id,
+            "SYSTEM",
+            1,
+            VolumeRate::new::<gallon_per_second>(
+                HydraulicCircuit::SYSTEM_SECTION_STATIC_LEAK_GAL_P_S,
+            ),
+            Volume::new::<gallon>(1.),
+            Volume::new::<gallon>(1.),
+            Some(Accumulator::new_system_accumulator(
+                Pressure::new::<psi>(1885.),
+                Volume::new::<gallon>(0.264),
+                Volume::new::<gallon>(0.),
+                Pressure::new::<psi>(3000.),
+            )),
+            Pressure::new::<psi>(1400.),
+            Pressure::new::<psi>(2000.),
+            None,
+            false,
+            false,
+            None,
+            None,
+        )
+    }
+
+    fn accumulator_precharge(test_bed: &mut SimulationTestBed<TestAircraft<Section>>) -> Pressure {
+        test_bed.command_element(|s| s.accumulator.as_mut().unwrap().gas_precharge_pressure())
+    }
+
+    fn power_transfer_unit(context: &mut InitContext) -> PowerTransferUnit {
+        PowerTransferUnit::new(context, &TestPowerTransferUnitCharacteristics)
+    }
+
+    fn fluid(context: &mut InitContext) -> Fluid {
+        Fluid::new(
+            context,
+            HydraulicColor::Green,
+            Pressure::new::<pascal>(HydraulicCircuit::FLUID_BULK_MODULUS_PASCAL),
+        )
+    }
+
+    fn pump_wear(context: &mut InitContext) -> PumpWear {
+        PumpWear::new(context, "HYD_GREEN_EPUMP_WEAR".to_owned())
+    }
+
     fn reservoir(
         context: &mut InitContext,
         hyd_loop_id: HydraulicColor,
@@ -3712,8 +4584,11 @@ mod tests {
             Volume::new::<gallon>(3.),
         );
 
-        let priority_valve =
-            PriorityValve::new(Pressure::new::<psi>(1500.), Pressure::new::<psi>(2000.));
+        let priority_valve = PriorityValve::new(
+            loop_color,
+            Pressure::new::<psi>(1500.),
+            Pressure::new::<psi>(2000.),
+        );
 
         HydraulicCircuit::new(
             context,
@@ -3733,6 +4608,8 @@ mod tests {
             priority_valve,
             Pressure::new::<psi>(1885.),
             Volume::new::<gallon>(0.264),
+            ElectricalBusType::DirectCurrentEssential,
+            ElectricalBusType::DirectCurrentGndFltService,
         )
     }
 
@@ -2,9 +2,10 @@ use std::time::Duration;
 use uom::si::{f64::*, ratio::ratio, volume::gallon};
 
 use crate::{
+    failures::{Failure, FailureType},
     shared::{
         low_pass_filter::LowPassFilter, random_from_normal_distribution, ElectricalBusType,
-        ElectricalBuses, ReverserPosition,
+        ElectricalBuses, ReverserDoorId, ReverserPosition,
     },
     simulation::{SimulationElement, SimulationElementVisitor, UpdateContext},
 };
@@ -20,6 +21,7 @@ struct ReverserActuator {
     nominal_speed: f64,
 
     nominal_pressure: Pressure,
+    speed_to_hyd_flow_gain: f64,
 
     volume_to_actuator_accumulator: Volume,
     volume_to_res_accumulator: Volume,
@@ -30,9 +32,12 @@ impl ReverserActuator {
 
     const SPEED_TIME_CONSTANT: Duration = Duration::from_millis(250);
 
-    const SPEED_TO_HYD_FLOW_GAIN: f64 = 0.0005;
+    // Opening speed of an unlocked door pushed by aerodynamic loads, and the opening at which
+    // aerodynamic loads are balanced by the door itself
+    const AERODYNAMIC_OPENING_SPEED_RATIO_PER_S: f64 = 0.05;
+    const MAX_AERODYNAMIC_OPENING_RATIO: f64 = 0.1;
 
-    fn new(nominal_pressure: Pressure) -> Self {
+    fn new(nominal_pressure: Pressure, speed_to_hyd_flow_gain: f64) -> Self {
         Self {
             position: Ratio::default(),
             current_speed: LowPassFilter::new(Self::SPEED_TIME_CONSTANT),
@@ -41,6 +46,7 @@ impl ReverserActuator {
                 Self::SPEED_RATIO_STD_DEVIATION,
             ),
             nominal_pressure,
+            speed_to_hyd_flow_gain,
 
             volume_to_actuator_accumulator: Volume::default(),
             volume_to_res_accumulator: Volume::default(),
@@ -52,8 +58,14 @@ impl ReverserActuator {
         context: &UpdateContext,
         pressure: Pressure,
         is_mechanically_locked: bool,
+        is_pushed_by_aerodynamic_loads: bool,
     ) {
-        self.update_current_speed(context, pressure, is_mechanically_locked);
+        self.update_current_speed(
+            context,
+            pressure,
+            is_mechanically_locked,
+            is_pushed_by_aerodynamic_loads,
+        );
 
         self.position += context.delta_as_secs_f64() * self.current_speed.output();
 
@@ -76,18 +88,30 @@ impl ReverserActuator {
         context: &UpdateContext,
         pressure: Pressure,
         is_mechanically_locked: bool,
+        is_pushed_by_aerodynamic_loads: bool,
     ) {
         if is_mechanically_locked {
             self.current_speed.reset(Ratio::default());
         } else {
-            self.current_speed
-                .update(context.delta(), self.max_speed_from_pressure(pressure));
+            let mut speed = self.max_speed_from_pressure(pressure);
+
+            // Without hydraulic pressure holding it stowed, airflow slowly opens an unlocked door
+            if is_pushed_by_aerodynamic_loads
+                && speed.get::<ratio>() >= 0.
+                && self.position.get::<ratio>() < Self::MAX_AERODYNAMIC_OPENING_RATIO
+            {
+                speed = speed.max(Ratio::new::<ratio>(
+                    Self::AERODYNAMIC_OPENING_SPEED_RATIO_PER_S,
+                ));
+            }
+
+            self.current_speed.update(context.delta(), speed);
         }
     }
 
     fn update_flow(&mut self) {
         let volume_used = Volume::new::<gallon>(
-            self.current_speed.output().get::<ratio>().abs() * Self::SPEED_TO_HYD_FLOW_GAIN,
+            self.current_speed.output().get::<ratio>().abs() * self.speed_to_hyd_flow_gain,
         );
         self.volume_to_actuator_accumulator += volume_used;
         self.volume_to_res_accumulator += volume_used;
@@ -132,10 +156,16 @@ impl ElectricalLock {
         }
     }
 
-    fn update(&mut self, controller: &impl ReverserInterface, actuator_position: Ratio) {
+    fn update(
+        &mut self,
+        controller: &impl ReverserInterface,
+        actuator_position: Ratio,
+        is_failed_unlocked: bool,
+    ) {
         let is_locking = !controller.should_unlock() || !self.is_powered;
 
-        self.is_locked = is_locking && actuator_position.get::<ratio>() < 0.01;
+        self.is_locked =
+            is_locking && !is_failed_unlocked && actuator_position.get::<ratio>() < 0.01;
     }
 
     fn is_locked(&self) -> bool {
@@ -194,6 +224,77 @@ impl SimulationElement for DirectionalValve {
     }
 }
 
+/// A single reverser door (translating sleeve or pivoting door) with its own actuator,
+/// tertiary lock and proximity sensors.
+struct ReverserDoor {
+    electrical_lock: ElectricalLock,
+    actuator: ReverserActuator,
+
+    unlocked_failure: Failure,
+}
+impl ReverserDoor {
+    const STOWED_PROXIMITY_SENSOR_THRESHOLD_RATIO: f64 = 0.01;
+    const DEPLOYED_PROXIMITY_SENSOR_THRESHOLD_RATIO: f64 = 0.95;
+
+    fn new(
+        id: ReverserDoorId,
+        nominal_hydraulic_pressure: Pressure,
+        speed_to_hyd_flow_gain: f64,
+        electrical_lock_powered_by: ElectricalBusType,
+    ) -> Self {
+        Self {
+            electrical_lock: ElectricalLock::new(electrical_lock_powered_by),
+            actuator: ReverserActuator::new(nominal_hydraulic_pressure, speed_to_hyd_flow_gain),
+
+            unlocked_failure: Failure::new(FailureType::ReverserDoorUnlocked(id)),
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        controller: &impl ReverserInterface,
+        actuator_pressure: Pressure,
+    ) {
+        self.electrical_lock.update(
+            controller,
+            self.actuator.position(),
+            self.unlocked_failure.is_active(),
+        );
+
+        self.actuator.update(
+            context,
+            actuator_pressure,
+            self.electrical_lock.is_locked(),
+            self.unlocked_failure.is_active() && context.is_in_flight(),
+        );
+    }
+
+    fn position(&self) -> Ratio {
+        self.actuator.position()
+    }
+
+    fn proximity_sensor_stowed(&self) -> bool {
+        self.position().get::<ratio>() < Self::STOWED_PROXIMITY_SENSOR_THRESHOLD_RATIO
+    }
+
+    fn proximity_sensor_deployed(&self) -> bool {
+        self.position().get::<ratio>() > Self::DEPLOYED_PROXIMITY_SENSOR_THRESHOLD_RATIO
+    }
+
+    fn is_unlocked(&self) -> bool {
+        !self.electrical_lock.is_locked() || !self.proximity_sensor_stowed()
+    }
+}
+impl SimulationElement for ReverserDoor {
+    fn accept<V: SimulationElementVisitor>(&mut self, visitor: &mut V) {
+        self.electrical_lock.accept(visitor);
+        self.unlocked_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
 pub trait ReverserInterface {
     fn should_unlock(&self) -> bool;
     fn should_power_valves(&self) -> bool;
@@ -285,12 +386,15 @@ impl SimulationElement for ReverserHydraulicManifold {
 }
 
 pub struct ReverserAssembly {
-    electrical_lock: ElectricalLock,
     hydraulic_manifold: ReverserHydraulicManifold,
-    actuator: ReverserActuator,
+    doors: Vec<ReverserDoor>,
 }
 impl ReverserAssembly {
+    const SPEED_TO_HYD_FLOW_GAIN: f64 = 0.0005;
+
     pub fn new(
+        engine_number: usize,
+        number_of_doors: usize,
         nominal_hydraulic_pressure: Pressure,
         switch_high_threshold_pressure: Pressure,
         switch_low_threshold_pressure: Pressure,
@@ -299,14 +403,22 @@ impl ReverserAssembly {
         hyd_valves_secondary_powered_by: ElectricalBusType,
     ) -> Self {
         Self {
-            electrical_lock: ElectricalLock::new(electrical_lock_powered_by),
             hydraulic_manifold: ReverserHydraulicManifold::new(
                 hyd_valves_primary_powered_by,
                 hyd_valves_secondary_powered_by,
                 switch_high_threshold_pressure,
                 switch_low_threshold_pressure,
             ),
-            actuator: ReverserActuator::new(nominal_hydraulic_pressure),
+            doors: (1..=number_of_doors)
+                .map(|door_number| {
+                    ReverserDoor::new(
+                        ReverserDoorId::new(engine_number, door_number),
+                        nominal_hydraulic_pressure,
+                        Self::SPEED_TO_HYD_FLOW_GAIN / number_of_doors as f64,
+                        electrical_lock_powered_by,
+                    )
+                })
+                .collect(),
         }
     }
 
@@ -316,21 +428,55 @@ impl ReverserAssembly {
         controller: &impl ReverserInterface,
         pressure: Pressure,
     ) {
-        self.electrical_lock
-            .update(controller, self.reverser_position());
-
         self.hydraulic_manifold
             .update(context, pressure, controller);
 
-        self.actuator.update(
-            context,
-            self.hydraulic_manifold.actuator_pressure(),
-            self.electrical_lock.is_locked(),
-        );
+        let actuator_pressure = self.hydraulic_manifold.actuator_pressure();
+        for door in &mut self.doors {
+            door.update(context, controller, actuator_pressure);
+        }
     }
 
     pub fn actuator(&mut self) -> &mut impl Actuator {
-        &mut self.actuator
+        self
+    }
+
+    pub fn number_of_doors(&self) -> usize {
+        self.doors.len()
+    }
+
+    pub fn door_position(&self, door_index: usize) -> Ratio {
+        self.doors[door_index].position()
+    }
+
+    /// A door is unlocked when its tertiary lock is released or it has left its stowed position
+    pub fn door_is_unlocked(&self, door_index: usize) -> bool {
+        self.doors[door_index].is_unlocked()
+    }
+
+    pub fn any_door_unlocked(&self) -> bool {
+        self.doors.iter().any(|door| door.is_unlocked())
+    }
+}
+impl Actuator for ReverserAssembly {
+    fn used_volume(&self) -> Volume {
+        self.doors
+            .iter()
+            .map(|door| door.actuator.used_volume())
+            .sum()
+    }
+
+    fn reservoir_return(&self) -> Volume {
+        self.doors
+            .iter()
+            .map(|door| door.actuator.reservoir_return())
+            .sum()
+    }
+
+    fn reset_volumes(&mut self) {
+        for door in &mut self.doors {
+            door.actuator.reset_volumes();
+        }
     }
 }
 impl ReverserFeedback for ReverserAssembly {
@@ -339,17 +485,17 @@ impl ReverserFeedback for ReverserAssembly {
     }
 
     fn proximity_sensor_all_stowed(&self) -> bool {
-        self.reverser_position().get::<ratio>() < 0.01
+        self.doors.iter().all(|door| door.proximity_sensor_stowed())
     }
 
     fn proximity_sensor_all_deployed(&self) -> bool {
-        self.reverser_position().get::<ratio>() > 0.95
+        self.doors
+            .iter()
+            .all(|door| door.proximity_sensor_deployed())
     }
 
     fn proximity_sensor_at_least_one_stowed(&self) -> bool {
-        // We do not model multiple doors for now, placeholder is a higher threshold for one door stowed only
-        self.reverser_position().get::<ratio>() < 0.2
-            && self.reverser_position().get::<ratio>() >= 0.01
+        self.doors.iter().any(|door| door.proximity_sensor_stowed())
     }
 
     fn pressure_switch_pressurised(&self) -> bool {
@@ -357,18 +503,20 @@ impl ReverserFeedback for ReverserAssembly {
     }
 
     fn tertiary_lock_is_locked(&self) -> bool {
-        self.electrical_lock.is_locked()
+        self.doors
+            .iter()
+            .all(|door| door.electrical_lock.is_locked())
     }
 }
 impl ReverserPosition for ReverserAssembly {
     fn reverser_position(&self) -> Ratio {
-        self.actuator.position()
+        self.doors.iter().map(|door| door.position()).sum::<Ratio>() / self.doors.len() as f64
     }
 }
 impl SimulationElement for ReverserAssembly {
     fn accept<V: SimulationElementVisitor>(&mut self, visitor: &mut V) {
-        self.electrical_lock.accept(visitor);
         self.hydraulic_manifold.accept(visitor);
+        accept_iterable!(self.doors, visitor);
 
         visitor.visit(self);
     }
@@ -459,6 +607,8 @@ mod tests {
                 controller: TestReverserController::default(),
 
                 reverser: ReverserAssembly::new(
+                    1,
+                    2,
                     Pressure::new::<psi>(3000.),
                     Pressure::new::<psi>(2100.),
                     Pressure::new::<psi>(1750.),
@@ -496,7 +646,15 @@ mod tests {
         }
 
         fn reverser_is_locked(&self) -> bool {
-            self.reverser.electrical_lock.is_locked()
+            self.reverser.tertiary_lock_is_locked()
+        }
+
+        fn door_position(&self, door_index: usize) -> Ratio {
+            self.reverser.door_position(door_index)
+        }
+
+        fn door_is_unlocked(&self, door_index: usize) -> bool {
+            self.reverser.door_is_unlocked(door_index)
         }
 
         fn set_hyd_pressure(&mut self, pressure: Pressure) {
@@ -556,7 +714,7 @@ mod tests {
 
                 println!(
                     "Reverser Pos: {:.3} ,Hyds Input/Manifold/Actuator {:.0}/{:.0}/{:.0}",
-                    self.reverser.actuator.position().get::<ratio>(),
+                    self.reverser.reverser_position().get::<ratio>(),
                     self.hydraulic_pressure.get::<psi>(),
                     self.reverser
                         .hydraulic_manifold
@@ -708,4 +866,82 @@ mod tests {
         assert!(test_bed.query(|a| a.reverser_position().get::<ratio>()) <= 0.01);
         assert!(test_bed.query(|a| a.reverser_is_locked()));
     }
+
+    #[test]
+    fn reverser_all_doors_deploy_and_stow() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+
+        test_bed.command(|a| a.set_hyd_pressure(Pressure::new::<psi>(3000.)));
+        test_bed.command(|a| a.set_isolation_valve(false));
+        test_bed.command(|a| a.set_deploy_reverser(true));
+        test_bed.command(|a| a.set_lock_reverser(false));
+
+        test_bed.run_with_delta(Duration::from_millis(1000));
+        assert!(test_bed.query(|a| !a.reverser.proximity_sensor_all_stowed()));
+        assert!(test_bed.query(|a| !a.reverser.proximity_sensor_at_least_one_stowed()));
+        assert!(test_bed.query(|a| !a.reverser.proximity_sensor_all_deployed()));
+
+        test_bed.run_with_delta(Duration::from_millis(2000));
+        assert!(test_bed.query(|a| a.reverser.proximity_sensor_all_deployed()));
+        assert!(test_bed.query(|a| a.door_position(0).get::<ratio>()) >= 0.99);
+        assert!(test_bed.query(|a| a.door_position(1).get::<ratio>()) >= 0.99);
+
+        test_bed.command(|a| a.set_lock_reverser(true));
+        test_bed.command(|a| a.set_deploy_reverser(false));
+        test_bed.run_with_delta(Duration::from_millis(3000));
+
+        assert!(test_bed.query(|a| a.reverser.proximity_sensor_all_stowed()));
+        assert!(test_bed.query(|a| !a.reverser.any_door_unlocked()));
+    }
+
+    #[test]
+    fn reverser_door_unlocked_failure_on_ground_releases_only_this_door_lock() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.set_on_ground(true);
+
+        test_bed.fail(FailureType::ReverserDoorUnlocked(ReverserDoorId::new(1, 2)));
+        test_bed.run_with_delta(Duration::from_millis(2000));
+
+        assert!(test_bed.query(|a| !a.door_is_unlocked(0)));
+        assert!(test_bed.query(|a| a.door_is_unlocked(1)));
+        assert!(test_bed.query(|a| a.reverser.any_door_unlocked()));
+        assert!(test_bed.query(|a| !a.reverser_is_locked()));
+
+        assert!(test_bed.query(|a| a.reverser.proximity_sensor_all_stowed()));
+        assert!(test_bed.query(|a| a.door_position(1).get::<ratio>()) == 0.);
+    }
+
+    #[test]
+    fn reverser_door_unlocked_failure_in_flight_opens_door_asymmetrically() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.set_on_ground(false);
+
+        test_bed.fail(FailureType::ReverserDoorUnlocked(ReverserDoorId::new(1, 2)));
+        test_bed.run_with_delta(Duration::from_millis(5000));
+
+        assert!(test_bed.query(|a| a.door_position(0).get::<ratio>()) == 0.);
+        assert!(test_bed.query(|a| a.door_position(1).get::<ratio>()) >= 0.05);
+        assert!(test_bed.query(|a| a.door_position(1).get::<ratio>()) <= 0.15);
+
+        assert!(test_bed.query(|a| !a.reverser.proximity_sensor_all_stowed()));
+        assert!(test_bed.query(|a| a.reverser.proximity_sensor_at_least_one_stowed()));
+        assert!(test_bed.query(|a| !a.reverser.proximity_sensor_all_deployed()));
+    }
+
+    #[test]
+    fn reverser_door_unlocked_in_flight_is_stowed_back_with_hydraulic_pressure() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.set_on_ground(false);
+
+        test_bed.fail(FailureType::ReverserDoorUnlocked(ReverserDoorId::new(1, 1)));
+        test_bed.run_with_delta(Duration::from_millis(5000));
+        assert!(test_bed.query(|a| a.door_position(0).get::<ratio>()) >= 0.05);
+
+        test_bed.command(|a| a.set_hyd_pressure(Pressure::new::<psi>(3000.)));
+        test_bed.command(|a| a.set_isolation_valve(false));
+        test_bed.run_with_delta(Duration::from_millis(2000));
+
+        assert!(test_bed.query(|a| a.door_position(0).get::<ratio>()) <= 0.01);
+        assert!(test_bed.query(|a| a.door_is_unlocked(0)));
+    }
 }
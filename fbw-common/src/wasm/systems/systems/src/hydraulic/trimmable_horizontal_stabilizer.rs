@@ -131,19 +131,66 @@ impl DriveMotor {
     }
 }
 
-#[derive(PartialEq, Clone, Copy)]
-enum ElectricalTrimMotorElecBus {
-    _Norm = 0,
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum PitchTrimMotorPowerSupply {
+    Normal = 0,
     Standby = 1,
 }
 
+/// Power supply of a flight computer channel in charge of a pitch trim motor.
+/// Computer uses its normal bus while it is powered, and transfers to its standby bus if normal bus is lost.
+pub struct PitchTrimMotorPowerSupplySelector {
+    normal_bus: ElectricalBusType,
+    standby_bus: Option<ElectricalBusType>,
+
+    selected_supply: PitchTrimMotorPowerSupply,
+    is_powered: bool,
+}
+impl PitchTrimMotorPowerSupplySelector {
+    pub fn new(normal_bus: ElectricalBusType, standby_bus: Option<ElectricalBusType>) -> Self {
+        Self {
+            normal_bus,
+            standby_bus,
+
+            selected_supply: PitchTrimMotorPowerSupply::Normal,
+            is_powered: false,
+        }
+    }
+
+    pub fn selected_supply(&self) -> PitchTrimMotorPowerSupply {
+        self.selected_supply
+    }
+
+    pub fn is_powered(&self) -> bool {
+        self.is_powered
+    }
+}
+impl SimulationElement for PitchTrimMotorPowerSupplySelector {
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        let standby_is_powered = self
+            .standby_bus
+            .map_or(false, |standby_bus| buses.is_powered(standby_bus));
+
+        let (selected_supply, is_powered) = if buses.is_powered(self.normal_bus) {
+            (PitchTrimMotorPowerSupply::Normal, true)
+        } else if standby_is_powered {
+            (PitchTrimMotorPowerSupply::Standby, true)
+        } else {
+            (PitchTrimMotorPowerSupply::Normal, false)
+        };
+
+        self.selected_supply = selected_supply;
+        self.is_powered = is_powered;
+    }
+}
+
 struct ElectricDriveMotor {
     motor: DriveMotor,
 
-    is_powered: bool,
+    is_bus_powered: Vec<bool>,
 
     powered_by_bus_array: Vec<ElectricalBusType>,
-    powered_by_bus: ElectricalTrimMotorElecBus,
+    powered_by_bus: PitchTrimMotorPowerSupply,
 }
 impl ElectricDriveMotor {
     /// Creates an electric motor driving the trim input system.
@@ -161,17 +208,16 @@ impl ElectricDriveMotor {
 
         Self {
             motor: DriveMotor::new(max_speed, speed_error_breakpoint, speed_regulation_coef_map),
-            is_powered: true,
+            is_bus_powered: vec![true; powered_by_bus_array.len()],
 
             powered_by_bus_array,
 
-            // TODO: Init with Standby to select most restrictive case until the bus selection logic is implemented
-            powered_by_bus: ElectricalTrimMotorElecBus::Standby,
+            powered_by_bus: PitchTrimMotorPowerSupply::Normal,
         }
     }
 
     fn set_active_state(&mut self, is_active: bool) {
-        self.motor.set_active_state(is_active && self.is_powered);
+        self.motor.set_active_state(is_active && self.is_powered());
     }
 
     fn update(
@@ -189,21 +235,26 @@ impl ElectricDriveMotor {
     }
 
     /// Selects which bus powers the motor (if more than one bus available for that motor)
-    // TODO: The logic to select this bus is not implemented yet in flight computers
-    fn _set_power_bus_in_use(&mut self, elec_bus_in_use: ElectricalTrimMotorElecBus) {
+    fn set_power_bus_in_use(&mut self, elec_bus_in_use: PitchTrimMotorPowerSupply) {
         self.powered_by_bus = elec_bus_in_use;
     }
 
     fn is_powered(&self) -> bool {
-        self.is_powered
+        let bus_selected_index: usize =
+            (self.powered_by_bus as usize).min(self.powered_by_bus_array.len() - 1);
+
+        self.is_bus_powered[bus_selected_index]
     }
 }
 impl SimulationElement for ElectricDriveMotor {
     fn receive_power(&mut self, buses: &impl ElectricalBuses) {
-        let bus_selected_index: usize =
-            (self.powered_by_bus as usize).min(self.powered_by_bus_array.len() - 1);
-
-        self.is_powered = buses.is_powered(self.powered_by_bus_array[bus_selected_index]);
+        for (is_powered, bus) in self
+            .is_bus_powered
+            .iter_mut()
+            .zip(&self.powered_by_bus_array)
+        {
+            *is_powered = buses.is_powered(*bus);
+        }
     }
 }
 
@@ -333,6 +384,7 @@ impl ElectricMotorClutch {
 pub trait PitchTrimActuatorController {
     fn commanded_position(&self) -> Angle;
     fn energised_motor(&self) -> [bool; 3];
+    fn motor_power_supply(&self) -> [PitchTrimMotorPowerSupply; 3];
 }
 
 pub trait ManualPitchTrimController {
@@ -368,33 +420,19 @@ impl PitchTrimActuator {
         total_actuator_range_angle: Angle,
         max_elec_motor_speed: AngularVelocity,
         elec_motor_over_trim_actuator_ratio: Ratio,
+        elec_motors_power_buses: [(ElectricalBusType, Option<ElectricalBusType>); 3],
     ) -> Self {
         Self {
             manual_override_id: context.get_identifier("HYD_THS_TRIM_MANUAL_OVERRIDE".to_owned()),
 
-            electric_motors: [
-                ElectricDriveMotor::new(
-                    max_elec_motor_speed,
-                    Self::ELECTRIC_MOTOR_POSITION_ERROR_BREAKPOINT,
-                    Self::ELECTRIC_MOTOR_SPEED_REGULATION_COEF_MAP,
-                    vec![
-                        ElectricalBusType::DirectCurrent(2),
-                        ElectricalBusType::DirectCurrentHot(2),
-                    ],
-                ),
-                ElectricDriveMotor::new(
-                    max_elec_motor_speed,
-                    Self::ELECTRIC_MOTOR_POSITION_ERROR_BREAKPOINT,
-                    Self::ELECTRIC_MOTOR_SPEED_REGULATION_COEF_MAP,
-                    vec![ElectricalBusType::DirectCurrentEssential],
-                ),
+            electric_motors: elec_motors_power_buses.map(|(normal_bus, standby_bus)| {
                 ElectricDriveMotor::new(
                     max_elec_motor_speed,
                     Self::ELECTRIC_MOTOR_POSITION_ERROR_BREAKPOINT,
                     Self::ELECTRIC_MOTOR_SPEED_REGULATION_COEF_MAP,
-                    vec![ElectricalBusType::DirectCurrent(2)],
-                ),
-            ],
+                    std::iter::once(normal_bus).chain(standby_bus).collect(),
+                )
+            }),
             electric_clutches: [ElectricMotorClutch::default(); 3],
             manual_override_active: false,
 
@@ -415,6 +453,7 @@ impl PitchTrimActuator {
         manual_controller: &impl ManualPitchTrimController,
         ths_hydraulic_assembly: &TrimmableHorizontalStabilizerActuator,
     ) {
+        self.update_motors_power_supply(electric_controller);
         self.update_clutches_state(electric_controller);
         self.update_motors(context, electric_controller, ths_hydraulic_assembly);
 
@@ -472,6 +511,16 @@ impl PitchTrimActuator {
         sum_of_speeds
     }
 
+    fn update_motors_power_supply(&mut self, controller: &impl PitchTrimActuatorController) {
+        for (motor, power_supply) in self
+            .electric_motors
+            .iter_mut()
+            .zip(controller.motor_power_supply())
+        {
+            motor.set_power_bus_in_use(power_supply);
+        }
+    }
+
     fn update_clutches_state(&mut self, controller: &impl PitchTrimActuatorController) {
         for (clutch_index, clutch) in self.electric_clutches.iter_mut().enumerate() {
             clutch.set_is_powered(self.electric_motors[clutch_index].is_powered());
@@ -575,6 +624,7 @@ impl TrimmableHorizontalStabilizerAssembly {
 
         max_elec_motor_speed: AngularVelocity,
         elec_motor_over_trim_actuator_ratio: Ratio,
+        elec_motors_power_buses: [(ElectricalBusType, Option<ElectricalBusType>); 3],
 
         min_ths_deflection: Angle,
         ths_deflection_range: Angle,
@@ -586,6 +636,7 @@ impl TrimmableHorizontalStabilizerAssembly {
                 total_actuator_range_angle,
                 max_elec_motor_speed,
                 elec_motor_over_trim_actuator_ratio,
+                elec_motors_power_buses,
             ),
             trim_wheel: TrimWheels::new(
                 context,
@@ -829,6 +880,7 @@ mod tests {
         control_active: bool,
         position_request: Angle,
         motor_idx_in_control: usize,
+        power_supply: [PitchTrimMotorPowerSupply; 3],
     }
     impl TestElecTrimControl {
         fn with_motor_idx_and_pos_demand(
@@ -839,6 +891,7 @@ mod tests {
                 control_active: true,
                 position_request,
                 motor_idx_in_control,
+                power_supply: [PitchTrimMotorPowerSupply::Normal; 3],
             }
         }
 
@@ -847,6 +900,7 @@ mod tests {
                 control_active: false,
                 position_request: Angle::default(),
                 motor_idx_in_control: 0,
+                power_supply: [PitchTrimMotorPowerSupply::Normal; 3],
             }
         }
     }
@@ -864,6 +918,10 @@ mod tests {
                 energized_array
             }
         }
+
+        fn motor_power_supply(&self) -> [PitchTrimMotorPowerSupply; 3] {
+            self.power_supply
+        }
     }
 
     struct TestManualTrimControl {
@@ -910,6 +968,9 @@ mod tests {
         dc_hot_bus: ElectricalBus,
         dc_ess_bus: ElectricalBus,
         is_elec_powered: bool,
+        is_dc_2_powered: bool,
+        is_dc_hot_powered: bool,
+        is_dc_ess_powered: bool,
     }
     impl TestAircraft {
         fn new(context: &mut InitContext) -> Self {
@@ -925,6 +986,14 @@ mod tests {
                     Angle::new::<degree>(360. * 8.19), // 1.87 rotations down 6.32 up
                     AngularVelocity::new::<revolution_per_minute>(5000.),
                     Ratio::new::<ratio>(2035. / 6.13),
+                    [
+                        (
+                            ElectricalBusType::DirectCurrent(2),
+                            Some(ElectricalBusType::DirectCurrentHot(2)),
+                        ),
+                        (ElectricalBusType::DirectCurrentEssential, None),
+                        (ElectricalBusType::DirectCurrent(2), None),
+                    ],
                     Angle::new::<degree>(-4.),
                     Angle::new::<degree>(17.5),
                 ),
@@ -940,6 +1009,9 @@ mod tests {
                 dc_hot_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrentHot(2)),
                 dc_ess_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrentEssential),
                 is_elec_powered: true,
+                is_dc_2_powered: true,
+                is_dc_hot_powered: true,
+                is_dc_ess_powered: true,
             }
         }
 
@@ -975,6 +1047,20 @@ mod tests {
         fn set_no_elec_power(&mut self) {
             self.is_elec_powered = false;
         }
+
+        fn set_motor_power_supply(
+            &mut self,
+            motor_idx: usize,
+            power_supply: PitchTrimMotorPowerSupply,
+        ) {
+            self.elec_trim_control.power_supply[motor_idx] = power_supply;
+        }
+
+        fn set_buses_powered(&mut self, dc_2: bool, dc_hot: bool, dc_ess: bool) {
+            self.is_dc_2_powered = dc_2;
+            self.is_dc_hot_powered = dc_hot;
+            self.is_dc_ess_powered = dc_ess;
+        }
     }
     impl Aircraft for TestAircraft {
         fn update_before_power_distribution(
@@ -987,9 +1073,15 @@ mod tests {
             electricity.supplied_by(&self.powered_source_dc);
 
             if self.is_elec_powered {
-                electricity.flow(&self.powered_source_dc, &self.dc_2_bus);
-                electricity.flow(&self.powered_source_dc, &self.dc_ess_bus);
-                electricity.flow(&self.powered_source_dc, &self.dc_hot_bus);
+                if self.is_dc_2_powered {
+                    electricity.flow(&self.powered_source_dc, &self.dc_2_bus);
+                }
+                if self.is_dc_ess_powered {
+                    electricity.flow(&self.powered_source_dc, &self.dc_ess_bus);
+                }
+                if self.is_dc_hot_powered {
+                    electricity.flow(&self.powered_source_dc, &self.dc_hot_bus);
+                }
             }
         }
 
@@ -1124,6 +1216,54 @@ mod tests {
         assert!(deflection.get::<degree>() <= 0.1);
     }
 
+    #[rstest]
+    #[case(0, false, true, true, false)]
+    #[case(0, true, false, true, true)]
+    #[case(1, true, true, false, false)]
+    #[case(2, false, true, true, false)]
+    fn trim_assembly_motor_n_with_bus_loss_on_normal_supply(
+        #[case] motor_idx: usize,
+        #[case] dc_2: bool,
+        #[case] dc_hot: bool,
+        #[case] dc_ess: bool,
+        #[case] should_trim: bool,
+    ) {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+
+        test_bed.command(|a| a.set_elec_trim_demand(Angle::new::<degree>(10.), motor_idx));
+        test_bed.command(|a| a.set_buses_powered(dc_2, dc_hot, dc_ess));
+        test_bed.run_with_delta(Duration::from_millis(20000));
+
+        let deflection: Angle = test_bed.read_by_name("HYD_FINAL_THS_DEFLECTION");
+        assert_eq!(deflection.get::<degree>() > 9.9, should_trim);
+    }
+
+    #[test]
+    fn trim_assembly_motor_0_on_standby_supply_trims_without_dc_2() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+
+        test_bed.command(|a| a.set_elec_trim_demand(Angle::new::<degree>(10.), 0));
+        test_bed.command(|a| a.set_motor_power_supply(0, PitchTrimMotorPowerSupply::Standby));
+        test_bed.command(|a| a.set_buses_powered(false, true, true));
+        test_bed.run_with_delta(Duration::from_millis(20000));
+
+        let deflection: Angle = test_bed.read_by_name("HYD_FINAL_THS_DEFLECTION");
+        assert!(deflection.get::<degree>() > 9.9);
+    }
+
+    #[test]
+    fn trim_assembly_motor_0_on_standby_supply_is_stuck_without_dc_hot() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+
+        test_bed.command(|a| a.set_elec_trim_demand(Angle::new::<degree>(10.), 0));
+        test_bed.command(|a| a.set_motor_power_supply(0, PitchTrimMotorPowerSupply::Standby));
+        test_bed.command(|a| a.set_buses_powered(true, false, true));
+        test_bed.run_with_delta(Duration::from_millis(20000));
+
+        let deflection: Angle = test_bed.read_by_name("HYD_FINAL_THS_DEFLECTION");
+        assert!(deflection.get::<degree>().abs() <= 0.1);
+    }
+
     #[test]
     fn trim_assembly_min_motor_0() {
         let mut test_bed = SimulationTestBed::new(TestAircraft::new);
@@ -21,6 +21,10 @@ use uom::si::{
 };
 
 use nalgebra::Vector3;
+
+pub mod shock_strut;
+use shock_strut::{LandingGearCharacteristics, LandingMaintenanceReport, ShockStrut};
+
 pub trait GearSystemSensors {
     fn is_wheel_id_up_and_locked(&self, wheel_id: GearWheel, lgciu_id: LgciuId) -> bool;
     fn is_wheel_id_down_and_locked(&self, wheel_id: GearWheel, lgciu_id: LgciuId) -> bool;
@@ -130,7 +134,8 @@ impl SimulationElement for TiltingGear {
 /// Note that this type somewhat hides the gear's position.
 /// The real aircraft also can only check whether or not the gear is up and
 /// locked or down and locked. No in between state.
-/// It provides as well the state of all weight on wheel sensors
+/// It provides as well the state of all weight on wheel sensors, the vertical load of each
+/// shock strut and a maintenance report of all landings.
 pub struct LandingGear {
     center_compression_id: VariableIdentifier,
     left_compression_id: VariableIdentifier,
@@ -139,6 +144,12 @@ pub struct LandingGear {
     center_compression: Ratio,
     left_compression: Ratio,
     right_compression: Ratio,
+
+    nose_strut: ShockStrut,
+    left_strut: ShockStrut,
+    right_strut: ShockStrut,
+
+    landing_report: LandingMaintenanceReport,
 }
 impl LandingGear {
     pub const GEAR_CENTER_COMPRESSION: &'static str = "GEAR ANIMATION POSITION";
@@ -148,7 +159,7 @@ impl LandingGear {
     // Is extended at 0.5, we set a super small margin of 0.02 from fully extended so 0.52
     const COMPRESSION_THRESHOLD_FOR_WEIGHT_ON_WHEELS_RATIO: f64 = 0.52;
 
-    pub fn new(context: &mut InitContext) -> Self {
+    pub fn new(context: &mut InitContext, characteristics: LandingGearCharacteristics) -> Self {
         Self {
             center_compression_id: context.get_identifier(Self::GEAR_CENTER_COMPRESSION.to_owned()),
             left_compression_id: context.get_identifier(Self::GEAR_LEFT_COMPRESSION.to_owned()),
@@ -157,9 +168,45 @@ impl LandingGear {
             center_compression: Ratio::default(),
             left_compression: Ratio::default(),
             right_compression: Ratio::default(),
+
+            nose_strut: ShockStrut::new(context, "NOSE", characteristics.nose_gear()),
+            left_strut: ShockStrut::new(context, "LEFT", characteristics.main_gear()),
+            right_strut: ShockStrut::new(context, "RIGHT", characteristics.main_gear()),
+
+            landing_report: LandingMaintenanceReport::new(
+                context,
+                characteristics.max_landing_weight(),
+            ),
         }
     }
 
+    pub fn update(&mut self, context: &UpdateContext) {
+        self.nose_strut.update(context, self.center_compression);
+        self.left_strut.update(context, self.left_compression);
+        self.right_strut.update(context, self.right_compression);
+
+        self.landing_report.update(
+            context,
+            self.is_wheel_id_compressed(GearWheel::LEFT)
+                || self.is_wheel_id_compressed(GearWheel::RIGHT),
+            self.left_strut
+                .vertical_load()
+                .max(self.right_strut.vertical_load()),
+        );
+    }
+
+    pub fn strut_vertical_load(&self, wheel_id: GearWheel) -> Force {
+        match wheel_id {
+            GearWheel::NOSE => self.nose_strut.vertical_load(),
+            GearWheel::LEFT => self.left_strut.vertical_load(),
+            GearWheel::RIGHT => self.right_strut.vertical_load(),
+        }
+    }
+
+    pub fn landing_report(&self) -> &LandingMaintenanceReport {
+        &self.landing_report
+    }
+
     fn is_wheel_id_compressed(&self, wheel_id: GearWheel) -> bool {
         self.wheel_id_compression(wheel_id)
             > Ratio::new::<ratio>(Self::COMPRESSION_THRESHOLD_FOR_WEIGHT_ON_WHEELS_RATIO)
@@ -174,6 +221,15 @@ impl LandingGear {
     }
 }
 impl SimulationElement for LandingGear {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.nose_strut.accept(visitor);
+        self.left_strut.accept(visitor);
+        self.right_strut.accept(visitor);
+        self.landing_report.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn read(&mut self, reader: &mut SimulatorReader) {
         self.center_compression = reader.read(&self.center_compression_id);
         self.left_compression = reader.read(&self.left_compression_id);
@@ -1447,7 +1503,7 @@ mod tests {
     impl TestGearAircraft {
         fn new(context: &mut InitContext) -> Self {
             Self {
-                landing_gear: LandingGear::new(context),
+                landing_gear: LandingGear::new(context, LandingGearCharacteristics::a320()),
                 lgcius: LandingGearControlInterfaceUnitSet::new(
                     context,
                     ElectricalBusType::DirectCurrentEssential,
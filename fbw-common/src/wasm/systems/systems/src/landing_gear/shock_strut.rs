@@ -0,0 +1,694 @@
+use crate::{
+    shared::{
+        event_report::{EventReport, ReportedEvent},
+        low_pass_filter::LowPassFilter,
+        STANDARD_GRAVITY_MS2,
+    },
+    simulation::{
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
+    },
+};
+
+use std::time::Duration;
+use uom::si::{
+    f64::*,
+    force::{kilonewton, newton},
+    length::meter,
+    mass::{kilogram, pound},
+    ratio::ratio,
+    velocity::{foot_per_second, meter_per_second},
+};
+
+#[derive(Clone, Copy)]
+pub struct ShockStrutCharacteristics {
+    max_stroke: Length,
+    static_stroke: Length,
+    static_weight_ratio: Ratio,
+
+    // Oleo orifice damping: damping_force = damping_coefficient * stroke_rate^2
+    // Expressed in N / (m/s)^2
+    damping_coefficient: f64,
+}
+impl ShockStrutCharacteristics {
+    pub fn new(
+        max_stroke: Length,
+        static_stroke: Length,
+        static_weight_ratio: Ratio,
+        damping_coefficient: f64,
+    ) -> Self {
+        Self {
+            max_stroke,
+            static_stroke,
+            static_weight_ratio,
+            damping_coefficient,
+        }
+    }
+
+    pub fn a320_nose_gear() -> Self {
+        Self::new(
+            Length::new::<meter>(0.405),
+            Length::new::<meter>(0.17),
+            Ratio::new::<ratio>(0.08),
+            8500.,
+        )
+    }
+
+    pub fn a320_main_gear() -> Self {
+        Self::new(
+            Length::new::<meter>(0.47),
+            Length::new::<meter>(0.21),
+            Ratio::new::<ratio>(0.46),
+            48500.,
+        )
+    }
+
+    pub fn a380_nose_gear() -> Self {
+        Self::new(
+            Length::new::<meter>(0.5),
+            Length::new::<meter>(0.22),
+            Ratio::new::<ratio>(0.05),
+            50000.,
+        )
+    }
+
+    /// A single equivalent strut standing for both body and wing gears of one side
+    pub fn a380_main_gear() -> Self {
+        Self::new(
+            Length::new::<meter>(0.61),
+            Length::new::<meter>(0.27),
+            Ratio::new::<ratio>(0.475),
+            300000.,
+        )
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct LandingGearCharacteristics {
+    nose_gear: ShockStrutCharacteristics,
+    main_gear: ShockStrutCharacteristics,
+    max_landing_weight: Mass,
+}
+impl LandingGearCharacteristics {
+    pub fn new(
+        nose_gear: ShockStrutCharacteristics,
+        main_gear: ShockStrutCharacteristics,
+        max_landing_weight: Mass,
+    ) -> Self {
+        Self {
+            nose_gear,
+            main_gear,
+            max_landing_weight,
+        }
+    }
+
+    pub fn a320() -> Self {
+        Self::new(
+            ShockStrutCharacteristics::a320_nose_gear(),
+            ShockStrutCharacteristics::a320_main_gear(),
+            Mass::new::<kilogram>(64500.),
+        )
+    }
+
+    pub fn a380() -> Self {
+        Self::new(
+            ShockStrutCharacteristics::a380_nose_gear(),
+            ShockStrutCharacteristics::a380_main_gear(),
+            Mass::new::<kilogram>(394000.),
+        )
+    }
+
+    pub fn nose_gear(&self) -> ShockStrutCharacteristics {
+        self.nose_gear
+    }
+
+    pub fn main_gear(&self) -> ShockStrutCharacteristics {
+        self.main_gear
+    }
+
+    pub fn max_landing_weight(&self) -> Mass {
+        self.max_landing_weight
+    }
+}
+
+/// Oleo-pneumatic shock strut.
+///
+/// Spring force comes from the nitrogen chamber following a polytropic compression law, sized so
+/// the strut carries its share of the aircraft weight at static stroke. Damping force comes from
+/// oil flowing through the orifice and rises with the square of the stroke rate, which at first
+/// contact is the touchdown sink rate.
+pub struct ShockStrut {
+    vertical_load_id: VariableIdentifier,
+
+    characteristics: ShockStrutCharacteristics,
+
+    stroke: Length,
+    stroke_rate: LowPassFilter<Velocity>,
+
+    vertical_load: Force,
+}
+impl ShockStrut {
+    // Fast compression during touchdown is close to adiabatic
+    const POLYTROPIC_INDEX: f64 = 1.35;
+
+    // Stroke beyond this ratio is considered bottoming and is not allowed to reach infinite force
+    const MAX_USABLE_STROKE_RATIO: f64 = 0.98;
+
+    // Compression ratio when strut is fully extended, 1 is fully compressed
+    const FULLY_EXTENDED_COMPRESSION_RATIO: f64 = 0.5;
+
+    const STROKE_RATE_FILTER_TIME_CONSTANT: Duration = Duration::from_millis(50);
+
+    pub fn new(
+        context: &mut InitContext,
+        name: &str,
+        characteristics: ShockStrutCharacteristics,
+    ) -> Self {
+        Self {
+            vertical_load_id: context.get_identifier(format!("GEAR_{}_STRUT_LOAD", name)),
+
+            characteristics,
+
+            stroke: Length::default(),
+            stroke_rate: LowPassFilter::new(Self::STROKE_RATE_FILTER_TIME_CONSTANT),
+
+            vertical_load: Force::default(),
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, compression: Ratio) {
+        let new_stroke = self.stroke_from_compression(compression);
+
+        let raw_stroke_rate = if context.delta_as_secs_f64() > 0. {
+            (new_stroke - self.stroke) / context.delta_as_time()
+        } else {
+            Velocity::default()
+        };
+        self.stroke_rate.update(context.delta(), raw_stroke_rate);
+
+        self.stroke = new_stroke;
+
+        self.vertical_load = if self.stroke > Length::default() {
+            (self.spring_force(context.total_weight()) + self.damping_force()).max(Force::default())
+        } else {
+            Force::default()
+        };
+    }
+
+    fn stroke_from_compression(&self, compression: Ratio) -> Length {
+        let stroke_ratio = ((compression.get::<ratio>() - Self::FULLY_EXTENDED_COMPRESSION_RATIO)
+            / (1. - Self::FULLY_EXTENDED_COMPRESSION_RATIO))
+            .clamp(0., Self::MAX_USABLE_STROKE_RATIO);
+
+        stroke_ratio * self.characteristics.max_stroke
+    }
+
+    fn spring_force(&self, aircraft_weight: Mass) -> Force {
+        let static_force = Force::new::<newton>(
+            aircraft_weight.get::<kilogram>()
+                * STANDARD_GRAVITY_MS2
+                * self.characteristics.static_weight_ratio.get::<ratio>(),
+        );
+
+        let static_gas_length_ratio = 1.
+            - (self.characteristics.static_stroke / self.characteristics.max_stroke).get::<ratio>();
+        let current_gas_length_ratio =
+            1. - (self.stroke / self.characteristics.max_stroke).get::<ratio>();
+
+        static_force
+            * (static_gas_length_ratio / current_gas_length_ratio).powf(Self::POLYTROPIC_INDEX)
+    }
+
+    fn damping_force(&self) -> Force {
+        let rate = self.stroke_rate.output().get::<meter_per_second>();
+
+        Force::new::<newton>(self.characteristics.damping_coefficient * rate * rate.abs())
+    }
+
+    pub fn vertical_load(&self) -> Force {
+        self.vertical_load
+    }
+
+    pub fn stroke(&self) -> Length {
+        self.stroke
+    }
+}
+impl SimulationElement for ShockStrut {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(
+            &self.vertical_load_id,
+            self.vertical_load.get::<kilonewton>(),
+        );
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LandingSeverity {
+    Normal = 0,
+    Hard = 1,
+    SevereHard = 2,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct LandingEvent {
+    sink_rate: Velocity,
+    gross_weight: Mass,
+    peak_load_factor: f64,
+    peak_gear_load: Force,
+    severity: LandingSeverity,
+    is_overweight: bool,
+}
+impl LandingEvent {
+    fn new(sink_rate: Velocity, gross_weight: Mass, max_landing_weight: Mass) -> Self {
+        Self {
+            sink_rate,
+            gross_weight,
+            peak_load_factor: 0.,
+            peak_gear_load: Force::default(),
+            severity: LandingSeverity::Normal,
+            is_overweight: gross_weight > max_landing_weight,
+        }
+    }
+
+    pub fn sink_rate(&self) -> Velocity {
+        self.sink_rate
+    }
+
+    pub fn gross_weight(&self) -> Mass {
+        self.gross_weight
+    }
+
+    pub fn peak_load_factor(&self) -> f64 {
+        self.peak_load_factor
+    }
+
+    pub fn peak_gear_load(&self) -> Force {
+        self.peak_gear_load
+    }
+
+    pub fn severity(&self) -> LandingSeverity {
+        self.severity
+    }
+
+    pub fn is_overweight(&self) -> bool {
+        self.is_overweight
+    }
+
+    pub fn requires_inspection(&self) -> bool {
+        self.severity != LandingSeverity::Normal || self.is_overweight
+    }
+}
+impl ReportedEvent<6> for LandingEvent {
+    fn reported_values(&self) -> [f64; 6] {
+        [
+            self.sink_rate.get::<foot_per_second>(),
+            self.peak_load_factor,
+            self.peak_gear_load.get::<kilonewton>(),
+            self.gross_weight.get::<pound>(),
+            self.severity as u8 as f64,
+            if self.is_overweight { 1. } else { 0. },
+        ]
+    }
+}
+
+/// Records every landing with its touchdown sink rate, peak vertical load factor and peak main
+/// gear load, and classifies it against hard landing inspection criteria.
+///
+/// Sink rate is the last value measured before main gear contact. Peaks are captured over a short
+/// window after touchdown so that bounces are included in the same event.
+pub struct LandingMaintenanceReport {
+    hard_landing_count_id: VariableIdentifier,
+    overweight_landing_count_id: VariableIdentifier,
+
+    max_landing_weight: Mass,
+
+    airborne_duration: Duration,
+    sink_rate_before_touchdown: Velocity,
+
+    recording: Option<LandingEvent>,
+    recording_duration: Duration,
+
+    report: EventReport<LandingEvent, 6>,
+}
+impl LandingMaintenanceReport {
+    const MIN_AIRBORNE_DURATION_FOR_LANDING: Duration = Duration::from_secs(5);
+    const RECORDING_WINDOW_AFTER_TOUCHDOWN: Duration = Duration::from_secs(2);
+
+    const HARD_LANDING_SINK_RATE_FOOT_PER_SECOND: f64 = 10.;
+    const SEVERE_HARD_LANDING_SINK_RATE_FOOT_PER_SECOND: f64 = 14.;
+    const OVERWEIGHT_HARD_LANDING_SINK_RATE_FOOT_PER_SECOND: f64 = 6.;
+
+    const HARD_LANDING_LOAD_FACTOR: f64 = 2.6;
+    const SEVERE_HARD_LANDING_LOAD_FACTOR: f64 = 2.86;
+    const OVERWEIGHT_HARD_LANDING_LOAD_FACTOR: f64 = 1.7;
+
+    pub fn new(context: &mut InitContext, max_landing_weight: Mass) -> Self {
+        Self {
+            hard_landing_count_id: context
+                .get_identifier("LANDING_REPORT_HARD_LANDING_COUNT".to_owned()),
+            overweight_landing_count_id: context
+                .get_identifier("LANDING_REPORT_OVERWEIGHT_LANDING_COUNT".to_owned()),
+
+            max_landing_weight,
+
+            airborne_duration: Duration::default(),
+            sink_rate_before_touchdown: Velocity::default(),
+
+            recording: None,
+            recording_duration: Duration::default(),
+
+            report: EventReport::new(
+                context,
+                "LANDING_REPORT_LANDING_COUNT",
+                [
+                    "LANDING_REPORT_SINK_RATE",
+                    "LANDING_REPORT_PEAK_LOAD_FACTOR",
+                    "LANDING_REPORT_PEAK_GEAR_LOAD",
+                    "LANDING_REPORT_GROSS_WEIGHT",
+                    "LANDING_REPORT_SEVERITY",
+                    "LANDING_REPORT_OVERWEIGHT",
+                ],
+            ),
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        main_gear_compressed: bool,
+        main_gear_load: Force,
+    ) {
+        if !main_gear_compressed {
+            self.airborne_duration += context.delta();
+            self.sink_rate_before_touchdown = (-context.vertical_speed()).max(Velocity::default());
+        } else {
+            if self.recording.is_none()
+                && self.airborne_duration >= Self::MIN_AIRBORNE_DURATION_FOR_LANDING
+            {
+                self.recording = Some(LandingEvent::new(
+                    self.sink_rate_before_touchdown,
+                    context.total_weight(),
+                    self.max_landing_weight,
+                ));
+                self.recording_duration = Duration::default();
+            }
+
+            self.airborne_duration = Duration::default();
+        }
+
+        self.update_recording(context, main_gear_load);
+    }
+
+    fn update_recording(&mut self, context: &UpdateContext, main_gear_load: Force) {
+        if let Some(event) = self.recording.as_mut() {
+            let load_factor = -context.acceleration_plane_reference_unfiltered_ms2_vector()[1]
+                / STANDARD_GRAVITY_MS2;
+
+            event.peak_load_factor = event.peak_load_factor.max(load_factor);
+            event.peak_gear_load = event.peak_gear_load.max(main_gear_load);
+
+            self.recording_duration += context.delta();
+
+            if self.recording_duration >= Self::RECORDING_WINDOW_AFTER_TOUCHDOWN {
+                let mut event = *event;
+                event.severity = Self::severity(&event);
+
+                self.report.record(event);
+                self.recording = None;
+            }
+        }
+    }
+
+    fn severity(event: &LandingEvent) -> LandingSeverity {
+        let sink_rate = event.sink_rate.get::<foot_per_second>();
+
+        if sink_rate >= Self::SEVERE_HARD_LANDING_SINK_RATE_FOOT_PER_SECOND
+            || event.peak_load_factor >= Self::SEVERE_HARD_LANDING_LOAD_FACTOR
+        {
+            LandingSeverity::SevereHard
+        } else if sink_rate >= Self::HARD_LANDING_SINK_RATE_FOOT_PER_SECOND
+            || event.peak_load_factor >= Self::HARD_LANDING_LOAD_FACTOR
+            || (event.is_overweight
+                && (sink_rate >= Self::OVERWEIGHT_HARD_LANDING_SINK_RATE_FOOT_PER_SECOND
+                    || event.peak_load_factor >= Self::OVERWEIGHT_HARD_LANDING_LOAD_FACTOR))
+        {
+            LandingSeverity::Hard
+        } else {
+            LandingSeverity::Normal
+        }
+    }
+
+    pub fn events(&self) -> &[LandingEvent] {
+        self.report.events()
+    }
+
+    pub fn last_event(&self) -> Option<&LandingEvent> {
+        self.report.last_event()
+    }
+
+    fn hard_landing_count(&self) -> usize {
+        self.report
+            .count_where(|event| event.severity != LandingSeverity::Normal)
+    }
+
+    fn overweight_landing_count(&self) -> usize {
+        self.report.count_where(|event| event.is_overweight)
+    }
+}
+impl SimulationElement for LandingMaintenanceReport {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.report.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.hard_landing_count_id, self.hard_landing_count());
+        writer.write(
+            &self.overweight_landing_count_id,
+            self.overweight_landing_count(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::simulation::test::{ReadByName, SimulationTestBed, TestBed, WriteByName};
+    use crate::simulation::{Aircraft, SimulationElementVisitor};
+    use uom::si::{
+        acceleration::meter_per_second_squared, mass::kilogram, velocity::foot_per_minute,
+    };
+
+    struct TestAircraft {
+        strut: ShockStrut,
+        report: LandingMaintenanceReport,
+
+        compression: Ratio,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                strut: ShockStrut::new(
+                    context,
+                    "LEFT",
+                    ShockStrutCharacteristics::a320_main_gear(),
+                ),
+                report: LandingMaintenanceReport::new(context, Mass::new::<kilogram>(64500.)),
+
+                compression: Ratio::new::<ratio>(0.5),
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.strut.update(context, self.compression);
+            self.report.update(
+                context,
+                self.compression.get::<ratio>() > 0.52,
+                self.strut.vertical_load(),
+            );
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.strut.accept(visitor);
+            self.report.accept(visitor);
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed(weight_kg: f64) -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.write_by_name("TOTAL WEIGHT", Mass::new::<kilogram>(weight_kg));
+        test_bed.set_norm_acc(Acceleration::new::<meter_per_second_squared>(0.));
+        test_bed
+    }
+
+    fn fly_and_touch_down(
+        test_bed: &mut SimulationTestBed<TestAircraft>,
+        sink_rate_foot_per_minute: f64,
+        touchdown_acceleration_ms2: f64,
+    ) {
+        test_bed.command(|a| a.compression = Ratio::new::<ratio>(0.5));
+        test_bed.set_vertical_speed(Velocity::new::<foot_per_minute>(-sink_rate_foot_per_minute));
+        test_bed.run_with_delta(Duration::from_secs(10));
+
+        // Strut compresses over a few frames at touchdown
+        test_bed.set_norm_acc(Acceleration::new::<meter_per_second_squared>(
+            touchdown_acceleration_ms2,
+        ));
+        for compression in [0.55, 0.6, 0.65, 0.7] {
+            test_bed.command(|a| a.compression = Ratio::new::<ratio>(compression));
+            test_bed.set_vertical_speed(Velocity::new::<foot_per_minute>(0.));
+            test_bed.run_with_delta(Duration::from_millis(50));
+        }
+
+        test_bed.set_norm_acc(Acceleration::new::<meter_per_second_squared>(0.));
+        test_bed.run_with_delta(Duration::from_secs(3));
+    }
+
+    #[test]
+    fn strut_has_no_load_when_extended() {
+        let mut test_bed = test_bed(60000.);
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(test_bed.query(|a| a.strut.vertical_load()) == Force::default());
+
+        let load: f64 = test_bed.read_by_name("GEAR_LEFT_STRUT_LOAD");
+        assert!(load == 0.);
+    }
+
+    #[test]
+    fn static_strut_carries_its_share_of_weight() {
+        let mut test_bed = test_bed(60000.);
+
+        // Static stroke of a320 main gear is 0.21m out of 0.47m
+        let compression = 0.5 + 0.5 * 0.21 / 0.47;
+        test_bed.command(|a| a.compression = Ratio::new::<ratio>(compression));
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        let expected_load_kn = 60000. * STANDARD_GRAVITY_MS2 * 0.46 / 1000.;
+        let load = test_bed.query(|a| a.strut.vertical_load().get::<kilonewton>());
+        assert!((load - expected_load_kn).abs() < 1.);
+    }
+
+    #[test]
+    fn strut_load_increases_with_compression_speed() {
+        let mut slow_test_bed = test_bed(60000.);
+        slow_test_bed.command(|a| a.compression = Ratio::new::<ratio>(0.6));
+        slow_test_bed.run_with_delta(Duration::from_millis(500));
+        let slow_load = slow_test_bed.query(|a| a.strut.vertical_load());
+
+        let mut fast_test_bed = test_bed(60000.);
+        fast_test_bed.command(|a| a.compression = Ratio::new::<ratio>(0.6));
+        fast_test_bed.run_with_delta(Duration::from_millis(50));
+        let fast_load = fast_test_bed.query(|a| a.strut.vertical_load());
+
+        assert!(fast_load > slow_load);
+    }
+
+    #[test]
+    fn no_landing_recorded_without_flight() {
+        let mut test_bed = test_bed(60000.);
+        test_bed.command(|a| a.compression = Ratio::new::<ratio>(0.7));
+        test_bed.run_with_delta(Duration::from_secs(10));
+
+        assert!(test_bed.query(|a| a.report.events().is_empty()));
+    }
+
+    #[test]
+    fn normal_landing_is_recorded() {
+        let mut test_bed = test_bed(60000.);
+        fly_and_touch_down(&mut test_bed, 180., 3.);
+
+        assert!(test_bed.query(|a| a.report.events().len()) == 1);
+
+        let event = test_bed.query(|a| *a.report.last_event().unwrap());
+        assert!(event.severity() == LandingSeverity::Normal);
+        assert!(!event.is_overweight());
+        assert!(!event.requires_inspection());
+        assert!((event.sink_rate().get::<foot_per_second>() - 3.).abs() < 0.01);
+        assert!(event.peak_load_factor() > 1.2);
+        assert!(event.peak_gear_load() > Force::default());
+
+        let count: f64 = test_bed.read_by_name("LANDING_REPORT_LANDING_COUNT");
+        assert!(count == 1.);
+        let hard_count: f64 = test_bed.read_by_name("LANDING_REPORT_HARD_LANDING_COUNT");
+        assert!(hard_count == 0.);
+    }
+
+    #[test]
+    fn hard_landing_from_sink_rate() {
+        let mut test_bed = test_bed(60000.);
+        fly_and_touch_down(&mut test_bed, 660., 3.);
+
+        let event = test_bed.query(|a| *a.report.last_event().unwrap());
+        assert!(event.severity() == LandingSeverity::Hard);
+
+        let severity: f64 = test_bed.read_by_name("LANDING_REPORT_SEVERITY");
+        assert!(severity == 1.);
+        let sink_rate: f64 = test_bed.read_by_name("LANDING_REPORT_SINK_RATE");
+        assert!((sink_rate - 11.).abs() < 0.01);
+    }
+
+    #[test]
+    fn hard_landing_from_load_factor() {
+        let mut test_bed = test_bed(60000.);
+        fly_and_touch_down(&mut test_bed, 180., 17.);
+
+        let event = test_bed.query(|a| *a.report.last_event().unwrap());
+        assert!(event.peak_load_factor() > 2.6);
+        assert!(event.severity() == LandingSeverity::Hard);
+    }
+
+    #[test]
+    fn severe_hard_landing_from_sink_rate() {
+        let mut test_bed = test_bed(60000.);
+        fly_and_touch_down(&mut test_bed, 900., 3.);
+
+        let event = test_bed.query(|a| *a.report.last_event().unwrap());
+        assert!(event.severity() == LandingSeverity::SevereHard);
+    }
+
+    #[test]
+    fn overweight_landing_is_flagged() {
+        let mut test_bed = test_bed(70000.);
+        fly_and_touch_down(&mut test_bed, 180., 3.);
+
+        let event = test_bed.query(|a| *a.report.last_event().unwrap());
+        assert!(event.is_overweight());
+        assert!(event.severity() == LandingSeverity::Normal);
+        assert!(event.requires_inspection());
+
+        let overweight: bool = test_bed.read_by_name("LANDING_REPORT_OVERWEIGHT");
+        assert!(overweight);
+        let count: f64 = test_bed.read_by_name("LANDING_REPORT_OVERWEIGHT_LANDING_COUNT");
+        assert!(count == 1.);
+    }
+
+    #[test]
+    fn overweight_landing_uses_lower_hard_landing_sink_rate() {
+        let mut test_bed = test_bed(70000.);
+        fly_and_touch_down(&mut test_bed, 420., 3.);
+
+        let event = test_bed.query(|a| *a.report.last_event().unwrap());
+        assert!(event.severity() == LandingSeverity::Hard);
+    }
+
+    #[test]
+    fn successive_landings_are_all_recorded() {
+        let mut test_bed = test_bed(60000.);
+        fly_and_touch_down(&mut test_bed, 180., 3.);
+        fly_and_touch_down(&mut test_bed, 660., 3.);
+
+        assert!(test_bed.query(|a| a.report.events().len()) == 2);
+
+        let count: f64 = test_bed.read_by_name("LANDING_REPORT_LANDING_COUNT");
+        assert!(count == 2.);
+        let hard_count: f64 = test_bed.read_by_name("LANDING_REPORT_HARD_LANDING_COUNT");
+        assert!(hard_count == 1.);
+    }
+}
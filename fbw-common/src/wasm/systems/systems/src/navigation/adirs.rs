@@ -5,7 +5,8 @@ use crate::{
     shared::{
         arinc429::{Arinc429Word, SignStatus},
         low_pass_filter::LowPassFilter,
-        AdirsDiscreteOutputs, AdirsMeasurementOutputs, MachNumber,
+        AdirsDiscreteOutputs, AdirsMeasurementOutputs, ElectricalBusType, ElectricalBuses,
+        MachNumber,
     },
     simulation::{
         Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -21,6 +22,7 @@ use uom::si::{
     angle::degree,
     angle::radian,
     angular_velocity::degree_per_second,
+    electric_potential::volt,
     f64::*,
     length::foot,
     pressure::hectopascal,
@@ -36,8 +38,6 @@ pub struct AirDataInertialReferenceSystemOverheadPanel {
 }
 impl AirDataInertialReferenceSystemOverheadPanel {
     const ADIRS_ON_BAT_NAME: &'static str = "ADIRS_ON_BAT";
-    const DURATION_AFTER_WHICH_ON_BAT_ILLUMINATES: Duration = Duration::from_millis(10500);
-    const ON_BAT_ILLUMINATION_DURATION: Duration = Duration::from_millis(5500);
 
     pub fn new(context: &mut InitContext) -> Self {
         Self {
@@ -60,23 +60,8 @@ impl AirDataInertialReferenceSystemOverheadPanel {
         }
     }
 
-    pub fn update(&mut self, context: &UpdateContext, adirs: &AirDataInertialReferenceSystem) {
-        self.mode_selectors.iter_mut().for_each(|mode_selector| {
-            mode_selector.update(context);
-        });
-
-        // Having the illumination logic here and in the mode selectors is
-        // a bit silly, as this depends on whether or not any IR is supplied by
-        // the battery. Once the battery supplying the IR is modelled, this logic
-        // can be moved.
-        self.on_bat
-            .set_illuminated(self.mode_selectors.iter().any(|mode_selector| {
-                let duration = mode_selector.not_off_duration();
-                duration >= Self::DURATION_AFTER_WHICH_ON_BAT_ILLUMINATES
-                    && duration
-                        < Self::DURATION_AFTER_WHICH_ON_BAT_ILLUMINATES
-                            + Self::ON_BAT_ILLUMINATION_DURATION
-            }));
+    pub fn update(&mut self, adirs: &AirDataInertialReferenceSystem) {
+        self.on_bat.set_illuminated(adirs.any_adiru_on_battery());
 
         self.ir
             .iter_mut()
@@ -129,7 +114,6 @@ impl From<f64> for InertialReferenceMode {
 struct InertialReferenceModeSelector {
     mode_id: VariableIdentifier,
     mode: InertialReferenceMode,
-    not_off_duration: Duration,
 }
 impl InertialReferenceModeSelector {
     fn new(context: &mut InitContext, number: usize) -> Self {
@@ -138,7 +122,6 @@ impl InertialReferenceModeSelector {
             // We start in an aligned state to support starting on the
             // runway or in the air.
             mode: InertialReferenceMode::Navigation,
-            not_off_duration: Duration::from_secs(0),
         }
     }
 
@@ -149,18 +132,6 @@ impl InertialReferenceModeSelector {
     fn mode(&self) -> InertialReferenceMode {
         self.mode
     }
-
-    fn not_off_duration(&self) -> Duration {
-        self.not_off_duration
-    }
-
-    fn update(&mut self, context: &UpdateContext) {
-        if self.mode == InertialReferenceMode::Off {
-            self.not_off_duration = Duration::from_secs(0)
-        } else {
-            self.not_off_duration += context.delta();
-        }
-    }
 }
 impl SimulationElement for InertialReferenceModeSelector {
     fn read(&mut self, reader: &mut SimulatorReader) {
@@ -187,6 +158,40 @@ impl From<f64> for AlignState {
     }
 }
 
+/// The operating mode of the IR part of an ADIRU. It is driven by the mode selector,
+/// the progress of the alignment and the availability of electrical power.
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum InertialReferenceOperatingMode {
+    Off,
+    Align,
+    Navigation,
+    Attitude,
+}
+
+/// The source an ADIRU is currently powered by. The ADIRU is normally supplied
+/// with AC power and reverts to its DC backup supply (ON BAT) when AC power is lost.
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum AdiruPowerSupply {
+    Unpowered,
+    Ac,
+    Battery,
+}
+
+#[derive(Clone, Copy)]
+struct AdiruPowerStatus {
+    supply: AdiruPowerSupply,
+    dc_fail: bool,
+}
+impl AdiruPowerStatus {
+    fn is_powered(&self) -> bool {
+        self.supply != AdiruPowerSupply::Unpowered
+    }
+
+    fn is_on_battery(&self) -> bool {
+        self.supply == AdiruPowerSupply::Battery
+    }
+}
+
 #[derive(Clone, Copy)]
 struct AdirsSimulatorData {
     mach_id: VariableIdentifier,
@@ -366,7 +371,16 @@ impl AirDataInertialReferenceSystem {
     // TODO this is an FMS thing, nothing to do with ADIRUs
     const USES_GPS_AS_PRIMARY_KEY: &'static str = "ADIRS_USES_GPS_AS_PRIMARY";
 
-    pub fn new(context: &mut InitContext, vmo: Velocity, mmo: MachNumber) -> Self {
+    /// Creates the ADIRS. Each ADIRU is supplied by the AC bus at the same index of
+    /// `ac_powered_by` and uses the DC bus at the same index of `dc_powered_by` as its
+    /// backup supply.
+    pub fn new(
+        context: &mut InitContext,
+        vmo: Velocity,
+        mmo: MachNumber,
+        ac_powered_by: [ElectricalBusType; 3],
+        dc_powered_by: [ElectricalBusType; 3],
+    ) -> Self {
         Self {
             remaining_alignment_time_id: context
                 .get_identifier(Self::REMAINING_ALIGNMENT_TIME_KEY.to_owned()),
@@ -376,9 +390,30 @@ impl AirDataInertialReferenceSystem {
                 .get_identifier(Self::USES_GPS_AS_PRIMARY_KEY.to_owned()),
 
             adirus: [
-                AirDataInertialReferenceUnit::new(context, 1, vmo, mmo),
-                AirDataInertialReferenceUnit::new(context, 2, vmo, mmo),
-                AirDataInertialReferenceUnit::new(context, 3, vmo, mmo),
+                AirDataInertialReferenceUnit::new(
+                    context,
+                    1,
+                    vmo,
+                    mmo,
+                    ac_powered_by[0],
+                    dc_powered_by[0],
+                ),
+                AirDataInertialReferenceUnit::new(
+                    context,
+                    2,
+                    vmo,
+                    mmo,
+                    ac_powered_by[1],
+                    dc_powered_by[1],
+                ),
+                AirDataInertialReferenceUnit::new(
+                    context,
+                    3,
+                    vmo,
+                    mmo,
+                    ac_powered_by[2],
+                    dc_powered_by[2],
+                ),
             ],
             configured_align_time: AlignTime::Realistic,
             simulator_data: AdirsSimulatorData::new(context),
@@ -418,6 +453,10 @@ impl AirDataInertialReferenceSystem {
     fn ir_has_fault(&self, number: usize) -> bool {
         self.adirus[number - 1].ir_has_fault()
     }
+
+    fn any_adiru_on_battery(&self) -> bool {
+        self.adirus.iter().any(|adiru| adiru.is_on_battery())
+    }
 }
 impl SimulationElement for AirDataInertialReferenceSystem {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -506,6 +545,12 @@ impl AdirsMeasurementOutputs for AirDataInertialReferenceSystem {
 struct AirDataInertialReferenceUnit {
     state_id: VariableIdentifier,
 
+    ac_powered_by: ElectricalBusType,
+    dc_powered_by: ElectricalBusType,
+    is_ac_powered: bool,
+    dc_potential: ElectricPotential,
+    power_status: AdiruPowerStatus,
+
     adr: AirDataReference,
     ir: InertialReference,
 
@@ -516,9 +561,27 @@ struct AirDataInertialReferenceUnit {
     low_speed_warning_4_260kts: bool,
 }
 impl AirDataInertialReferenceUnit {
-    fn new(context: &mut InitContext, number: usize, vmo: Velocity, mmo: MachNumber) -> Self {
+    const MINIMUM_DC_POTENTIAL_VOLTS: f64 = 18.;
+
+    fn new(
+        context: &mut InitContext,
+        number: usize,
+        vmo: Velocity,
+        mmo: MachNumber,
+        ac_powered_by: ElectricalBusType,
+        dc_powered_by: ElectricalBusType,
+    ) -> Self {
         Self {
             state_id: context.get_identifier(Self::state_id(number)),
+            ac_powered_by,
+            dc_powered_by,
+            is_ac_powered: false,
+            dc_potential: ElectricPotential::new::<volt>(0.),
+            // We start powered to support starting on the runway or in the air.
+            power_status: AdiruPowerStatus {
+                supply: AdiruPowerSupply::Ac,
+                dc_fail: false,
+            },
             adr: AirDataReference::new(context, number, vmo, mmo),
             ir: InertialReference::new(context, number),
 
@@ -536,13 +599,50 @@ impl AirDataInertialReferenceUnit {
         align_time: AlignTime,
         simulator_data: AdirsSimulatorData,
     ) {
-        self.adr.update(context, overhead, simulator_data);
-        self.ir
-            .update(context, &self.adr, overhead, align_time, simulator_data);
+        self.update_power_status();
+
+        // Without power the ADIRU behaves as if its mode selector was set to OFF.
+        let selected_mode = if self.power_status.is_powered() {
+            overhead.mode_of(self.ir.number)
+        } else {
+            InertialReferenceMode::Off
+        };
+
+        self.adr
+            .update(context, overhead, selected_mode, simulator_data);
+        self.ir.update(
+            context,
+            &self.adr,
+            overhead,
+            selected_mode,
+            self.power_status,
+            align_time,
+            simulator_data,
+        );
 
         self.update_discrete_outputs();
     }
 
+    fn update_power_status(&mut self) {
+        let dc_is_normal =
+            self.dc_potential >= ElectricPotential::new::<volt>(Self::MINIMUM_DC_POTENTIAL_VOLTS);
+
+        self.power_status = AdiruPowerStatus {
+            supply: if self.is_ac_powered {
+                AdiruPowerSupply::Ac
+            } else if dc_is_normal {
+                AdiruPowerSupply::Battery
+            } else {
+                AdiruPowerSupply::Unpowered
+            },
+            dc_fail: !dc_is_normal,
+        };
+    }
+
+    fn is_on_battery(&self) -> bool {
+        self.ir.is_on_battery()
+    }
+
     fn is_fully_aligned(&self) -> bool {
         self.ir.is_fully_aligned()
     }
@@ -680,6 +780,11 @@ impl SimulationElement for AirDataInertialReferenceUnit {
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.state_id, self.state())
     }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_ac_powered = buses.is_powered(self.ac_powered_by);
+        self.dc_potential = buses.potential_of(self.dc_powered_by).raw();
+    }
 }
 
 struct AdirsData<T> {
@@ -939,10 +1044,11 @@ impl AirDataReference {
         &mut self,
         context: &UpdateContext,
         overhead: &AirDataInertialReferenceSystemOverheadPanel,
+        selected_mode: InertialReferenceMode,
         simulator_data: AdirsSimulatorData,
     ) {
         self.is_on = overhead.adr_is_on(self.number);
-        self.update_remaining_initialisation_duration(context, overhead);
+        self.update_remaining_initialisation_duration(context, selected_mode);
         self.update_values(context, simulator_data);
         self.update_discrete_word_1();
     }
@@ -950,12 +1056,12 @@ impl AirDataReference {
     fn update_remaining_initialisation_duration(
         &mut self,
         context: &UpdateContext,
-        overhead: &AirDataInertialReferenceSystemOverheadPanel,
+        selected_mode: InertialReferenceMode,
     ) {
         self.remaining_initialisation_duration = remaining_initialisation_duration(
             context,
             Self::INITIALISATION_DURATION,
-            overhead.mode_of(self.number),
+            selected_mode,
             self.remaining_initialisation_duration,
         );
     }
@@ -1218,6 +1324,20 @@ bitflags! {
 struct InertialReference {
     number: usize,
     is_on: bool,
+    operating_mode: InertialReferenceOperatingMode,
+    power_status: AdiruPowerStatus,
+    /// The time passed since the IR left the OFF mode.
+    powered_up_duration: Duration,
+    /// Memorises a loss of power while the IR was operating on its DC backup supply.
+    dc_fail_during_dc_operation: bool,
+    excess_motion_during_align: bool,
+    adr_data_not_received: bool,
+    set_heading_id: VariableIdentifier,
+    set_heading_value: f64,
+    set_heading_entry: Option<Angle>,
+    /// The offset between the heading entered by the crew in ATT mode and the heading
+    /// sensed at that time. None when the heading hasn't been initialised.
+    attitude_heading_offset: Option<Angle>,
     /// The remaining time to align, where 0 indicates the IR system is aligned.
     /// None indicates the IR system isn't aligning nor aligned.
     remaining_align_duration: Option<Duration>,
@@ -1261,6 +1381,9 @@ impl InertialReference {
     const FAST_ALIGNMENT_TIME_IN_SECS: f64 = 90.;
     const IR_FAULT_FLASH_DURATION: Duration = Duration::from_millis(50);
     const ATTITUDE_INITIALISATION_DURATION: Duration = Duration::from_secs(28);
+    const DURATION_AFTER_WHICH_ON_BAT_ILLUMINATES: Duration = Duration::from_millis(10500);
+    const ON_BAT_ILLUMINATION_DURATION: Duration = Duration::from_millis(5500);
+    const EXCESS_MOTION_GROUND_SPEED_KNOTS: f64 = 5.;
     const PITCH: &'static str = "PITCH";
     const ROLL: &'static str = "ROLL";
     const HEADING: &'static str = "HEADING";
@@ -1296,6 +1419,20 @@ impl InertialReference {
         Self {
             number,
             is_on: true,
+            // We start in NAV mode to support starting on the runway or in the air.
+            operating_mode: InertialReferenceOperatingMode::Navigation,
+            power_status: AdiruPowerStatus {
+                supply: AdiruPowerSupply::Ac,
+                dc_fail: false,
+            },
+            powered_up_duration: Duration::from_secs(0),
+            dc_fail_during_dc_operation: false,
+            excess_motion_during_align: false,
+            adr_data_not_received: false,
+            set_heading_id: context.get_identifier(Self::set_heading_id(number)),
+            set_heading_value: 0.,
+            set_heading_entry: None,
+            attitude_heading_offset: None,
             // We start in an aligned state to support starting on the
             // runway or in the air.
             remaining_align_duration: Some(Duration::from_secs(0)),
@@ -1340,33 +1477,94 @@ impl InertialReference {
         context: &UpdateContext,
         true_airspeed_source: &impl TrueAirspeedSource,
         overhead: &AirDataInertialReferenceSystemOverheadPanel,
+        selected_mode: InertialReferenceMode,
+        power_status: AdiruPowerStatus,
         configured_align_time: AlignTime,
         simulator_data: AdirsSimulatorData,
     ) {
         self.is_on = overhead.ir_is_on(self.number);
 
-        self.update_fault_flash_duration(context, overhead);
-        self.update_remaining_attitude_align_duration(context, overhead);
+        self.update_power_status(selected_mode, power_status);
+        self.update_fault_flash_duration(context, selected_mode);
+        self.update_remaining_attitude_align_duration(context, selected_mode);
         self.update_remaining_align_duration(
             context,
-            overhead,
+            selected_mode,
             configured_align_time,
             simulator_data,
         );
+        self.update_operating_mode(context, selected_mode);
+        self.update_attitude_heading_initialisation(simulator_data);
+        self.adr_data_not_received = true_airspeed_source.true_airspeed().is_failure_warning();
 
         self.update_latitude(simulator_data);
         self.update_attitude_values(context, simulator_data);
-        self.update_heading_values(overhead, simulator_data);
-        self.update_non_attitude_values(context, true_airspeed_source, overhead, simulator_data);
-        self.update_maint_word(overhead);
+        self.update_heading_values(simulator_data);
+        self.update_non_attitude_values(context, true_airspeed_source, simulator_data);
+        self.update_maint_word();
+    }
+
+    fn update_power_status(
+        &mut self,
+        selected_mode: InertialReferenceMode,
+        power_status: AdiruPowerStatus,
+    ) {
+        if self.power_status.is_on_battery() && !power_status.is_powered() {
+            self.dc_fail_during_dc_operation = true;
+        } else if power_status.is_powered() && selected_mode == InertialReferenceMode::Off {
+            // Switching the IR off while powered clears the memorised DC fault.
+            self.dc_fail_during_dc_operation = false;
+        }
+
+        self.power_status = power_status;
+    }
+
+    fn update_operating_mode(
+        &mut self,
+        context: &UpdateContext,
+        selected_mode: InertialReferenceMode,
+    ) {
+        let previous_mode = self.operating_mode;
+        self.operating_mode = match selected_mode {
+            InertialReferenceMode::Off => InertialReferenceOperatingMode::Off,
+            InertialReferenceMode::Attitude => InertialReferenceOperatingMode::Attitude,
+            InertialReferenceMode::Navigation if self.is_fully_aligned() => {
+                InertialReferenceOperatingMode::Navigation
+            }
+            InertialReferenceMode::Navigation => InertialReferenceOperatingMode::Align,
+        };
+
+        if previous_mode == InertialReferenceOperatingMode::Navigation
+            && self.operating_mode == InertialReferenceOperatingMode::Attitude
+        {
+            // When reverting from NAV to ATT the IR retains the heading it knew at that time.
+            self.attitude_heading_offset = Some(Angle::new::<degree>(0.));
+        } else if self.operating_mode != InertialReferenceOperatingMode::Attitude {
+            self.attitude_heading_offset = None;
+        }
+
+        if self.operating_mode == InertialReferenceOperatingMode::Off {
+            self.powered_up_duration = Duration::from_secs(0);
+        } else {
+            self.powered_up_duration += context.delta();
+        }
+    }
+
+    fn update_attitude_heading_initialisation(&mut self, simulator_data: AdirsSimulatorData) {
+        if let Some(heading) = self.set_heading_entry.take() {
+            if self.operating_mode == InertialReferenceOperatingMode::Attitude {
+                self.attitude_heading_offset =
+                    Some((heading - self.sensed_heading(simulator_data)).normalised());
+            }
+        }
     }
 
     fn update_fault_flash_duration(
         &mut self,
         context: &UpdateContext,
-        overhead: &AirDataInertialReferenceSystemOverheadPanel,
+        selected_mode: InertialReferenceMode,
     ) {
-        if self.alignment_starting(overhead.mode_of(self.number)) {
+        if self.alignment_starting(selected_mode) {
             self.ir_fault_flash_duration = Some(Self::IR_FAULT_FLASH_DURATION);
         } else if let Some(flash_duration) = self.ir_fault_flash_duration {
             let remaining = subtract_delta_from_duration(context, flash_duration);
@@ -1381,12 +1579,12 @@ impl InertialReference {
     fn update_remaining_attitude_align_duration(
         &mut self,
         context: &UpdateContext,
-        overhead: &AirDataInertialReferenceSystemOverheadPanel,
+        selected_mode: InertialReferenceMode,
     ) {
         self.remaining_attitude_initialisation_duration = remaining_initialisation_duration(
             context,
             Self::ATTITUDE_INITIALISATION_DURATION,
-            overhead.mode_of(self.number),
+            selected_mode,
             self.remaining_attitude_initialisation_duration,
         );
     }
@@ -1394,7 +1592,7 @@ impl InertialReference {
     fn update_remaining_align_duration(
         &mut self,
         context: &UpdateContext,
-        overhead: &AirDataInertialReferenceSystemOverheadPanel,
+        selected_mode: InertialReferenceMode,
         configured_align_time: AlignTime,
         simulator_data: AdirsSimulatorData,
     ) {
@@ -1406,16 +1604,28 @@ impl InertialReference {
             self.remaining_align_duration = Some(Duration::from_secs_f64(0.));
         }
 
-        self.remaining_align_duration = match overhead.mode_of(self.number) {
+        let total_alignment_duration =
+            Self::total_alignment_duration(configured_align_time, simulator_data.latitude);
+        self.remaining_align_duration = match selected_mode {
             InertialReferenceMode::Navigation => match self.remaining_align_duration {
                 Some(remaining) => Some(subtract_delta_from_duration(context, remaining)),
-                None => Some(Self::total_alignment_duration(
-                    configured_align_time,
-                    simulator_data.latitude,
-                )),
+                None => Some(total_alignment_duration),
             },
             InertialReferenceMode::Off | InertialReferenceMode::Attitude => None,
         };
+
+        // Moving the aircraft during the alignment is detected as excess motion,
+        // which automatically restarts the alignment.
+        if self.is_aligning() {
+            if simulator_data.ground_speed
+                >= Velocity::new::<knot>(Self::EXCESS_MOTION_GROUND_SPEED_KNOTS)
+            {
+                self.remaining_align_duration = Some(total_alignment_duration);
+                self.excess_motion_during_align = true;
+            }
+        } else {
+            self.excess_motion_during_align = false;
+        }
     }
 
     fn update_latitude(&mut self, simulator_data: AdirsSimulatorData) {
@@ -1439,7 +1649,7 @@ impl InertialReference {
         let ssm = if self.is_on && self.is_attitude_aligned() {
             SignStatus::NormalOperation
         } else {
-            SignStatus::NoComputedData
+            self.unavailable_ssm()
         };
         // Calculate the attitudes and body rotation rates.
         // Correct the signs so that they conform to standard aeronautical norms.
@@ -1479,18 +1689,14 @@ impl InertialReference {
             .set_value(context.vert_accel() / g + pitch.cos() * roll.cos(), ssm);
     }
 
-    fn update_heading_values(
-        &mut self,
-        overhead: &AirDataInertialReferenceSystemOverheadPanel,
-        simulator_data: AdirsSimulatorData,
-    ) {
+    fn update_heading_values(&mut self, simulator_data: AdirsSimulatorData) {
         // TODO BNR labels (that most things use) are actually +/- 180
 
         // TODO tests for when should be mag or true in mag labels
 
         let heading_available = self.is_on
             && (self.is_fully_aligned()
-                || (overhead.mode_of(self.number) == InertialReferenceMode::Navigation
+                || (self.operating_mode == InertialReferenceOperatingMode::Align
                     && self
                         .remaining_align_duration
                         .map_or(false, |duration| duration.as_secs() < 120)));
@@ -1498,26 +1704,26 @@ impl InertialReference {
         let true_heading_ssm = if heading_available {
             SignStatus::NormalOperation
         } else {
-            SignStatus::NoComputedData
+            self.unavailable_ssm()
         };
         self.true_heading
             .set_value(simulator_data.true_heading, true_heading_ssm);
 
-        // TODO in ATT mode NCD until heading initialised on MCDU
-        let magnetic_heading_ssm = if self.is_on
-            && (heading_available
-                || (overhead.mode_of(self.number) == InertialReferenceMode::Attitude
-                    && self.is_attitude_aligned()))
-        {
-            SignStatus::NormalOperation
-        } else {
-            SignStatus::NoComputedData
-        };
-        self.heading.set_value(
-            if self.has_magnetic_data() {
-                simulator_data.heading
+        // In reality the heading is NCD in ATT mode until it has been initialised through the MCDU.
+        // As no MCDU entry exists yet, the sensed heading is provided until a heading is set.
+        let attitude_heading_available = self.operating_mode
+            == InertialReferenceOperatingMode::Attitude
+            && self.is_attitude_aligned();
+        let magnetic_heading_ssm =
+            if self.is_on && (heading_available || attitude_heading_available) {
+                SignStatus::NormalOperation
             } else {
-                simulator_data.true_heading
+                self.unavailable_ssm()
+            };
+        self.heading.set_value(
+            match self.attitude_heading_offset {
+                Some(offset) => (self.sensed_heading(simulator_data) + offset).normalised(),
+                None => self.sensed_heading(simulator_data),
             },
             magnetic_heading_ssm,
         );
@@ -1527,15 +1733,16 @@ impl InertialReference {
         &mut self,
         context: &UpdateContext,
         true_airspeed_source: &impl TrueAirspeedSource,
-        overhead: &AirDataInertialReferenceSystemOverheadPanel,
         simulator_data: AdirsSimulatorData,
     ) {
         // In ATT mode these labels are not even transmitted
         // In Align, NCD prior to NAV
 
-        let no_transmission = match overhead.mode_of(self.number) {
-            InertialReferenceMode::Navigation => false,
-            InertialReferenceMode::Off | InertialReferenceMode::Attitude => true,
+        let no_transmission = match self.operating_mode {
+            InertialReferenceOperatingMode::Align | InertialReferenceOperatingMode::Navigation => {
+                false
+            }
+            InertialReferenceOperatingMode::Off | InertialReferenceOperatingMode::Attitude => true,
         } || !self.is_on;
 
         // The IR does not compute the wind if the TAS is less than 100 knots or NCD
@@ -1626,13 +1833,12 @@ impl InertialReference {
         &mut self,
         context: &UpdateContext,
         true_airspeed_source: &impl TrueAirspeedSource,
-        overhead: &AirDataInertialReferenceSystemOverheadPanel,
         simulator_data: AdirsSimulatorData,
     ) {
         let ssm = if self.is_on && self.is_fully_aligned() {
             SignStatus::NormalOperation
         } else {
-            SignStatus::NoComputedData
+            self.unavailable_ssm()
         };
 
         let ground_speed_above_minimum_threshold = simulator_data.ground_speed
@@ -1699,14 +1905,13 @@ impl InertialReference {
         self.latitude.set_value(simulator_data.latitude, ssm);
         self.longitude.set_value(simulator_data.longitude, ssm);
 
-        self.update_wind_velocity(context, true_airspeed_source, overhead, simulator_data);
+        self.update_wind_velocity(context, true_airspeed_source, simulator_data);
     }
 
-    fn update_maint_word(&mut self, overhead: &AirDataInertialReferenceSystemOverheadPanel) {
-        // TODO check status of these during mode transitions (first need to implement mode FSM)
+    fn update_maint_word(&mut self) {
         let mut maint_word: IrMaintFlags = IrMaintFlags::default();
 
-        if !self.is_on {
+        if !self.is_on || !self.power_status.is_powered() {
             // FIXME should be no transmission (can we just do this at a higher level...)
             self.maint_word
                 .set_value(maint_word.bits(), SignStatus::FailureWarning);
@@ -1717,35 +1922,51 @@ impl InertialReference {
             maint_word |= IrMaintFlags::ALIGNMENT_NOT_READY;
         }
 
-        if overhead.mode_of(self.number) == InertialReferenceMode::Attitude {
+        if self.operating_mode == InertialReferenceOperatingMode::Attitude {
             maint_word |= IrMaintFlags::REV_ATT_MODE;
+
+            if self.attitude_heading_offset.is_none() {
+                maint_word |= IrMaintFlags::VALID_SET_HEADING;
+            }
         }
 
-        if self.is_fully_aligned() {
+        if self.operating_mode == InertialReferenceOperatingMode::Navigation {
             maint_word |= IrMaintFlags::NAV_MODE;
         }
 
-        // TODO request heading setting in att mode if not set
-
-        // TODO attitude invalid fault
+        if matches!(
+            self.operating_mode,
+            InertialReferenceOperatingMode::Align | InertialReferenceOperatingMode::Attitude
+        ) && !self.is_attitude_aligned()
+        {
+            maint_word |= IrMaintFlags::ATTITUDE_INVALID;
+        }
 
-        // TODO dc < 18 V
+        if self.power_status.dc_fail {
+            maint_word |= IrMaintFlags::DC_FAIL;
+        }
 
-        // TODO on DC (re-implement ON BAT light properly at the same time)
+        if self.power_status.is_on_battery() {
+            maint_word |= IrMaintFlags::ON_DC;
+        }
 
         // TODO ADR input data fault
 
         // TODO unimportant nav fault
 
-        // TODO DC fault during DC operation last power up
+        if self.dc_fail_during_dc_operation {
+            maint_word |= IrMaintFlags::DC_FAIL_ON_DC;
+        }
 
-        // TODO align fault
+        if self.excess_motion_during_align {
+            maint_word |= IrMaintFlags::ALIGN_FAULT | IrMaintFlags::EXCESS_MOTION_ERROR;
+        }
 
         // TODO No IRS initial pos
 
-        // TODO excess motion during align
-
-        // TODO ADR data not received or parity error
+        if self.adr_data_not_received {
+            maint_word |= IrMaintFlags::ADR_IR_FAULT;
+        }
 
         if self.extreme_latitude {
             maint_word |= IrMaintFlags::EXTREME_LATITUDE;
@@ -1769,8 +1990,6 @@ impl InertialReference {
 
         self.maint_word
             .set_value(maint_word.bits(), SignStatus::NormalOperation);
-
-        // TODO tests!
     }
 
     fn alignment_starting(&self, selected_mode: InertialReferenceMode) -> bool {
@@ -1794,6 +2013,19 @@ impl InertialReference {
         self.is_on
     }
 
+    /// Indicates whether the IR is operating on its DC backup supply, or testing it during
+    /// the first seconds after leaving the OFF mode.
+    fn is_on_battery(&self) -> bool {
+        let battery_test_in_progress = self.powered_up_duration
+            >= Self::DURATION_AFTER_WHICH_ON_BAT_ILLUMINATES
+            && self.powered_up_duration
+                < Self::DURATION_AFTER_WHICH_ON_BAT_ILLUMINATES
+                    + Self::ON_BAT_ILLUMINATION_DURATION;
+
+        self.operating_mode != InertialReferenceOperatingMode::Off
+            && (self.power_status.is_on_battery() || battery_test_in_progress)
+    }
+
     fn is_aligning(&self) -> bool {
         match self.remaining_align_duration.as_ref() {
             Some(remaining) => *remaining > Duration::from_secs(0),
@@ -1817,6 +2049,28 @@ impl InertialReference {
         !self.extreme_latitude
     }
 
+    fn sensed_heading(&self, simulator_data: AdirsSimulatorData) -> Angle {
+        if self.has_magnetic_data() {
+            simulator_data.heading
+        } else {
+            simulator_data.true_heading
+        }
+    }
+
+    /// Returns the SSM of data which cannot be computed. Without power the IR
+    /// doesn't transmit at all, which for now is represented by a failure warning.
+    fn unavailable_ssm(&self) -> SignStatus {
+        if self.power_status.is_powered() {
+            SignStatus::NoComputedData
+        } else {
+            SignStatus::FailureWarning
+        }
+    }
+
+    fn set_heading_id(number: usize) -> String {
+        format!("ADIRS_IR_{}_SET_HEADING", number)
+    }
+
     fn latitude(&self) -> Arinc429Word<Angle> {
         Arinc429Word::new(self.latitude.value(), self.latitude.ssm())
     }
@@ -1845,6 +2099,19 @@ impl InertialReference {
     }
 }
 impl SimulationElement for InertialReference {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        // The MCDU writes the heading entered by the crew in the range (0, 360].
+        // A new entry is recognised by a change of the value.
+        let set_heading_value: f64 = reader.read(&self.set_heading_id);
+        if set_heading_value != self.set_heading_value
+            && set_heading_value > 0.
+            && set_heading_value <= 360.
+        {
+            self.set_heading_entry = Some(Angle::new::<degree>(set_heading_value));
+        }
+        self.set_heading_value = set_heading_value;
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         self.pitch.write_to(writer);
         self.roll.write_to(writer);
@@ -1948,6 +2215,9 @@ impl NormaliseAngleExt for Angle {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::electrical::test::TestElectricitySource;
+    use crate::electrical::{ElectricalBus, Electricity};
+    use crate::shared::PotentialOrigin;
     use crate::simulation::test::{ReadByName, WriteByName};
     use crate::{
         shared::arinc429::Arinc429Word,
@@ -1969,25 +2239,100 @@ mod tests {
     };
 
     struct TestAircraft {
+        ac_electricity_source: TestElectricitySource,
+        dc_electricity_source: TestElectricitySource,
+        ac_ess_bus: ElectricalBus,
+        ac_1_bus: ElectricalBus,
+        ac_2_bus: ElectricalBus,
+        dc_hot_1_bus: ElectricalBus,
+        dc_hot_2_bus: ElectricalBus,
+        is_ac_powered: bool,
+        is_dc_powered: bool,
+        dc_potential: ElectricPotential,
         adirs: AirDataInertialReferenceSystem,
         overhead: AirDataInertialReferenceSystemOverheadPanel,
     }
     impl TestAircraft {
         fn new(context: &mut InitContext) -> Self {
             Self {
+                ac_electricity_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::EngineGenerator(1),
+                ),
+                dc_electricity_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::Battery(1),
+                ),
+                ac_ess_bus: ElectricalBus::new(
+                    context,
+                    ElectricalBusType::AlternatingCurrentEssential,
+                ),
+                ac_1_bus: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(1)),
+                ac_2_bus: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(2)),
+                dc_hot_1_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrentHot(1)),
+                dc_hot_2_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrentHot(2)),
+                is_ac_powered: true,
+                is_dc_powered: true,
+                dc_potential: ElectricPotential::new::<volt>(28.),
                 adirs: AirDataInertialReferenceSystem::new(
                     context,
                     Velocity::new::<knot>(340.),
                     MachNumber(0.82),
+                    [
+                        ElectricalBusType::AlternatingCurrentEssential,
+                        ElectricalBusType::AlternatingCurrent(2),
+                        ElectricalBusType::AlternatingCurrent(1),
+                    ],
+                    [
+                        ElectricalBusType::DirectCurrentHot(2),
+                        ElectricalBusType::DirectCurrentHot(2),
+                        ElectricalBusType::DirectCurrentHot(1),
+                    ],
                 ),
                 overhead: AirDataInertialReferenceSystemOverheadPanel::new(context),
             }
         }
+
+        fn set_ac_power(&mut self, is_powered: bool) {
+            self.is_ac_powered = is_powered;
+        }
+
+        fn set_dc_power(&mut self, is_powered: bool) {
+            self.is_dc_powered = is_powered;
+        }
+
+        fn set_dc_potential(&mut self, potential: ElectricPotential) {
+            self.dc_potential = potential;
+        }
     }
     impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            self.ac_electricity_source
+                .power_with_potential(ElectricPotential::new::<volt>(115.));
+            self.dc_electricity_source
+                .power_with_potential(self.dc_potential);
+            electricity.supplied_by(&self.ac_electricity_source);
+            electricity.supplied_by(&self.dc_electricity_source);
+
+            if self.is_ac_powered {
+                electricity.flow(&self.ac_electricity_source, &self.ac_ess_bus);
+                electricity.flow(&self.ac_electricity_source, &self.ac_1_bus);
+                electricity.flow(&self.ac_electricity_source, &self.ac_2_bus);
+            }
+
+            if self.is_dc_powered {
+                electricity.flow(&self.dc_electricity_source, &self.dc_hot_1_bus);
+                electricity.flow(&self.dc_electricity_source, &self.dc_hot_2_bus);
+            }
+        }
+
         fn update_after_power_distribution(&mut self, context: &UpdateContext) {
             self.adirs.update(context, &self.overhead);
-            self.overhead.update(context, &self.adirs);
+            self.overhead.update(&self.adirs);
         }
     }
     impl SimulationElement for TestAircraft {
@@ -2194,6 +2539,34 @@ mod tests {
             self
         }
 
+        fn ac_power_lost(mut self) -> Self {
+            self.command(|a| a.set_ac_power(false));
+            self
+        }
+
+        fn ac_power_restored(mut self) -> Self {
+            self.command(|a| a.set_ac_power(true));
+            self
+        }
+
+        fn dc_power_lost(mut self) -> Self {
+            self.command(|a| a.set_dc_power(false));
+            self
+        }
+
+        fn dc_potential_of(mut self, potential: ElectricPotential) -> Self {
+            self.command(|a| a.set_dc_potential(potential));
+            self
+        }
+
+        fn heading_set_to(mut self, number: usize, heading: Angle) -> Self {
+            self.write_by_name(
+                &InertialReference::set_heading_id(number),
+                heading.get::<degree>(),
+            );
+            self
+        }
+
         fn ir_fault_light_illuminated(&mut self, number: usize) -> bool {
             self.read_by_name(&OnOffFaultPushButton::has_fault_id(&format!(
                 "ADIRS_IR_{}",
@@ -2596,6 +2969,12 @@ mod tests {
             ))
         }
 
+        fn has_maint_word_flag(&mut self, adiru_number: usize, flag: IrMaintFlags) -> bool {
+            IrMaintFlags::from_bits(self.maint_word(adiru_number).value())
+                .unwrap()
+                .contains(flag)
+        }
+
         fn uses_gps_as_primary(&mut self) -> bool {
             self.read_by_name(AirDataInertialReferenceSystem::USES_GPS_AS_PRIMARY_KEY)
         }
@@ -2986,8 +3365,7 @@ mod tests {
         test_bed.run_without_delta();
 
         test_bed.run_with_delta(
-            AirDataInertialReferenceSystemOverheadPanel::DURATION_AFTER_WHICH_ON_BAT_ILLUMINATES
-                - Duration::from_millis(1),
+            InertialReference::DURATION_AFTER_WHICH_ON_BAT_ILLUMINATES - Duration::from_millis(1),
         );
         assert!(!test_bed.on_bat_light_illuminated());
 
@@ -2995,8 +3373,7 @@ mod tests {
         assert!(test_bed.on_bat_light_illuminated());
 
         test_bed.run_with_delta(
-            AirDataInertialReferenceSystemOverheadPanel::ON_BAT_ILLUMINATION_DURATION
-                - Duration::from_millis(1),
+            InertialReference::ON_BAT_ILLUMINATION_DURATION - Duration::from_millis(1),
         );
         assert!(test_bed.on_bat_light_illuminated());
 
@@ -3021,7 +3398,7 @@ mod tests {
             .ir_mode_selector_set_to(2, InertialReferenceMode::Navigation);
         test_bed.run_without_delta();
         test_bed.run_with_delta(
-            AirDataInertialReferenceSystemOverheadPanel::DURATION_AFTER_WHICH_ON_BAT_ILLUMINATES
+            InertialReference::DURATION_AFTER_WHICH_ON_BAT_ILLUMINATES
                 - additional_duration
                 - Duration::from_millis(1),
         );
@@ -3031,8 +3408,7 @@ mod tests {
         assert!(test_bed.on_bat_light_illuminated());
 
         test_bed.run_with_delta(
-            AirDataInertialReferenceSystemOverheadPanel::ON_BAT_ILLUMINATION_DURATION
-                + additional_duration
+            InertialReference::ON_BAT_ILLUMINATION_DURATION + additional_duration
                 - Duration::from_millis(1),
         );
         assert!(test_bed.on_bat_light_illuminated());
@@ -3046,9 +3422,7 @@ mod tests {
         let mut test_bed =
             test_bed_with().ir_mode_selector_set_to(1, InertialReferenceMode::Navigation);
         test_bed.run_without_delta();
-        test_bed.run_with_delta(
-            AirDataInertialReferenceSystemOverheadPanel::DURATION_AFTER_WHICH_ON_BAT_ILLUMINATES,
-        );
+        test_bed.run_with_delta(InertialReference::DURATION_AFTER_WHICH_ON_BAT_ILLUMINATES);
 
         assert!(test_bed.on_bat_light_illuminated());
 
@@ -4576,4 +4950,268 @@ mod tests {
             );
         }
     }
+
+    mod mode_state_machine {
+        use super::*;
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn reverts_to_dc_backup_supply_when_ac_power_is_lost(#[case] adiru_number: usize) {
+            let mut test_bed = all_adirus_aligned_test_bed_with().ac_power_lost();
+            test_bed.run();
+
+            assert!(test_bed.is_aligned(adiru_number));
+            assert!(test_bed.on_bat_light_illuminated());
+            assert!(test_bed.has_maint_word_flag(adiru_number, IrMaintFlags::ON_DC));
+        }
+
+        #[test]
+        fn on_bat_light_extinguishes_when_ac_power_is_restored() {
+            let mut test_bed = all_adirus_aligned_test_bed_with().ac_power_lost();
+            test_bed.run();
+
+            test_bed = test_bed.then_continue_with().ac_power_restored();
+            test_bed.run();
+
+            assert!(!test_bed.on_bat_light_illuminated());
+            assert!(!test_bed.has_maint_word_flag(1, IrMaintFlags::ON_DC));
+        }
+
+        #[test]
+        fn on_bat_light_isnt_illuminated_when_on_dc_backup_supply_with_mode_selectors_off() {
+            let mut test_bed = test_bed_with().ac_power_lost();
+            test_bed.run();
+
+            assert!(!test_bed.on_bat_light_illuminated());
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn loses_alignment_and_stops_transmitting_when_all_power_is_lost(
+            #[case] adiru_number: usize,
+        ) {
+            let mut test_bed = all_adirus_aligned_test_bed_with()
+                .ac_power_lost()
+                .and()
+                .dc_power_lost();
+            test_bed.run();
+
+            assert!(!test_bed.is_aligned(adiru_number));
+            assert!(!test_bed.is_aligning(adiru_number));
+            assert!(test_bed.heading(adiru_number).is_failure_warning());
+            assert!(test_bed.pitch(adiru_number).is_failure_warning());
+            assert!(test_bed.ground_speed(adiru_number).is_failure_warning());
+            assert!(test_bed.maint_word(adiru_number).is_failure_warning());
+            assert!(!test_bed.on_bat_light_illuminated());
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn realigns_when_power_is_restored(#[case] adiru_number: usize) {
+            let mut test_bed = all_adirus_aligned_test_bed_with()
+                .ac_power_lost()
+                .and()
+                .dc_power_lost();
+            test_bed.run();
+
+            test_bed = test_bed.then_continue_with().ac_power_restored();
+            test_bed.run();
+
+            assert!(test_bed.is_aligning(adiru_number));
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn dc_fail_when_dc_backup_supply_below_18_volts(#[case] adiru_number: usize) {
+            let mut test_bed = all_adirus_aligned_test_bed_with()
+                .dc_potential_of(ElectricPotential::new::<volt>(17.9));
+            test_bed.run();
+
+            assert!(test_bed.has_maint_word_flag(adiru_number, IrMaintFlags::DC_FAIL));
+
+            test_bed = test_bed
+                .then_continue_with()
+                .dc_potential_of(ElectricPotential::new::<volt>(18.));
+            test_bed.run();
+
+            assert!(!test_bed.has_maint_word_flag(adiru_number, IrMaintFlags::DC_FAIL));
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn memorises_dc_fail_during_dc_operation_until_switched_off(#[case] adiru_number: usize) {
+            let mut test_bed = all_adirus_aligned_test_bed_with().ac_power_lost();
+            test_bed.run();
+
+            test_bed = test_bed.then_continue_with().dc_power_lost();
+            test_bed.run();
+
+            test_bed = test_bed.then_continue_with().ac_power_restored();
+            test_bed.run();
+
+            assert!(test_bed.has_maint_word_flag(adiru_number, IrMaintFlags::DC_FAIL_ON_DC));
+
+            test_bed = test_bed
+                .then_continue_with()
+                .ir_mode_selector_set_to(adiru_number, InertialReferenceMode::Off);
+            test_bed.run();
+
+            assert!(!test_bed.has_maint_word_flag(adiru_number, IrMaintFlags::DC_FAIL_ON_DC));
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn excess_motion_during_align_restarts_the_alignment(#[case] adiru_number: usize) {
+            let mut test_bed = test_bed_with()
+                .align_time_configured_as(AlignTime::Fast)
+                .and()
+                .ir_mode_selector_set_to(adiru_number, InertialReferenceMode::Navigation);
+            test_bed.run_without_delta();
+            test_bed.run_with_delta(Duration::from_secs(60));
+
+            test_bed = test_bed
+                .then_continue_with()
+                .ground_speed_of(Velocity::new::<knot>(
+                    InertialReference::EXCESS_MOTION_GROUND_SPEED_KNOTS,
+                ));
+            test_bed.run_with_delta(Duration::from_secs(1));
+
+            assert_eq!(
+                test_bed.remaining_alignment_time(),
+                Duration::from_secs_f64(InertialReference::FAST_ALIGNMENT_TIME_IN_SECS)
+            );
+            assert!(test_bed.has_maint_word_flag(adiru_number, IrMaintFlags::ALIGN_FAULT));
+            assert!(test_bed.has_maint_word_flag(adiru_number, IrMaintFlags::EXCESS_MOTION_ERROR));
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn align_fault_clears_once_aligned(#[case] adiru_number: usize) {
+            let mut test_bed = test_bed_with()
+                .align_time_configured_as(AlignTime::Fast)
+                .ground_speed_of(Velocity::new::<knot>(10.))
+                .and()
+                .ir_mode_selector_set_to(adiru_number, InertialReferenceMode::Navigation);
+            test_bed.run();
+
+            test_bed = test_bed
+                .then_continue_with()
+                .ground_speed_of(Velocity::new::<knot>(0.))
+                .wait_for_alignment_of(adiru_number);
+
+            assert!(!test_bed.has_maint_word_flag(adiru_number, IrMaintFlags::ALIGN_FAULT));
+            assert!(!test_bed.has_maint_word_flag(adiru_number, IrMaintFlags::EXCESS_MOTION_ERROR));
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn attitude_is_invalid_while_initialising(#[case] adiru_number: usize) {
+            let mut test_bed = test_bed_with()
+                .ir_mode_selector_set_to(adiru_number, InertialReferenceMode::Attitude);
+            test_bed.run_without_delta();
+
+            assert!(test_bed.has_maint_word_flag(adiru_number, IrMaintFlags::ATTITUDE_INVALID));
+
+            test_bed.run_with_delta(InertialReference::ATTITUDE_INITIALISATION_DURATION);
+
+            assert!(!test_bed.has_maint_word_flag(adiru_number, IrMaintFlags::ATTITUDE_INVALID));
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn in_att_mode_set_heading_is_requested_until_set(#[case] adiru_number: usize) {
+            let mut test_bed = test_bed_with()
+                .heading_of(Angle::new::<degree>(80.))
+                .and()
+                .ir_mode_selector_set_to(adiru_number, InertialReferenceMode::Attitude);
+            test_bed.run_without_delta();
+            test_bed.run_with_delta(InertialReference::ATTITUDE_INITIALISATION_DURATION);
+
+            assert_about_eq!(
+                test_bed
+                    .heading(adiru_number)
+                    .normal_value()
+                    .unwrap()
+                    .get::<degree>(),
+                80.
+            );
+            assert!(test_bed.has_maint_word_flag(adiru_number, IrMaintFlags::VALID_SET_HEADING));
+
+            test_bed = test_bed
+                .then_continue_with()
+                .heading_set_to(adiru_number, Angle::new::<degree>(90.));
+            test_bed.run();
+
+            assert_about_eq!(
+                test_bed
+                    .heading(adiru_number)
+                    .normal_value()
+                    .unwrap()
+                    .get::<degree>(),
+                90.
+            );
+            assert!(!test_bed.has_maint_word_flag(adiru_number, IrMaintFlags::VALID_SET_HEADING));
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn in_att_mode_set_heading_follows_heading_changes(#[case] adiru_number: usize) {
+            let mut test_bed = test_bed_with()
+                .heading_of(Angle::new::<degree>(80.))
+                .and()
+                .ir_mode_selector_set_to(adiru_number, InertialReferenceMode::Attitude);
+            test_bed.run_without_delta();
+            test_bed.run_with_delta(InertialReference::ATTITUDE_INITIALISATION_DURATION);
+
+            test_bed = test_bed
+                .then_continue_with()
+                .heading_set_to(adiru_number, Angle::new::<degree>(90.));
+            test_bed.run();
+
+            test_bed = test_bed
+                .then_continue_with()
+                .heading_of(Angle::new::<degree>(100.));
+            test_bed.run();
+
+            assert_about_eq!(
+                test_bed
+                    .heading(adiru_number)
+                    .normal_value()
+                    .unwrap()
+                    .get::<degree>(),
+                110.
+            );
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn adr_data_not_received_when_adr_is_off(#[case] adiru_number: usize) {
+            let mut test_bed = all_adirus_aligned_test_bed_with().adr_push_button_off(adiru_number);
+            test_bed.run();
+
+            assert!(test_bed.has_maint_word_flag(adiru_number, IrMaintFlags::ADR_IR_FAULT));
+        }
+    }
 }
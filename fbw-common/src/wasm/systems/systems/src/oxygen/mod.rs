@@ -0,0 +1,600 @@
+use crate::{
+    overhead::MomentaryPushButton,
+    shared::{CabinAltitude, ElectricalBusType, ElectricalBuses},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    },
+};
+
+use std::time::Duration;
+
+use uom::si::{f64::*, length::foot, pressure::psi, ratio::ratio, volume_rate::liter_per_minute};
+
+pub struct OxygenOverheadPanel {
+    crew_supply_is_off_id: VariableIdentifier,
+    crew_supply_is_off: bool,
+
+    mask_man_on: MomentaryPushButton,
+    tmr_reset: MomentaryPushButton,
+}
+impl OxygenOverheadPanel {
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            crew_supply_is_off_id: context.get_identifier("PUSH_OVHD_OXYGEN_CREW".to_owned()),
+            crew_supply_is_off: false,
+
+            mask_man_on: MomentaryPushButton::new(context, "OXYGEN_MASK_MAN_ON"),
+            tmr_reset: MomentaryPushButton::new(context, "OXYGEN_TMR_RESET"),
+        }
+    }
+
+    pub fn crew_supply_is_on(&self) -> bool {
+        !self.crew_supply_is_off
+    }
+
+    pub fn mask_man_on_is_pressed(&self) -> bool {
+        self.mask_man_on.is_pressed()
+    }
+
+    pub fn tmr_reset_is_pressed(&self) -> bool {
+        self.tmr_reset.is_pressed()
+    }
+}
+impl SimulationElement for OxygenOverheadPanel {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.mask_man_on.accept(visitor);
+        self.tmr_reset.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.crew_supply_is_off = reader.read(&self.crew_supply_is_off_id);
+    }
+}
+
+/// The crew oxygen bottle feeds the cockpit diluter-demand masks through a supply valve
+/// and a low pressure regulator.
+struct CrewOxygenBottle {
+    pressure_id: VariableIdentifier,
+    pressure_is_low_id: VariableIdentifier,
+    regulator_low_pressure_id: VariableIdentifier,
+    masks_in_use_id: VariableIdentifier,
+
+    capacity: Volume,
+    remaining_oxygen: Volume,
+    masks_in_use: u8,
+
+    supply_valve_is_open: bool,
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+}
+impl CrewOxygenBottle {
+    const FULL_PRESSURE_PSI: f64 = 1850.;
+    const LOW_PRESSURE_PSI: f64 = 300.;
+    const REGULATED_PRESSURE_PSI: f64 = 78.;
+    const REGULATOR_LOW_PRESSURE_PSI: f64 = 50.;
+
+    /// Volume of air breathed by one crew member, of which the mask supplies the oxygen fraction
+    const BREATHING_RATE_LITER_PER_MINUTE: f64 = 20.;
+    const MIN_OXYGEN_FRACTION: f64 = 0.2;
+    /// Cabin altitude above which the diluter supplies pure oxygen
+    const PURE_OXYGEN_ALTITUDE_FOOT: f64 = 35000.;
+
+    fn new(context: &mut InitContext, capacity: Volume, powered_by: ElectricalBusType) -> Self {
+        Self {
+            pressure_id: context.get_identifier("OXYGEN_CREW_BOTTLE_PRESSURE".to_owned()),
+            pressure_is_low_id: context
+                .get_identifier("OXYGEN_CREW_BOTTLE_PRESSURE_IS_LOW".to_owned()),
+            regulator_low_pressure_id: context.get_identifier("OXYGEN_CREW_REGUL_LO_PR".to_owned()),
+            masks_in_use_id: context.get_identifier("OXYGEN_CREW_MASKS_IN_USE".to_owned()),
+
+            capacity,
+            remaining_oxygen: capacity,
+            masks_in_use: 0,
+
+            supply_valve_is_open: false,
+            powered_by,
+            is_powered: false,
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        supply_is_selected_on: bool,
+        cabin_altitude: Length,
+    ) {
+        self.supply_valve_is_open = supply_is_selected_on && self.is_powered;
+
+        if self.supply_valve_is_open {
+            let consumption = Self::oxygen_demand(cabin_altitude)
+                * self.masks_in_use as f64
+                * context.delta_as_time();
+
+            self.remaining_oxygen = (self.remaining_oxygen - consumption).max(Volume::default());
+        }
+    }
+
+    fn oxygen_demand(cabin_altitude: Length) -> VolumeRate {
+        let oxygen_fraction = (cabin_altitude.get::<foot>() / Self::PURE_OXYGEN_ALTITUDE_FOOT)
+            .clamp(Self::MIN_OXYGEN_FRACTION, 1.);
+
+        VolumeRate::new::<liter_per_minute>(Self::BREATHING_RATE_LITER_PER_MINUTE * oxygen_fraction)
+    }
+
+    fn pressure(&self) -> Pressure {
+        Pressure::new::<psi>(
+            Self::FULL_PRESSURE_PSI * (self.remaining_oxygen / self.capacity).get::<ratio>(),
+        )
+    }
+
+    fn pressure_is_low(&self) -> bool {
+        self.pressure() < Pressure::new::<psi>(Self::LOW_PRESSURE_PSI)
+    }
+
+    fn low_pressure_circuit_pressure(&self) -> Pressure {
+        if self.supply_valve_is_open {
+            self.pressure()
+                .min(Pressure::new::<psi>(Self::REGULATED_PRESSURE_PSI))
+        } else {
+            Pressure::default()
+        }
+    }
+
+    fn regulator_pressure_is_low(&self) -> bool {
+        self.low_pressure_circuit_pressure()
+            < Pressure::new::<psi>(Self::REGULATOR_LOW_PRESSURE_PSI)
+    }
+}
+impl SimulationElement for CrewOxygenBottle {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.masks_in_use = reader.read(&self.masks_in_use_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.pressure_id, self.pressure());
+        writer.write(&self.pressure_is_low_id, self.pressure_is_low());
+        writer.write(
+            &self.regulator_low_pressure_id,
+            self.regulator_pressure_is_low(),
+        );
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+}
+
+/// Passenger masks are fed by chemical oxygen generators which, once started,
+/// produce oxygen for a fixed time and cannot be stopped.
+struct PassengerOxygen {
+    masks_deployed_id: VariableIdentifier,
+    sys_on_light_id: VariableIdentifier,
+    generators_active_id: VariableIdentifier,
+    generators_remaining_time_id: VariableIdentifier,
+
+    masks_deployed: bool,
+    sys_on: bool,
+    generators_started: bool,
+    generator_duration: Duration,
+    generator_remaining_time: Duration,
+
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+}
+impl PassengerOxygen {
+    const AUTO_DEPLOYMENT_CABIN_ALTITUDE_FOOT: f64 = 14000.;
+
+    fn new(
+        context: &mut InitContext,
+        generator_duration: Duration,
+        powered_by: ElectricalBusType,
+    ) -> Self {
+        Self {
+            masks_deployed_id: context.get_identifier("OXYGEN_MASKS_DEPLOYED".to_owned()),
+            sys_on_light_id: context.get_identifier("OXYGEN_PASSENGER_LIGHT_ON".to_owned()),
+            generators_active_id: context
+                .get_identifier("OXYGEN_PASSENGER_GENERATORS_ACTIVE".to_owned()),
+            generators_remaining_time_id: context
+                .get_identifier("OXYGEN_PASSENGER_GENERATORS_REMAINING_TIME".to_owned()),
+
+            masks_deployed: false,
+            sys_on: false,
+            generators_started: false,
+            generator_duration,
+            generator_remaining_time: Duration::ZERO,
+
+            powered_by,
+            is_powered: false,
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        overhead: &OxygenOverheadPanel,
+        cabin_altitude: Length,
+    ) {
+        self.generator_remaining_time = self
+            .generator_remaining_time
+            .saturating_sub(context.delta());
+
+        if !self.is_powered {
+            return;
+        }
+
+        // The reset also restows the masks, but generators which have been started stay spent
+        if overhead.tmr_reset_is_pressed() {
+            self.sys_on = false;
+            self.masks_deployed = false;
+        }
+
+        let deployment_is_requested = overhead.mask_man_on_is_pressed()
+            || cabin_altitude.get::<foot>() > Self::AUTO_DEPLOYMENT_CABIN_ALTITUDE_FOOT;
+
+        if deployment_is_requested && !self.masks_deployed {
+            self.masks_deployed = true;
+            self.sys_on = true;
+
+            if !self.generators_started {
+                self.generators_started = true;
+                self.generator_remaining_time = self.generator_duration;
+            }
+        }
+    }
+
+    fn generators_are_active(&self) -> bool {
+        !self.generator_remaining_time.is_zero()
+    }
+}
+impl SimulationElement for PassengerOxygen {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.masks_deployed_id, self.masks_deployed);
+        writer.write(&self.sys_on_light_id, self.sys_on);
+        writer.write(&self.generators_active_id, self.generators_are_active());
+        writer.write(
+            &self.generators_remaining_time_id,
+            self.generator_remaining_time.as_secs_f64(),
+        );
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+}
+
+pub struct OxygenSystem {
+    crew_bottle: CrewOxygenBottle,
+    passenger_oxygen: PassengerOxygen,
+}
+impl OxygenSystem {
+    pub fn new(
+        context: &mut InitContext,
+        crew_bottle_capacity: Volume,
+        passenger_generator_duration: Duration,
+        powered_by: ElectricalBusType,
+    ) -> Self {
+        Self {
+            crew_bottle: CrewOxygenBottle::new(context, crew_bottle_capacity, powered_by),
+            passenger_oxygen: PassengerOxygen::new(
+                context,
+                passenger_generator_duration,
+                powered_by,
+            ),
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        overhead: &OxygenOverheadPanel,
+        pressurization: &impl CabinAltitude,
+    ) {
+        let cabin_altitude = pressurization.altitude();
+
+        self.crew_bottle
+            .update(context, overhead.crew_supply_is_on(), cabin_altitude);
+        self.passenger_oxygen
+            .update(context, overhead, cabin_altitude);
+    }
+
+    pub fn crew_bottle_pressure(&self) -> Pressure {
+        self.crew_bottle.pressure()
+    }
+
+    pub fn crew_regulator_pressure_is_low(&self) -> bool {
+        self.crew_bottle.regulator_pressure_is_low()
+    }
+
+    pub fn passenger_masks_deployed(&self) -> bool {
+        self.passenger_oxygen.masks_deployed
+    }
+
+    pub fn passenger_generators_are_active(&self) -> bool {
+        self.passenger_oxygen.generators_are_active()
+    }
+}
+impl SimulationElement for OxygenSystem {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.crew_bottle.accept(visitor);
+        self.passenger_oxygen.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        shared::PotentialOrigin,
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+            Aircraft,
+        },
+    };
+    use uom::si::volume::liter;
+
+    struct TestPressurization {
+        cabin_altitude: Length,
+    }
+    impl CabinAltitude for TestPressurization {
+        fn altitude(&self) -> Length {
+            self.cabin_altitude
+        }
+    }
+
+    struct TestAircraft {
+        oxygen: OxygenSystem,
+        oxygen_overhead: OxygenOverheadPanel,
+        pressurization: TestPressurization,
+
+        powered_source: TestElectricitySource,
+        dc_ess_bus: ElectricalBus,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                oxygen: OxygenSystem::new(
+                    context,
+                    Volume::new::<liter>(3256.),
+                    Duration::from_secs(15 * 60),
+                    ElectricalBusType::DirectCurrentEssential,
+                ),
+                oxygen_overhead: OxygenOverheadPanel::new(context),
+                pressurization: TestPressurization {
+                    cabin_altitude: Length::default(),
+                },
+                powered_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::Battery(1),
+                ),
+                dc_ess_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrentEssential),
+            }
+        }
+
+        fn set_cabin_altitude(&mut self, altitude: Length) {
+            self.pressurization.cabin_altitude = altitude;
+        }
+
+        fn unpower(&mut self) {
+            self.powered_source.unpower();
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            electricity.supplied_by(&self.powered_source);
+            electricity.flow(&self.powered_source, &self.dc_ess_bus);
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.oxygen
+                .update(context, &self.oxygen_overhead, &self.pressurization);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.oxygen.accept(visitor);
+            self.oxygen_overhead.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        SimulationTestBed::new(TestAircraft::new)
+    }
+
+    #[test]
+    fn crew_bottle_starts_full() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        let pressure: f64 = test_bed.read_by_name("OXYGEN_CREW_BOTTLE_PRESSURE");
+        assert!((pressure - 1850.).abs() < 1.);
+        assert!(!test_bed.query(|a| a.oxygen.crew_regulator_pressure_is_low()));
+    }
+
+    #[test]
+    fn crew_bottle_pressure_decreases_with_masks_in_use() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("OXYGEN_CREW_MASKS_IN_USE", 2);
+        test_bed.run_multiple_frames(Duration::from_secs(30 * 60));
+
+        let pressure = test_bed.query(|a| a.oxygen.crew_bottle_pressure());
+        assert!(pressure < Pressure::new::<psi>(1800.));
+        assert!(pressure > Pressure::new::<psi>(1500.));
+    }
+
+    #[test]
+    fn crew_consumption_increases_with_cabin_altitude() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("OXYGEN_CREW_MASKS_IN_USE", 2);
+        test_bed.run_multiple_frames(Duration::from_secs(10 * 60));
+        let pressure_at_sea_level = test_bed.query(|a| a.oxygen.crew_bottle_pressure());
+
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("OXYGEN_CREW_MASKS_IN_USE", 2);
+        test_bed.command(|a| a.set_cabin_altitude(Length::new::<foot>(30000.)));
+        test_bed.run_multiple_frames(Duration::from_secs(10 * 60));
+        let pressure_at_altitude = test_bed.query(|a| a.oxygen.crew_bottle_pressure());
+
+        assert!(pressure_at_altitude < pressure_at_sea_level);
+    }
+
+    #[test]
+    fn crew_supply_off_closes_valve_and_stops_consumption() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("PUSH_OVHD_OXYGEN_CREW", true);
+        test_bed.write_by_name("OXYGEN_CREW_MASKS_IN_USE", 2);
+        test_bed.run_multiple_frames(Duration::from_secs(10 * 60));
+
+        assert!(
+            (test_bed
+                .query(|a| a.oxygen.crew_bottle_pressure())
+                .get::<psi>()
+                - 1850.)
+                .abs()
+                < 1.
+        );
+
+        let regul_lo_pr: bool = test_bed.read_by_name("OXYGEN_CREW_REGUL_LO_PR");
+        assert!(regul_lo_pr);
+    }
+
+    #[test]
+    fn crew_supply_valve_closes_without_power() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.unpower());
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.oxygen.crew_regulator_pressure_is_low()));
+    }
+
+    #[test]
+    fn passenger_masks_stay_stowed_at_normal_cabin_altitude() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_cabin_altitude(Length::new::<foot>(8000.)));
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.oxygen.passenger_masks_deployed()));
+        assert!(!test_bed.query(|a| a.oxygen.passenger_generators_are_active()));
+    }
+
+    #[test]
+    fn passenger_masks_deploy_above_threshold_cabin_altitude() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_cabin_altitude(Length::new::<foot>(14500.)));
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.oxygen.passenger_masks_deployed()));
+        assert!(test_bed.query(|a| a.oxygen.passenger_generators_are_active()));
+
+        let sys_on: bool = test_bed.read_by_name("OXYGEN_PASSENGER_LIGHT_ON");
+        assert!(sys_on);
+    }
+
+    #[test]
+    fn passenger_masks_deploy_with_mask_man_on() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("OVHD_OXYGEN_MASK_MAN_ON_IS_PRESSED", true);
+        test_bed.run();
+
+        let masks_deployed: bool = test_bed.read_by_name("OXYGEN_MASKS_DEPLOYED");
+        assert!(masks_deployed);
+    }
+
+    #[test]
+    fn passenger_masks_stay_deployed_when_cabin_altitude_decreases() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_cabin_altitude(Length::new::<foot>(20000.)));
+        test_bed.run();
+        test_bed.command(|a| a.set_cabin_altitude(Length::new::<foot>(8000.)));
+        test_bed.run_multiple_frames(Duration::from_secs(60));
+
+        assert!(test_bed.query(|a| a.oxygen.passenger_masks_deployed()));
+    }
+
+    #[test]
+    fn passenger_masks_do_not_deploy_without_power() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.unpower());
+        test_bed.write_by_name("OVHD_OXYGEN_MASK_MAN_ON_IS_PRESSED", true);
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.oxygen.passenger_masks_deployed()));
+    }
+
+    #[test]
+    fn passenger_generators_expire_after_their_duration() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("OVHD_OXYGEN_MASK_MAN_ON_IS_PRESSED", true);
+        test_bed.run();
+        test_bed.write_by_name("OVHD_OXYGEN_MASK_MAN_ON_IS_PRESSED", false);
+        test_bed.run_multiple_frames(Duration::from_secs(14 * 60));
+
+        assert!(test_bed.query(|a| a.oxygen.passenger_generators_are_active()));
+
+        test_bed.run_multiple_frames(Duration::from_secs(2 * 60));
+
+        assert!(!test_bed.query(|a| a.oxygen.passenger_generators_are_active()));
+        assert!(test_bed.query(|a| a.oxygen.passenger_masks_deployed()));
+    }
+
+    #[test]
+    fn tmr_reset_extinguishes_sys_on_and_restows_masks() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("OVHD_OXYGEN_MASK_MAN_ON_IS_PRESSED", true);
+        test_bed.run();
+        test_bed.write_by_name("OVHD_OXYGEN_MASK_MAN_ON_IS_PRESSED", false);
+        test_bed.write_by_name("OVHD_OXYGEN_TMR_RESET_IS_PRESSED", true);
+        test_bed.run();
+
+        let sys_on: bool = test_bed.read_by_name("OXYGEN_PASSENGER_LIGHT_ON");
+        assert!(!sys_on);
+        assert!(!test_bed.query(|a| a.oxygen.passenger_masks_deployed()));
+    }
+
+    #[test]
+    fn redeploying_masks_after_tmr_reset_does_not_restart_spent_generators() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("OVHD_OXYGEN_MASK_MAN_ON_IS_PRESSED", true);
+        test_bed.run();
+        test_bed.write_by_name("OVHD_OXYGEN_MASK_MAN_ON_IS_PRESSED", false);
+        test_bed.run_multiple_frames(Duration::from_secs(16 * 60));
+
+        test_bed.write_by_name("OVHD_OXYGEN_TMR_RESET_IS_PRESSED", true);
+        test_bed.run();
+        test_bed.write_by_name("OVHD_OXYGEN_TMR_RESET_IS_PRESSED", false);
+        test_bed.write_by_name("OVHD_OXYGEN_MASK_MAN_ON_IS_PRESSED", true);
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.oxygen.passenger_masks_deployed()));
+        assert!(!test_bed.query(|a| a.oxygen.passenger_generators_are_active()));
+    }
+
+    #[test]
+    fn redeploying_masks_after_tmr_reset_does_not_extend_running_generators() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("OVHD_OXYGEN_MASK_MAN_ON_IS_PRESSED", true);
+        test_bed.run();
+        test_bed.write_by_name("OVHD_OXYGEN_MASK_MAN_ON_IS_PRESSED", false);
+        test_bed.run_multiple_frames(Duration::from_secs(10 * 60));
+
+        test_bed.write_by_name("OVHD_OXYGEN_TMR_RESET_IS_PRESSED", true);
+        test_bed.run();
+        test_bed.write_by_name("OVHD_OXYGEN_TMR_RESET_IS_PRESSED", false);
+        test_bed.command(|a| a.set_cabin_altitude(Length::new::<foot>(20000.)));
+        test_bed.run_multiple_frames(Duration::from_secs(6 * 60));
+
+        assert!(test_bed.query(|a| a.oxygen.passenger_masks_deployed()));
+        assert!(!test_bed.query(|a| a.oxygen.passenger_generators_are_active()));
+    }
+}
@@ -0,0 +1,523 @@
+use crate::{
+    failures::{Failure, FailureType},
+    icing_state::{ActiveDeicingController, IcingState},
+    pneumatic::{
+        valve::{PneumaticContainerConnector, PneumaticExhaust},
+        PneumaticContainer, PneumaticPipe,
+    },
+    shared::{DelayedTrueLogicGate, ElectricalBusType, ElectricalBuses, PneumaticValve},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    },
+};
+
+use std::time::Duration;
+
+use uom::si::{
+    f64::*, pressure::psi, ratio::ratio, thermodynamic_temperature::degree_celsius,
+    volume::cubic_meter,
+};
+
+pub struct EngineAntiIcePushButton {
+    position_id: VariableIdentifier,
+    is_on: bool,
+}
+impl EngineAntiIcePushButton {
+    pub fn new_off(context: &mut InitContext, engine_number: usize) -> Self {
+        Self {
+            position_id: context.get_identifier(format!(
+                "BUTTON_OVHD_ANTI_ICE_ENG_{}_POSITION",
+                engine_number
+            )),
+            is_on: false,
+        }
+    }
+
+    pub fn is_on(&self) -> bool {
+        self.is_on
+    }
+}
+impl SimulationElement for EngineAntiIcePushButton {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.position_id, self.is_on());
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.is_on = reader.read(&self.position_id);
+    }
+}
+
+/// The nacelle anti-ice valve is electrically controlled and pneumatically operated.
+/// Its solenoid keeps the valve closed while energized, so the valve opens when anti-ice
+/// is selected or when electrical power is lost, provided enough bleed pressure is available.
+struct EngineAntiIceValve {
+    open_amount: Ratio,
+    connector: PneumaticContainerConnector,
+    stuck_closed_failure: Failure,
+
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+}
+impl EngineAntiIceValve {
+    const VALVE_SPEED: f64 = 0.5;
+    const MIN_OPENING_PRESSURE_PSIG: f64 = 5.;
+    const TRANSFER_SPEED: f64 = 5.;
+
+    fn new(engine_number: usize, powered_by: ElectricalBusType) -> Self {
+        Self {
+            open_amount: Ratio::default(),
+            connector: PneumaticContainerConnector::new(),
+            stuck_closed_failure: Failure::new(FailureType::EngineAntiIceValveStuckClosed(
+                engine_number,
+            )),
+            powered_by,
+            is_powered: false,
+        }
+    }
+
+    fn update_move_fluid(
+        &mut self,
+        context: &UpdateContext,
+        is_selected_on: bool,
+        bleed: &mut impl PneumaticContainer,
+        nacelle: &mut impl PneumaticContainer,
+    ) {
+        let solenoid_is_energized = self.is_powered && !is_selected_on;
+        let has_opening_pressure = (bleed.pressure() - context.ambient_pressure())
+            > Pressure::new::<psi>(Self::MIN_OPENING_PRESSURE_PSIG);
+
+        let target_open_amount = if !solenoid_is_energized
+            && has_opening_pressure
+            && !self.stuck_closed_failure.is_active()
+        {
+            1.
+        } else {
+            0.
+        };
+
+        let open_amount_change = context.delta_as_secs_f64() * Self::VALVE_SPEED;
+        let current_open_amount = self.open_amount.get::<ratio>();
+        self.open_amount = Ratio::new::<ratio>(if target_open_amount > current_open_amount {
+            target_open_amount.min(current_open_amount + open_amount_change)
+        } else {
+            target_open_amount.max(current_open_amount - open_amount_change)
+        });
+
+        self.connector
+            .with_transfer_speed_factor(self.open_amount)
+            .update_move_fluid_with_transfer_speed(context, bleed, nacelle, Self::TRANSFER_SPEED);
+    }
+
+    fn is_fully_open(&self) -> bool {
+        self.open_amount.get::<ratio>() > 1. - 1e-4
+    }
+
+    fn is_fully_closed(&self) -> bool {
+        self.open_amount.get::<ratio>() < 1e-4
+    }
+}
+impl PneumaticValve for EngineAntiIceValve {
+    fn is_open(&self) -> bool {
+        !self.is_fully_closed()
+    }
+}
+impl SimulationElement for EngineAntiIceValve {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.stuck_closed_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+}
+
+/// Monitors the pressure in the nacelle anti-ice duct, downstream of the valve.
+struct EngineAntiIcePressureSwitch {
+    is_pressurized: bool,
+    failure: Failure,
+}
+impl EngineAntiIcePressureSwitch {
+    const SWITCHING_PRESSURE_PSIG: f64 = 4.;
+
+    fn new(engine_number: usize) -> Self {
+        Self {
+            is_pressurized: false,
+            failure: Failure::new(FailureType::EngineAntiIcePressureSwitch(engine_number)),
+        }
+    }
+
+    fn update(&mut self, context: &UpdateContext, duct: &impl PneumaticContainer) {
+        self.is_pressurized = !self.failure.is_active()
+            && (duct.pressure() - context.ambient_pressure())
+                > Pressure::new::<psi>(Self::SWITCHING_PRESSURE_PSIG);
+    }
+
+    fn is_pressurized(&self) -> bool {
+        self.is_pressurized
+    }
+}
+impl SimulationElement for EngineAntiIcePressureSwitch {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+struct NacelleLipHeating {
+    deicing_rate: Ratio,
+}
+impl NacelleLipHeating {
+    /// Temperature rise of the lip above ambient at which it is fully deiced
+    const FULL_DEICING_TEMPERATURE_RISE_C: f64 = 100.;
+
+    fn new() -> Self {
+        Self {
+            deicing_rate: Ratio::default(),
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        is_supplied: bool,
+        duct: &impl PneumaticContainer,
+    ) {
+        let temperature_rise = duct.temperature().get::<degree_celsius>()
+            - context.ambient_temperature().get::<degree_celsius>();
+
+        self.deicing_rate = if is_supplied {
+            Ratio::new::<ratio>(
+                (temperature_rise / Self::FULL_DEICING_TEMPERATURE_RISE_C).clamp(0., 1.),
+            )
+        } else {
+            Ratio::default()
+        };
+    }
+}
+impl ActiveDeicingController for NacelleLipHeating {
+    fn active_deicing_normalized_rate(&self) -> Ratio {
+        self.deicing_rate
+    }
+}
+
+/// Anti-ice of one engine air intake. Hot bleed air is ducted from the engine to the
+/// nacelle lip and exhausted overboard.
+pub struct EngineAntiIce {
+    valve_open_id: VariableIdentifier,
+    pressure_switch_pressurized_id: VariableIdentifier,
+    has_fault_id: VariableIdentifier,
+
+    valve: EngineAntiIceValve,
+    nacelle_duct: PneumaticPipe,
+    exhaust: PneumaticExhaust,
+    pressure_switch: EngineAntiIcePressureSwitch,
+    lip_heating: NacelleLipHeating,
+    nacelle_icing: IcingState,
+
+    valve_position_disagrees: DelayedTrueLogicGate,
+    pressure_disagrees: DelayedTrueLogicGate,
+}
+impl EngineAntiIce {
+    const NACELLE_DUCT_VOLUME_CUBIC_METER: f64 = 0.1;
+    const EXHAUST_SPEED: f64 = 2.;
+
+    // Covers the valve travel time before a disagreement is reported
+    const VALVE_DISAGREEMENT_CONFIRMATION_TIME: Duration = Duration::from_secs(5);
+    const PRESSURE_DISAGREEMENT_CONFIRMATION_TIME: Duration = Duration::from_secs(10);
+
+    const NACELLE_TIME_TO_FULLY_ICED: Duration = Duration::from_secs(300);
+    const NACELLE_TIME_TO_PASSIVE_FULLY_DEICED: Duration = Duration::from_secs(600);
+    const NACELLE_TIME_TO_ACTIVE_FULLY_DEICED: Duration = Duration::from_secs(60);
+
+    pub fn new(
+        context: &mut InitContext,
+        engine_number: usize,
+        powered_by: ElectricalBusType,
+    ) -> Self {
+        Self {
+            valve_open_id: context
+                .get_identifier(format!("PNEU_ENG_{}_ANTI_ICE_VALVE_OPEN", engine_number)),
+            pressure_switch_pressurized_id: context.get_identifier(format!(
+                "PNEU_ENG_{}_ANTI_ICE_PRESSURE_SWITCH_PRESSURIZED",
+                engine_number
+            )),
+            has_fault_id: context
+                .get_identifier(format!("PNEU_ENG_{}_ANTI_ICE_HAS_FAULT", engine_number)),
+            valve: EngineAntiIceValve::new(engine_number, powered_by),
+            nacelle_duct: PneumaticPipe::new(
+                Volume::new::<cubic_meter>(Self::NACELLE_DUCT_VOLUME_CUBIC_METER),
+                Pressure::new::<psi>(14.7),
+                ThermodynamicTemperature::new::<degree_celsius>(15.),
+            ),
+            exhaust: PneumaticExhaust::new(
+                Self::EXHAUST_SPEED,
+                Self::EXHAUST_SPEED,
+                Pressure::default(),
+            ),
+            pressure_switch: EngineAntiIcePressureSwitch::new(engine_number),
+            lip_heating: NacelleLipHeating::new(),
+            nacelle_icing: IcingState::new(
+                context,
+                &format!("ENG_{}_NACELLE", engine_number),
+                Self::NACELLE_TIME_TO_FULLY_ICED,
+                Self::NACELLE_TIME_TO_PASSIVE_FULLY_DEICED,
+                Some(Self::NACELLE_TIME_TO_ACTIVE_FULLY_DEICED),
+            ),
+            valve_position_disagrees: DelayedTrueLogicGate::new(
+                Self::VALVE_DISAGREEMENT_CONFIRMATION_TIME,
+            ),
+            pressure_disagrees: DelayedTrueLogicGate::new(
+                Self::PRESSURE_DISAGREEMENT_CONFIRMATION_TIME,
+            ),
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        is_selected_on: bool,
+        bleed: &mut impl PneumaticContainer,
+    ) {
+        self.valve
+            .update_move_fluid(context, is_selected_on, bleed, &mut self.nacelle_duct);
+        self.exhaust
+            .update_move_fluid(context, &mut self.nacelle_duct);
+
+        self.pressure_switch.update(context, &self.nacelle_duct);
+
+        self.valve_position_disagrees.update(
+            context,
+            (is_selected_on && !self.valve.is_fully_open())
+                || (!is_selected_on && !self.valve.is_fully_closed()),
+        );
+        self.pressure_disagrees.update(
+            context,
+            self.valve.is_fully_open() != self.pressure_switch.is_pressurized(),
+        );
+
+        self.lip_heating
+            .update(context, self.valve.is_open(), &self.nacelle_duct);
+        self.nacelle_icing.update(context, Some(&self.lip_heating));
+    }
+
+    pub fn valve_is_open(&self) -> bool {
+        self.valve.is_open()
+    }
+
+    pub fn is_pressurized(&self) -> bool {
+        self.pressure_switch.is_pressurized()
+    }
+
+    /// The valve position disagrees with the selection, or the pressure switch
+    /// disagrees with the valve position.
+    pub fn has_fault(&self) -> bool {
+        self.valve_position_disagrees.output() || self.pressure_disagrees.output()
+    }
+
+    pub fn fluid_flow(&self) -> MassRate {
+        self.exhaust.fluid_flow()
+    }
+}
+impl SimulationElement for EngineAntiIce {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.valve.accept(visitor);
+        self.pressure_switch.accept(visitor);
+        self.nacelle_icing.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.valve_open_id, self.valve_is_open());
+        writer.write(&self.pressure_switch_pressurized_id, self.is_pressurized());
+        writer.write(&self.has_fault_id, self.has_fault());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        shared::PotentialOrigin,
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+            Aircraft,
+        },
+    };
+
+    struct TestAircraft {
+        engine_anti_ice: EngineAntiIce,
+        bleed: PneumaticPipe,
+        is_selected_on: bool,
+
+        powered_source: TestElectricitySource,
+        dc_1_bus: ElectricalBus,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                engine_anti_ice: EngineAntiIce::new(
+                    context,
+                    1,
+                    ElectricalBusType::DirectCurrent(1),
+                ),
+                bleed: Self::pressurised_bleed(Pressure::new::<psi>(45.)),
+                is_selected_on: false,
+                powered_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::Battery(1),
+                ),
+                dc_1_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(1)),
+            }
+        }
+
+        fn pressurised_bleed(pressure_psig: Pressure) -> PneumaticPipe {
+            // Large enough not to be depleted by the nacelle during a test
+            PneumaticPipe::new(
+                Volume::new::<cubic_meter>(1000.),
+                Pressure::new::<psi>(14.7) + pressure_psig,
+                ThermodynamicTemperature::new::<degree_celsius>(200.),
+            )
+        }
+
+        fn select_on(&mut self, is_on: bool) {
+            self.is_selected_on = is_on;
+        }
+
+        fn depressurise_bleed(&mut self) {
+            self.bleed = Self::pressurised_bleed(Pressure::default());
+        }
+
+        fn unpower(&mut self) {
+            self.powered_source.unpower();
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            electricity.supplied_by(&self.powered_source);
+            electricity.flow(&self.powered_source, &self.dc_1_bus);
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.engine_anti_ice
+                .update(context, self.is_selected_on, &mut self.bleed);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.engine_anti_ice.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.write_by_name("AMBIENT TEMPERATURE", -12.);
+        test_bed.write_by_name("AMBIENT IN CLOUD", 1.);
+
+        test_bed
+    }
+
+    fn run_for(test_bed: &mut SimulationTestBed<TestAircraft>, duration: Duration) {
+        test_bed.run_multiple_frames(duration);
+    }
+
+    #[test]
+    fn valve_stays_closed_when_not_selected() {
+        let mut test_bed = test_bed();
+        run_for(&mut test_bed, Duration::from_secs(10));
+
+        assert!(!test_bed.query(|a| a.engine_anti_ice.valve_is_open()));
+        assert!(!test_bed.query(|a| a.engine_anti_ice.is_pressurized()));
+        assert!(!test_bed.query(|a| a.engine_anti_ice.has_fault()));
+    }
+
+    #[test]
+    fn valve_opens_and_pressurises_nacelle_when_selected() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.select_on(true));
+        run_for(&mut test_bed, Duration::from_secs(10));
+
+        assert!(test_bed.query(|a| a.engine_anti_ice.valve_is_open()));
+        assert!(test_bed.query(|a| a.engine_anti_ice.is_pressurized()));
+        assert!(test_bed.query(|a| a.engine_anti_ice.fluid_flow()) > MassRate::default());
+        assert!(!test_bed.query(|a| a.engine_anti_ice.has_fault()));
+
+        let is_open: bool = test_bed.read_by_name("PNEU_ENG_1_ANTI_ICE_VALVE_OPEN");
+        assert!(is_open);
+    }
+
+    #[test]
+    fn valve_fails_open_when_unpowered() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.unpower());
+        run_for(&mut test_bed, Duration::from_secs(10));
+
+        assert!(test_bed.query(|a| a.engine_anti_ice.valve_is_open()));
+    }
+
+    #[test]
+    fn valve_does_not_open_without_bleed_pressure() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.depressurise_bleed());
+        test_bed.command(|a| a.select_on(true));
+        run_for(&mut test_bed, Duration::from_secs(10));
+
+        assert!(!test_bed.query(|a| a.engine_anti_ice.valve_is_open()));
+        assert!(test_bed.query(|a| a.engine_anti_ice.has_fault()));
+    }
+
+    #[test]
+    fn valve_stuck_closed_gives_fault() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::EngineAntiIceValveStuckClosed(1));
+        test_bed.command(|a| a.select_on(true));
+        run_for(&mut test_bed, Duration::from_secs(10));
+
+        assert!(!test_bed.query(|a| a.engine_anti_ice.valve_is_open()));
+
+        let has_fault: bool = test_bed.read_by_name("PNEU_ENG_1_ANTI_ICE_HAS_FAULT");
+        assert!(has_fault);
+    }
+
+    #[test]
+    fn failed_pressure_switch_gives_fault_with_valve_open() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::EngineAntiIcePressureSwitch(1));
+        test_bed.command(|a| a.select_on(true));
+        run_for(&mut test_bed, Duration::from_secs(20));
+
+        assert!(test_bed.query(|a| a.engine_anti_ice.valve_is_open()));
+        assert!(!test_bed.query(|a| a.engine_anti_ice.is_pressurized()));
+        assert!(test_bed.query(|a| a.engine_anti_ice.has_fault()));
+    }
+
+    #[test]
+    fn nacelle_ices_without_anti_ice() {
+        let mut test_bed = test_bed();
+        run_for(&mut test_bed, Duration::from_secs(120));
+
+        let icing_state: f64 = test_bed.read_by_name("ICING_STATE_ENG_1_NACELLE");
+        assert!(icing_state > 0.2);
+    }
+
+    #[test]
+    fn nacelle_stays_clear_of_ice_with_anti_ice() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.select_on(true));
+        run_for(&mut test_bed, Duration::from_secs(120));
+
+        let icing_state: f64 = test_bed.read_by_name("ICING_STATE_ENG_1_NACELLE");
+        assert!(icing_state < 0.05);
+    }
+}
@@ -2,8 +2,8 @@ use crate::{
     failures::{Failure, FailureType},
     pneumatic::valve::*,
     shared::{
-        ControllerSignal, ElectricalBusType, ElectricalBuses, EngineCorrectedN1, EngineCorrectedN2,
-        HydraulicColor, PneumaticValve,
+        BleedDuctId, ControllerSignal, DelayedTrueLogicGate, ElectricalBusType, ElectricalBuses,
+        EngineCorrectedN1, EngineCorrectedN2, HydraulicColor, PneumaticValve,
     },
     simulation::{
         InitContext, Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -11,9 +11,12 @@ use crate::{
     },
 };
 
+use std::time::Duration;
+
 use uom::si::{
     f64::*,
     mass::kilogram,
+    mass_rate::kilogram_per_second,
     pressure::{pascal, psi},
     ratio::{percent, ratio},
     temperature_interval,
@@ -21,6 +24,7 @@ use uom::si::{
     volume::cubic_meter,
 };
 
+pub mod engine_anti_ice;
 pub mod valve;
 
 pub trait PneumaticValveSignal {
@@ -529,17 +533,20 @@ impl From<f64> for EngineState {
 
 pub struct Precooler {
     heat_transfer_coefficient: f64,
+    is_clogged: bool,
     internal_connector: PneumaticContainerConnector,
     exhaust: PneumaticExhaust,
 }
 impl Precooler {
     const HEAT_CAPACITY_CONSTANT_PRESSURE: f64 = 1.005e3;
+    const CLOGGED_HEAT_TRANSFER_FACTOR: f64 = 0.1;
 
     /// The `heat_transfer_coefficient` contains both the heat transfer coefficient and the area of exchange.
     /// Typical values of the heat transfer coefficient for air to air coolers are 60-180 W/(m^2*K).
     pub fn new(heat_transfer_coefficient: f64) -> Self {
         Self {
             heat_transfer_coefficient,
+            is_clogged: false,
             internal_connector: PneumaticContainerConnector::new(),
             exhaust: PneumaticExhaust::new(3., 3., Pressure::new::<psi>(0.)),
         }
@@ -557,8 +564,14 @@ impl Precooler {
                 - container_one.temperature().get::<degree_celsius>(),
         );
 
+        let heat_transfer_coefficient = if self.is_clogged {
+            self.heat_transfer_coefficient * Self::CLOGGED_HEAT_TRANSFER_FACTOR
+        } else {
+            self.heat_transfer_coefficient
+        };
+
         let mass_energy_change = temperature_gradient / Self::HEAT_CAPACITY_CONSTANT_PRESSURE
-            * (self.heat_transfer_coefficient * context.delta_as_secs_f64());
+            * (heat_transfer_coefficient * context.delta_as_secs_f64());
 
         supply.update_temperature(-mass_energy_change / supply.mass().get::<kilogram>());
         container_one
@@ -568,6 +581,11 @@ impl Precooler {
         self.internal_connector
             .update_move_fluid(context, container_one, container_two);
     }
+
+    /// A clogged precooler matrix only exchanges a fraction of its nominal heat
+    pub fn set_clogged(&mut self, is_clogged: bool) {
+        self.is_clogged = is_clogged;
+    }
 }
 
 pub struct VariableVolumeContainer {
@@ -896,6 +914,108 @@ impl ControllerSignal<ThermodynamicTemperature> for BleedTemperatureSensor {
     }
 }
 
+/// A rupture of a bleed duct. While failed, hot bleed air escapes from the duct
+/// into the surrounding compartment.
+pub struct BleedDuctLeak {
+    exhaust: PneumaticExhaust,
+    fluid_flow: MassRate,
+    fluid_temperature: ThermodynamicTemperature,
+
+    leak_failure: Failure,
+}
+impl BleedDuctLeak {
+    const LEAK_EXHAUST_SPEED: f64 = 1.;
+
+    pub fn new(id: BleedDuctId) -> Self {
+        Self {
+            exhaust: PneumaticExhaust::new(
+                Self::LEAK_EXHAUST_SPEED,
+                Self::LEAK_EXHAUST_SPEED,
+                Pressure::default(),
+            ),
+            fluid_flow: MassRate::default(),
+            fluid_temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
+
+            leak_failure: Failure::new(FailureType::BleedDuctLeak(id)),
+        }
+    }
+
+    pub fn update_move_fluid(
+        &mut self,
+        context: &UpdateContext,
+        duct: &mut impl PneumaticContainer,
+    ) {
+        if self.leak_failure.is_active() {
+            self.fluid_temperature = duct.temperature();
+            self.exhaust.update_move_fluid(context, duct);
+            self.fluid_flow = self.exhaust.fluid_flow();
+        } else {
+            self.update_isolated();
+        }
+    }
+
+    /// Used when the leaking duct section is isolated from any bleed source
+    pub fn update_isolated(&mut self) {
+        self.fluid_flow = MassRate::default();
+    }
+
+    pub fn fluid_flow(&self) -> MassRate {
+        self.fluid_flow
+    }
+
+    pub fn fluid_temperature(&self) -> ThermodynamicTemperature {
+        self.fluid_temperature
+    }
+}
+impl SimulationElement for BleedDuctLeak {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.leak_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+/// A sensing element routed along a bleed duct. It detects hot air escaping from the duct
+/// once the surrounding compartment has heated above the alarm temperature.
+pub struct BleedLeakDetectionLoop {
+    alarm_temperature: ThermodynamicTemperature,
+    hot_air_detected: DelayedTrueLogicGate,
+
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+}
+impl BleedLeakDetectionLoop {
+    const DETECTION_DELAY: Duration = Duration::from_secs(5);
+    const MIN_DETECTABLE_LEAK_FLOW_KG_PER_S: f64 = 0.01;
+
+    pub fn new(alarm_temperature: ThermodynamicTemperature, powered_by: ElectricalBusType) -> Self {
+        Self {
+            alarm_temperature,
+            hot_air_detected: DelayedTrueLogicGate::new(Self::DETECTION_DELAY),
+            powered_by,
+            is_powered: false,
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, leak: &BleedDuctLeak) {
+        self.hot_air_detected.update(
+            context,
+            leak.fluid_flow().get::<kilogram_per_second>()
+                > Self::MIN_DETECTABLE_LEAK_FLOW_KG_PER_S
+                && leak.fluid_temperature() > self.alarm_temperature,
+        );
+    }
+
+    pub fn leak_detected(&self) -> bool {
+        self.is_powered && self.hot_air_detected.output()
+    }
+}
+impl SimulationElement for BleedLeakDetectionLoop {
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+}
+
 struct Solenoid {
     is_energized: bool,
     is_powered: bool,
@@ -116,6 +116,7 @@ pub struct SolenoidValve<const N: usize> {
     characteristics: PneumaticValveCharacteristics<N>,
     solenoid: Solenoid,
     open_amount: Ratio,
+    jammed_open_amount: Option<Ratio>,
 }
 impl<const N: usize> SolenoidValve<N> {
     pub fn new(
@@ -127,6 +128,7 @@ impl<const N: usize> SolenoidValve<N> {
             characteristics,
             solenoid: Solenoid::new(powered_by),
             open_amount: Ratio::default(),
+            jammed_open_amount: None,
         }
     }
 
@@ -134,6 +136,11 @@ impl<const N: usize> SolenoidValve<N> {
         self.solenoid.update(controller);
     }
 
+    /// Mechanically jams the valve at the given open amount, or frees it when `None`
+    pub fn set_jammed_open_amount(&mut self, jammed_open_amount: Option<Ratio>) {
+        self.jammed_open_amount = jammed_open_amount;
+    }
+
     pub fn update_move_fluid(
         &mut self,
         context: &UpdateContext,
@@ -152,11 +159,14 @@ impl<const N: usize> SolenoidValve<N> {
         let current_open_amount = self.open_amount.get::<ratio>();
         let open_amount_change = context.delta_as_secs_f64() * self.characteristics.valve_speed;
 
-        self.open_amount = Ratio::new::<ratio>(if target_open_amount > current_open_amount {
-            target_open_amount.min(current_open_amount + open_amount_change)
-        } else {
-            target_open_amount.max(current_open_amount - open_amount_change)
-        });
+        self.open_amount = match self.jammed_open_amount {
+            Some(jammed_open_amount) => jammed_open_amount,
+            None => Ratio::new::<ratio>(if target_open_amount > current_open_amount {
+                target_open_amount.min(current_open_amount + open_amount_change)
+            } else {
+                target_open_amount.max(current_open_amount - open_amount_change)
+            }),
+        };
 
         self.connector
             .with_transfer_speed_factor(self.open_amount)
@@ -186,6 +196,7 @@ impl<const N: usize> SimulationElement for SolenoidValve<N> {
 
 pub struct ElectroPneumaticValve {
     open_amount: Ratio,
+    jammed_open_amount: Option<Ratio>,
     connector: PneumaticContainerConnector,
     is_powered: bool,
     powered_by: ElectricalBusType,
@@ -196,19 +207,27 @@ impl ElectroPneumaticValve {
     pub fn new(powered_by: ElectricalBusType) -> Self {
         Self {
             open_amount: Ratio::default(),
+            jammed_open_amount: None,
             connector: PneumaticContainerConnector::new(),
             is_powered: false,
             powered_by,
         }
     }
 
+    /// Mechanically jams the valve at the given open amount, or frees it when `None`
+    pub fn set_jammed_open_amount(&mut self, jammed_open_amount: Option<Ratio>) {
+        self.jammed_open_amount = jammed_open_amount;
+    }
+
     pub fn update_move_fluid(
         &mut self,
         context: &UpdateContext,
         container_one: &mut impl PneumaticContainer,
         container_two: &mut impl PneumaticContainer,
     ) {
-        if !self.is_powered {
+        if let Some(jammed_open_amount) = self.jammed_open_amount {
+            self.open_amount = jammed_open_amount;
+        } else if !self.is_powered {
             self.set_open_amount_from_pressure_difference(
                 container_one.pressure() - container_two.pressure(),
             )
@@ -250,7 +269,9 @@ impl ControllablePneumaticValve for ElectroPneumaticValve {
         &mut self,
         controller: &U,
     ) {
-        if self.is_powered {
+        if let Some(jammed_open_amount) = self.jammed_open_amount {
+            self.open_amount = jammed_open_amount;
+        } else if self.is_powered {
             if let Some(signal) = controller.signal() {
                 self.open_amount = signal.target_open_amount();
             }
@@ -1,3 +1,5 @@
+use std::fmt::Display;
+
 #[derive(Clone, Copy)]
 pub struct Arinc429Word<T: Copy> {
     value: T,
@@ -130,6 +132,207 @@ pub(crate) fn to_arinc429(value: f64, ssm: SignStatus) -> f64 {
     int_value as f64
 }
 
+/// An ARINC 429 label, identifying the data carried by a word. Labels are
+/// conventionally written in octal notation, e.g. `Arinc429Label::new(0o206)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Arinc429Label(u8);
+impl Arinc429Label {
+    pub const fn new(label: u8) -> Self {
+        Self(label)
+    }
+
+    pub fn value(&self) -> u8 {
+        self.0
+    }
+}
+impl Display for Arinc429Label {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:03o}", self.0)
+    }
+}
+
+/// The way the data field (bits 11 to 29) of a word is encoded.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Arinc429Encoding {
+    /// Two's complement binary with the sign in bit 29, followed by the given number of
+    /// significant bits. The most significant bit is worth half of the range, thus the
+    /// resolution equals the range divided by 2 to the power of the significant bits.
+    Bnr { significant_bits: u8, range: f64 },
+    /// Binary coded decimal with the given number of digits, where the least significant
+    /// digit is worth the given resolution. The most significant digit is located in bits 27 to 29
+    /// and thus cannot exceed 7. The sign is encoded in the SSM.
+    Bcd { digits: u8, resolution: f64 },
+    /// Individual bits in the data field.
+    Discrete,
+}
+impl Arinc429Encoding {
+    const DATA_FIELD_BITS: u32 = 19;
+    const DATA_FIELD_OFFSET: u32 = 10;
+    const MAX_BNR_SIGNIFICANT_BITS: u8 = 18;
+    const MAX_BCD_DIGITS: u8 = 5;
+
+    /// Returns the value represented by the least significant bit or digit.
+    pub fn resolution(&self) -> f64 {
+        match *self {
+            Arinc429Encoding::Bnr {
+                significant_bits,
+                range,
+            } => range / 2_f64.powi(significant_bits as i32),
+            Arinc429Encoding::Bcd { resolution, .. } => resolution,
+            Arinc429Encoding::Discrete => 1.,
+        }
+    }
+
+    fn encode_data(&self, value: f64) -> u32 {
+        match *self {
+            Arinc429Encoding::Bnr {
+                significant_bits, ..
+            } => {
+                debug_assert!(significant_bits <= Self::MAX_BNR_SIGNIFICANT_BITS);
+                let max = (1_i64 << significant_bits) - 1;
+                let raw = ((value / self.resolution()).round() as i64).clamp(-max - 1, max);
+                let field_bits = significant_bits as u32 + 1;
+
+                ((raw as u32) & ((1 << field_bits) - 1))
+                    << (Self::DATA_FIELD_OFFSET + Self::DATA_FIELD_BITS - field_bits)
+            }
+            Arinc429Encoding::Bcd { digits, .. } => {
+                debug_assert!((1..=Self::MAX_BCD_DIGITS).contains(&digits));
+                let max = 8 * 10_u32.pow(digits as u32 - 1) - 1;
+                let mut raw = ((value.abs() / self.resolution()).round() as u32).min(max);
+
+                (0..digits).fold(0, |data, digit| {
+                    let decimal = raw % 10;
+                    raw /= 10;
+                    data | (decimal << Self::bcd_digit_offset(digits, digit))
+                })
+            }
+            Arinc429Encoding::Discrete => {
+                ((value as u32) & ((1 << Self::DATA_FIELD_BITS) - 1)) << Self::DATA_FIELD_OFFSET
+            }
+        }
+    }
+
+    fn decode_data(&self, word: u32) -> f64 {
+        match *self {
+            Arinc429Encoding::Bnr {
+                significant_bits, ..
+            } => {
+                // Moving the sign bit into bit 32 allows the arithmetic shift
+                // to sign extend the field.
+                let bits_above_data_field = 32 - Self::DATA_FIELD_OFFSET - Self::DATA_FIELD_BITS;
+                let raw =
+                    ((word << bits_above_data_field) as i32) >> (32 - significant_bits as u32 - 1);
+
+                raw as f64 * self.resolution()
+            }
+            Arinc429Encoding::Bcd { digits, .. } => {
+                let raw = (0..digits).rev().fold(0, |value, digit| {
+                    // The most significant digit only consists of 3 bits.
+                    let mask = if digit == digits - 1 { 0x7 } else { 0xf };
+                    value * 10 + ((word >> Self::bcd_digit_offset(digits, digit)) & mask)
+                });
+
+                raw as f64 * self.resolution()
+            }
+            Arinc429Encoding::Discrete => {
+                ((word >> Self::DATA_FIELD_OFFSET) & ((1 << Self::DATA_FIELD_BITS) - 1)) as f64
+            }
+        }
+    }
+
+    /// Returns the bit offset of the given BCD digit, where digit 0 is the least significant digit.
+    fn bcd_digit_offset(digits: u8, digit: u8) -> u32 {
+        Self::DATA_FIELD_OFFSET + Self::DATA_FIELD_BITS - 3 - 4 * (digits - 1 - digit) as u32
+    }
+
+    /// Returns the SSM bits for the given sign status, or `None` when
+    /// the encoding cannot represent the status and the word shouldn't be transmitted.
+    fn encode_ssm(&self, ssm: SignStatus, value: f64) -> Option<u32> {
+        match (*self, ssm) {
+            (_, SignStatus::NoComputedData) => Some(0b01),
+            (_, SignStatus::FunctionalTest) => Some(0b10),
+            (Arinc429Encoding::Bnr { .. }, status) => Some(u64::from(status) as u32),
+            (Arinc429Encoding::Bcd { .. }, SignStatus::NormalOperation) => {
+                Some(if value < 0. { 0b11 } else { 0b00 })
+            }
+            (Arinc429Encoding::Bcd { .. }, SignStatus::FailureWarning) => None,
+            (Arinc429Encoding::Discrete, SignStatus::NormalOperation) => Some(0b00),
+            (Arinc429Encoding::Discrete, SignStatus::FailureWarning) => Some(0b11),
+        }
+    }
+
+    fn decode_ssm(&self, ssm: u32) -> (SignStatus, f64) {
+        match (*self, ssm) {
+            (_, 0b01) => (SignStatus::NoComputedData, 1.),
+            (_, 0b10) => (SignStatus::FunctionalTest, 1.),
+            (Arinc429Encoding::Bnr { .. }, status) => (status.into(), 1.),
+            (Arinc429Encoding::Bcd { .. }, 0b11) => (SignStatus::NormalOperation, -1.),
+            (Arinc429Encoding::Bcd { .. }, _) => (SignStatus::NormalOperation, 1.),
+            (Arinc429Encoding::Discrete, 0b11) => (SignStatus::FailureWarning, 1.),
+            (Arinc429Encoding::Discrete, _) => (SignStatus::NormalOperation, 1.),
+        }
+    }
+}
+
+/// A 32 bit ARINC 429 word as it is transmitted on a bus. It consists of the label (bits 1 to 8),
+/// the SDI (bits 9 and 10), the data field (bits 11 to 29), the SSM (bits 30 and 31) and an
+/// odd parity bit (bit 32).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Arinc429RawWord(u32);
+impl Arinc429RawWord {
+    const SDI_OFFSET: u32 = 8;
+    const SSM_OFFSET: u32 = 29;
+    const PARITY_OFFSET: u32 = 31;
+
+    /// Encodes the given value. Returns `None` when the word cannot be transmitted, which
+    /// is the case for BCD words with a failure warning.
+    pub fn encode(
+        label: Arinc429Label,
+        sdi: u8,
+        encoding: Arinc429Encoding,
+        value: f64,
+        ssm: SignStatus,
+    ) -> Option<Self> {
+        let ssm_bits = encoding.encode_ssm(ssm, value)?;
+
+        // The label is transmitted most significant bit first, which reverses its bit order.
+        let word = label.0.reverse_bits() as u32
+            | (((sdi as u32) & 0b11) << Self::SDI_OFFSET)
+            | encoding.encode_data(value)
+            | (ssm_bits << Self::SSM_OFFSET);
+        let parity = (word.count_ones() + 1) % 2;
+
+        Some(Self(word | (parity << Self::PARITY_OFFSET)))
+    }
+
+    pub fn from_bits(bits: u32) -> Self {
+        Self(bits)
+    }
+
+    pub fn bits(&self) -> u32 {
+        self.0
+    }
+
+    pub fn label(&self) -> Arinc429Label {
+        Arinc429Label::new((self.0 as u8).reverse_bits())
+    }
+
+    pub fn sdi(&self) -> u8 {
+        ((self.0 >> Self::SDI_OFFSET) & 0b11) as u8
+    }
+
+    pub fn has_valid_parity(&self) -> bool {
+        self.0.count_ones() % 2 == 1
+    }
+
+    pub fn decode(&self, encoding: Arinc429Encoding) -> Arinc429Word<f64> {
+        let (ssm, sign) = encoding.decode_ssm((self.0 >> Self::SSM_OFFSET) & 0b11);
+
+        Arinc429Word::new(sign * encoding.decode_data(self.0), ssm)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -189,4 +392,190 @@ mod tests {
         }
         assert_eq!(expected_ssm, result.ssm());
     }
+
+    #[test]
+    fn label_is_transmitted_in_reverse_bit_order() {
+        let word = Arinc429RawWord::encode(
+            Arinc429Label::new(0o206),
+            0,
+            Arinc429Encoding::Discrete,
+            0.,
+            SignStatus::NormalOperation,
+        )
+        .unwrap();
+
+        assert_eq!(word.bits() & 0xff, 0b0110_0001);
+        assert_eq!(word.label(), Arinc429Label::new(0o206));
+    }
+
+    #[test]
+    fn label_displays_in_octal_notation() {
+        assert_eq!(format!("{}", Arinc429Label::new(0o15)), "015");
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(1)]
+    #[case(2)]
+    #[case(3)]
+    fn sdi_is_encoded(#[case] sdi: u8) {
+        let word = Arinc429RawWord::encode(
+            Arinc429Label::new(0o270),
+            sdi,
+            Arinc429Encoding::Discrete,
+            0.,
+            SignStatus::NormalOperation,
+        )
+        .unwrap();
+
+        assert_eq!(word.sdi(), sdi);
+    }
+
+    #[rstest]
+    #[case(0.)]
+    #[case(1.)]
+    #[case(-1.)]
+    #[case(123.456)]
+    fn words_have_odd_parity(#[case] value: f64) {
+        let word = Arinc429RawWord::encode(
+            Arinc429Label::new(0o206),
+            1,
+            bnr_encoding(),
+            value,
+            SignStatus::NormalOperation,
+        )
+        .unwrap();
+
+        assert!(word.has_valid_parity());
+        assert!(!Arinc429RawWord::from_bits(word.bits() ^ 0x400).has_valid_parity());
+    }
+
+    #[rstest]
+    #[case(0.)]
+    #[case(250.)]
+    #[case(-250.)]
+    #[case(1023.9375)]
+    #[case(-1024.)]
+    fn bnr_conversion_is_symmetric(#[case] value: f64) {
+        let result = encode_and_decode(bnr_encoding(), value, SignStatus::NormalOperation);
+
+        assert_eq!(result.value(), value);
+        assert_eq!(result.ssm(), SignStatus::NormalOperation);
+    }
+
+    #[test]
+    fn bnr_value_is_rounded_to_resolution() {
+        let result = encode_and_decode(bnr_encoding(), 100.04, SignStatus::NormalOperation);
+
+        assert_eq!(bnr_encoding().resolution(), 0.0625);
+        assert_eq!(result.value(), 100.0625);
+    }
+
+    #[rstest]
+    #[case(5000., 1023.9375)]
+    #[case(-5000., -1024.)]
+    fn bnr_value_is_limited_to_range(#[case] value: f64, #[case] expected: f64) {
+        let result = encode_and_decode(bnr_encoding(), value, SignStatus::NormalOperation);
+
+        assert_eq!(result.value(), expected);
+    }
+
+    #[rstest]
+    #[case(SignStatus::FailureWarning)]
+    #[case(SignStatus::FunctionalTest)]
+    #[case(SignStatus::NoComputedData)]
+    #[case(SignStatus::NormalOperation)]
+    fn bnr_ssm_conversion_is_symmetric(#[case] ssm: SignStatus) {
+        let result = encode_and_decode(bnr_encoding(), 1., ssm);
+
+        assert_eq!(result.ssm(), ssm);
+    }
+
+    #[rstest]
+    #[case(0.)]
+    #[case(359.)]
+    #[case(-12.5)]
+    #[case(7999.9)]
+    fn bcd_conversion_is_symmetric(#[case] value: f64) {
+        let result = encode_and_decode(bcd_encoding(), value, SignStatus::NormalOperation);
+
+        assert!((result.value() - value).abs() < 0.01);
+        assert_eq!(result.ssm(), SignStatus::NormalOperation);
+    }
+
+    #[test]
+    fn bcd_value_is_limited_to_range() {
+        let result = encode_and_decode(bcd_encoding(), 12345., SignStatus::NormalOperation);
+
+        assert!((result.value() - 7999.9).abs() < 0.01);
+    }
+
+    #[test]
+    fn bcd_digits_are_encoded_in_four_bit_groups() {
+        let word = Arinc429RawWord::encode(
+            Arinc429Label::new(0o15),
+            0,
+            Arinc429Encoding::Bcd {
+                digits: 3,
+                resolution: 1.,
+            },
+            359.,
+            SignStatus::NormalOperation,
+        )
+        .unwrap();
+
+        assert_eq!((word.bits() >> 18) & 0x7ff, 0b011_0101_1001);
+    }
+
+    #[test]
+    fn bcd_failure_warning_is_not_transmitted() {
+        assert!(Arinc429RawWord::encode(
+            Arinc429Label::new(0o15),
+            0,
+            bcd_encoding(),
+            1.,
+            SignStatus::FailureWarning,
+        )
+        .is_none());
+    }
+
+    #[rstest]
+    #[case(SignStatus::FailureWarning)]
+    #[case(SignStatus::FunctionalTest)]
+    #[case(SignStatus::NoComputedData)]
+    #[case(SignStatus::NormalOperation)]
+    fn discrete_conversion_is_symmetric(#[case] ssm: SignStatus) {
+        let result = encode_and_decode(
+            Arinc429Encoding::Discrete,
+            f64::from(0b101_0000_0000_0001),
+            ssm,
+        );
+
+        assert_eq!(result.value(), 0b101_0000_0000_0001 as f64);
+        assert_eq!(result.ssm(), ssm);
+    }
+
+    fn bnr_encoding() -> Arinc429Encoding {
+        Arinc429Encoding::Bnr {
+            significant_bits: 14,
+            range: 1024.,
+        }
+    }
+
+    fn bcd_encoding() -> Arinc429Encoding {
+        Arinc429Encoding::Bcd {
+            digits: 5,
+            resolution: 0.1,
+        }
+    }
+
+    fn encode_and_decode(
+        encoding: Arinc429Encoding,
+        value: f64,
+        ssm: SignStatus,
+    ) -> Arinc429Word<f64> {
+        Arinc429RawWord::encode(Arinc429Label::new(0o206), 0, encoding, value, ssm)
+            .unwrap()
+            .decode(encoding)
+    }
 }
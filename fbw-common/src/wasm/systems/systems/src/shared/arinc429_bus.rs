@@ -0,0 +1,359 @@
+use crate::{
+    shared::arinc429::{
+        Arinc429Encoding, Arinc429Label, Arinc429RawWord, Arinc429Word, SignStatus,
+    },
+    simulation::UpdateContext,
+};
+use std::time::Duration;
+
+/// Describes how a label is encoded and how often it is transmitted.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Arinc429LabelDefinition {
+    label: Arinc429Label,
+    encoding: Arinc429Encoding,
+    transmission_interval: Duration,
+}
+impl Arinc429LabelDefinition {
+    pub const fn new(
+        label: Arinc429Label,
+        encoding: Arinc429Encoding,
+        transmission_interval: Duration,
+    ) -> Self {
+        Self {
+            label,
+            encoding,
+            transmission_interval,
+        }
+    }
+
+    pub fn label(&self) -> Arinc429Label {
+        self.label
+    }
+
+    pub fn encoding(&self) -> Arinc429Encoding {
+        self.encoding
+    }
+
+    pub fn transmission_interval(&self) -> Duration {
+        self.transmission_interval
+    }
+}
+
+struct Arinc429Channel {
+    definition: Arinc429LabelDefinition,
+    value: f64,
+    ssm: SignStatus,
+    transmitting: bool,
+    time_until_transmission: Duration,
+    last_word: Option<Arinc429RawWord>,
+    time_since_last_word: Duration,
+}
+impl Arinc429Channel {
+    fn new(definition: Arinc429LabelDefinition) -> Self {
+        Self {
+            definition,
+            value: 0.,
+            ssm: SignStatus::NoComputedData,
+            transmitting: false,
+            time_until_transmission: Duration::ZERO,
+            last_word: None,
+            time_since_last_word: Duration::ZERO,
+        }
+    }
+
+    fn update(&mut self, delta: Duration, sdi: u8) {
+        self.time_since_last_word += delta;
+
+        if delta < self.time_until_transmission {
+            self.time_until_transmission -= delta;
+            return;
+        }
+
+        let overshoot = delta - self.time_until_transmission;
+        self.time_until_transmission = self
+            .definition
+            .transmission_interval
+            .saturating_sub(overshoot);
+
+        if self.transmitting {
+            if let Some(word) = Arinc429RawWord::encode(
+                self.definition.label,
+                sdi,
+                self.definition.encoding,
+                self.value,
+                self.ssm,
+            ) {
+                self.last_word = Some(word);
+                self.time_since_last_word = overshoot;
+            }
+        }
+    }
+
+    fn is_stale(&self) -> bool {
+        self.last_word.is_none()
+            || self.time_since_last_word
+                > self.definition.transmission_interval * Arinc429Bus::STALE_AFTER_INTERVALS
+    }
+}
+
+/// A point to point ARINC 429 bus from a single transmitter to any number of receivers.
+/// Each label is transmitted at its own interval. Receivers only see the last transmitted word
+/// of a label, and consider it stale when no word has been received for a few intervals,
+/// which is the case when the transmitter stops transmitting the label or loses power.
+pub struct Arinc429Bus {
+    sdi: u8,
+    channels: Vec<Arinc429Channel>,
+}
+impl Arinc429Bus {
+    const STALE_AFTER_INTERVALS: u32 = 3;
+
+    pub fn new(sdi: u8, definitions: &[Arinc429LabelDefinition]) -> Self {
+        Self {
+            sdi,
+            channels: definitions
+                .iter()
+                .map(|&definition| Arinc429Channel::new(definition))
+                .collect(),
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext) {
+        let sdi = self.sdi;
+        self.channels
+            .iter_mut()
+            .for_each(|channel| channel.update(context.delta(), sdi));
+    }
+
+    /// Sets the value to transmit for the given label from its next transmission onwards.
+    pub fn set_value(&mut self, label: Arinc429Label, value: f64, ssm: SignStatus) {
+        let channel = self.channel_mut(label);
+        channel.value = value;
+        channel.ssm = ssm;
+        channel.transmitting = true;
+    }
+
+    /// Stops transmitting the given label until a new value is set.
+    pub fn set_no_transmission(&mut self, label: Arinc429Label) {
+        self.channel_mut(label).transmitting = false;
+    }
+
+    /// Stops transmitting all labels, e.g. when the transmitter is unpowered or failed.
+    pub fn set_no_transmission_all(&mut self) {
+        self.channels
+            .iter_mut()
+            .for_each(|channel| channel.transmitting = false);
+    }
+
+    /// Returns the last received word of the given label,
+    /// or `None` when no word has been received recently.
+    pub fn read(&self, label: Arinc429Label) -> Option<Arinc429Word<f64>> {
+        let channel = self.channel(label);
+        if channel.is_stale() {
+            None
+        } else {
+            channel
+                .last_word
+                .map(|word| word.decode(channel.definition.encoding))
+        }
+    }
+
+    pub fn raw_word(&self, label: Arinc429Label) -> Option<Arinc429RawWord> {
+        let channel = self.channel(label);
+        if channel.is_stale() {
+            None
+        } else {
+            channel.last_word
+        }
+    }
+
+    pub fn is_stale(&self, label: Arinc429Label) -> bool {
+        self.channel(label).is_stale()
+    }
+
+    fn channel(&self, label: Arinc429Label) -> &Arinc429Channel {
+        self.channels
+            .iter()
+            .find(|channel| channel.definition.label == label)
+            .unwrap_or_else(|| panic!("Label {} is not defined on this bus.", label))
+    }
+
+    fn channel_mut(&mut self, label: Arinc429Label) -> &mut Arinc429Channel {
+        self.channels
+            .iter_mut()
+            .find(|channel| channel.definition.label == label)
+            .unwrap_or_else(|| panic!("Label {} is not defined on this bus.", label))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::{
+        test::{SimulationTestBed, TestBed},
+        Aircraft, InitContext, SimulationElement,
+    };
+
+    const ALTITUDE: Arinc429Label = Arinc429Label::new(0o203);
+    const HEADING: Arinc429Label = Arinc429Label::new(0o320);
+    const DEFINITIONS: [Arinc429LabelDefinition; 2] = [
+        Arinc429LabelDefinition::new(
+            ALTITUDE,
+            Arinc429Encoding::Bnr {
+                significant_bits: 17,
+                range: 131072.,
+            },
+            Duration::from_millis(50),
+        ),
+        Arinc429LabelDefinition::new(
+            HEADING,
+            Arinc429Encoding::Bcd {
+                digits: 4,
+                resolution: 0.1,
+            },
+            Duration::from_millis(200),
+        ),
+    ];
+
+    struct TestAircraft {
+        bus: Arinc429Bus,
+    }
+    impl TestAircraft {
+        fn new(_: &mut InitContext) -> Self {
+            Self {
+                bus: Arinc429Bus::new(1, &DEFINITIONS),
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.bus.update(context);
+        }
+    }
+    impl SimulationElement for TestAircraft {}
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        SimulationTestBed::new(TestAircraft::new)
+    }
+
+    #[test]
+    fn nothing_is_received_before_the_first_transmission() {
+        let test_bed = test_bed();
+
+        assert!(test_bed.query(|a| a.bus.is_stale(ALTITUDE)));
+        assert!(test_bed.query(|a| a.bus.read(ALTITUDE)).is_none());
+    }
+
+    #[test]
+    fn transmitted_value_is_received() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| {
+            a.bus
+                .set_value(ALTITUDE, 12000., SignStatus::NormalOperation)
+        });
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        let word = test_bed.query(|a| a.bus.read(ALTITUDE)).unwrap();
+        assert_eq!(word.value(), 12000.);
+        assert!(word.is_normal_operation());
+        assert_eq!(
+            test_bed.query(|a| a.bus.raw_word(ALTITUDE)).unwrap().sdi(),
+            1
+        );
+    }
+
+    #[test]
+    fn value_changes_are_only_received_on_the_next_transmission() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| {
+            a.bus
+                .set_value(ALTITUDE, 12000., SignStatus::NormalOperation)
+        });
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        test_bed.command(|a| {
+            a.bus
+                .set_value(ALTITUDE, 13000., SignStatus::NormalOperation)
+        });
+        test_bed.run_with_delta(Duration::from_millis(20));
+        assert_eq!(
+            test_bed.query(|a| a.bus.read(ALTITUDE)).unwrap().value(),
+            12000.
+        );
+
+        test_bed.run_with_delta(Duration::from_millis(30));
+        assert_eq!(
+            test_bed.query(|a| a.bus.read(ALTITUDE)).unwrap().value(),
+            13000.
+        );
+    }
+
+    #[test]
+    fn label_becomes_stale_when_no_longer_transmitted() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| {
+            a.bus
+                .set_value(ALTITUDE, 12000., SignStatus::NormalOperation)
+        });
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        test_bed.command(|a| a.bus.set_no_transmission(ALTITUDE));
+        test_bed.run_with_delta(Duration::from_millis(140));
+        assert!(!test_bed.query(|a| a.bus.is_stale(ALTITUDE)));
+
+        test_bed.run_with_delta(Duration::from_millis(20));
+        assert!(test_bed.query(|a| a.bus.is_stale(ALTITUDE)));
+        assert!(test_bed.query(|a| a.bus.read(ALTITUDE)).is_none());
+    }
+
+    #[test]
+    fn all_labels_become_stale_when_the_transmitter_stops() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| {
+            a.bus
+                .set_value(ALTITUDE, 12000., SignStatus::NormalOperation);
+            a.bus.set_value(HEADING, 270., SignStatus::NormalOperation);
+        });
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        test_bed.command(|a| a.bus.set_no_transmission_all());
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(test_bed.query(|a| a.bus.is_stale(ALTITUDE)));
+        assert!(test_bed.query(|a| a.bus.is_stale(HEADING)));
+    }
+
+    #[test]
+    fn transmission_resumes_when_a_new_value_is_set() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.bus.set_no_transmission(ALTITUDE));
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        test_bed.command(|a| a.bus.set_value(ALTITUDE, 500., SignStatus::NormalOperation));
+        test_bed.run_with_delta(Duration::from_millis(50));
+
+        assert_eq!(
+            test_bed.query(|a| a.bus.read(ALTITUDE)).unwrap().value(),
+            500.
+        );
+    }
+
+    #[test]
+    fn bcd_label_with_failure_warning_is_not_transmitted() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.bus.set_value(HEADING, 270., SignStatus::NormalOperation));
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        test_bed.command(|a| a.bus.set_value(HEADING, 270., SignStatus::FailureWarning));
+        test_bed.run_with_delta(Duration::from_millis(700));
+
+        assert!(test_bed.query(|a| a.bus.is_stale(HEADING)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn reading_an_undefined_label_panics() {
+        let test_bed = test_bed();
+
+        test_bed.query(|a| a.bus.read(Arinc429Label::new(0o377)));
+    }
+}
@@ -0,0 +1,129 @@
+use crate::simulation::{
+    InitContext, SimulationElement, SimulatorWriter, VariableIdentifier, Write,
+};
+
+/// An event of a maintenance report, providing the values written for the last recorded event.
+pub trait ReportedEvent<const N: usize> {
+    fn reported_values(&self) -> [f64; N];
+}
+
+/// Keeps the events recorded by a maintenance report.
+///
+/// Writes the number of recorded events and the values of the last recorded one.
+pub struct EventReport<T: ReportedEvent<N>, const N: usize> {
+    count_id: VariableIdentifier,
+    last_event_ids: [VariableIdentifier; N],
+
+    events: Vec<T>,
+}
+impl<T: ReportedEvent<N>, const N: usize> EventReport<T, N> {
+    pub fn new(context: &mut InitContext, count_name: &str, last_event_names: [&str; N]) -> Self {
+        Self {
+            count_id: context.get_identifier(count_name.to_owned()),
+            last_event_ids: last_event_names.map(|name| context.get_identifier(name.to_owned())),
+
+            events: vec![],
+        }
+    }
+
+    pub fn record(&mut self, event: T) {
+        self.events.push(event);
+    }
+
+    pub fn events(&self) -> &[T] {
+        &self.events
+    }
+
+    pub fn last_event(&self) -> Option<&T> {
+        self.events.last()
+    }
+
+    pub fn count(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn count_where(&self, predicate: impl Fn(&T) -> bool) -> usize {
+        self.events.iter().filter(|event| predicate(event)).count()
+    }
+}
+impl<T: ReportedEvent<N>, const N: usize> SimulationElement for EventReport<T, N> {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.count_id, self.count());
+
+        if let Some(event) = self.last_event() {
+            for (id, value) in self.last_event_ids.iter().zip(event.reported_values()) {
+                writer.write(id, value);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::simulation::test::{ReadByName, SimulationTestBed, TestBed};
+    use crate::simulation::{Aircraft, SimulationElementVisitor, UpdateContext};
+
+    struct TestEvent {
+        value: f64,
+        is_major: bool,
+    }
+    impl ReportedEvent<2> for TestEvent {
+        fn reported_values(&self) -> [f64; 2] {
+            [self.value, if self.is_major { 1. } else { 0. }]
+        }
+    }
+
+    struct TestAircraft {
+        report: EventReport<TestEvent, 2>,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                report: EventReport::new(
+                    context,
+                    "TEST_REPORT_COUNT",
+                    ["TEST_REPORT_LAST_VALUE", "TEST_REPORT_LAST_IS_MAJOR"],
+                ),
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, _: &UpdateContext) {}
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.report.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    #[test]
+    fn writes_count_and_last_event() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.command(|a| {
+            a.report.record(TestEvent {
+                value: 3.,
+                is_major: true,
+            })
+        });
+        test_bed.command(|a| {
+            a.report.record(TestEvent {
+                value: 5.,
+                is_major: false,
+            })
+        });
+        test_bed.run();
+
+        let count: f64 = test_bed.read_by_name("TEST_REPORT_COUNT");
+        let last_value: f64 = test_bed.read_by_name("TEST_REPORT_LAST_VALUE");
+        let last_is_major: bool = test_bed.read_by_name("TEST_REPORT_LAST_IS_MAJOR");
+        assert!(count == 2.);
+        assert!(last_value == 5.);
+        assert!(!last_is_major);
+
+        assert!(test_bed.query(|a| a.report.count_where(|event| event.is_major)) == 1);
+    }
+}
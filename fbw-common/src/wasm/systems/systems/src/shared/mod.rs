@@ -20,6 +20,7 @@ use uom::si::{
     velocity::knot,
 };
 
+pub mod event_report;
 pub mod low_pass_filter;
 pub mod pid;
 pub mod update_iterator;
@@ -28,10 +29,14 @@ mod random;
 pub use random::*;
 
 pub mod arinc429;
+pub mod arinc429_bus;
 pub mod arinc825;
 pub mod can_bus;
 pub mod power_supply_relay;
 
+/// Standard acceleration of gravity, used to convert accelerations into load factors
+pub const STANDARD_GRAVITY_MS2: f64 = 9.80665;
+
 pub trait ReservoirAirPressure {
     fn green_reservoir_pressure(&self) -> Pressure;
     fn blue_reservoir_pressure(&self) -> Pressure;
@@ -92,11 +97,24 @@ pub trait FeedbackPositionPickoffUnit {
     fn angle(&self) -> Angle;
 }
 
+/// Asymmetry position pick-off units (APPU) measure the position of the left and right wing
+/// transmissions of a flap or slat system, in the same reference as the FPPU.
+pub trait AsymmetryPositionPickoffUnits {
+    fn left_angle(&self) -> Angle;
+    fn right_angle(&self) -> Angle;
+}
+
 pub trait CargoDoorLocked {
     fn fwd_cargo_door_locked(&self) -> bool;
     fn aft_cargo_door_locked(&self) -> bool;
 }
 
+pub trait CargoSmokeDetection {
+    fn fwd_cargo_smoke_detected(&self) -> bool;
+    fn aft_cargo_smoke_detected(&self) -> bool;
+    fn bulk_cargo_smoke_detected(&self) -> bool;
+}
+
 pub trait LgciuWeightOnWheels {
     fn right_gear_compressed(&self, treat_ext_pwr_as_ground: bool) -> bool;
     fn right_gear_extended(&self, treat_ext_pwr_as_ground: bool) -> bool;
@@ -194,6 +212,76 @@ pub enum GearActuatorId {
     GearDoorRight,
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FlapSlatTransmissionId {
+    FlapsLeft,
+    FlapsRight,
+    SlatsLeft,
+    SlatsRight,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BleedDuctId {
+    EnginePylon(usize),
+    Wing(usize),
+    Apu,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ReverserDoorId {
+    engine_number: usize,
+    door_number: usize,
+}
+impl ReverserDoorId {
+    pub const fn new(engine_number: usize, door_number: usize) -> Self {
+        Self {
+            engine_number,
+            door_number,
+        }
+    }
+
+    pub fn engine_number(&self) -> usize {
+        self.engine_number
+    }
+
+    pub fn door_number(&self) -> usize {
+        self.door_number
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FlightControlSurfaceId {
+    LeftAileron(usize),
+    RightAileron(usize),
+    LeftElevator(usize),
+    RightElevator(usize),
+    Rudder(usize),
+    LeftSpoiler(usize),
+    RightSpoiler(usize),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct FlightControlActuatorId {
+    surface: FlightControlSurfaceId,
+    actuator_number: usize,
+}
+impl FlightControlActuatorId {
+    pub const fn new(surface: FlightControlSurfaceId, actuator_number: usize) -> Self {
+        Self {
+            surface,
+            actuator_number,
+        }
+    }
+
+    pub fn surface(&self) -> FlightControlSurfaceId {
+        self.surface
+    }
+
+    pub fn actuator_number(&self) -> usize {
+        self.actuator_number
+    }
+}
+
 pub trait EngineCorrectedN1 {
     fn corrected_n1(&self) -> Ratio;
 }
@@ -206,6 +294,10 @@ pub trait EngineUncorrectedN2 {
     fn uncorrected_n2(&self) -> Ratio;
 }
 
+pub trait EngineUncorrectedN3 {
+    fn uncorrected_n3(&self) -> Ratio;
+}
+
 pub trait CabinAltitude {
     fn altitude(&self) -> Length;
 }
@@ -0,0 +1,844 @@
+use crate::{
+    shared::{
+        event_report::{EventReport, ReportedEvent},
+        STANDARD_GRAVITY_MS2,
+    },
+    simulation::{
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
+    },
+};
+
+use std::time::Duration;
+use uom::si::{
+    acceleration::meter_per_second_squared, f64::*, force::kilonewton, mass::kilogram,
+    torque::newton_meter, velocity::knot,
+};
+
+#[derive(Clone)]
+pub struct StructuralLimits {
+    vmo: Velocity,
+    mmo: f64,
+
+    // Placard speed applicable from each flaps surface position, sorted by increasing position
+    flaps_placard_speeds: Vec<(f64, Velocity)>,
+
+    // Min and max load factors in g
+    clean_load_factor_limits: (f64, f64),
+    flaps_extended_load_factor_limits: (f64, f64),
+
+    wing_root_limit_bending_moment: Torque,
+    engine_mass: Mass,
+}
+impl StructuralLimits {
+    pub fn new(
+        vmo: Velocity,
+        mmo: f64,
+        flaps_placard_speeds: Vec<(f64, Velocity)>,
+        clean_load_factor_limits: (f64, f64),
+        flaps_extended_load_factor_limits: (f64, f64),
+        wing_root_limit_bending_moment: Torque,
+        engine_mass: Mass,
+    ) -> Self {
+        Self {
+            vmo,
+            mmo,
+            flaps_placard_speeds,
+            clean_load_factor_limits,
+            flaps_extended_load_factor_limits,
+            wing_root_limit_bending_moment,
+            engine_mass,
+        }
+    }
+
+    pub fn a380() -> Self {
+        Self::new(
+            Velocity::new::<knot>(340.),
+            0.89,
+            vec![
+                (0.05, Velocity::new::<knot>(222.)),
+                (0.4, Velocity::new::<knot>(220.)),
+                (0.65, Velocity::new::<knot>(196.)),
+                (0.9, Velocity::new::<knot>(182.)),
+            ],
+            (-1., 2.5),
+            (0., 2.),
+            Torque::new::<newton_meter>(100000000.),
+            Mass::new::<kilogram>(6700.),
+        )
+    }
+
+    fn flaps_placard_speed(&self, flaps_position: f64) -> Option<Velocity> {
+        self.flaps_placard_speeds
+            .iter()
+            .take_while(|(position, _)| flaps_position >= *position)
+            .last()
+            .map(|(_, speed)| *speed)
+    }
+
+    fn load_factor_limits(&self, flaps_position: f64) -> (f64, f64) {
+        if self.flaps_placard_speed(flaps_position).is_some() {
+            self.flaps_extended_load_factor_limits
+        } else {
+            self.clean_load_factor_limits
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StructuralEventType {
+    VmoExceedance = 0,
+    MmoExceedance = 1,
+    FlapsPlacardExceedance = 2,
+    PositiveLoadFactorExceedance = 3,
+    NegativeLoadFactorExceedance = 4,
+}
+impl StructuralEventType {
+    const TYPES_NUMBER: usize = 5;
+
+    fn is_overspeed(&self) -> bool {
+        matches!(
+            self,
+            StructuralEventType::VmoExceedance
+                | StructuralEventType::MmoExceedance
+                | StructuralEventType::FlapsPlacardExceedance
+        )
+    }
+}
+
+/// A limit exceedance. Limit and peak value are in knots for speed limits, in mach for MMO and in
+/// g for load factor limits.
+#[derive(Clone, Copy, Debug)]
+pub struct StructuralEvent {
+    event_type: StructuralEventType,
+    limit: f64,
+    peak_value: f64,
+    duration: Duration,
+}
+impl StructuralEvent {
+    fn new(event_type: StructuralEventType, limit: f64, value: f64) -> Self {
+        Self {
+            event_type,
+            limit,
+            peak_value: value,
+            duration: Duration::default(),
+        }
+    }
+
+    fn record(&mut self, delta: Duration, limit: f64, value: f64) {
+        self.peak_value = if self.event_type == StructuralEventType::NegativeLoadFactorExceedance {
+            self.peak_value.min(value)
+        } else {
+            self.peak_value.max(value)
+        };
+
+        // Limit can change during the event (flaps moving): keep the most restrictive one
+        self.limit = if self.event_type == StructuralEventType::NegativeLoadFactorExceedance {
+            self.limit.max(limit)
+        } else {
+            self.limit.min(limit)
+        };
+
+        self.duration += delta;
+    }
+
+    pub fn event_type(&self) -> StructuralEventType {
+        self.event_type
+    }
+
+    pub fn limit(&self) -> f64 {
+        self.limit
+    }
+
+    pub fn peak_value(&self) -> f64 {
+        self.peak_value
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+}
+impl ReportedEvent<4> for StructuralEvent {
+    fn reported_values(&self) -> [f64; 4] {
+        [
+            self.event_type as u8 as f64,
+            self.peak_value,
+            self.limit,
+            self.duration.as_secs_f64(),
+        ]
+    }
+}
+
+/// Accumulates bending moment cycles seen by one wing root.
+///
+/// Each moment reversal larger than a noise gate closes a half cycle whose damage is computed from
+/// a S-N curve referenced to the limit bending moment (Miner's rule). Damage of 1 means the
+/// reference fatigue life is consumed.
+struct WingRootFatigue {
+    limit_bending_moment: Torque,
+
+    // Moments are tracked as ratio of the limit bending moment
+    peak_moment: f64,
+    last_turning_point: f64,
+    current_extreme: f64,
+    is_rising: bool,
+
+    damage: f64,
+}
+impl WingRootFatigue {
+    const S_N_CURVE_EXPONENT: f64 = 4.;
+    const CYCLES_TO_FAILURE_AT_LIMIT_RANGE: f64 = 1000.;
+
+    // Reversals smaller than this ratio of limit moment are considered noise and not counted
+    const MIN_COUNTED_RANGE_RATIO: f64 = 0.02;
+
+    fn new(limit_bending_moment: Torque) -> Self {
+        Self {
+            limit_bending_moment,
+            peak_moment: 0.,
+            last_turning_point: 0.,
+            current_extreme: 0.,
+            is_rising: true,
+            damage: 0.,
+        }
+    }
+
+    fn update(&mut self, bending_moment: Torque) {
+        let moment =
+            bending_moment.get::<newton_meter>() / self.limit_bending_moment.get::<newton_meter>();
+
+        self.peak_moment = self.peak_moment.max(moment);
+
+        let reversal = if self.is_rising {
+            self.current_extreme - moment
+        } else {
+            moment - self.current_extreme
+        };
+
+        if reversal > Self::MIN_COUNTED_RANGE_RATIO {
+            self.count_half_cycle();
+
+            self.last_turning_point = self.current_extreme;
+            self.current_extreme = moment;
+            self.is_rising = !self.is_rising;
+        } else if (self.is_rising && moment > self.current_extreme)
+            || (!self.is_rising && moment < self.current_extreme)
+        {
+            self.current_extreme = moment;
+        }
+    }
+
+    fn count_half_cycle(&mut self) {
+        let range = (self.current_extreme - self.last_turning_point).abs();
+
+        self.damage +=
+            0.5 * range.powf(Self::S_N_CURVE_EXPONENT) / Self::CYCLES_TO_FAILURE_AT_LIMIT_RANGE;
+    }
+}
+
+/// Monitors structural loads for the post flight maintenance report.
+///
+/// Records overspeed (VMO, MMO, flaps placard) and load factor limit exceedances as events, counts
+/// gust load factor exceedances in flight, tracks peak engine pylon loads and accumulates wing root
+/// bending fatigue over the whole ground-air-ground cycle.
+pub struct StructuralLoadMonitor<const N: usize> {
+    overspeed_count_id: VariableIdentifier,
+    overload_count_id: VariableIdentifier,
+    max_load_factor_id: VariableIdentifier,
+    min_load_factor_id: VariableIdentifier,
+    wing_root_peak_moment_ids: [VariableIdentifier; 2],
+    wing_fatigue_damage_ids: [VariableIdentifier; 2],
+    pylon_peak_load_ids: Vec<VariableIdentifier>,
+    gust_exceedance_ids: Vec<VariableIdentifier>,
+
+    limits: StructuralLimits,
+
+    load_factor: f64,
+    max_load_factor: f64,
+    min_load_factor: f64,
+
+    wing_roots_fatigue: [WingRootFatigue; 2],
+    pylons_peak_load: [Force; N],
+
+    gust_exceedances: [usize; 3],
+    gust_exceedance_armed: [bool; 3],
+
+    recordings: [Option<StructuralEvent>; StructuralEventType::TYPES_NUMBER],
+    report: EventReport<StructuralEvent, 4>,
+}
+impl<const N: usize> StructuralLoadMonitor<N> {
+    // Incremental load factor levels (from 1g) counted as gust exceedances
+    const GUST_EXCEEDANCE_LEVELS: [f64; 3] = [0.3, 0.5, 1.];
+
+    pub fn new(context: &mut InitContext, limits: StructuralLimits) -> Self {
+        Self {
+            overspeed_count_id: context
+                .get_identifier("STRUCTURAL_REPORT_OVERSPEED_COUNT".to_owned()),
+            overload_count_id: context
+                .get_identifier("STRUCTURAL_REPORT_OVERLOAD_COUNT".to_owned()),
+            max_load_factor_id: context
+                .get_identifier("STRUCTURAL_REPORT_MAX_LOAD_FACTOR".to_owned()),
+            min_load_factor_id: context
+                .get_identifier("STRUCTURAL_REPORT_MIN_LOAD_FACTOR".to_owned()),
+            wing_root_peak_moment_ids: ["LEFT", "RIGHT"].map(|side| {
+                context.get_identifier(format!(
+                    "STRUCTURAL_REPORT_{}_WING_ROOT_PEAK_MOMENT_RATIO",
+                    side
+                ))
+            }),
+            wing_fatigue_damage_ids: ["LEFT", "RIGHT"].map(|side| {
+                context.get_identifier(format!("STRUCTURAL_REPORT_{}_WING_FATIGUE_DAMAGE", side))
+            }),
+            pylon_peak_load_ids: (1..=N)
+                .map(|engine_number| {
+                    context.get_identifier(format!(
+                        "STRUCTURAL_REPORT_ENGINE_{}_PYLON_PEAK_LOAD",
+                        engine_number
+                    ))
+                })
+                .collect(),
+            gust_exceedance_ids: (1..=Self::GUST_EXCEEDANCE_LEVELS.len())
+                .map(|level| {
+                    context.get_identifier(format!("STRUCTURAL_REPORT_GUST_EXCEEDANCE_{}", level))
+                })
+                .collect(),
+
+            wing_roots_fatigue: [1, 2]
+                .map(|_| WingRootFatigue::new(limits.wing_root_limit_bending_moment)),
+            limits,
+
+            load_factor: 1.,
+            max_load_factor: 1.,
+            min_load_factor: 1.,
+
+            pylons_peak_load: [Force::default(); N],
+
+            gust_exceedances: [0; 3],
+            gust_exceedance_armed: [true; 3],
+
+            recordings: [None; StructuralEventType::TYPES_NUMBER],
+            report: EventReport::new(
+                context,
+                "STRUCTURAL_REPORT_EVENT_COUNT",
+                [
+                    "STRUCTURAL_REPORT_LAST_EVENT_TYPE",
+                    "STRUCTURAL_REPORT_LAST_EVENT_PEAK",
+                    "STRUCTURAL_REPORT_LAST_EVENT_LIMIT",
+                    "STRUCTURAL_REPORT_LAST_EVENT_DURATION",
+                ],
+            ),
+        }
+    }
+
+    /// Pylons accelerations are the wing motion at each pylon. Gust acceleration is the vertical
+    /// body acceleration from turbulence not seen by the simulator, added to the sim load factor.
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        flaps_position: f64,
+        wing_root_bending_moments: [Torque; 2],
+        pylons_accelerations: [Acceleration; N],
+        gust_acceleration: Acceleration,
+    ) {
+        self.load_factor = (-context.acceleration_plane_reference_unfiltered_ms2_vector()[1]
+            + gust_acceleration.get::<meter_per_second_squared>())
+            / STANDARD_GRAVITY_MS2;
+
+        for (fatigue, moment) in self
+            .wing_roots_fatigue
+            .iter_mut()
+            .zip(wing_root_bending_moments)
+        {
+            fatigue.update(moment);
+        }
+
+        self.update_pylons_loads(pylons_accelerations);
+
+        if context.is_in_flight() {
+            self.max_load_factor = self.max_load_factor.max(self.load_factor);
+            self.min_load_factor = self.min_load_factor.min(self.load_factor);
+
+            self.update_gust_exceedances();
+        }
+
+        self.update_limit_exceedances(context, flaps_position);
+    }
+
+    fn update_pylons_loads(&mut self, pylons_accelerations: [Acceleration; N]) {
+        for (peak_load, pylon_acceleration) in
+            self.pylons_peak_load.iter_mut().zip(pylons_accelerations)
+        {
+            let pylon_load = self.limits.engine_mass
+                * Acceleration::new::<meter_per_second_squared>(
+                    self.load_factor * STANDARD_GRAVITY_MS2
+                        + pylon_acceleration.get::<meter_per_second_squared>(),
+                );
+
+            *peak_load = peak_load.max(pylon_load.abs());
+        }
+    }
+
+    fn update_gust_exceedances(&mut self) {
+        let incremental_load_factor = (self.load_factor - 1.).abs();
+
+        for ((level, count), armed) in Self::GUST_EXCEEDANCE_LEVELS
+            .iter()
+            .zip(&mut self.gust_exceedances)
+            .zip(&mut self.gust_exceedance_armed)
+        {
+            if *armed && incremental_load_factor >= *level {
+                *count += 1;
+                *armed = false;
+            } else if incremental_load_factor < *level {
+                *armed = true;
+            }
+        }
+    }
+
+    fn update_limit_exceedances(&mut self, context: &UpdateContext, flaps_position: f64) {
+        let is_in_flight = context.is_in_flight();
+
+        let airspeed_knot = context.indicated_airspeed().get::<knot>();
+        let vmo_knot = self.limits.vmo.get::<knot>();
+        self.update_exceedance(
+            context,
+            StructuralEventType::VmoExceedance,
+            is_in_flight && airspeed_knot > vmo_knot,
+            vmo_knot,
+            airspeed_knot,
+        );
+
+        let mach = f64::from(context.mach_number());
+        self.update_exceedance(
+            context,
+            StructuralEventType::MmoExceedance,
+            is_in_flight && mach > self.limits.mmo,
+            self.limits.mmo,
+            mach,
+        );
+
+        let placard_knot = self
+            .limits
+            .flaps_placard_speed(flaps_position)
+            .map(|speed| speed.get::<knot>());
+        self.update_exceedance(
+            context,
+            StructuralEventType::FlapsPlacardExceedance,
+            is_in_flight && placard_knot.map_or(false, |placard| airspeed_knot > placard),
+            placard_knot.unwrap_or(vmo_knot),
+            airspeed_knot,
+        );
+
+        let (min_load_factor, max_load_factor) = self.limits.load_factor_limits(flaps_position);
+        self.update_exceedance(
+            context,
+            StructuralEventType::PositiveLoadFactorExceedance,
+            is_in_flight && self.load_factor > max_load_factor,
+            max_load_factor,
+            self.load_factor,
+        );
+        self.update_exceedance(
+            context,
+            StructuralEventType::NegativeLoadFactorExceedance,
+            is_in_flight && self.load_factor < min_load_factor,
+            min_load_factor,
+            self.load_factor,
+        );
+    }
+
+    fn update_exceedance(
+        &mut self,
+        context: &UpdateContext,
+        event_type: StructuralEventType,
+        is_exceeded: bool,
+        limit: f64,
+        value: f64,
+    ) {
+        let recording = &mut self.recordings[event_type as usize];
+
+        if is_exceeded {
+            recording
+                .get_or_insert_with(|| StructuralEvent::new(event_type, limit, value))
+                .record(context.delta(), limit, value);
+        } else if let Some(event) = recording.take() {
+            self.report.record(event);
+        }
+    }
+
+    pub fn events(&self) -> &[StructuralEvent] {
+        self.report.events()
+    }
+
+    pub fn last_event(&self) -> Option<&StructuralEvent> {
+        self.report.last_event()
+    }
+
+    pub fn max_load_factor(&self) -> f64 {
+        self.max_load_factor
+    }
+
+    pub fn min_load_factor(&self) -> f64 {
+        self.min_load_factor
+    }
+
+    pub fn gust_exceedances(&self) -> [usize; 3] {
+        self.gust_exceedances
+    }
+
+    pub fn pylon_peak_load(&self, engine_number: usize) -> Force {
+        self.pylons_peak_load[engine_number - 1]
+    }
+
+    // Fatigue damage of left (0) and right (1) wing roots
+    pub fn wing_fatigue_damage(&self) -> [f64; 2] {
+        [
+            self.wing_roots_fatigue[0].damage,
+            self.wing_roots_fatigue[1].damage,
+        ]
+    }
+
+    // Peak bending moment of left (0) and right (1) wing roots as ratio of limit bending moment
+    pub fn wing_root_peak_moment_ratio(&self) -> [f64; 2] {
+        [
+            self.wing_roots_fatigue[0].peak_moment,
+            self.wing_roots_fatigue[1].peak_moment,
+        ]
+    }
+
+    fn overspeed_count(&self) -> usize {
+        self.report
+            .count_where(|event| event.event_type.is_overspeed())
+    }
+
+    fn overload_count(&self) -> usize {
+        self.report
+            .count_where(|event| !event.event_type.is_overspeed())
+    }
+}
+impl<const N: usize> SimulationElement for StructuralLoadMonitor<N> {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.report.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.overspeed_count_id, self.overspeed_count());
+        writer.write(&self.overload_count_id, self.overload_count());
+
+        writer.write(&self.max_load_factor_id, self.max_load_factor);
+        writer.write(&self.min_load_factor_id, self.min_load_factor);
+
+        for (id, fatigue) in self
+            .wing_root_peak_moment_ids
+            .iter()
+            .zip(&self.wing_roots_fatigue)
+        {
+            writer.write(id, fatigue.peak_moment);
+        }
+
+        for (id, fatigue) in self
+            .wing_fatigue_damage_ids
+            .iter()
+            .zip(&self.wing_roots_fatigue)
+        {
+            writer.write(id, fatigue.damage);
+        }
+
+        for (id, peak_load) in self.pylon_peak_load_ids.iter().zip(self.pylons_peak_load) {
+            writer.write(id, peak_load.get::<kilonewton>());
+        }
+
+        for (id, count) in self.gust_exceedance_ids.iter().zip(self.gust_exceedances) {
+            writer.write(id, count);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::simulation::test::{ReadByName, SimulationTestBed, TestBed, WriteByName};
+    use crate::simulation::{Aircraft, SimulationElementVisitor};
+
+    struct TestAircraft {
+        monitor: StructuralLoadMonitor<4>,
+
+        flaps_position: f64,
+        wing_root_bending_moment: Torque,
+        pylons_acceleration: Acceleration,
+        gust_acceleration: Acceleration,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                monitor: StructuralLoadMonitor::new(context, StructuralLimits::a380()),
+
+                flaps_position: 0.,
+                wing_root_bending_moment: Torque::default(),
+                pylons_acceleration: Acceleration::default(),
+                gust_acceleration: Acceleration::default(),
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.monitor.update(
+                context,
+                self.flaps_position,
+                [self.wing_root_bending_moment; 2],
+                [self.pylons_acceleration; 4],
+                self.gust_acceleration,
+            );
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.monitor.accept(visitor);
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed_in_flight() -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.set_on_ground(false);
+        test_bed.set_indicated_airspeed(Velocity::new::<knot>(280.));
+        test_bed.write_by_name("AIRSPEED MACH", 0.8);
+        test_bed.set_norm_acc(Acceleration::new::<meter_per_second_squared>(0.));
+        test_bed
+    }
+
+    fn set_load_factor(test_bed: &mut SimulationTestBed<TestAircraft>, load_factor: f64) {
+        test_bed.set_norm_acc(Acceleration::new::<meter_per_second_squared>(
+            (load_factor - 1.) * STANDARD_GRAVITY_MS2,
+        ));
+    }
+
+    fn cycle_wing_bending(
+        test_bed: &mut SimulationTestBed<TestAircraft>,
+        moment_newton_meter: f64,
+    ) {
+        for _ in 0..10 {
+            test_bed.command(|a| {
+                a.wing_root_bending_moment = Torque::new::<newton_meter>(moment_newton_meter)
+            });
+            test_bed.run_with_delta(Duration::from_millis(100));
+            test_bed.command(|a| a.wing_root_bending_moment = Torque::default());
+            test_bed.run_with_delta(Duration::from_millis(100));
+        }
+    }
+
+    #[test]
+    fn no_event_in_normal_cruise() {
+        let mut test_bed = test_bed_in_flight();
+        test_bed.run_with_delta(Duration::from_secs(10));
+
+        assert!(test_bed.query(|a| a.monitor.events().is_empty()));
+        assert!(test_bed.query(|a| a.monitor.gust_exceedances()) == [0; 3]);
+
+        let count: f64 = test_bed.read_by_name("STRUCTURAL_REPORT_EVENT_COUNT");
+        assert!(count == 0.);
+    }
+
+    #[test]
+    fn vmo_exceedance_is_recorded_when_speed_is_back_below_vmo() {
+        let mut test_bed = test_bed_in_flight();
+        test_bed.set_indicated_airspeed(Velocity::new::<knot>(345.));
+        test_bed.run_with_delta(Duration::from_secs(2));
+        test_bed.set_indicated_airspeed(Velocity::new::<knot>(352.));
+        test_bed.run_with_delta(Duration::from_secs(2));
+
+        // Event still in progress
+        assert!(test_bed.query(|a| a.monitor.events().is_empty()));
+
+        test_bed.set_indicated_airspeed(Velocity::new::<knot>(320.));
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        let event = test_bed.query(|a| *a.monitor.last_event().unwrap());
+        assert!(event.event_type() == StructuralEventType::VmoExceedance);
+        assert!((event.peak_value() - 352.).abs() < 0.01);
+        assert!((event.limit() - 340.).abs() < 0.01);
+        assert!((event.duration().as_secs_f64() - 4.).abs() < 0.01);
+
+        let overspeed_count: f64 = test_bed.read_by_name("STRUCTURAL_REPORT_OVERSPEED_COUNT");
+        assert!(overspeed_count == 1.);
+        let event_type: f64 = test_bed.read_by_name("STRUCTURAL_REPORT_LAST_EVENT_TYPE");
+        assert!(event_type == 0.);
+    }
+
+    #[test]
+    fn mmo_exceedance_is_recorded() {
+        let mut test_bed = test_bed_in_flight();
+        test_bed.write_by_name("AIRSPEED MACH", 0.91);
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.write_by_name("AIRSPEED MACH", 0.85);
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        let event = test_bed.query(|a| *a.monitor.last_event().unwrap());
+        assert!(event.event_type() == StructuralEventType::MmoExceedance);
+        assert!((event.peak_value() - 0.91).abs() < 0.001);
+    }
+
+    #[test]
+    fn flaps_placard_exceedance_only_with_flaps_extended() {
+        let mut test_bed = test_bed_in_flight();
+        test_bed.set_indicated_airspeed(Velocity::new::<knot>(230.));
+        test_bed.run_with_delta(Duration::from_secs(2));
+
+        assert!(test_bed.query(|a| a.monitor.events().is_empty()));
+
+        test_bed.command(|a| a.flaps_position = 0.5);
+        test_bed.run_with_delta(Duration::from_secs(2));
+        test_bed.command(|a| a.flaps_position = 0.);
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        let event = test_bed.query(|a| *a.monitor.last_event().unwrap());
+        assert!(event.event_type() == StructuralEventType::FlapsPlacardExceedance);
+        assert!((event.limit() - 220.).abs() < 0.01);
+        assert!((event.peak_value() - 230.).abs() < 0.01);
+    }
+
+    #[test]
+    fn load_factor_limit_is_lower_with_flaps_extended() {
+        let mut test_bed = test_bed_in_flight();
+        test_bed.set_indicated_airspeed(Velocity::new::<knot>(170.));
+        set_load_factor(&mut test_bed, 2.2);
+        test_bed.run_with_delta(Duration::from_secs(1));
+        set_load_factor(&mut test_bed, 1.);
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(test_bed.query(|a| a.monitor.events().is_empty()));
+
+        test_bed.command(|a| a.flaps_position = 1.);
+        set_load_factor(&mut test_bed, 2.2);
+        test_bed.run_with_delta(Duration::from_secs(1));
+        set_load_factor(&mut test_bed, 1.);
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        let event = test_bed.query(|a| *a.monitor.last_event().unwrap());
+        assert!(event.event_type() == StructuralEventType::PositiveLoadFactorExceedance);
+        assert!((event.peak_value() - 2.2).abs() < 0.01);
+        assert!((event.limit() - 2.).abs() < 0.01);
+
+        let overload_count: f64 = test_bed.read_by_name("STRUCTURAL_REPORT_OVERLOAD_COUNT");
+        assert!(overload_count == 1.);
+    }
+
+    #[test]
+    fn negative_load_factor_exceedance_is_recorded() {
+        let mut test_bed = test_bed_in_flight();
+        set_load_factor(&mut test_bed, -1.3);
+        test_bed.run_with_delta(Duration::from_secs(1));
+        set_load_factor(&mut test_bed, 1.);
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        let event = test_bed.query(|a| *a.monitor.last_event().unwrap());
+        assert!(event.event_type() == StructuralEventType::NegativeLoadFactorExceedance);
+        assert!((event.peak_value() + 1.3).abs() < 0.01);
+        assert!((test_bed.query(|a| a.monitor.min_load_factor()) + 1.3).abs() < 0.01);
+    }
+
+    #[test]
+    fn no_event_on_ground() {
+        let mut test_bed = test_bed_in_flight();
+        test_bed.set_on_ground(true);
+        test_bed.set_indicated_airspeed(Velocity::new::<knot>(360.));
+        set_load_factor(&mut test_bed, 3.);
+        test_bed.run_with_delta(Duration::from_secs(1));
+        set_load_factor(&mut test_bed, 1.);
+        test_bed.set_indicated_airspeed(Velocity::new::<knot>(0.));
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(test_bed.query(|a| a.monitor.events().is_empty()));
+        assert!(test_bed.query(|a| a.monitor.max_load_factor()) == 1.);
+    }
+
+    #[test]
+    fn gust_exceedances_are_counted_once_per_crossing() {
+        let mut test_bed = test_bed_in_flight();
+
+        for _ in 0..3 {
+            set_load_factor(&mut test_bed, 1.6);
+            test_bed.run_with_delta(Duration::from_millis(200));
+            set_load_factor(&mut test_bed, 1.6);
+            test_bed.run_with_delta(Duration::from_millis(200));
+            set_load_factor(&mut test_bed, 1.);
+            test_bed.run_with_delta(Duration::from_millis(200));
+        }
+
+        set_load_factor(&mut test_bed, 0.6);
+        test_bed.run_with_delta(Duration::from_millis(200));
+        set_load_factor(&mut test_bed, 1.);
+        test_bed.run_with_delta(Duration::from_millis(200));
+
+        assert!(test_bed.query(|a| a.monitor.gust_exceedances()) == [4, 3, 0]);
+
+        let count: f64 = test_bed.read_by_name("STRUCTURAL_REPORT_GUST_EXCEEDANCE_1");
+        assert!(count == 4.);
+    }
+
+    #[test]
+    fn gust_acceleration_adds_to_load_factor() {
+        let mut test_bed = test_bed_in_flight();
+        test_bed.command(|a| {
+            a.gust_acceleration =
+                Acceleration::new::<meter_per_second_squared>(0.4 * STANDARD_GRAVITY_MS2)
+        });
+        test_bed.run_with_delta(Duration::from_millis(200));
+        test_bed.command(|a| a.gust_acceleration = Acceleration::default());
+        test_bed.run_with_delta(Duration::from_millis(200));
+
+        assert!((test_bed.query(|a| a.monitor.max_load_factor()) - 1.4).abs() < 0.001);
+        assert!(test_bed.query(|a| a.monitor.gust_exceedances()) == [1, 0, 0]);
+
+        let expected_load_kn = 6700. * 1.4 * STANDARD_GRAVITY_MS2 / 1000.;
+        let load = test_bed.query(|a| a.monitor.pylon_peak_load(1).get::<kilonewton>());
+        assert!((load - expected_load_kn).abs() < 0.1);
+    }
+
+    #[test]
+    fn wing_fatigue_grows_faster_with_larger_bending_cycles() {
+        let mut small_test_bed = test_bed_in_flight();
+        cycle_wing_bending(&mut small_test_bed, 20000000.);
+        let small_damage = small_test_bed.query(|a| a.monitor.wing_fatigue_damage()[0]);
+
+        let mut large_test_bed = test_bed_in_flight();
+        cycle_wing_bending(&mut large_test_bed, 40000000.);
+        let large_damage = large_test_bed.query(|a| a.monitor.wing_fatigue_damage()[0]);
+
+        assert!(small_damage > 0.);
+        assert!(large_damage > 10. * small_damage);
+
+        let peak_moment_ratio: f64 =
+            large_test_bed.read_by_name("STRUCTURAL_REPORT_LEFT_WING_ROOT_PEAK_MOMENT_RATIO");
+        assert!((peak_moment_ratio - 0.4).abs() < 0.001);
+    }
+
+    #[test]
+    fn small_bending_noise_does_not_consume_fatigue_life() {
+        let mut test_bed = test_bed_in_flight();
+        cycle_wing_bending(&mut test_bed, 1000000.);
+
+        assert!(test_bed.query(|a| a.monitor.wing_fatigue_damage()) == [0.; 2]);
+    }
+
+    #[test]
+    fn pylon_peak_load_includes_load_factor_and_wing_motion() {
+        let mut test_bed = test_bed_in_flight();
+        set_load_factor(&mut test_bed, 1.5);
+        test_bed
+            .command(|a| a.pylons_acceleration = Acceleration::new::<meter_per_second_squared>(2.));
+        test_bed.run_with_delta(Duration::from_secs(1));
+        set_load_factor(&mut test_bed, 1.);
+        test_bed.command(|a| a.pylons_acceleration = Acceleration::default());
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        let expected_load_kn = 6700. * (1.5 * STANDARD_GRAVITY_MS2 + 2.) / 1000.;
+        let load = test_bed.query(|a| a.monitor.pylon_peak_load(1).get::<kilonewton>());
+        assert!((load - expected_load_kn).abs() < 0.1);
+
+        let load: f64 = test_bed.read_by_name("STRUCTURAL_REPORT_ENGINE_4_PYLON_PEAK_LOAD");
+        assert!((load - expected_load_kn).abs() < 0.1);
+    }
+}
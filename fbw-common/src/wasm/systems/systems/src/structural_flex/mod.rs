@@ -1,5 +1,7 @@
 pub mod elevator_flex;
 pub mod engine_wobble;
+pub mod load_monitoring;
+pub mod turbulence;
 pub mod wing_flex;
 
 use crate::shared::low_pass_filter::LowPassFilter;
@@ -0,0 +1,433 @@
+use crate::{
+    shared::update_iterator::MaxStepLoop,
+    simulation::{
+        InitContext, Read, SimulationElement, SimulatorReader, UpdateContext, VariableIdentifier,
+    },
+};
+
+use rand::{Rng, SeedableRng};
+use rand_distr::StandardNormal;
+use rand_pcg::Pcg32;
+
+use uom::si::{
+    acceleration::meter_per_second_squared,
+    area::square_meter,
+    f64::*,
+    force::newton,
+    length::{foot, meter},
+    mass::kilogram,
+    mass_density::kilogram_per_cubic_meter,
+    velocity::meter_per_second,
+};
+
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TurbulenceIntensity {
+    Light,
+    Moderate,
+    Severe,
+}
+impl TurbulenceIntensity {
+    // Vertical gust rms velocities, medium altitude values from MIL-HDBK-1797
+    fn rms_vertical_gust(&self) -> Velocity {
+        Velocity::new::<meter_per_second>(match self {
+            TurbulenceIntensity::Light => 1.5,
+            TurbulenceIntensity::Moderate => 3.,
+            TurbulenceIntensity::Severe => 7.,
+        })
+    }
+}
+
+/// Generates a vertical gust velocity following the Dryden turbulence spectrum and the lift it adds
+/// on the wing.
+///
+/// White noise comes from a seeded generator, so a given seed and intensity always produce the same
+/// turbulence. This allows evaluating structural responses independently of the simulator weather.
+pub struct DrydenTurbulence {
+    updater: MaxStepLoop,
+    random_source: Pcg32,
+
+    rms_vertical_gust: Velocity,
+    scale_length: Length,
+
+    wing_area: Area,
+    // Wing lift coefficient slope per radian of angle of attack
+    lift_curve_slope: f64,
+
+    lagged_noise: f64,
+    double_lagged_noise: f64,
+
+    vertical_gust: Velocity,
+    gust_lift: Force,
+    gust_acceleration: Acceleration,
+}
+impl DrydenTurbulence {
+    const TURBULENCE_SIM_TIME_STEP: Duration = Duration::from_millis(10);
+
+    // Dryden vertical scale length above 2000ft
+    const SCALE_LENGTH_FOOT: f64 = 1750.;
+
+    // Avoids gust filter time constant growing unbounded at low speeds
+    const MIN_AIRSPEED_FOR_FILTERING_METER_PER_SECOND: f64 = 30.;
+
+    pub fn new(
+        seed: u64,
+        intensity: TurbulenceIntensity,
+        wing_area: Area,
+        lift_curve_slope: f64,
+    ) -> Self {
+        Self {
+            updater: MaxStepLoop::new(Self::TURBULENCE_SIM_TIME_STEP),
+            random_source: Pcg32::seed_from_u64(seed),
+
+            rms_vertical_gust: intensity.rms_vertical_gust(),
+            scale_length: Length::new::<foot>(Self::SCALE_LENGTH_FOOT),
+
+            wing_area,
+            lift_curve_slope,
+
+            lagged_noise: 0.,
+            double_lagged_noise: 0.,
+
+            vertical_gust: Velocity::default(),
+            gust_lift: Force::default(),
+            gust_acceleration: Acceleration::default(),
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext) {
+        self.update_vertical_gust(context);
+        self.update_gust_lift(context);
+    }
+
+    // Dryden vertical gust transfer function sigma * sqrt(L/V) * (1 + sqrt(3) * L/V * s) / (1 + L/V * s)^2
+    //      solved as two cascaded first order lags:  output = sqrt(3) * lag1 + (1 - sqrt(3)) * lag2
+    fn update_vertical_gust(&mut self, context: &UpdateContext) {
+        let airspeed = context
+            .true_airspeed()
+            .get::<meter_per_second>()
+            .max(Self::MIN_AIRSPEED_FOR_FILTERING_METER_PER_SECOND);
+
+        let time_constant = self.scale_length.get::<meter>() / airspeed;
+        let gain = self.rms_vertical_gust.get::<meter_per_second>() * time_constant.sqrt();
+
+        self.updater.update(context);
+
+        for cur_time_step in self.updater {
+            let time_step = cur_time_step.as_secs_f64();
+
+            // Unit intensity white noise: variance scaled by the sample time
+            let white_noise =
+                self.random_source.sample::<f64, _>(StandardNormal) / time_step.sqrt();
+
+            let filter_coefficient = 1. - (-time_step / time_constant).exp();
+
+            self.lagged_noise += filter_coefficient * (gain * white_noise - self.lagged_noise);
+            self.double_lagged_noise +=
+                filter_coefficient * (self.lagged_noise - self.double_lagged_noise);
+        }
+
+        self.vertical_gust = Velocity::new::<meter_per_second>(
+            3_f64.sqrt() * self.lagged_noise + (1. - 3_f64.sqrt()) * self.double_lagged_noise,
+        );
+    }
+
+    // Lift increment from the angle of attack change the gust induces: 0.5 * rho * V * S * CLalpha * Wg
+    fn update_gust_lift(&mut self, context: &UpdateContext) {
+        if context.is_in_flight() {
+            self.gust_lift = Force::new::<newton>(
+                0.5 * context
+                    .ambient_air_density()
+                    .get::<kilogram_per_cubic_meter>()
+                    * context.true_airspeed().get::<meter_per_second>()
+                    * self.wing_area.get::<square_meter>()
+                    * self.lift_curve_slope
+                    * self.vertical_gust.get::<meter_per_second>(),
+            );
+
+            let weight_kg = context.total_weight().get::<kilogram>();
+            self.gust_acceleration = if weight_kg > 0. {
+                Acceleration::new::<meter_per_second_squared>(
+                    self.gust_lift.get::<newton>() / weight_kg,
+                )
+            } else {
+                Acceleration::default()
+            };
+        } else {
+            self.gust_lift = Force::default();
+            self.gust_acceleration = Acceleration::default();
+        }
+    }
+
+    pub fn vertical_gust(&self) -> Velocity {
+        self.vertical_gust
+    }
+
+    pub fn gust_lift(&self) -> Force {
+        self.gust_lift
+    }
+
+    pub fn gust_acceleration(&self) -> Acceleration {
+        self.gust_acceleration
+    }
+}
+
+/// Dryden turbulence enabled through dev simvars, so that tests or an offline runner can apply a
+/// defined turbulence intensity to the structure.
+///
+/// Intensity simvar: 0 disabled, 1 light, 2 moderate, 3 severe. Changing intensity or seed restarts
+/// the turbulence from the new seed.
+pub struct TurbulenceGenerator {
+    intensity_id: VariableIdentifier,
+    seed_id: VariableIdentifier,
+
+    intensity: Option<TurbulenceIntensity>,
+    seed: u64,
+
+    wing_area: Area,
+    lift_curve_slope: f64,
+
+    turbulence: Option<DrydenTurbulence>,
+    turbulence_settings: Option<(TurbulenceIntensity, u64)>,
+}
+impl TurbulenceGenerator {
+    pub fn new(context: &mut InitContext, wing_area: Area, lift_curve_slope: f64) -> Self {
+        Self {
+            intensity_id: context
+                .get_identifier("STRUCTURAL_FLEX_DEV_TURBULENCE_INTENSITY".to_owned()),
+            seed_id: context.get_identifier("STRUCTURAL_FLEX_DEV_TURBULENCE_SEED".to_owned()),
+
+            intensity: None,
+            seed: 0,
+
+            wing_area,
+            lift_curve_slope,
+
+            turbulence: None,
+            turbulence_settings: None,
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext) {
+        let settings = self.intensity.map(|intensity| (intensity, self.seed));
+
+        if settings != self.turbulence_settings {
+            self.turbulence = settings.map(|(intensity, seed)| {
+                DrydenTurbulence::new(seed, intensity, self.wing_area, self.lift_curve_slope)
+            });
+            self.turbulence_settings = settings;
+        }
+
+        if let Some(turbulence) = &mut self.turbulence {
+            turbulence.update(context);
+        }
+    }
+
+    pub fn gust_lift(&self) -> Force {
+        self.turbulence
+            .as_ref()
+            .map_or(Force::default(), |turbulence| turbulence.gust_lift())
+    }
+
+    pub fn gust_acceleration(&self) -> Acceleration {
+        self.turbulence
+            .as_ref()
+            .map_or(Acceleration::default(), |turbulence| {
+                turbulence.gust_acceleration()
+            })
+    }
+}
+impl SimulationElement for TurbulenceGenerator {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        let intensity: f64 = reader.read(&self.intensity_id);
+        self.intensity = match intensity.round() as u8 {
+            1 => Some(TurbulenceIntensity::Light),
+            2 => Some(TurbulenceIntensity::Moderate),
+            3 => Some(TurbulenceIntensity::Severe),
+            _ => None,
+        };
+
+        let seed: f64 = reader.read(&self.seed_id);
+        self.seed = seed.max(0.) as u64;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::simulation::test::{SimulationTestBed, TestBed, WriteByName};
+    use crate::simulation::{Aircraft, SimulationElementVisitor};
+    use uom::si::velocity::knot;
+
+    const WING_AREA_SQUARE_METER: f64 = 845.;
+    const LIFT_CURVE_SLOPE: f64 = 5.;
+
+    struct TestAircraft {
+        turbulence: DrydenTurbulence,
+    }
+    impl TestAircraft {
+        fn new(seed: u64, intensity: TurbulenceIntensity) -> Self {
+            Self {
+                turbulence: DrydenTurbulence::new(
+                    seed,
+                    intensity,
+                    Area::new::<square_meter>(WING_AREA_SQUARE_METER),
+                    LIFT_CURVE_SLOPE,
+                ),
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.turbulence.update(context);
+        }
+    }
+    impl SimulationElement for TestAircraft {}
+
+    struct GeneratorTestAircraft {
+        generator: TurbulenceGenerator,
+    }
+    impl GeneratorTestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                generator: TurbulenceGenerator::new(
+                    context,
+                    Area::new::<square_meter>(WING_AREA_SQUARE_METER),
+                    LIFT_CURVE_SLOPE,
+                ),
+            }
+        }
+    }
+    impl Aircraft for GeneratorTestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.generator.update(context);
+        }
+    }
+    impl SimulationElement for GeneratorTestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.generator.accept(visitor);
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed(seed: u64, intensity: TurbulenceIntensity) -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(|_| TestAircraft::new(seed, intensity));
+        test_bed.set_on_ground(false);
+        test_bed.set_true_airspeed(Velocity::new::<knot>(250.));
+        test_bed.set_ambient_air_density(MassDensity::new::<kilogram_per_cubic_meter>(0.9));
+        test_bed.write_by_name("TOTAL WEIGHT", Mass::new::<kilogram>(400000.));
+        test_bed
+    }
+
+    fn vertical_gusts(test_bed: &mut SimulationTestBed<TestAircraft>, frames: usize) -> Vec<f64> {
+        (0..frames)
+            .map(|_| {
+                test_bed.run_with_delta(Duration::from_millis(100));
+                test_bed.query(|a| a.turbulence.vertical_gust().get::<meter_per_second>())
+            })
+            .collect()
+    }
+
+    fn rms(values: &[f64]) -> f64 {
+        (values.iter().map(|value| value.powi(2)).sum::<f64>() / values.len() as f64).sqrt()
+    }
+
+    #[test]
+    fn same_seed_generates_same_turbulence() {
+        let mut test_bed_1 = test_bed(42, TurbulenceIntensity::Moderate);
+        let mut test_bed_2 = test_bed(42, TurbulenceIntensity::Moderate);
+
+        assert!(vertical_gusts(&mut test_bed_1, 100) == vertical_gusts(&mut test_bed_2, 100));
+    }
+
+    #[test]
+    fn different_seeds_generate_different_turbulence() {
+        let mut test_bed_1 = test_bed(1, TurbulenceIntensity::Moderate);
+        let mut test_bed_2 = test_bed(2, TurbulenceIntensity::Moderate);
+
+        assert!(vertical_gusts(&mut test_bed_1, 100) != vertical_gusts(&mut test_bed_2, 100));
+    }
+
+    #[test]
+    fn vertical_gust_rms_matches_intensity() {
+        for intensity in [
+            TurbulenceIntensity::Light,
+            TurbulenceIntensity::Moderate,
+            TurbulenceIntensity::Severe,
+        ] {
+            let mut test_bed = test_bed(7, intensity);
+
+            // Skip filter settling time
+            vertical_gusts(&mut test_bed, 100);
+            let gusts = vertical_gusts(&mut test_bed, 6000);
+
+            let expected_rms = intensity.rms_vertical_gust().get::<meter_per_second>();
+            assert!((rms(&gusts) - expected_rms).abs() < 0.25 * expected_rms);
+        }
+    }
+
+    #[test]
+    fn gust_lift_gives_acceleration_on_plane_mass() {
+        let mut test_bed = test_bed(3, TurbulenceIntensity::Severe);
+        test_bed.run_with_delta(Duration::from_secs(5));
+
+        let gust = test_bed.query(|a| a.turbulence.vertical_gust().get::<meter_per_second>());
+        let lift = test_bed.query(|a| a.turbulence.gust_lift().get::<newton>());
+        let acceleration = test_bed.query(|a| {
+            a.turbulence
+                .gust_acceleration()
+                .get::<meter_per_second_squared>()
+        });
+
+        assert!(gust != 0.);
+        assert!(lift.signum() == gust.signum());
+        assert!((acceleration - lift / 400000.).abs() < 0.001);
+    }
+
+    #[test]
+    fn no_gust_lift_on_ground() {
+        let mut test_bed = test_bed(3, TurbulenceIntensity::Severe);
+        test_bed.set_on_ground(true);
+        test_bed.run_with_delta(Duration::from_secs(5));
+
+        assert!(test_bed.query(|a| a.turbulence.gust_lift()) == Force::default());
+        assert!(test_bed.query(|a| a.turbulence.gust_acceleration()) == Acceleration::default());
+    }
+
+    #[test]
+    fn generator_is_disabled_by_default() {
+        let mut test_bed = SimulationTestBed::new(GeneratorTestAircraft::new);
+        test_bed.set_on_ground(false);
+        test_bed.set_true_airspeed(Velocity::new::<knot>(250.));
+        test_bed.run_with_delta(Duration::from_secs(5));
+
+        assert!(test_bed.query(|a| a.generator.gust_lift()) == Force::default());
+    }
+
+    #[test]
+    fn generator_enabled_by_dev_simvar_matches_seeded_turbulence() {
+        let mut generator_test_bed = SimulationTestBed::new(GeneratorTestAircraft::new);
+        generator_test_bed.set_on_ground(false);
+        generator_test_bed.set_true_airspeed(Velocity::new::<knot>(250.));
+        generator_test_bed
+            .set_ambient_air_density(MassDensity::new::<kilogram_per_cubic_meter>(0.9));
+        generator_test_bed.write_by_name("TOTAL WEIGHT", Mass::new::<kilogram>(400000.));
+        generator_test_bed.write_by_name("STRUCTURAL_FLEX_DEV_TURBULENCE_INTENSITY", 2.);
+        generator_test_bed.write_by_name("STRUCTURAL_FLEX_DEV_TURBULENCE_SEED", 42.);
+
+        let mut reference_test_bed = test_bed(42, TurbulenceIntensity::Moderate);
+
+        for _ in 0..20 {
+            generator_test_bed.run_with_delta(Duration::from_millis(100));
+            reference_test_bed.run_with_delta(Duration::from_millis(100));
+
+            let generator_lift = generator_test_bed.query(|a| a.generator.gust_lift());
+            let reference_lift = reference_test_bed.query(|a| a.turbulence.gust_lift());
+            assert!(generator_lift == reference_lift);
+        }
+
+        assert!(generator_test_bed.query(|a| a.generator.gust_lift()) != Force::default());
+    }
+}
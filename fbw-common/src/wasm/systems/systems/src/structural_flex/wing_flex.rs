@@ -16,6 +16,7 @@ use uom::si::{
     mass::{kilogram, pound},
     ratio::percent,
     ratio::ratio,
+    torque::newton_meter,
     velocity::meter_per_second,
 };
 
@@ -236,7 +237,9 @@ impl WingLift {
         }
     }
 
-    pub fn update(&mut self, context: &UpdateContext) {
+    // Turbulence lift is an additional lift applied in flight, coming from a turbulence model
+    //      independent of the sim acceleration inputs
+    pub fn update(&mut self, context: &UpdateContext, turbulence_lift: Force) {
         let total_weight_on_wheels = self.gear_weight_on_wheels.total_weight_on_wheels();
 
         let raw_accel_no_grav = context.vert_accel().get::<meter_per_second_squared>();
@@ -251,7 +254,7 @@ impl WingLift {
         let lift = if total_weight_on_wheels.get::<kilogram>() > 500. {
             (lift_1g + self.ground_wow_filtered.output()).max(0.)
         } else {
-            lift_1g + lift_delta_from_accel_n
+            lift_1g + lift_delta_from_accel_n + turbulence_lift.get::<newton>()
         };
 
         self.total_lift = Force::new::<newton>(lift);
@@ -653,6 +656,19 @@ impl<const NODE_NUMBER: usize, const LINK_NUMBER: usize> FlexPhysicsNG<NODE_NUMB
     pub fn node_fuel_mass(&self, node_id: usize) -> Mass {
         self.nodes[node_id].fuel_mass()
     }
+
+    // Bending moment at wing root: shear force carried by each flex link integrated along the span
+    //      Positive moment bends the wing tip up
+    pub fn root_bending_moment(&self, nodes_x_coordinates: [f64; NODE_NUMBER]) -> Torque {
+        let moment_newton_meter: f64 = self
+            .flex_constraints
+            .iter()
+            .zip(nodes_x_coordinates.windows(2))
+            .map(|(constraint, x)| constraint.total_force.get::<newton>() * (x[1] - x[0]))
+            .sum();
+
+        Torque::new::<newton_meter>(moment_newton_meter)
+    }
 }
 impl SimulationElement for FlexPhysicsNG<5, 4> {
     fn read(&mut self, reader: &mut SimulatorReader) {
@@ -15,26 +15,22 @@ pub(super) fn engine_anti_ice(
             builder.on_change(
                 ExecuteOn::PostTick,
                 vec![
-                    Variable::named(&format!(
-                        "BUTTON_OVHD_ANTI_ICE_ENG_{}_POSITION",
-                        engine_number
-                    )),
+                    Variable::named(&format!("PNEU_ENG_{}_ANTI_ICE_VALVE_OPEN", engine_number)),
                     Variable::aircraft("ENG ANTI ICE", "bool", engine_number),
                 ],
                 Box::new(move |prev_values, new_values| {
-                    let was_eng_anti_ice_push_button_on = to_bool(prev_values[0]);
-                    let is_eng_anti_ice_push_button_on = to_bool(new_values[0]);
+                    let was_eng_anti_ice_valve_open = to_bool(prev_values[0]);
+                    let is_eng_anti_ice_valve_open = to_bool(new_values[0]);
                     let is_eng_anti_ice_on = to_bool(new_values[1]);
 
-                    let has_eng_anti_ice_button_changed =
-                        was_eng_anti_ice_push_button_on != is_eng_anti_ice_push_button_on;
-                    let eng_anti_ice_disagrees =
-                        is_eng_anti_ice_on != is_eng_anti_ice_push_button_on;
+                    let has_eng_anti_ice_valve_changed =
+                        was_eng_anti_ice_valve_open != is_eng_anti_ice_valve_open;
+                    let eng_anti_ice_disagrees = is_eng_anti_ice_on != is_eng_anti_ice_valve_open;
 
-                    if has_eng_anti_ice_button_changed && eng_anti_ice_disagrees {
+                    if has_eng_anti_ice_valve_changed && eng_anti_ice_disagrees {
                         execute_calculator_code::<()>(&format!(
                             "{} (>K:ANTI_ICE_SET_ENG{})",
-                            match is_eng_anti_ice_push_button_on {
+                            match is_eng_anti_ice_valve_open {
                                 true => 1,
                                 false => 0,
                             },